@@ -63,6 +63,14 @@ internal class Program
         return sb;
     }
 
+    // build.rs can replace the table with one generated from datasheet CSVs
+    private static void GeneratedTableSwitch(StringBuilder sb, string name)
+    {
+        sb.Append("#[cfg(generated_tables)]\n");
+        sb.Append($"include!(concat!(env!(\"OUT_DIR\"), \"/{name}.rs\"));\n\n");
+        sb.Append("#[cfg(not(generated_tables))]\n");
+    }
+
     private static readonly Dictionary<Language, string> Languages = new()
     {
         [Language.English] = "en",
@@ -71,6 +79,19 @@ internal class Program
         [Language.French] = "fr",
     };
 
+    // the global client has no Korean or Chinese text, so those fields are left to Default
+    private static void AppendRegionalFields(StringBuilder sb, uint indent)
+    {
+        sb.Append(' ', (int)indent);
+        sb.Append("..Default::default()\n");
+    }
+
+    // the sheets use 0 for values that aren't set
+    private static string OptionalValue(uint value)
+    {
+        return value == 0 ? "None" : $"Some({value})";
+    }
+
     private static string GetFlagName(string jobName)
     {
         // Convert job name to flag format: add underscores before capital letters and make uppercase
@@ -112,6 +133,8 @@ internal class Program
             sb.Append($"{key}: \"{name}\",\n");
         }
 
+        AppendRegionalFields(sb, indent + 4);
+
         for (var i = 0; i < indent; i++)
         {
             sb.Append(' ');
@@ -122,9 +145,14 @@ internal class Program
         return sb.ToString();
     }
 
+    // rows of ExVersion, as variants of `Expansion`
+    private static readonly string[] Expansions =
+        ["ARealmReborn", "Heavensward", "Stormblood", "Shadowbringers", "Endwalker", "Dawntrail"];
+
     private string GenerateDuties()
     {
-        var sb = DefaultHeader(true);
+        var sb = DefaultHeader();
+        sb.Append("use super::{Expansion, LocalisedText, PartySize};\n");
         sb.Append('\n');
 
         sb.Append("#[derive(Debug, Copy, Clone)]\n");
@@ -132,10 +160,17 @@ internal class Program
         sb.Append("    pub name: LocalisedText,\n");
         sb.Append("    pub high_end: bool,\n");
         sb.Append("    pub content_kind: ContentKind,\n");
+        sb.Append("    pub level: Option<u8>,\n");
+        sb.Append("    /// `None` if unknown or not synced.\n");
+        sb.Append("    pub item_level: Option<u16>,\n");
+        sb.Append("    pub party_size: PartySize,\n");
+        sb.Append("    pub expansion: Option<Expansion>,\n");
+        sb.Append("    pub icon: Option<u32>,\n");
+        sb.Append("    pub image: Option<u32>,\n");
         sb.Append("}\n\n");
 
         sb.Append("#[derive(Debug, Clone, Copy)]\n");
-        sb.Append("#[allow(unused)]\n");
+        sb.Append("#[allow(unused, clippy::upper_case_acronyms)]\n");
         sb.Append("#[repr(u32)]\n");
         sb.Append("pub enum ContentKind {\n");
         foreach (var kind in this.Data[Language.English].GetExcelSheet<ContentType>()!)
@@ -151,9 +186,7 @@ internal class Program
         sb.Append("}\n\n");
 
         sb.Append("impl ContentKind {\n");
-        sb.Append("    #[expect(unused)]\n");
-
-        sb.Append("    fn from_u32(kind: u32) -> Self {\n");
+        sb.Append("    pub fn from_u32(kind: u32) -> Self {\n");
         sb.Append("        match kind {\n");
         foreach (var kind in this.Data[Language.English].GetExcelSheet<ContentType>()!)
         {
@@ -185,6 +218,7 @@ internal class Program
 
         sb.Append("}\n\n");
 
+        GeneratedTableSwitch(sb, "duties");
         sb.Append("lazy_static::lazy_static! {\n");
         sb.Append("    pub static ref DUTIES: HashMap<u32, DutyInfo> = maplit::hashmap! {\n");
 
@@ -212,7 +246,35 @@ internal class Program
             sb.Append($"        {cfc.RowId} => DutyInfo {{\n");
             sb.Append($"            name: {name},\n");
             sb.Append($"            high_end: {highEnd},\n");
+            var memberType = cfc.ContentMemberType.Value;
+            var members = memberType.TanksPerParty + memberType.HealersPerParty + memberType.MeleesPerParty +
+                          memberType.RangedPerParty;
+            var partySize = cfc.AllianceRoulette
+                ? "Alliance"
+                : members switch
+                {
+                    0 => "Unknown",
+                    <= 4 => "Light",
+                    _ => "Full",
+                };
+
+            var expansion = "None";
+            if (cfc.TerritoryType.RowId != 0)
+            {
+                var exVersion = cfc.TerritoryType.Value.ExVersion.RowId;
+                if (exVersion < Expansions.Length)
+                {
+                    expansion = $"Some(Expansion::{Expansions[exVersion]})";
+                }
+            }
+
             sb.Append($"            content_kind: ContentKind::{contentKind},\n");
+            sb.Append($"            level: {OptionalValue(cfc.ClassJobLevelRequired)},\n");
+            sb.Append($"            item_level: {OptionalValue(cfc.ItemLevelSync)},\n");
+            sb.Append($"            party_size: PartySize::{partySize},\n");
+            sb.Append($"            expansion: {expansion},\n");
+            sb.Append($"            icon: {OptionalValue(cfc.Icon)},\n");
+            sb.Append($"            image: {OptionalValue(cfc.Image)},\n");
             sb.Append("        },\n");
         }
 
@@ -397,10 +459,10 @@ internal class Program
         sb.Append("#[derive(Debug)]\n");
         sb.Append("pub struct RouletteInfo {\n");
         sb.Append("    pub name: LocalisedText,\n");
-        sb.Append("    #[expect(unused)]\n");
         sb.Append("    pub pvp: bool,\n");
         sb.Append("}\n\n");
 
+        GeneratedTableSwitch(sb, "roulettes");
         sb.Append("lazy_static::lazy_static! {\n");
         sb.Append("    pub static ref ROULETTES: HashMap<u32, RouletteInfo> = maplit::hashmap! {\n");
 
@@ -433,12 +495,16 @@ internal class Program
         return sb.ToString();
     }
 
+    // WorldDCGroupType regions whose data centres players can travel between
+    private static readonly HashSet<byte> TravelRegions = new() { 1, 2, 3, 4 };
+
     private string GenerateWorlds()
     {
         var sb = DefaultHeader();
-        sb.Append("use ffxiv_types::World;\n\n");
+        sb.Append("use super::{Region, WorldInfo};\n\n");
+        GeneratedTableSwitch(sb, "worlds");
         sb.Append("lazy_static::lazy_static! {\n");
-        sb.Append("    pub static ref WORLDS: HashMap<u32, World> = maplit::hashmap! {\n");
+        sb.Append("    pub static ref WORLDS: HashMap<u32, WorldInfo> = maplit::hashmap! {\n");
 
         foreach (var world in this.Data[Language.English].GetExcelSheet<World>()!)
         {
@@ -448,12 +514,22 @@ internal class Program
             }
 
             var name = world.Name.ExtractText();
-            if (name.Length <= 0)
+            var dataCentre = world.DataCenter.Value.Name.ExtractText();
+            if (name.Length <= 0 || dataCentre.Length <= 0)
             {
                 continue;
             }
 
-            sb.Append($"        {world.RowId} => World::{name},\n");
+            var region = world.DataCenter.Value.Region;
+            // the sheets have no column for it, see TRAVEL_REGIONS in datasheet.rs
+            var travel = TravelRegions.Contains(region);
+            sb.Append($"        {world.RowId} => WorldInfo {{\n");
+            sb.Append($"            id: {world.RowId},\n");
+            sb.Append($"            name: \"{name}\",\n");
+            sb.Append($"            data_centre: \"{dataCentre}\",\n");
+            sb.Append($"            region: Region::from_u32({region}),\n");
+            sb.Append($"            travel: {(travel ? "true" : "false")},\n");
+            sb.Append("        },\n");
         }
 
         sb.Append("    };\n");
@@ -462,10 +538,16 @@ internal class Program
         return sb.ToString();
     }
 
+    // TerritoryIntendedUse rows of territories with FATEs, see FATE_INTENDED_USES in datasheet.rs
+    private static readonly HashSet<uint> FateIntendedUses = new() { 1, 41, 48, 61 };
+
     private string GenerateTerritoryNames()
     {
-        var sb = DefaultHeader(true);
-        sb.Append("\nlazy_static::lazy_static! {\n");
+        var sb = new StringBuilder("use std::collections::{HashMap, HashSet};\n");
+        sb.Append("use super::LocalisedText;\n");
+        sb.Append("\n");
+        GeneratedTableSwitch(sb, "territory_names");
+        sb.Append("lazy_static::lazy_static! {\n");
         sb.Append("    pub static ref TERRITORY_NAMES: HashMap<u32, LocalisedText> = maplit::hashmap! {\n");
 
         foreach (var tt in this.Data[Language.English].GetExcelSheet<TerritoryType>()!)
@@ -491,13 +573,30 @@ internal class Program
         sb.Append("    };\n");
         sb.Append("}\n");
 
+        sb.Append("\n#[cfg(not(generated_tables))]\n");
+        sb.Append("lazy_static::lazy_static! {\n");
+        sb.Append("    pub static ref FATE_TERRITORIES: HashSet<u32> = maplit::hashset! {\n");
+
+        foreach (var tt in this.Data[Language.English].GetExcelSheet<TerritoryType>()!)
+        {
+            if (FateIntendedUses.Contains(tt.TerritoryIntendedUse.RowId))
+            {
+                sb.Append($"        {tt.RowId},\n");
+            }
+        }
+
+        sb.Append("    };\n");
+        sb.Append("}\n");
+
         return sb.ToString();
     }
 
     private string GenerateAutoTranslate()
     {
         var sb = DefaultHeader(true);
-        sb.Append("\nlazy_static::lazy_static! {\n");
+        sb.Append("\n");
+        GeneratedTableSwitch(sb, "auto_translate");
+        sb.Append("lazy_static::lazy_static! {\n");
         sb.Append("    pub static ref AUTO_TRANSLATE: HashMap<(u32, u32), LocalisedText> = maplit::hashmap! {\n");
 
         var parser = AutoTranslate.Parser();
@@ -613,6 +712,7 @@ internal class Program
                             }
                         }
 
+                        AppendRegionalFields(builder, 12);
                         builder.Append("        },\n");
 
                         if (lines != 4)
@@ -643,13 +743,16 @@ internal class Program
     private string GenerateTreasureMaps()
     {
         var sb = DefaultHeader(true);
-        sb.Append("\nlazy_static::lazy_static! {\n");
+        sb.Append("\n");
+        GeneratedTableSwitch(sb, "treasure_maps");
+        sb.Append("lazy_static::lazy_static! {\n");
         sb.Append("    pub static ref TREASURE_MAPS: HashMap<u32, LocalisedText> = maplit::hashmap! {\n");
         sb.Append("        0 => LocalisedText {\n");
         sb.Append("            en: \"All Levels\",\n");
         sb.Append("            ja: \"レベルを指定しない\",\n");
         sb.Append("            de: \"Jede Stufe\",\n");
         sb.Append("            fr: \"Tous niveaux\",\n");
+        AppendRegionalFields(sb, 12);
         sb.Append("        },\n");
 
         var i = 1;
@@ -2,9 +2,12 @@
     function setUpLanguage() {
         let language = document.getElementById('language');
         for (let elem of language.querySelectorAll('[data-value]')) {
-            elem.addEventListener('click', () => {
-                document.cookie = `lang=${encodeURIComponent(elem.dataset.value)};path=/;max-age=31536000;samesite=lax`;
-                window.location.reload();
+            // the server remembers the choice in the lang cookie
+            elem.addEventListener('click', event => {
+                event.preventDefault();
+                let url = new URL(window.location.href);
+                url.searchParams.set('lang', elem.dataset.value);
+                window.location.assign(url);
             });
         }
     }
@@ -5,6 +5,11 @@
         allowed: [],
         centre: 'All',
         roles: 0n,
+        expansion: 'all',
+        partySize: 'all',
+        tag: 'all',
+        progPoint: 'all',
+        strat: 'all',
         list: null,
         lang: null,
     };
@@ -50,6 +55,39 @@
         });
     }
 
+    // filters in the url, which the server has already applied, win over
+    // the saved ones
+    function applyQuery() {
+        let params = new URLSearchParams(window.location.search);
+        let categories = (params.get('category') || '')
+            .split(',')
+            .filter(category => category !== '');
+        let centre = params.get('centre');
+        let roles = params.get('roles');
+        if (categories.length === 0 && !centre && !roles) {
+            return;
+        }
+
+        if (stateWasNull) {
+            // nothing was saved, so start from the defaults
+            state.allowed = Array.from(document.getElementById('category-filter').options, option => option.value);
+            state.roles = 0n;
+            stateWasNull = false;
+        }
+
+        if (categories.length > 0) {
+            state.allowed = categories;
+        }
+
+        if (centre) {
+            state.centre = centre;
+        }
+
+        if (roles) {
+            state.roles = BigInt(roles);
+        }
+    }
+
     function reflectState() {
         let category = document.getElementById('category-filter');
         for (let option of category.options) {
@@ -81,6 +119,12 @@
             state.roles = newRolesState;
         }
 
+        document.getElementById('expansion-filter').value = state.expansion;
+        document.getElementById('party-size-filter').value = state.partySize;
+        document.getElementById('tag-filter').value = state.tag;
+        document.getElementById('prog-point-filter').value = state.progPoint;
+        document.getElementById('strat-filter').value = state.strat;
+
         let language = document.getElementById('language');
         if (state.lang === null) {
             state.lang = language.dataset.accept;
@@ -125,7 +169,37 @@
             return state.roles === 0n || state.roles & BigInt(item.elm.dataset.joinableRoles);
         }
 
-        state.list.filter(item => dataCentreFilter(item) && categoryFilter(item) && roleFilter(item));
+        // as with categories, duties whose details are unknown are always shown
+        function expansionFilter(item) {
+            let expansion = item.elm.dataset.expansion;
+            return state.expansion === 'all' || expansion === 'unknown' || state.expansion === expansion;
+        }
+
+        function partySizeFilter(item) {
+            let partySize = item.elm.dataset.partySize;
+            return state.partySize === 'all' || partySize === 'unknown' || state.partySize === partySize;
+        }
+
+        function tagFilter(item) {
+            return state.tag === 'all' || item.elm.dataset.tags.split(' ').includes(state.tag);
+        }
+
+        function progPointFilter(item) {
+            return state.progPoint === 'all' || state.progPoint === item.elm.dataset.progPoint;
+        }
+
+        function stratFilter(item) {
+            return state.strat === 'all' || state.strat === item.elm.dataset.strat;
+        }
+
+        state.list.filter(item => dataCentreFilter(item)
+            && categoryFilter(item)
+            && roleFilter(item)
+            && expansionFilter(item)
+            && partySizeFilter(item)
+            && tagFilter(item)
+            && progPointFilter(item)
+            && stratFilter(item));
     }
 
     function setUpDataCentreFilter() {
@@ -197,12 +271,163 @@
         });
     }
 
+    // keeps the listings up to date without reloading. the server renders the
+    // cards, so they look the same as the ones that came with the page
+    function setUpLiveUpdates() {
+        let container = document.getElementById('listings');
+        let params = new URLSearchParams(window.location.search);
+        // mirrors ListingsSort::compare, smaller keys first. with the default
+        // order, updated listings go to the top
+        let sortKeys = {
+            newest: elem => -Number(elem.dataset.created),
+            expiring_soon: elem => Number(elem.dataset.expires),
+            slots_open: elem => -Number(elem.dataset.slotsOpen),
+            item_level: elem => -Number(elem.dataset.minItemLevel),
+            category: elem => Number(elem.dataset.categoryOrder),
+        };
+        let sortKey = sortKeys[params.get('sort')];
+
+        function insertSorted(elem) {
+            if (sortKey === undefined) {
+                container.prepend(elem);
+                return;
+            }
+
+            let key = sortKey(elem);
+            for (let other of container.querySelectorAll(':scope > .listing')) {
+                if (sortKey(other) > key) {
+                    other.before(elem);
+                    return;
+                }
+            }
+
+            container.append(elem);
+        }
+
+        function updateCards(cards) {
+            for (let card of cards) {
+                let existing = container.querySelector(`:scope > .listing[data-id="${card.id}"]`);
+                if (card.html === null) {
+                    if (existing !== null) {
+                        existing.remove();
+                    }
+
+                    continue;
+                }
+
+                let template = document.createElement('template');
+                template.innerHTML = card.html.trim();
+                let elem = template.content.firstElementChild;
+                if (existing !== null) {
+                    // contributed cards don't know when the listing was first seen
+                    elem.dataset.created = existing.dataset.created;
+                    existing.remove();
+                }
+
+                insertSorted(elem);
+            }
+
+            let empty = container.querySelector(':scope > .no-listings');
+            if (empty !== null) {
+                empty.remove();
+            }
+
+            state.list.reIndex();
+            refilter();
+        }
+
+        function removeExpired() {
+            let now = Date.now();
+            let removed = false;
+            for (let elem of container.querySelectorAll(':scope > .listing')) {
+                if (Number(elem.dataset.expires) < now) {
+                    elem.remove();
+                    removed = true;
+                }
+            }
+
+            if (removed) {
+                state.list.reIndex();
+                refilter();
+            }
+        }
+
+        function connect() {
+            let protocol = window.location.protocol === 'https:' ? 'wss:' : 'ws:';
+            let ws = new WebSocket(`${protocol}//${window.location.host}/api/ws`);
+            ws.addEventListener('open', () => {
+                ws.send(JSON.stringify({
+                    type: 'subscribe',
+                    channel: 'listing_cards',
+                    lang: document.documentElement.lang,
+                    query: window.location.search.slice(1),
+                }));
+            });
+            ws.addEventListener('message', event => {
+                let message = JSON.parse(event.data);
+                if (message.type === 'listing_cards') {
+                    updateCards(message.cards);
+                }
+            });
+            ws.addEventListener('close', () => setTimeout(connect, 30 * 1000));
+        }
+
+        connect();
+        setInterval(removeExpired, 60 * 1000);
+    }
+
+    // listings are sorted by the server
+    function setUpSort() {
+        let select = document.getElementById('sort');
+        select.addEventListener('change', () => {
+            let url = new URL(window.location.href);
+            url.searchParams.set('sort', select.value);
+            window.location.assign(url);
+        });
+    }
+
+    function setUpDutyFilters() {
+        let expansion = document.getElementById('expansion-filter');
+        expansion.addEventListener('change', () => {
+            state.expansion = expansion.value;
+            refilter();
+        });
+
+        let partySize = document.getElementById('party-size-filter');
+        partySize.addEventListener('change', () => {
+            state.partySize = partySize.value;
+            refilter();
+        });
+
+        let tag = document.getElementById('tag-filter');
+        tag.addEventListener('change', () => {
+            state.tag = tag.value;
+            refilter();
+        });
+
+        let progPoint = document.getElementById('prog-point-filter');
+        progPoint.addEventListener('change', () => {
+            state.progPoint = progPoint.value;
+            refilter();
+        });
+
+        let strat = document.getElementById('strat-filter');
+        strat.addEventListener('change', () => {
+            state.strat = strat.value;
+            refilter();
+        });
+    }
+
     addJsClass();
     saveLoadState();
+    applyQuery();
     reflectState();
     state.list = setUpList();
     setUpDataCentreFilter();
     setUpCategoryFilter();
     setUpRoleFilter();
+    setUpDutyFilters();
+    setUpSort();
     refilter();
+    setUpLiveUpdates();
 })();
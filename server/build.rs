@@ -0,0 +1,182 @@
+//! Regenerates the `ffxiv` lookup tables from game datasheets.
+//!
+//! By default the checked-in tables in `src/ffxiv` are used. Set `RPF_GAME_DATA`
+//! to a directory of datasheet CSVs (see `src/ffxiv/datasheet.rs` for the
+//! layout) to generate them at build time instead.
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+#[path = "src/ffxiv/datasheet.rs"]
+#[allow(dead_code)]
+mod datasheet;
+
+use datasheet::{Names, Tables};
+
+fn main() {
+    println!("cargo::rustc-check-cfg=cfg(generated_tables)");
+    println!("cargo::rerun-if-changed=build.rs");
+    println!("cargo::rerun-if-changed=src/ffxiv/datasheet.rs");
+    println!("cargo::rerun-if-env-changed=RPF_GAME_DATA");
+
+    let Some(data_dir) = std::env::var_os("RPF_GAME_DATA") else {
+        return;
+    };
+    let data_dir = PathBuf::from(data_dir);
+    println!("cargo::rerun-if-changed={}", data_dir.display());
+
+    let tables = match Tables::load(&data_dir) {
+        Ok(tables) => tables,
+        Err(e) => panic!("could not load game data from {}: {}", data_dir.display(), e),
+    };
+
+    let out_dir = PathBuf::from(std::env::var_os("OUT_DIR").unwrap());
+    write(&out_dir, "duties.rs", &duties(&tables));
+    write(&out_dir, "roulettes.rs", &roulettes(&tables));
+    write(&out_dir, "territory_names.rs", &territory_names(&tables));
+    write(&out_dir, "auto_translate.rs", &auto_translate(&tables));
+    write(&out_dir, "treasure_maps.rs", &treasure_maps(&tables));
+    write(&out_dir, "worlds.rs", &worlds(&tables));
+
+    println!("cargo::rustc-cfg=generated_tables");
+}
+
+fn write(out_dir: &Path, name: &str, contents: &str) {
+    let path = out_dir.join(name);
+    if let Err(e) = std::fs::write(&path, contents) {
+        panic!("could not write {}: {}", path.display(), e);
+    }
+}
+
+fn localised_text(out: &mut String, indent: &str, names: &Names) {
+    let fields = ["en", "ja", "de", "fr", "ko", "zh_hans", "zh_hant"];
+    writeln!(out, "LocalisedText {{").unwrap();
+    // regional text is only there when the regional clients' sheets are
+    for (i, (field, name)) in fields.iter().zip(names).enumerate() {
+        if i < 4 || !name.is_empty() {
+            writeln!(out, "{indent}    {}: {:?},", field, name).unwrap();
+        }
+    }
+    if names[4..].iter().any(String::is_empty) {
+        writeln!(out, "{indent}    ..Default::default()").unwrap();
+    }
+    write!(out, "{indent}}}").unwrap();
+}
+
+/// The sheets use 0 for values that aren't set.
+fn optional(value: u32) -> String {
+    match value {
+        0 => "None".to_string(),
+        value => format!("Some({})", value),
+    }
+}
+
+fn table<K>(
+    name: &str,
+    key_type: &str,
+    value_type: &str,
+    rows: impl Iterator<Item = K>,
+    mut entry: impl FnMut(&mut String, K),
+) -> String {
+    let mut out = String::new();
+    writeln!(out, "lazy_static::lazy_static! {{").unwrap();
+    writeln!(
+        out,
+        "    pub static ref {}: HashMap<{}, {}> = maplit::hashmap! {{",
+        name, key_type, value_type,
+    )
+    .unwrap();
+    for row in rows {
+        entry(&mut out, row);
+    }
+    writeln!(out, "    }};").unwrap();
+    writeln!(out, "}}").unwrap();
+    out
+}
+
+fn duties(tables: &Tables) -> String {
+    table("DUTIES", "u32", "DutyInfo", tables.duties.iter(), |out, (id, duty)| {
+        write!(out, "        {} => DutyInfo {{\n            name: ", id).unwrap();
+        localised_text(out, "            ", &duty.name);
+        writeln!(out, ",").unwrap();
+        writeln!(out, "            high_end: {},", duty.high_end).unwrap();
+        writeln!(out, "            content_kind: ContentKind::from_u32({}),", duty.content_type).unwrap();
+        writeln!(out, "            level: {},", optional(duty.level)).unwrap();
+        writeln!(out, "            item_level: {},", optional(duty.item_level)).unwrap();
+        writeln!(
+            out,
+            "            party_size: PartySize::from_members({}, {}),",
+            duty.members_per_party, duty.alliance,
+        )
+        .unwrap();
+        match duty.ex_version {
+            Some(ex_version) => writeln!(out, "            expansion: Expansion::from_u32({}),", ex_version).unwrap(),
+            None => writeln!(out, "            expansion: None,").unwrap(),
+        }
+        writeln!(out, "            icon: {},", optional(duty.icon)).unwrap();
+        writeln!(out, "            image: {},", optional(duty.image)).unwrap();
+        writeln!(out, "        }},").unwrap();
+    })
+}
+
+fn roulettes(tables: &Tables) -> String {
+    table("ROULETTES", "u32", "RouletteInfo", tables.roulettes.iter(), |out, (id, roulette)| {
+        write!(out, "        {} => RouletteInfo {{\n            name: ", id).unwrap();
+        localised_text(out, "            ", &roulette.name);
+        writeln!(out, ",").unwrap();
+        writeln!(out, "            pvp: {},", roulette.pvp).unwrap();
+        writeln!(out, "        }},").unwrap();
+    })
+}
+
+fn territory_names(tables: &Tables) -> String {
+    let mut out = table("TERRITORY_NAMES", "u32", "LocalisedText", tables.territory_names.iter(), |out, (id, names)| {
+        write!(out, "        {} => ", id).unwrap();
+        localised_text(out, "        ", names);
+        writeln!(out, ",").unwrap();
+    });
+
+    writeln!(out).unwrap();
+    writeln!(out, "lazy_static::lazy_static! {{").unwrap();
+    writeln!(out, "    pub static ref FATE_TERRITORIES: HashSet<u32> = maplit::hashset! {{").unwrap();
+    for id in &tables.fate_territories {
+        writeln!(out, "        {},", id).unwrap();
+    }
+    writeln!(out, "    }};").unwrap();
+    writeln!(out, "}}").unwrap();
+    out
+}
+
+fn auto_translate(tables: &Tables) -> String {
+    table(
+        "AUTO_TRANSLATE",
+        "(u32, u32)",
+        "LocalisedText",
+        tables.auto_translate.iter(),
+        |out, ((group, key), names)| {
+            write!(out, "        ({}, {}) => ", group, key).unwrap();
+            localised_text(out, "        ", names);
+            writeln!(out, ",").unwrap();
+        },
+    )
+}
+
+fn treasure_maps(tables: &Tables) -> String {
+    table("TREASURE_MAPS", "u32", "LocalisedText", tables.treasure_maps.iter(), |out, (id, names)| {
+        write!(out, "        {} => ", id).unwrap();
+        localised_text(out, "        ", names);
+        writeln!(out, ",").unwrap();
+    })
+}
+
+fn worlds(tables: &Tables) -> String {
+    table("WORLDS", "u32", "WorldInfo", tables.worlds.iter(), |out, (id, world)| {
+        writeln!(out, "        {} => WorldInfo {{", id).unwrap();
+        writeln!(out, "            id: {},", id).unwrap();
+        writeln!(out, "            name: {:?},", world.name).unwrap();
+        writeln!(out, "            data_centre: {:?},", world.data_centre).unwrap();
+        writeln!(out, "            region: Region::from_u32({}),", world.region).unwrap();
+        writeln!(out, "            travel: {},", world.travel).unwrap();
+        writeln!(out, "        }},").unwrap();
+    })
+}
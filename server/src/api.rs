@@ -1,35 +1,332 @@
+use crate::description::Tag;
 use crate::ffxiv;
 use crate::ffxiv::duties::DutyInfo;
-use crate::ffxiv::Language;
-use crate::listing::{ConditionFlags, DutyFinderSettingsFlags, LootRuleFlags, ObjectiveFlags, PartyFinderListing, PartyFinderSlot, SearchAreaFlags};
-use crate::listing_container::QueriedListing;
+use crate::ffxiv::jobs::JOBS_TO_FLAGS;
+use crate::ffxiv::search::SearchResult;
+use crate::ffxiv::{Expansion, Language, LanguageChain, PartySize};
+use crate::listing::{ConditionFlags, DutyFinderSettingsFlags, LootRuleFlags, ObjectiveFlags, PartyFinderListing, PartyFinderSlot, PreparedListing, SearchAreaFlags};
+use crate::listing_container::{ListingsSort, QueriedListing};
 use crate::mongo::get_current_listings;
 use crate::sestring_ext::SeStringExt;
 use crate::web::State;
 use crate::ws::WsApiClient;
 use chrono::{DateTime, Utc};
-use serde::Serialize;
+use ffxiv_types::jobs::{ClassJob, Job};
+use serde::{Deserialize, Deserializer, Serialize};
 use sestring::SeString;
+use std::collections::HashSet;
 use std::convert::Infallible;
-use std::sync::Arc;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::{Arc, OnceLock};
 use warp::filters::BoxedFilter;
 use warp::http::StatusCode;
 use warp::{Filter, Reply};
 
+mod admin;
+mod duties;
+mod meta;
+pub mod segments;
+mod stats;
+mod timeseries;
+mod webhooks;
+
 pub fn api(state: Arc<State>) -> BoxedFilter<(impl Reply,)> {
     warp::path("api")
-        .and(ws(state.clone()).or(listings(state.clone())))
+        .and(
+            ws(state.clone())
+                .or(listings(state.clone()))
+                .or(duties::duties())
+                .or(meta::meta())
+                .or(stats::stats(state.clone()))
+                .or(stats::stats_seven_days(state.clone()))
+                .or(timeseries::timeseries(state.clone()))
+                .or(webhooks::webhooks(state.clone()))
+                .or(admin::admin(state.clone())),
+        )
         .boxed()
 }
 
+/// Optional filters for `/api/listings` and the listings page.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ListingsQuery {
+    /// Comma-separated party finder categories, as in the category filter.
+    pub category: Option<String>,
+    /// The data centre the listing was created on, or "All".
+    pub centre: Option<String>,
+    /// Job flags, any of which must be able to join.
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub roles: Option<u32>,
+    /// Comma-separated job codes, like "whm,sch", any of which must be able
+    /// to join.
+    pub jobs: Option<String>,
+    /// A duty name or acronym in any language, as searched by `/api/duties`.
+    pub duty: Option<String>,
+    #[serde(skip)]
+    resolved_duty: OnceLock<Option<SearchResult>>,
+    pub expansion: Option<Expansion>,
+    pub party_size: Option<PartySize>,
+    pub content_kind: Option<u32>,
+    pub high_end: Option<bool>,
+    /// The searcher's item level. Listings requiring more are excluded.
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub item_level: Option<u16>,
+    /// Words to find in the recruiter, duty or description, in any language.
+    pub q: Option<String>,
+    /// Comma-separated description tags, all of which must be present.
+    pub tags: Option<String>,
+    /// A prog point from the description, like "P5" or "enrage".
+    pub prog_point: Option<String>,
+    /// A strat from the description, like "PF" or "Hector".
+    pub strat: Option<String>,
+    #[serde(default)]
+    pub sort: ListingsSort,
+    /// Include `description_html` in each listing.
+    #[serde(default)]
+    pub description_html: bool,
+    /// Include `description_segments` in each listing.
+    #[serde(default)]
+    pub description_segments: bool,
+}
+
+impl ListingsQuery {
+    pub fn matches(&self, listing: &PartyFinderListing) -> bool {
+        self.matches_prepared(&PreparedListing::new(listing))
+    }
+
+    /// [`ListingsQuery::matches`], reusing what's been worked out for `prepared` by other queries.
+    pub fn matches_prepared(&self, prepared: &PreparedListing) -> bool {
+        let listing = prepared.listing;
+        if !self.categories().is_empty() {
+            // listings with an unknown category are always shown
+            let category = listing.html_pf_category();
+            if category != "unknown" && !self.has_category(category) {
+                return false;
+            }
+        }
+
+        if self.data_centre().is_some() && !listing.data_centre_name().is_some_and(|name| self.has_centre(name)) {
+            return false;
+        }
+
+        if let Some(roles) = self.job_flags().filter(|&roles| roles != 0) {
+            // the jobs present aren't accurate for alliance raids and pvp
+            if listing.num_parties == 1 && listing.joinable_roles() & roles == 0 {
+                return false;
+            }
+        }
+
+        if self.duty.as_deref().is_some_and(|duty| !duty.trim().is_empty()) {
+            let Some(duty) = self.resolved_duty() else {
+                return false;
+            };
+
+            let same_duty = duty.duty_type == listing.duty_type
+                && duty.category.is_none_or(|category| category == listing.category)
+                && duty.ids.contains(&listing.duty);
+            if !same_duty {
+                return false;
+            }
+        }
+
+        let info = listing.duty_info();
+
+        // as with categories, duties whose details are unknown are always shown
+        if let Some(expansion) = self.expansion {
+            if info.and_then(|info| info.expansion).is_some_and(|known| known != expansion) {
+                return false;
+            }
+        }
+
+        if let Some(party_size) = self.party_size {
+            let known = listing.party_size();
+            if known != PartySize::Unknown && known != party_size {
+                return false;
+            }
+        }
+
+        if let Some(content_kind) = self.content_kind {
+            if content_kind != listing.content_kind() {
+                return false;
+            }
+        }
+
+        if let Some(high_end) = self.high_end {
+            if high_end != listing.high_end() {
+                return false;
+            }
+        }
+
+        if let Some(item_level) = self.item_level {
+            if listing.min_item_level > item_level {
+                return false;
+            }
+        }
+
+        if let Some(q) = &self.q {
+            if !prepared.matches_search(q) {
+                return false;
+            }
+        }
+
+        let prog_point = non_empty(&self.prog_point);
+        let strat = non_empty(&self.strat);
+        if self.tags.is_some() || prog_point.is_some() || strat.is_some() {
+            let found = prepared.description_tags();
+
+            if let Some(tags) = &self.tags {
+                let all_found = tags
+                    .split(',')
+                    .filter(|tag| !tag.is_empty())
+                    .all(|tag| Tag::from_code(tag).is_some_and(|tag| found.has(tag)));
+                if !all_found {
+                    return false;
+                }
+            }
+
+            let same = |wanted: Option<&str>, found: Option<&str>| {
+                wanted.is_none_or(|wanted| found.is_some_and(|found| found.eq_ignore_ascii_case(wanted)))
+            };
+            if !same(prog_point, found.prog_point.as_deref()) || !same(strat, found.strat) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// `roles` and `jobs` together.
+    pub fn job_flags(&self) -> Option<u32> {
+        let jobs = self.jobs.as_deref().map(|jobs| {
+            jobs.split(',')
+                .filter_map(|code| code.trim().parse::<Job>().ok())
+                .filter_map(|job| JOBS_TO_FLAGS.get(ClassJob::Job(job).as_str()))
+                .fold(0, |flags, job| flags | job.bits())
+        });
+
+        match (self.roles, jobs) {
+            (None, None) => None,
+            (roles, jobs) => Some(roles.unwrap_or_default() | jobs.unwrap_or_default()),
+        }
+    }
+
+    /// The duty named by `duty`, searched once per query. Only whole names
+    /// and acronyms count, so that a vague query like "the" matches nothing
+    /// rather than an arbitrary duty.
+    pub fn resolved_duty(&self) -> Option<&SearchResult> {
+        self.resolved_duty
+            .get_or_init(|| {
+                let duty = self.duty.as_deref()?;
+                ffxiv::search::search(duty, 1)
+                    .into_iter()
+                    .next()
+                    .filter(|result| result.score >= ffxiv::search::EXACT_SCORE)
+            })
+            .as_ref()
+    }
+
+    pub fn categories(&self) -> Vec<&str> {
+        self.category
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .filter(|category| !category.is_empty())
+            .collect()
+    }
+
+    pub fn has_category(&self, category: &str) -> bool {
+        self.categories().contains(&category)
+    }
+
+    pub fn data_centre(&self) -> Option<&str> {
+        self.centre
+            .as_deref()
+            .filter(|centre| !centre.is_empty() && !centre.eq_ignore_ascii_case("all"))
+    }
+
+    pub fn has_centre(&self, centre: &str) -> bool {
+        self.data_centre().is_some_and(|data_centre| data_centre.eq_ignore_ascii_case(centre))
+    }
+
+    pub fn search(&self) -> &str {
+        self.q.as_deref().unwrap_or_default()
+    }
+
+    /// Parses a query string, leaving out parameters that don't parse rather
+    /// than failing, so that a stale or mistyped link still shows the page.
+    pub fn parse_lenient(query: &str) -> Self {
+        let mut pairs: Vec<(String, String)> = serde_urlencoded::from_str(query).unwrap_or_default();
+        let mut seen = HashSet::new();
+        pairs.retain(|(key, _)| seen.insert(key.clone()));
+        // parameters are independent, so the ones that parse alone parse together
+        pairs.retain(|pair| {
+            serde_urlencoded::to_string([pair])
+                .is_ok_and(|query| serde_urlencoded::from_str::<Self>(&query).is_ok())
+        });
+
+        serde_urlencoded::to_string(&pairs)
+            .ok()
+            .and_then(|query| serde_urlencoded::from_str(&query).ok())
+            .unwrap_or_default()
+    }
+
+    /// The query string of the request, parsed with `parse_lenient`.
+    pub fn lenient() -> BoxedFilter<(Self,)> {
+        warp::query::raw()
+            .or(warp::any().map(String::new))
+            .unify()
+            .map(|query: String| Self::parse_lenient(&query))
+            .boxed()
+    }
+}
+
+fn non_empty(value: &Option<String>) -> Option<&str> {
+    value.as_deref().map(str::trim).filter(|value| !value.is_empty())
+}
+
+/// Forms submit an empty string for an unset number. Numbers are accepted
+/// too, as saved searches are stored as bson.
+fn empty_as_none<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + FromStr,
+    T::Err: Display,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum NumberOrString<T> {
+        Number(T),
+        String(String),
+    }
+
+    match Option::<NumberOrString<T>>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(NumberOrString::Number(value)) => Ok(Some(value)),
+        Some(NumberOrString::String(value)) if value.is_empty() => Ok(None),
+        Some(NumberOrString::String(value)) => value.parse().map(Some).map_err(serde::de::Error::custom),
+    }
+}
+
 fn listings(state: Arc<State>) -> BoxedFilter<(impl Reply,)> {
-    async fn logic(state: Arc<State>) -> Result<warp::reply::Response, Infallible> {
+    async fn logic(state: Arc<State>, query: ListingsQuery) -> Result<warp::reply::Response, Infallible> {
         let listings = get_current_listings(state.collection()).await;
 
         match listings {
-            Ok(listings) => {
+            Ok(mut listings) => {
+                listings.retain(|listing| query.matches(&listing.listing));
+                query.sort.sort(&mut listings);
+
                 let listings: Vec<ApiReadableListingContainer> = listings.into_iter()
-                    .map(|listing| listing.into())
+                    .map(|listing| {
+                        let description_html = query.description_html
+                            .then(|| ApiLocalizedString::from_fn(|langs| listing.listing.description.html(langs)));
+                        let description_segments = query.description_segments
+                            .then(|| segments::segments(&listing.listing.description));
+                        let mut container: ApiReadableListingContainer = listing.into();
+                        container.listing.description_html = description_html;
+                        container.listing.description_segments = description_segments;
+                        container
+                    })
                     .collect();
                 Ok(warp::reply::json(&listings).into_response())
             },
@@ -44,7 +341,8 @@ fn listings(state: Arc<State>) -> BoxedFilter<(impl Reply,)> {
     warp::get()
         .and(warp::path("listings"))
         .and(warp::path::end())
-        .and_then(move || logic(state.clone()))
+        .and(warp::query::<ListingsQuery>())
+        .and_then(move |query| logic(state.clone(), query))
         .boxed()
 }
 
@@ -90,6 +388,16 @@ struct ApiReadableListing {
     // pub content_id: u32,
     recruiter: String,
     description: ApiLocalizedString,
+    // only with `?description_html=true`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description_html: Option<ApiLocalizedString>,
+    // only with `?description_segments=true`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description_segments: Option<Vec<segments::ApiReadableSegment>>,
+    // tags found in the description
+    tags: Vec<Tag>,
+    prog_point: Option<String>,
+    strat: Option<&'static str>,
     created_world: ApiReadableWorld,
     home_world: ApiReadableWorld,
     current_world: ApiReadableWorld,
@@ -113,29 +421,51 @@ struct ApiReadableListing {
     slots_filled: Vec<Option<&'static str>>, // None if not filled, otherwise the job code
 }
 
+/// Text in each language, as the game has it. Untranslated text is empty
+/// rather than copied from another language.
 #[derive(Serialize)]
 struct ApiLocalizedString {
     en: String,
     ja: String,
     de: String,
     fr: String,
+    ko: String,
+    zh_hans: String,
+    zh_hant: String,
 }
 
-impl From<SeString> for ApiLocalizedString {
-    fn from(value: SeString) -> Self {
+impl ApiLocalizedString {
+    fn from_fn(f: impl Fn(&LanguageChain) -> String) -> Self {
+        let f = |lang: Language| f(&LanguageChain::only(lang));
         Self {
-            en: value.full_text(&Language::English),
-            ja: value.full_text(&Language::Japanese),
-            de: value.full_text(&Language::German),
-            fr: value.full_text(&Language::French),
+            en: f(Language::English),
+            ja: f(Language::Japanese),
+            de: f(Language::German),
+            fr: f(Language::French),
+            ko: f(Language::Korean),
+            zh_hans: f(Language::ChineseSimplified),
+            zh_hant: f(Language::ChineseTraditional),
         }
     }
 }
 
+impl From<SeString> for ApiLocalizedString {
+    fn from(value: SeString) -> Self {
+        Self::from_fn(|langs| value.full_text(langs))
+    }
+}
+
+impl From<ffxiv::LocalisedText> for ApiLocalizedString {
+    fn from(value: ffxiv::LocalisedText) -> Self {
+        Self::from_fn(|langs| value.text(langs).to_string())
+    }
+}
+
 impl From<PartyFinderListing> for ApiReadableListing {
     fn from(value: PartyFinderListing) -> Self {
-        let duty_info = ffxiv::duty(value.duty as u32)
-            .map(|di| di.into());
+        let duty_info = value.duty_info()
+            .map(|di| (&di).into());
+        let description_tags = value.description_tags();
         let slots_filled = value.jobs_present
             .into_iter()
             .map(|job| if job == 0 {
@@ -150,6 +480,11 @@ impl From<PartyFinderListing> for ApiReadableListing {
             id: value.id,
             recruiter: value.name.text(),
             description: value.description.into(),
+            description_html: None,
+            description_segments: None,
+            tags: description_tags.tags,
+            prog_point: description_tags.prog_point,
+            strat: description_tags.strat,
             created_world: value.created_world.into(),
             home_world: value.home_world.into(),
             current_world: value.current_world.into(),
@@ -177,15 +512,34 @@ impl From<PartyFinderListing> for ApiReadableListing {
 struct ApiReadableWorld {
     id: u16,
     name: &'static str,
+    data_centre: Option<&'static str>,
+    region: Option<&'static str>,
+    travel: bool,
 }
 
 impl From<u16> for ApiReadableWorld {
     fn from(value: u16) -> Self {
+        match crate::ffxiv::world(value as u32) {
+            Some(world) => world.into(),
+            None => Self {
+                id: value,
+                name: "Unknown",
+                data_centre: None,
+                region: None,
+                travel: false,
+            },
+        }
+    }
+}
+
+impl From<&ffxiv::WorldInfo> for ApiReadableWorld {
+    fn from(value: &ffxiv::WorldInfo) -> Self {
         Self {
-            id: value,
-            name: crate::ffxiv::WORLDS.get(&(value as u32))
-                .map(|w| w.as_str())
-                .unwrap_or("Unknown")
+            id: value.id as u16,
+            name: value.name,
+            data_centre: Some(value.data_centre),
+            region: Some(value.region.name()),
+            travel: value.travel,
         }
     }
 }
@@ -196,6 +550,15 @@ struct ApiReadableDutyInfo {
     pub high_end: bool,
     pub content_kind_id: u32,
     pub content_kind: String,
+    // null if unknown
+    pub level: Option<u8>,
+    // null if unknown or not synced
+    pub item_level: Option<u16>,
+    // null if unknown
+    pub party_size: Option<PartySize>,
+    pub expansion: Option<Expansion>,
+    pub icon: Option<u32>,
+    pub image: Option<u32>,
 }
 
 impl From<&DutyInfo> for ApiReadableDutyInfo {
@@ -205,6 +568,12 @@ impl From<&DutyInfo> for ApiReadableDutyInfo {
             high_end: value.high_end,
             content_kind_id: value.content_kind.as_u32(),
             content_kind: format!("{:?}", value.content_kind),
+            level: value.level,
+            item_level: value.item_level,
+            party_size: Some(value.party_size).filter(|&size| size != PartySize::Unknown),
+            expansion: value.expansion,
+            icon: value.icon,
+            image: value.image,
         }
     }
 }
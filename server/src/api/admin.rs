@@ -0,0 +1,97 @@
+use crate::stats::StatsWindow;
+use crate::web::State;
+use serde::Deserialize;
+use std::convert::Infallible;
+use std::sync::Arc;
+use warp::filters::BoxedFilter;
+use warp::http::StatusCode;
+use warp::{Filter, Rejection, Reply};
+
+pub fn admin(state: Arc<State>) -> BoxedFilter<(impl Reply,)> {
+    warp::path("admin")
+        .and(authorized(state.clone()))
+        .and(
+            stats_status(state.clone())
+                .or(stats_refresh(state.clone()))
+                .or(unknown_duties(state.clone()))
+                .or(unknown_duties_reset(state.clone())),
+        )
+        .boxed()
+}
+
+/// Rejects requests that don't carry the configured admin token as a bearer token.
+/// If no token is configured, every request is rejected.
+fn authorized(state: Arc<State>) -> impl Filter<Extract = (), Error = Rejection> + Clone {
+    warp::header::optional::<String>("authorization")
+        .and_then(move |header: Option<String>| {
+            let state = state.clone();
+            async move {
+                let Some(admin) = &state.config.admin else {
+                    return Err(warp::reject::not_found());
+                };
+
+                let expected = format!("Bearer {}", admin.token);
+                match header {
+                    Some(header) if constant_time_eq(header.as_bytes(), expected.as_bytes()) => Ok(()),
+                    _ => Err(warp::reject::not_found()),
+                }
+            }
+        })
+        .untuple_one()
+}
+
+pub(super) fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
+fn stats_status(state: Arc<State>) -> BoxedFilter<(impl Reply,)> {
+    async fn logic(state: Arc<State>) -> Result<warp::reply::Response, Infallible> {
+        let statuses = state.stats_scheduler.statuses().await;
+        Ok(warp::reply::json(&statuses).into_response())
+    }
+
+    warp::get()
+        .and(warp::path("stats"))
+        .and(warp::path::end())
+        .and_then(move || logic(state.clone()))
+        .boxed()
+}
+
+#[derive(Deserialize)]
+struct RefreshQuery {
+    window: Option<StatsWindow>,
+}
+
+fn stats_refresh(state: Arc<State>) -> BoxedFilter<(impl Reply,)> {
+    async fn logic(state: Arc<State>, query: RefreshQuery) -> Result<warp::reply::Response, Infallible> {
+        state.stats_scheduler.trigger(query.window);
+        Ok(warp::reply::with_status(warp::reply(), StatusCode::ACCEPTED).into_response())
+    }
+
+    warp::post()
+        .and(warp::path("stats"))
+        .and(warp::path("refresh"))
+        .and(warp::path::end())
+        .and(warp::query::<RefreshQuery>())
+        .and_then(move |query| logic(state.clone(), query))
+        .boxed()
+}
+
+fn unknown_duties(state: Arc<State>) -> BoxedFilter<(impl Reply,)> {
+    warp::get()
+        .and(warp::path("unknown-duties"))
+        .and(warp::path::end())
+        .map(move || warp::reply::json(&state.unknown_duties.snapshot()))
+        .boxed()
+}
+
+fn unknown_duties_reset(state: Arc<State>) -> BoxedFilter<(impl Reply,)> {
+    warp::delete()
+        .and(warp::path("unknown-duties"))
+        .and(warp::path::end())
+        .map(move || {
+            state.unknown_duties.reset();
+            warp::reply::with_status(warp::reply(), StatusCode::NO_CONTENT)
+        })
+        .boxed()
+}
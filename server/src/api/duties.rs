@@ -0,0 +1,72 @@
+use crate::ffxiv;
+use crate::ffxiv::search::{self, SearchResult};
+use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
+use warp::filters::BoxedFilter;
+use warp::http::StatusCode;
+use warp::{Filter, Reply};
+
+const DEFAULT_LIMIT: usize = 20;
+const MAX_LIMIT: usize = 100;
+
+#[derive(Deserialize)]
+struct DutySearchQuery {
+    q: String,
+    limit: Option<usize>,
+}
+
+pub fn duties() -> BoxedFilter<(impl Reply,)> {
+    async fn logic(query: DutySearchQuery) -> Result<warp::reply::Response, Infallible> {
+        if query.q.chars().count() > search::MAX_QUERY_CHARS {
+            return Ok(warp::reply::with_status("query is too long", StatusCode::BAD_REQUEST).into_response());
+        }
+
+        let limit = query.limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+        // scanning every name takes a while, so keep it off the async workers
+        let results = tokio::task::spawn_blocking(move || search::search(&query.q, limit)).await;
+        match results {
+            Ok(results) => {
+                let results: Vec<ApiReadableDutySearchResult> = results
+                    .into_iter()
+                    .map(|result| result.into())
+                    .collect();
+                Ok(warp::reply::json(&results).into_response())
+            }
+            Err(e) => {
+                eprintln!("error searching duties: {:#?}", e);
+                Ok(StatusCode::INTERNAL_SERVER_ERROR.into_response())
+            }
+        }
+    }
+
+    warp::get()
+        .and(warp::path("duties"))
+        .and(warp::path::end())
+        .and(warp::query::<DutySearchQuery>())
+        .and_then(logic)
+        .boxed()
+}
+
+#[derive(Serialize)]
+struct ApiReadableDutySearchResult {
+    // `Debug` of `DutyType`, as in listings
+    duty_type: String,
+    // `Debug` of `DutyCategory`, or null if any category matches
+    category: Option<String>,
+    // listing duty ids with this name
+    ids: Vec<u16>,
+    name: ffxiv::LocalisedText,
+    score: u32,
+}
+
+impl From<SearchResult> for ApiReadableDutySearchResult {
+    fn from(value: SearchResult) -> Self {
+        Self {
+            duty_type: format!("{:?}", value.duty_type),
+            category: value.category.map(|category| format!("{:?}", category)),
+            ids: value.ids,
+            name: value.name,
+            score: value.score,
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use super::{ApiReadableDutyInfo, ApiReadableWorld};
+use crate::ffxiv::{self, LocalisedText};
+use crate::listing::{DutyCategory, JobFlags, PartyFinderCategory};
+use serde::Serialize;
+use warp::filters::BoxedFilter;
+use warp::{Filter, Reply};
+
+/// The tables needed to interpret listings, so clients don't have to copy
+/// them out of the source.
+pub fn meta() -> BoxedFilter<(impl Reply,)> {
+    let jobs = warp::path("jobs")
+        .and(warp::path::end())
+        .map(|| warp::reply::json(&ApiReadableJobs::new()));
+    let worlds = warp::path("worlds")
+        .and(warp::path::end())
+        .map(|| {
+            let worlds: Vec<ApiReadableWorld> = ffxiv::all_worlds()
+                .into_values()
+                .map(|world| world.into())
+                .collect();
+            warp::reply::json(&worlds)
+        });
+    let duties = warp::path("duties")
+        .and(warp::path::end())
+        .map(|| warp::reply::json(&ApiReadableDuties::new()));
+    let categories = warp::path("categories")
+        .and(warp::path::end())
+        .map(|| {
+            let categories: Vec<ApiReadableCategory> = PartyFinderCategory::ALL
+                .into_iter()
+                .map(ApiReadableCategory::new)
+                .collect();
+            warp::reply::json(&categories)
+        });
+
+    warp::get()
+        .and(warp::path("meta"))
+        .and(jobs.or(worlds).or(duties).or(categories))
+        .boxed()
+}
+
+#[derive(Serialize)]
+struct ApiReadableJobs {
+    jobs: Vec<ApiReadableJob>,
+    // the groups used by the role filter, in order
+    roles: Vec<ApiReadableRole>,
+}
+
+impl ApiReadableJobs {
+    fn new() -> Self {
+        let mut jobs: Vec<ApiReadableJob> = ffxiv::JOBS
+            .iter()
+            .map(|(&id, job)| ApiReadableJob {
+                id,
+                code: job.code(),
+                name: job.name(),
+                role: job.role().map(|role| role.as_str()),
+                flag: ffxiv::jobs::JOBS_TO_FLAGS
+                    .get(job.as_str())
+                    .map(|flag| flag.bits()),
+            })
+            .collect();
+        jobs.sort_by_key(|job| job.id);
+
+        let roles = JobFlags::get_all_jobs()
+            .into_iter()
+            .map(|(name, jobs)| ApiReadableRole {
+                name,
+                jobs: jobs
+                    .iter()
+                    .flat_map(|job| job.classjobs())
+                    .map(|job| job.code())
+                    .collect(),
+                flags: jobs.iter().fold(0, |flags, job| flags | job.bits()),
+            })
+            .collect();
+
+        Self { jobs, roles }
+    }
+}
+
+#[derive(Serialize)]
+struct ApiReadableJob {
+    id: u32,
+    code: &'static str,
+    name: &'static str,
+    role: Option<&'static str>,
+    // the bit in `JobFlags`, if the job can be recruited for
+    flag: Option<u32>,
+}
+
+#[derive(Serialize)]
+struct ApiReadableRole {
+    name: LocalisedText,
+    jobs: Vec<&'static str>,
+    flags: u32,
+}
+
+#[derive(Serialize)]
+struct ApiReadableDuties {
+    duties: Vec<ApiReadableMetaDuty>,
+    roulettes: Vec<ApiReadableMetaRoulette>,
+}
+
+impl ApiReadableDuties {
+    fn new() -> Self {
+        Self {
+            duties: ffxiv::all_duties()
+                .into_iter()
+                .map(|(id, info)| ApiReadableMetaDuty {
+                    id,
+                    info: info.into(),
+                })
+                .collect(),
+            roulettes: ffxiv::all_roulettes()
+                .into_iter()
+                .map(|(id, info)| ApiReadableMetaRoulette {
+                    id,
+                    name: info.name,
+                    pvp: info.pvp,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ApiReadableMetaDuty {
+    id: u32,
+    #[serde(flatten)]
+    info: ApiReadableDutyInfo,
+}
+
+#[derive(Serialize)]
+struct ApiReadableMetaRoulette {
+    id: u32,
+    name: LocalisedText,
+    pvp: bool,
+}
+
+#[derive(Serialize)]
+struct ApiReadableCategory {
+    // the value used by the listings page's category filter
+    id: &'static str,
+    name: LocalisedText,
+    // `Debug` of the `DutyCategory`s in this category, as in listings
+    duty_categories: Vec<String>,
+}
+
+impl ApiReadableCategory {
+    fn new(category: PartyFinderCategory) -> Self {
+        Self {
+            id: category.as_str(),
+            name: category.name(),
+            duty_categories: DutyCategory::ALL
+                .into_iter()
+                .filter(|duty_category| duty_category.pf_category() == category)
+                .map(|duty_category| format!("{:?}", duty_category))
+                .collect(),
+        }
+    }
+}
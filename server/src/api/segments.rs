@@ -0,0 +1,102 @@
+use crate::ffxiv::{self, LocalisedText};
+use serde::Serialize;
+use sestring::{Payload, SeString};
+
+/// A payload of an SeString, for clients that render descriptions themselves.
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ApiReadableSegment {
+    Text {
+        text: String,
+    },
+    AutoTranslate {
+        group: u8,
+        key: u32,
+        // null if the entry is unknown
+        localized: Option<LocalisedText>,
+    },
+    // the link text follows as text segments, up to a `link_end`
+    Item {
+        id: u32,
+        hq: bool,
+        name: Option<String>,
+    },
+    MapLink {
+        territory_type: u32,
+        map: u32,
+        raw_x: i32,
+        raw_y: i32,
+        territory_name: Option<LocalisedText>,
+    },
+    Quest {
+        id: u32,
+    },
+    Status {
+        id: u32,
+    },
+    Player {
+        name: String,
+        world: u32,
+    },
+    LinkEnd,
+    Icon {
+        id: u32,
+    },
+    Emphasis {
+        enabled: bool,
+    },
+    // 0 resets the colour
+    Colour {
+        colour: u32,
+    },
+    Glow {
+        colour: u32,
+    },
+    NewLine,
+    Hyphen,
+}
+
+pub fn segments(value: &SeString) -> Vec<ApiReadableSegment> {
+    value.0.iter().filter_map(segment).collect()
+}
+
+fn segment(payload: &Payload) -> Option<ApiReadableSegment> {
+    Some(match payload {
+        Payload::Text(text) => ApiReadableSegment::Text {
+            text: text.0.clone(),
+        },
+        Payload::AutoTranslate(at) => ApiReadableSegment::AutoTranslate {
+            group: at.group,
+            key: at.key,
+            localized: ffxiv::auto_translate_text(u32::from(at.group), at.key).copied(),
+        },
+        Payload::Item(item) => ApiReadableSegment::Item {
+            id: item.id,
+            hq: item.hq,
+            name: item.name.clone(),
+        },
+        Payload::MapLink(map) => ApiReadableSegment::MapLink {
+            territory_type: map.territory_type,
+            map: map.map,
+            raw_x: map.raw_x,
+            raw_y: map.raw_y,
+            territory_name: ffxiv::territory_name(map.territory_type).copied(),
+        },
+        Payload::Quest(quest) => ApiReadableSegment::Quest { id: quest.0 },
+        Payload::Status(status) => ApiReadableSegment::Status { id: status.0 },
+        Payload::Player(player) => ApiReadableSegment::Player {
+            name: player.name.clone(),
+            world: player.server_id,
+        },
+        Payload::Raw(raw) if crate::sestring_ext::is_link_terminator(&raw.0) => ApiReadableSegment::LinkEnd,
+        Payload::Icon(icon) => ApiReadableSegment::Icon { id: icon.0 },
+        Payload::Emphasis(emphasis) => ApiReadableSegment::Emphasis {
+            enabled: emphasis.enabled(),
+        },
+        Payload::UiForeground(colour) => ApiReadableSegment::Colour { colour: colour.0 },
+        Payload::UiGlow(colour) => ApiReadableSegment::Glow { colour: colour.0 },
+        Payload::NewLine(_) => ApiReadableSegment::NewLine,
+        Payload::SeHyphen(_) => ApiReadableSegment::Hyphen,
+        Payload::DalamudLink(_) | Payload::Raw(_) => return None,
+    })
+}
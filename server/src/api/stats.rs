@@ -0,0 +1,166 @@
+use super::{ApiLocalizedString, ApiReadableWorld};
+use crate::stats::{DayInfo, DutyInfo, HostInfo, HourInfo, Statistics, StatsWindow};
+use crate::web::State;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::convert::Infallible;
+use std::sync::Arc;
+use warp::filters::BoxedFilter;
+use warp::http::StatusCode;
+use warp::{Filter, Reply};
+
+pub fn stats(state: Arc<State>) -> BoxedFilter<(impl Reply,)> {
+    warp::get()
+        .and(warp::path("stats"))
+        .and(warp::path::end())
+        .and_then(move || logic(state.clone(), StatsWindow::AllTime))
+        .boxed()
+}
+
+pub fn stats_seven_days(state: Arc<State>) -> BoxedFilter<(impl Reply,)> {
+    warp::get()
+        .and(warp::path("stats"))
+        .and(warp::path("7days"))
+        .and(warp::path::end())
+        .and_then(move || logic(state.clone(), StatsWindow::SevenDays))
+        .boxed()
+}
+
+async fn logic(state: Arc<State>, window: StatsWindow) -> Result<warp::reply::Response, Infallible> {
+    let stats = state.stats.read().await.get(window).cloned();
+
+    Ok(match stats {
+        Some(mut stats) => {
+            if state.config.stats.anonymise_hosts {
+                stats.stats.aliases.clear();
+            }
+            warp::reply::json(&ApiReadableStatistics::new(&stats.stats, stats.generated_at)).into_response()
+        }
+        None => warp::reply::with_status(
+            warp::reply(),
+            StatusCode::SERVICE_UNAVAILABLE,
+        )
+        .into_response(),
+    })
+}
+
+/// A version of `Statistics` with aliases and ids resolved into names.
+#[derive(Serialize)]
+struct ApiReadableStatistics {
+    generated_at: DateTime<Utc>,
+    num_listings: usize,
+    duties: Vec<ApiReadableDutyStat>,
+    hosts: Vec<ApiReadableHostStat>,
+    hours: Vec<ApiReadableHourStat>,
+    days: Vec<ApiReadableDayStat>,
+}
+
+impl ApiReadableStatistics {
+    fn new(value: &Statistics, generated_at: DateTime<Utc>) -> Self {
+        Self {
+            generated_at,
+            num_listings: value.num_listings(),
+            duties: value.duties.iter().map(|info| info.into()).collect(),
+            hosts: value
+                .hosts
+                .iter()
+                .map(|info| ApiReadableHostStat::new(value, info))
+                .collect(),
+            hours: value.hours.iter().map(|info| info.into()).collect(),
+            days: value.days.iter().map(|info| info.into()).collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ApiReadableDutyStat {
+    duty_type: u8,
+    category: u32,
+    duty: u16,
+    name: ApiLocalizedString,
+    count: usize,
+}
+
+impl From<&DutyInfo> for ApiReadableDutyStat {
+    fn from(value: &DutyInfo) -> Self {
+        Self {
+            duty_type: value.info.0,
+            category: value.info.1,
+            duty: value.info.2,
+            name: ApiLocalizedString::from_fn(|langs| value.name(langs).into_owned()),
+            count: value.count,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ApiReadableHostStat {
+    created_world: ApiReadableWorld,
+    count: usize,
+    num_other: usize,
+    top_hosts: Vec<ApiReadableHost>,
+}
+
+impl ApiReadableHostStat {
+    fn new(stats: &Statistics, value: &HostInfo) -> Self {
+        let top_hosts = value
+            .content_ids
+            .iter()
+            .map(|entry| {
+                let alias = stats.aliases.get(&entry.content_id);
+                ApiReadableHost {
+                    name: alias.map(|alias| alias.name.text()),
+                    home_world: alias.map(|alias| (alias.home_world as u16).into()),
+                    count: entry.count,
+                }
+            })
+            .collect();
+
+        Self {
+            created_world: (value.created_world as u16).into(),
+            count: value.count,
+            num_other: value.num_other(),
+            top_hosts,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ApiReadableHost {
+    name: Option<String>,
+    home_world: Option<ApiReadableWorld>,
+    count: usize,
+}
+
+#[derive(Serialize)]
+struct ApiReadableHourStat {
+    hour: u8,
+    count: usize,
+}
+
+impl From<&HourInfo> for ApiReadableHourStat {
+    fn from(value: &HourInfo) -> Self {
+        Self {
+            hour: value.hour,
+            count: value.count,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ApiReadableDayStat {
+    // 1 = Sunday, as returned by mongo's `$dayOfWeek`
+    day: u8,
+    name: &'static str,
+    count: usize,
+}
+
+impl From<&DayInfo> for ApiReadableDayStat {
+    fn from(value: &DayInfo) -> Self {
+        Self {
+            day: value.day,
+            name: value.name(),
+            count: value.count,
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use super::ApiLocalizedString;
+use crate::listing::{DutyCategory, DutyType};
+use crate::mongo::{get_listing_counts, ListingCount, TimeBucket, TimeSeriesSplit};
+use crate::web::State;
+use chrono::{DateTime, TimeDelta, Utc};
+use mongodb::bson::Bson;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+use std::sync::Arc;
+use warp::filters::BoxedFilter;
+use warp::http::StatusCode;
+use warp::{Filter, Reply};
+
+/// The most buckets a single request may span, to keep the aggregation bounded.
+const MAX_BUCKETS: i64 = 24 * 92;
+
+#[derive(Deserialize)]
+struct TimeSeriesQuery {
+    bucket: Option<TimeBucket>,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+    split: Option<TimeSeriesSplit>,
+}
+
+pub fn timeseries(state: Arc<State>) -> BoxedFilter<(impl Reply,)> {
+    async fn logic(
+        state: Arc<State>,
+        query: TimeSeriesQuery,
+    ) -> Result<warp::reply::Response, Infallible> {
+        let bucket = query.bucket.unwrap_or(TimeBucket::Day);
+        let to = query.to.unwrap_or_else(Utc::now);
+        let from = query.from.unwrap_or_else(|| match bucket {
+            TimeBucket::Hour => to - TimeDelta::try_days(7).unwrap(),
+            TimeBucket::Day => to - TimeDelta::try_days(90).unwrap(),
+        });
+
+        if from >= to {
+            return Ok(bad_request("`from` must be before `to`"));
+        }
+
+        if (to - from).num_seconds() / bucket.duration().num_seconds() > MAX_BUCKETS {
+            return Ok(bad_request("range is too large for this bucket size"));
+        }
+
+        let counts =
+            get_listing_counts(state.collection(), from, to, bucket, query.split).await;
+        Ok(match counts {
+            Ok(counts) => {
+                let series = ApiReadableTimeSeries::new(bucket, from, to, query.split, counts);
+                warp::reply::json(&series).into_response()
+            }
+            Err(e) => {
+                eprintln!("error generating time series: {:#?}", e);
+                warp::reply::with_status(warp::reply(), StatusCode::INTERNAL_SERVER_ERROR)
+                    .into_response()
+            }
+        })
+    }
+
+    warp::get()
+        .and(warp::path("timeseries"))
+        .and(warp::path::end())
+        .and(warp::query::<TimeSeriesQuery>())
+        .and_then(move |query| logic(state.clone(), query))
+        .boxed()
+}
+
+fn bad_request(message: &'static str) -> warp::reply::Response {
+    warp::reply::with_status(message, StatusCode::BAD_REQUEST).into_response()
+}
+
+#[derive(Serialize)]
+struct ApiReadableTimeSeries {
+    bucket: &'static str,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    series: Vec<ApiReadableSeries>,
+}
+
+#[derive(Serialize)]
+struct ApiReadableSeries {
+    key: String,
+    name: Option<ApiLocalizedString>,
+    points: Vec<ApiReadablePoint>,
+}
+
+#[derive(Serialize)]
+struct ApiReadablePoint {
+    time: DateTime<Utc>,
+    count: usize,
+}
+
+impl ApiReadableTimeSeries {
+    fn new(
+        bucket: TimeBucket,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        split: Option<TimeSeriesSplit>,
+        counts: Vec<ListingCount>,
+    ) -> Self {
+        // several mongo groups can end up in the same series (e.g. worlds in a data centre),
+        // so points are merged by time. `counts` is sorted by time already.
+        let mut series: BTreeMap<String, ApiReadableSeries> = BTreeMap::new();
+        for count in counts {
+            let (key, name) = series_key(split, &count.key.split);
+            let entry = series.entry(key.clone()).or_insert_with(|| ApiReadableSeries {
+                key,
+                name,
+                points: Vec::new(),
+            });
+
+            match entry.points.last_mut() {
+                Some(point) if point.time == count.key.time => point.count += count.count,
+                _ => entry.points.push(ApiReadablePoint {
+                    time: count.key.time,
+                    count: count.count,
+                }),
+            }
+        }
+
+        Self {
+            bucket: bucket.unit(),
+            from,
+            to,
+            series: series.into_values().collect(),
+        }
+    }
+}
+
+fn series_key(split: Option<TimeSeriesSplit>, value: &Bson) -> (String, Option<ApiLocalizedString>) {
+    let unknown = || ("unknown".to_string(), None);
+
+    match split {
+        None => ("all".to_string(), None),
+        Some(TimeSeriesSplit::Category) => {
+            let Some(category) = bson_u32(value).and_then(DutyCategory::from_u32) else {
+                return unknown();
+            };
+            let category = category.pf_category();
+            (category.as_str().to_string(), Some(category.name().into()))
+        }
+        Some(TimeSeriesSplit::Duty) => {
+            let Bson::Array(parts) = value else {
+                return unknown();
+            };
+            let parts: Vec<u32> = parts.iter().filter_map(bson_u32).collect();
+            let [duty_type, category, duty] = parts[..] else {
+                return unknown();
+            };
+            let key = format!("{}/{}/{}", duty_type, category, duty);
+            let (Some(kind), Some(category)) = (
+                DutyType::from_u8(duty_type as u8),
+                DutyCategory::from_u32(category),
+            ) else {
+                return (key, None);
+            };
+            let name = ApiLocalizedString::from_fn(|langs| {
+                crate::ffxiv::duty_name(kind, category, duty as u16, langs).into_owned()
+            });
+            (key, Some(name))
+        }
+        Some(TimeSeriesSplit::DataCentre) => {
+            let data_centre = bson_u32(value)
+                .and_then(crate::ffxiv::world)
+                .map(|world| world.data_centre);
+            match data_centre {
+                Some(name) => (name.to_string(), None),
+                None => unknown(),
+            }
+        }
+    }
+}
+
+fn bson_u32(value: &Bson) -> Option<u32> {
+    match value {
+        Bson::Int32(i) => u32::try_from(*i).ok(),
+        Bson::Int64(i) => u32::try_from(*i).ok(),
+        _ => None,
+    }
+}
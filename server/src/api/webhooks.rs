@@ -0,0 +1,153 @@
+use super::admin::constant_time_eq;
+use super::ListingsQuery;
+use crate::web::State;
+use crate::webhooks::{self, Limit, SavedFilters, Webhooks};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use warp::filters::BoxedFilter;
+use warp::http::StatusCode;
+use warp::{Filter, Reply};
+
+/// Saved searches, if `[webhooks]` is configured.
+pub fn webhooks(state: Arc<State>) -> BoxedFilter<(impl Reply,)> {
+    let enabled = {
+        let state = state.clone();
+        warp::any()
+            .and_then(move || {
+                let enabled = state.config.webhooks.is_some();
+                async move {
+                    if enabled {
+                        Ok(())
+                    } else {
+                        Err(warp::reject::not_found())
+                    }
+                }
+            })
+            .untuple_one()
+    };
+
+    warp::path("webhooks")
+        .and(enabled)
+        .and(register(state.clone()).or(delete(state.clone())))
+        .boxed()
+}
+
+#[derive(Deserialize)]
+struct RegisterRequest {
+    url: String,
+    /// `Accept-Language`-style codes to write the embeds in.
+    lang: Option<String>,
+    /// The same filters as `/api/listings`.
+    #[serde(default)]
+    query: ListingsQuery,
+}
+
+#[derive(Serialize)]
+struct Registered {
+    id: String,
+    token: String,
+    /// When the search is removed. Register it again to keep it.
+    expires_at: DateTime<Utc>,
+}
+
+fn register(state: Arc<State>) -> BoxedFilter<(impl Reply,)> {
+    async fn logic(
+        state: Arc<State>,
+        request: RegisterRequest,
+        remote: Option<SocketAddr>,
+        forwarded_for: Option<String>,
+    ) -> Result<warp::reply::Response, Infallible> {
+        let Some(config) = &state.config.webhooks else {
+            return Ok(StatusCode::NOT_FOUND.into_response());
+        };
+
+        let url = match webhooks::validate_url(config, &request.url) {
+            Ok(url) => url,
+            Err(e) => return Ok(warp::reply::with_status(e, StatusCode::BAD_REQUEST).into_response()),
+        };
+        let normalised = webhooks::normalise_url(&url);
+        let filters = SavedFilters::new(&request.query);
+        let ip = webhooks::client_ip(config, remote, forwarded_for.as_deref()).map(|ip| ip.to_string());
+
+        // checked before posting the test message, and again as the search is saved
+        if let Err(limit) = state.webhooks.check_limits(config, &normalised, &filters, &ip) {
+            return Ok(limit_reply(limit));
+        }
+
+        if let Err(e) = webhooks::verify(&url).await {
+            return Ok(warp::reply::with_status(e, StatusCode::BAD_REQUEST).into_response());
+        }
+
+        match Webhooks::register(&state, normalised, request.lang, filters, ip).await {
+            Ok(search) => Ok(warp::reply::with_status(
+                warp::reply::json(&Registered {
+                    id: search.id,
+                    token: search.token,
+                    expires_at: search.expires_at,
+                }),
+                StatusCode::CREATED,
+            )
+            .into_response()),
+            Err(e) => match e.downcast_ref::<Limit>() {
+                Some(&limit) => Ok(limit_reply(limit)),
+                None => {
+                    eprintln!("error saving search: {:#?}", e);
+                    Ok(StatusCode::INTERNAL_SERVER_ERROR.into_response())
+                }
+            },
+        }
+    }
+
+    warp::post()
+        .and(warp::path::end())
+        .and(warp::body::content_length_limit(16 * 1024))
+        .and(warp::body::json())
+        .and(warp::addr::remote())
+        .and(warp::header::optional::<String>("x-forwarded-for"))
+        .and_then(move |request, remote, forwarded_for| logic(state.clone(), request, remote, forwarded_for))
+        .boxed()
+}
+
+fn limit_reply(limit: Limit) -> warp::reply::Response {
+    let status = match limit {
+        Limit::Duplicate => StatusCode::CONFLICT,
+        Limit::PerIp | Limit::PerUrl => StatusCode::TOO_MANY_REQUESTS,
+        Limit::Total => StatusCode::SERVICE_UNAVAILABLE,
+    };
+
+    warp::reply::with_status(limit.message(), status).into_response()
+}
+
+/// Deletes a saved search, given the token it was registered with as a
+/// bearer token.
+fn delete(state: Arc<State>) -> BoxedFilter<(impl Reply,)> {
+    async fn logic(state: Arc<State>, id: String, header: Option<String>) -> Result<warp::reply::Response, Infallible> {
+        let authorized = match (state.webhooks.token(&id), header) {
+            (Some(token), Some(header)) => {
+                constant_time_eq(header.as_bytes(), format!("Bearer {}", token).as_bytes())
+            }
+            _ => false,
+        };
+        if !authorized {
+            return Ok(StatusCode::NOT_FOUND.into_response());
+        }
+
+        match Webhooks::remove(&state, &id).await {
+            Ok(()) => Ok(StatusCode::NO_CONTENT.into_response()),
+            Err(e) => {
+                eprintln!("error removing saved search: {:#?}", e);
+                Ok(StatusCode::INTERNAL_SERVER_ERROR.into_response())
+            }
+        }
+    }
+
+    warp::delete()
+        .and(warp::path::param::<String>())
+        .and(warp::path::end())
+        .and(warp::header::optional::<String>("authorization"))
+        .and_then(move |id, header| logic(state.clone(), id, header))
+        .boxed()
+}
@@ -1,18 +1,205 @@
 use serde::Deserialize;
 use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::time::Duration;
 
 #[derive(Deserialize)]
 pub struct Config {
     pub web: Web,
     pub mongo: Mongo,
+    #[serde(default)]
+    pub stats: Stats,
+    #[serde(default)]
+    pub admin: Option<Admin>,
+    #[serde(default)]
+    pub game_data: Option<GameData>,
+    #[serde(default)]
+    pub webhooks: Option<Webhooks>,
+}
+
+impl Config {
+    /// Rejects values that parse but can't work.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        // a zero interval would regenerate the stats in a hot loop
+        anyhow::ensure!(self.stats.interval_secs > 0, "stats.interval_secs must be greater than 0");
+        anyhow::ensure!(self.stats.retry_secs > 0, "stats.retry_secs must be greater than 0");
+        anyhow::ensure!(
+            self.stats.max_retry_secs >= self.stats.retry_secs,
+            "stats.max_retry_secs must be at least stats.retry_secs"
+        );
+
+        if let Some(admin) = &self.admin {
+            anyhow::ensure!(
+                admin.token.trim().chars().count() >= MIN_ADMIN_TOKEN_LEN,
+                "admin.token must be at least {} characters",
+                MIN_ADMIN_TOKEN_LEN
+            );
+            anyhow::ensure!(!admin.is_placeholder(), "admin.token must not be a placeholder");
+        }
+
+        if let Some(webhooks) = &self.webhooks {
+            anyhow::ensure!(
+                (1..=3650).contains(&webhooks.expire_days),
+                "webhooks.expire_days must be between 1 and 3650"
+            );
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Deserialize)]
 pub struct Web {
     pub host: SocketAddr,
+    /// The site's public url, for absolute links in feeds and webhooks. The
+    /// listings feed is only served when this is set.
+    #[serde(default)]
+    pub base_url: Option<String>,
 }
 
 #[derive(Deserialize)]
 pub struct Mongo {
     pub url: String,
 }
+
+#[derive(Deserialize)]
+#[serde(default)]
+pub struct Stats {
+    /// How often each stats window is regenerated, in seconds.
+    pub interval_secs: u64,
+    /// Delay before the first retry after a failed generation, in seconds.
+    /// Doubles with each consecutive failure.
+    pub retry_secs: u64,
+    /// Upper bound for the retry delay, in seconds.
+    pub max_retry_secs: u64,
+    /// Hide host names and home worlds on the stats page and API.
+    pub anonymise_hosts: bool,
+    /// Content ids excluded from the stats, in addition to self-service opt-outs.
+    pub opt_out: Vec<u32>,
+}
+
+impl Default for Stats {
+    fn default() -> Self {
+        Self {
+            interval_secs: 60 * 60 * 12,
+            retry_secs: 60,
+            max_retry_secs: 60 * 60,
+            anonymise_hosts: false,
+            opt_out: Vec::new(),
+        }
+    }
+}
+
+impl Stats {
+    pub fn interval(&self) -> Duration {
+        Duration::from_secs(self.interval_secs)
+    }
+
+    pub fn retry_delay(&self) -> Duration {
+        Duration::from_secs(self.retry_secs)
+    }
+
+    pub fn max_retry_delay(&self) -> Duration {
+        Duration::from_secs(self.max_retry_secs)
+    }
+}
+
+/// Admin tokens shorter than this are rejected, as they could be guessed.
+const MIN_ADMIN_TOKEN_LEN: usize = 16;
+
+/// Words from example tokens that someone might leave in place.
+const PLACEHOLDER_WORDS: [&str; 3] = ["changeme", "placeholder", "example"];
+
+#[derive(Deserialize)]
+pub struct Admin {
+    /// Bearer token required for `/api/admin` endpoints. They are disabled if unset.
+    pub token: String,
+}
+
+impl Admin {
+    /// Whether the token looks like one copied from an example, or is a
+    /// single repeated character.
+    fn is_placeholder(&self) -> bool {
+        let token: String = self
+            .token
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .collect();
+        let mut chars = token.chars();
+        let repeated = chars.next().is_some_and(|first| chars.all(|c| c == first));
+
+        repeated || PLACEHOLDER_WORDS.iter().any(|word| token.contains(word))
+    }
+}
+
+#[derive(Deserialize)]
+pub struct GameData {
+    /// Directory of datasheet CSVs, loaded at startup and on `SIGHUP`.
+    /// The built-in tables are used for anything missing from it. They have
+    /// no Korean or Chinese text, so those languages fall back to English
+    /// unless the regional clients' sheets are supplied here.
+    pub path: PathBuf,
+}
+
+#[derive(Deserialize)]
+#[serde(default)]
+pub struct Webhooks {
+    /// Hosts that saved searches may post to, over https.
+    pub allowed_hosts: Vec<String>,
+    /// The most saved searches that can be registered.
+    pub max_searches: usize,
+    /// The most saved searches a single client address can register.
+    pub max_searches_per_ip: usize,
+    /// The most saved searches that can post to the same webhook.
+    pub max_searches_per_url: usize,
+    /// How long a saved search lasts before it's removed, in days.
+    pub expire_days: u64,
+    /// Take the client's address from the last `X-Forwarded-For` entry, for
+    /// when the server is behind a reverse proxy.
+    pub trust_forwarded_for: bool,
+    /// Minimum time between two posts to the same webhook, in milliseconds.
+    pub min_interval_ms: u64,
+    /// How many times a post is tried before it's dropped.
+    pub max_attempts: u32,
+    /// Delay before the first retry, in milliseconds. Doubles with each retry.
+    pub retry_ms: u64,
+    /// How many posts can wait for a webhook before new listings are dropped.
+    pub queue_size: usize,
+}
+
+impl Default for Webhooks {
+    fn default() -> Self {
+        Self {
+            allowed_hosts: vec![
+                "discord.com".to_string(),
+                "discordapp.com".to_string(),
+                "canary.discord.com".to_string(),
+                "ptb.discord.com".to_string(),
+            ],
+            max_searches: 1000,
+            max_searches_per_ip: 5,
+            max_searches_per_url: 5,
+            expire_days: 30,
+            trust_forwarded_for: false,
+            min_interval_ms: 2000,
+            max_attempts: 5,
+            retry_ms: 1000,
+            queue_size: 50,
+        }
+    }
+}
+
+impl Webhooks {
+    pub fn min_interval(&self) -> Duration {
+        Duration::from_millis(self.min_interval_ms)
+    }
+
+    pub fn retry_delay(&self) -> Duration {
+        Duration::from_millis(self.retry_ms)
+    }
+
+    pub fn expire_after(&self) -> chrono::Duration {
+        chrono::Duration::days(self.expire_days as i64)
+    }
+}
@@ -0,0 +1,248 @@
+//! Tags that recruiters write into descriptions in free text, like "prog P5",
+//! "farm", "no sprouts" or "PF strats".
+
+use crate::ffxiv::{Language, LanguageChain};
+use crate::sestring_ext::SeStringExt;
+use serde::{Deserialize, Serialize};
+use sestring::SeString;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Tag {
+    Farm,
+    Clear,
+    Reclear,
+    Prog,
+    Practice,
+    Fresh,
+    Experienced,
+    NoSprouts,
+    VoiceChat,
+    Uptime,
+}
+
+impl Tag {
+    pub const ALL: [Self; 10] = [
+        Self::Farm,
+        Self::Clear,
+        Self::Reclear,
+        Self::Prog,
+        Self::Practice,
+        Self::Fresh,
+        Self::Experienced,
+        Self::NoSprouts,
+        Self::VoiceChat,
+        Self::Uptime,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Farm => "farm",
+            Self::Clear => "clear",
+            Self::Reclear => "reclear",
+            Self::Prog => "prog",
+            Self::Practice => "practice",
+            Self::Fresh => "fresh",
+            Self::Experienced => "experienced",
+            Self::NoSprouts => "no_sprouts",
+            Self::VoiceChat => "voice_chat",
+            Self::Uptime => "uptime",
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Farm => "farm",
+            Self::Clear => "clear",
+            Self::Reclear => "reclear",
+            Self::Prog => "prog",
+            Self::Practice => "practice",
+            Self::Fresh => "fresh",
+            Self::Experienced => "experienced",
+            Self::NoSprouts => "no sprouts",
+            Self::VoiceChat => "voice chat",
+            Self::Uptime => "uptime",
+        }
+    }
+
+    pub fn from_code(tag: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|t| t.as_str() == tag)
+    }
+}
+
+/// Phrases for each tag, matched against whole words of the lowercased
+/// description. Japanese has no spaces, so those are matched anywhere.
+const PHRASES: &[(Tag, &[&str])] = &[
+    (Tag::Farm, &["farm", "farming", "消化", "周回"]),
+    (Tag::Reclear, &["reclear", "re clear", "reclears", "再クリア"]),
+    (Tag::Clear, &["clear party", "clear run", "for clear", "clear pf", "クリア目的"]),
+    (Tag::Prog, &["prog", "progression", "learning"]),
+    (Tag::Practice, &["practice", "practise", "練習"]),
+    (Tag::Fresh, &["fresh", "blind", "first time", "first clear", "初見", "未予習"]),
+    (Tag::Experienced, &["exp", "experienced", "exp only", "経験者"]),
+    (Tag::NoSprouts, &["no sprout", "no sprouts", "no new players", "初心者不可"]),
+    (Tag::VoiceChat, &["vc", "voice", "voice chat", "discord", "ボイチャ", "ボイスチャット"]),
+    (Tag::Uptime, &["uptime", "max uptime"]),
+];
+
+/// Well-known strats, by the phrase that names them.
+const STRATS: &[(&str, &str)] = &[
+    ("pf strat", "PF"),
+    ("pf strats", "PF"),
+    ("game8", "Game8"),
+    ("hector", "Hector"),
+    ("nukemaru", "Nukemaru"),
+    ("toxic friends", "Toxic Friends"),
+    ("toxic strat", "Toxic Friends"),
+    ("toxic strats", "Toxic Friends"),
+    ("lpdu", "LPDU"),
+    ("naur", "NAUR"),
+    // on its own, this is more likely the data centre
+    ("elemental strat", "Elemental"),
+    ("elemental strats", "Elemental"),
+    ("raidplan", "Raidplan"),
+];
+
+/// Words that name a point in a fight other than a phase number.
+const PROG_POINTS: &[&str] = &["door", "final", "enrage", "adds", "intermission", "transition", "sanity"];
+
+/// Every strat, for filters.
+pub fn strats() -> Vec<&'static str> {
+    let mut strats: Vec<&'static str> = Vec::new();
+    for &(_, strat) in STRATS {
+        if !strats.contains(&strat) {
+            strats.push(strat);
+        }
+    }
+
+    strats
+}
+
+/// The prog points offered in filters: the phases of most fights, then the
+/// named points.
+pub fn prog_points() -> Vec<String> {
+    (1..=7)
+        .map(|phase| format!("P{}", phase))
+        .chain(PROG_POINTS.iter().map(|point| point.to_string()))
+        .collect()
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DescriptionTags {
+    pub tags: Vec<Tag>,
+    /// Where a prog party is at, like "P5" or "enrage".
+    pub prog_point: Option<String>,
+    pub strat: Option<&'static str>,
+}
+
+impl DescriptionTags {
+    pub fn has(&self, tag: Tag) -> bool {
+        self.tags.contains(&tag)
+    }
+
+    /// Whether anything was found, including a prog point or strat.
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty() && self.prog_point.is_none() && self.strat.is_none()
+    }
+
+    pub fn html_tags(&self) -> String {
+        self.tags
+            .iter()
+            .map(|tag| tag.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Analyses the description's text, with auto-translate phrases in English.
+pub fn analyse(description: &SeString) -> DescriptionTags {
+    analyse_text(&description.full_text(&LanguageChain::from(Language::English)))
+}
+
+pub fn analyse_text(text: &str) -> DescriptionTags {
+    let lower = text.to_lowercase();
+    let words: Vec<&str> = lower
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect();
+    // padded so that phrases only match whole words
+    let joined = format!(" {} ", words.join(" "));
+
+    let matches = |phrase: &str| {
+        if phrase.is_ascii() {
+            joined.contains(&format!(" {} ", phrase))
+        } else {
+            lower.contains(phrase)
+        }
+    };
+
+    let mut tags: Vec<Tag> = PHRASES
+        .iter()
+        .filter(|(_, phrases)| phrases.iter().any(|phrase| matches(phrase)))
+        .map(|&(tag, _)| tag)
+        .collect();
+
+    // "c41" and the like describe how many in the party have cleared
+    if !tags.contains(&Tag::Clear) && words.iter().any(|word| is_clear_count(word)) {
+        tags.push(Tag::Clear);
+    }
+
+    // a reclear is also a clear party, but not the other way around
+    if tags.contains(&Tag::Reclear) {
+        tags.retain(|&tag| tag != Tag::Clear);
+    }
+
+    tags.sort();
+
+    let prog_point = if tags.contains(&Tag::Prog) || tags.contains(&Tag::Practice) {
+        prog_point(&words)
+    } else {
+        None
+    };
+
+    let strat = STRATS
+        .iter()
+        .find(|(phrase, _)| matches(phrase))
+        .map(|&(_, strat)| strat);
+
+    DescriptionTags {
+        tags,
+        prog_point,
+        strat,
+    }
+}
+
+fn prog_point(words: &[&str]) -> Option<String> {
+    for (i, word) in words.iter().enumerate() {
+        if let Some(phase) = phase_number(word) {
+            return Some(format!("P{}", phase));
+        }
+
+        if *word == "phase" {
+            if let Some(phase) = words.get(i + 1).filter(|next| next.parse::<u8>().is_ok()) {
+                return Some(format!("P{}", phase));
+            }
+        }
+    }
+
+    words
+        .iter()
+        .find(|word| PROG_POINTS.contains(word))
+        .map(|word| word.to_string())
+}
+
+/// "p5" is a phase, but "p5s" is a savage floor.
+fn phase_number(word: &str) -> Option<u8> {
+    let number = word.strip_prefix('p')?;
+    if number.is_empty() || number.len() > 2 {
+        return None;
+    }
+
+    number.parse().ok()
+}
+
+fn is_clear_count(word: &str) -> bool {
+    word.len() == 3
+        && word.starts_with('c')
+        && word[1..].bytes().all(|b| b.is_ascii_digit())
+}
@@ -1,36 +1,56 @@
 use std::borrow::Cow;
-use std::{cmp::Ordering, str::FromStr};
-use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use serde::{Deserialize, Serialize};
 use crate::listing::{DutyCategory, DutyType};
 
 pub use self::{
     auto_translate::AUTO_TRANSLATE, duties::DUTIES, jobs::JOBS, roulettes::ROULETTES,
-    territory_names::TERRITORY_NAMES, treasure_maps::TREASURE_MAPS, worlds::WORLDS,
+    territory_names::{FATE_TERRITORIES, TERRITORY_NAMES}, treasure_maps::TREASURE_MAPS, worlds::WORLDS,
 };
 
 pub mod auto_translate;
+pub mod datasheet;
 pub mod duties;
+pub mod game_data;
 pub mod jobs;
 pub mod roulettes;
+pub mod search;
+pub mod special_duties;
 pub mod territory_names;
 pub mod treasure_maps;
 pub mod worlds;
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Language {
     English,
     Japanese,
     German,
     French,
+    Korean,
+    ChineseSimplified,
+    ChineseTraditional,
 }
 
 impl Language {
+    pub const ALL: [Language; 7] = [
+        Self::English,
+        Self::Japanese,
+        Self::German,
+        Self::French,
+        Self::Korean,
+        Self::ChineseSimplified,
+        Self::ChineseTraditional,
+    ];
+
     pub fn code(&self) -> &'static str {
         match self {
             Self::English => "en",
             Self::Japanese => "ja",
             Self::German => "de",
             Self::French => "fr",
+            Self::Korean => "ko",
+            Self::ChineseSimplified => "zh-Hans",
+            Self::ChineseTraditional => "zh-Hant",
         }
     }
 
@@ -40,195 +60,467 @@ impl Language {
             Self::Japanese => "日本語",
             Self::German => "deutsch",
             Self::French => "français",
+            Self::Korean => "한국어",
+            Self::ChineseSimplified => "简体中文",
+            Self::ChineseTraditional => "繁體中文",
+        }
+    }
+
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let tag = tag.trim().to_ascii_lowercase();
+        let mut subtags = tag.split(['-', '_']);
+        match subtags.next()? {
+            "en" => Some(Self::English),
+            "ja" => Some(Self::Japanese),
+            "de" => Some(Self::German),
+            "fr" => Some(Self::French),
+            "ko" => Some(Self::Korean),
+            // `zh` alone is simplified, as are `zh-CN` and `zh-SG`
+            "zh" => {
+                let traditional = subtags.any(|subtag| matches!(subtag, "hant" | "tw" | "hk" | "mo"));
+                Some(if traditional {
+                    Self::ChineseTraditional
+                } else {
+                    Self::ChineseSimplified
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The languages to show text in, most preferred first. Text missing in one
+/// language is looked up in the next.
+#[derive(Debug, Clone)]
+pub struct LanguageChain(Vec<Language>);
+
+impl LanguageChain {
+    /// Tried after the requested languages. Every entry of the tables has text in these.
+    const FALLBACK: [Language; 2] = [Language::English, Language::Japanese];
+
+    pub fn new(langs: impl IntoIterator<Item = Language>) -> Self {
+        let mut chain = Vec::new();
+        for lang in langs.into_iter().chain(Self::FALLBACK) {
+            if !chain.contains(&lang) {
+                chain.push(lang);
+            }
         }
+
+        Self(chain)
     }
 
+    /// Parses an `Accept-Language` header (or the `lang` cookie), most
+    /// preferred first. Entries with a weight of 0 are left out.
     pub fn from_codes(val: Option<&str>) -> Self {
         let val = match val {
             Some(v) => v,
-            None => return Self::English,
+            None => return Self::new([]),
         };
 
-        let mut parts: Vec<(&str, f32)> = val
+        let mut parts: Vec<(&str, u16)> = val
             .split(',')
-            .map(|part| {
-                let sub_parts: Vec<&str> = part.split(';').collect();
-                if sub_parts.len() == 1 {
-                    (sub_parts[0], 1.0)
-                } else if let Ok(val) = f32::from_str(sub_parts[0]) {
-                    (sub_parts[0], val)
-                } else {
-                    (sub_parts[0], 0.0)
-                }
+            .filter_map(|part| {
+                let mut params = part.split(';');
+                let tag = params.next()?.trim();
+                let weight = weight(params)?;
+                (weight > 0).then_some((tag, weight))
             })
             .collect();
-        parts.sort_unstable_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Less));
-        if parts.len() == 0 {
-            return Self::English;
-        }
+        // stable, so equal weights keep the order they were given in
+        parts.sort_by_key(|&(_, weight)| std::cmp::Reverse(weight));
 
-        for (lang, _) in parts {
-            let first = lang.split('-').next().unwrap();
-            match first {
-                "en" => return Self::English,
-                "ja" => return Self::Japanese,
-                "de" => return Self::German,
-                "fr" => return Self::French,
-                _ => {}
-            }
-        }
+        Self::new(parts.into_iter().filter_map(|(lang, _)| Language::from_tag(lang)))
+    }
+
+    /// Just `lang`, without falling back, for when a missing translation
+    /// should stay missing.
+    pub fn only(lang: Language) -> Self {
+        Self(vec![lang])
+    }
+
+    pub fn primary(&self) -> Language {
+        self.0[0]
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Language> + '_ {
+        self.0.iter().copied()
+    }
+
+    pub fn code(&self) -> &'static str {
+        self.primary().code()
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.primary().name()
+    }
+}
+
+/// The `q` parameter of an `Accept-Language` entry in thousandths, as in
+/// RFC 9110: 1 when it's missing, and `None` when it's malformed.
+fn weight<'a>(mut params: impl Iterator<Item = &'a str>) -> Option<u16> {
+    let q = params.find_map(|param| {
+        let (name, value) = param.split_once('=')?;
+        name.trim().eq_ignore_ascii_case("q").then(|| value.trim())
+    });
+    let Some(q) = q else {
+        return Some(1000);
+    };
+
+    let (whole, fraction) = q.split_once('.').unwrap_or((q, ""));
+    if fraction.len() > 3 || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
 
-        Self::English
+    let thousandths = fraction
+        .bytes()
+        .chain(std::iter::repeat(b'0'))
+        .take(3)
+        .fold(0, |n, b| n * 10 + u16::from(b - b'0'));
+    match (whole, thousandths) {
+        ("0", n) => Some(n),
+        ("1", 0) => Some(1000),
+        _ => None,
     }
 }
 
-#[derive(Debug, Copy, Clone, Serialize)]
+impl From<Language> for LanguageChain {
+    fn from(lang: Language) -> Self {
+        Self::new([lang])
+    }
+}
+
+/// Tables from the global client's data leave the regional languages to
+/// `Default`.
+#[derive(Debug, Default, Copy, Clone, Serialize)]
 pub struct LocalisedText {
     pub en: &'static str,
     pub ja: &'static str,
     pub de: &'static str,
     pub fr: &'static str,
+    /// Only available from the Korean client's data. Empty if unknown.
+    pub ko: &'static str,
+    /// Only available from the Chinese client's data. Empty if unknown.
+    pub zh_hans: &'static str,
+    /// Only available from the Traditional Chinese client's data. Empty if unknown.
+    pub zh_hant: &'static str,
 }
 
 impl LocalisedText {
-    pub fn text(&self, lang: &Language) -> &'static str {
+    /// The text in `lang`, empty if it isn't translated.
+    pub fn get(&self, lang: Language) -> &'static str {
         match lang {
             Language::English => self.en,
             Language::Japanese => self.ja,
             Language::German => self.de,
             Language::French => self.fr,
+            Language::Korean => self.ko,
+            Language::ChineseSimplified => self.zh_hans,
+            Language::ChineseTraditional => self.zh_hant,
+        }
+    }
+
+    /// The text in the first language of `langs` that has a translation,
+    /// empty if none do.
+    pub fn text(&self, langs: &LanguageChain) -> &'static str {
+        langs
+            .iter()
+            .map(|lang| self.get(lang))
+            .find(|text| !text.is_empty())
+            .unwrap_or_default()
+    }
+}
+
+/// How many players a duty is for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PartySize {
+    Unknown,
+    /// Up to four players.
+    Light,
+    /// Up to eight players.
+    Full,
+    /// Several parties, like alliance raids.
+    Alliance,
+}
+
+impl PartySize {
+    pub fn from_members(members_per_party: u32, alliance: bool) -> Self {
+        match members_per_party {
+            _ if alliance => Self::Alliance,
+            0 => Self::Unknown,
+            1..=4 => Self::Light,
+            _ => Self::Full,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Unknown => "unknown",
+            Self::Light => "light",
+            Self::Full => "full",
+            Self::Alliance => "alliance",
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Unknown => "Unknown",
+            Self::Light => "Light party",
+            Self::Full => "Full party",
+            Self::Alliance => "Alliance",
+        }
+    }
+}
+
+/// The expansion a duty was released in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Expansion {
+    ARealmReborn,
+    Heavensward,
+    Stormblood,
+    Shadowbringers,
+    Endwalker,
+    Dawntrail,
+}
+
+impl Expansion {
+    /// Converts a row of the `ExVersion` sheet.
+    pub fn from_u32(ex_version: u32) -> Option<Self> {
+        Some(match ex_version {
+            0 => Self::ARealmReborn,
+            1 => Self::Heavensward,
+            2 => Self::Stormblood,
+            3 => Self::Shadowbringers,
+            4 => Self::Endwalker,
+            5 => Self::Dawntrail,
+            _ => return None,
+        })
+    }
+
+    pub const ALL: [Self; 6] = [
+        Self::ARealmReborn,
+        Self::Heavensward,
+        Self::Stormblood,
+        Self::Shadowbringers,
+        Self::Endwalker,
+        Self::Dawntrail,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::ARealmReborn => "a_realm_reborn",
+            Self::Heavensward => "heavensward",
+            Self::Stormblood => "stormblood",
+            Self::Shadowbringers => "shadowbringers",
+            Self::Endwalker => "endwalker",
+            Self::Dawntrail => "dawntrail",
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::ARealmReborn => "A Realm Reborn",
+            Self::Heavensward => "Heavensward",
+            Self::Stormblood => "Stormblood",
+            Self::Shadowbringers => "Shadowbringers",
+            Self::Endwalker => "Endwalker",
+            Self::Dawntrail => "Dawntrail",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct WorldInfo {
+    pub id: u32,
+    pub name: &'static str,
+    pub data_centre: &'static str,
+    pub region: Region,
+    /// Whether characters can visit this world with data centre travel.
+    pub travel: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Region {
+    NorthAmerica,
+    Europe,
+    Japan,
+    Oceania,
+    China,
+    Korea,
+    Other(u32),
+}
+
+impl Region {
+    /// Converts the `Region` column of `WorldDCGroupType`.
+    pub fn from_u32(region: u32) -> Self {
+        match region {
+            1 => Self::Japan,
+            2 => Self::NorthAmerica,
+            3 => Self::Europe,
+            4 => Self::Oceania,
+            5 => Self::China,
+            6 => Self::Korea,
+            x => Self::Other(x),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Japan => "japan",
+            Self::NorthAmerica => "north america",
+            Self::Europe => "europe",
+            Self::Oceania => "oceania",
+            Self::China => "china",
+            Self::Korea => "korea",
+            Self::Other(_) => "other",
         }
     }
 }
 
 pub fn duty(duty: u32) -> Option<&'static duties::DutyInfo> {
-    crate::ffxiv::DUTIES
-        .get(&duty)
+    game_data::get()
+        .and_then(|data| data.duties.get(&duty))
+        .or_else(|| crate::ffxiv::DUTIES.get(&duty))
         .or_else(|| old::OLD_DUTIES.get(&duty))
 }
 
 pub fn roulette(roulette: u32) -> Option<&'static roulettes::RouletteInfo> {
-    crate::ffxiv::ROULETTES
-        .get(&roulette)
+    game_data::get()
+        .and_then(|data| data.roulettes.get(&roulette))
+        .or_else(|| crate::ffxiv::ROULETTES.get(&roulette))
         .or_else(|| old::OLD_ROULETTES.get(&roulette))
 }
 
+pub fn territory_name(territory: u32) -> Option<&'static LocalisedText> {
+    game_data::get()
+        .and_then(|data| data.territory_names.get(&territory))
+        .or_else(|| crate::ffxiv::TERRITORY_NAMES.get(&territory))
+}
+
+pub fn treasure_map(map: u32) -> Option<&'static LocalisedText> {
+    game_data::get()
+        .and_then(|data| data.treasure_maps.get(&map))
+        .or_else(|| crate::ffxiv::TREASURE_MAPS.get(&map))
+}
+
+pub fn world(world: u32) -> Option<&'static WorldInfo> {
+    game_data::get()
+        .and_then(|data| data.worlds.get(&world))
+        .or_else(|| crate::ffxiv::WORLDS.get(&world))
+}
+
+/// Whether any duty has a known party size. The checked-in table doesn't
+/// until it is regenerated, so filters on duty details are hidden without
+/// game data.
+pub fn duty_details_known() -> bool {
+    let known = |duty: &duties::DutyInfo| duty.party_size != PartySize::Unknown;
+    game_data::get().is_some_and(|data| data.duties.values().any(known))
+        || crate::ffxiv::DUTIES.values().any(known)
+}
+
+/// Every known duty, ordered by id.
+pub fn all_duties() -> BTreeMap<u32, &'static duties::DutyInfo> {
+    merged([
+        Some(&*old::OLD_DUTIES),
+        Some(&*crate::ffxiv::DUTIES),
+        game_data::get().map(|data| &data.duties),
+    ])
+}
+
+/// Every known roulette, ordered by id.
+pub fn all_roulettes() -> BTreeMap<u32, &'static roulettes::RouletteInfo> {
+    merged([
+        Some(&*old::OLD_ROULETTES),
+        Some(&*crate::ffxiv::ROULETTES),
+        game_data::get().map(|data| &data.roulettes),
+    ])
+}
+
+/// Every known world, ordered by id.
+pub fn all_worlds() -> BTreeMap<u32, &'static WorldInfo> {
+    merged([
+        Some(&*crate::ffxiv::WORLDS),
+        game_data::get().map(|data| &data.worlds),
+    ])
+}
+
+/// Merges tables with later ones taking precedence, ordered by id.
+fn merged<V>(tables: impl IntoIterator<Item = Option<&'static HashMap<u32, V>>>) -> BTreeMap<u32, &'static V> {
+    let mut merged = BTreeMap::new();
+    for table in tables.into_iter().flatten() {
+        merged.extend(table.iter().map(|(k, v)| (*k, v)));
+    }
+
+    merged
+}
+
+/// Data centre names grouped by region, sorted by name.
+pub fn data_centres() -> Vec<(Region, Vec<&'static str>)> {
+    let mut regions: BTreeMap<Region, BTreeSet<&'static str>> = BTreeMap::new();
+    for world in all_worlds().into_values() {
+        regions.entry(world.region).or_default().insert(world.data_centre);
+    }
+
+    regions
+        .into_iter()
+        .map(|(region, data_centres)| (region, data_centres.into_iter().collect()))
+        .collect()
+}
+
+pub fn auto_translate_text(group: u32, key: u32) -> Option<&'static LocalisedText> {
+    game_data::get()
+        .and_then(|data| data.auto_translate.get(&(group, key)))
+        .or_else(|| crate::ffxiv::AUTO_TRANSLATE.get(&(group, key)))
+}
+
+/// A row of `UIColor`, used by colour payloads in SeStrings.
+#[derive(Debug, Clone, Copy)]
+pub struct UiColour {
+    /// RGBA.
+    pub foreground: u32,
+    /// RGBA.
+    pub glow: u32,
+}
+
+/// There is no built-in colour table, so colours need loaded game data.
+pub fn ui_colour(colour: u32) -> Option<&'static UiColour> {
+    game_data::get().and_then(|data| data.ui_colours.get(&colour))
+}
+
+/// The duty info for a listing's duty, including the irregular ids in
+/// `special_duties`.
+pub fn listing_duty(duty_type: DutyType, category: DutyCategory, duty: u16) -> Option<duties::DutyInfo> {
+    let regular = match duty_type {
+        DutyType::Normal => crate::ffxiv::duty(u32::from(duty)).copied(),
+        DutyType::Roulette => roulette(u32::from(duty)).map(|info| duties::DutyInfo {
+            name: info.name,
+            high_end: false,
+            content_kind: duties::ContentKind::DutyRoulette,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
+        }),
+        DutyType::Other => None,
+    };
+
+    regular.or_else(|| special_duties::find(duty_type, category, duty)?.info(duty))
+}
+
 pub fn duty_name<'a>(
     duty_type: DutyType,
     category: DutyCategory,
     duty: u16,
-    lang: Language,
+    langs: &LanguageChain,
 ) -> Cow<'a, str> {
-    match (duty_type, category) {
-        (DutyType::Other, DutyCategory::Fate) => {
-            if let Some(name) = crate::ffxiv::TERRITORY_NAMES.get(&u32::from(duty)) {
-                return Cow::from(name.text(&lang));
-            }
-
-            return Cow::from("FATEs");
-        }
-        (DutyType::Other, DutyCategory::TheHunt) => {
-            return Cow::from(match lang {
-                Language::English => "The Hunt",
-                Language::Japanese => "モブハント",
-                Language::German => "Hohe Jagd",
-                Language::French => "Contrats de chasse",
-            })
-        }
-        (_, DutyCategory::None) if duty == 0 => {
-            return Cow::from(match lang {
-                Language::English => "None",
-                Language::Japanese => "設定なし",
-                Language::German => "Nicht festgelegt",
-                Language::French => "Non spécifiée",
-            })
-        }
-        (DutyType::Other, DutyCategory::DeepDungeon) if duty == 1 => {
-            return Cow::from(match lang {
-                Language::English => "The Palace of the Dead",
-                Language::Japanese => "死者の宮殿",
-                Language::German => "Palast der Toten",
-                Language::French => "Palais des morts",
-            })
-        }
-        (DutyType::Other, DutyCategory::DeepDungeon) if duty == 2 => {
-            return Cow::from(match lang {
-                Language::English => "Heaven-on-High",
-                Language::Japanese => "アメノミハシラ",
-                Language::German => "Himmelssäule",
-                Language::French => "Pilier des Cieux",
-            })
-        }
-        (DutyType::Other, DutyCategory::DeepDungeon) if duty == 3 => {
-            return Cow::from(match lang {
-                Language::English => "Eureka Orthos",
-                Language::Japanese => "オルト・エウレカ",
-                Language::German => "Eureka Orthos",
-                Language::French => "Eurêka Orthos",
-            })
-        }
-        (DutyType::Normal, _) => {
-            if let Some(info) = crate::ffxiv::duty(u32::from(duty)) {
-                return Cow::from(info.name.text(&lang));
-            }
-        }
-        (DutyType::Roulette, _) => {
-            if let Some(info) = roulette(u32::from(duty)) {
-                return Cow::from(info.name.text(&lang));
-            }
-        }
-        // gold saucer duties appear to be all over the place. source in comments:
-        (_, DutyCategory::GoldSaucer) if duty == 11 => {
-            return Cow::from(match lang {
-                // Addon 2308
-                Language::English => "GATEs",
-                Language::Japanese => "G.A.T.E.",
-                Language::German => "GATEs",
-                Language::French => "JACTA",
-            })
-        }
-        (_, DutyCategory::GoldSaucer) if duty >= 12 && duty <= 19 => {
-            // in the sheet, the order is sagolii, del sol, tranquil, random
-            // in PF, random comes first:
-            let row = match duty {
-                12 | 16 => 21 + (duty - 12),
-                13..=15 => 18 + (duty - 13),
-                17..=19 => 22 + (duty - 17),
-                _ => 0,
-            };
-            if let Some(info) = roulette(u32::from(row)) {
-                return Cow::from(info.name.text(&lang));
-            }
-        }
-        (_, DutyCategory::GoldSaucer) if duty >= 20 && duty <= 26 => {
-            let row = match duty {
-                20 => 195,
-                21 => 756,
-                22 => 199,
-                23 => 645,
-                24 => 650,
-                25 => 768,
-                26 => 769,
-                _ => 0,
-            };
-
-            if let Some(info) = crate::ffxiv::duty(row) {
-                return Cow::from(info.name.text(&lang));
-            }
-        }
-        (_, DutyCategory::TreasureHunt) => {
-            if let Some(name) = crate::ffxiv::TREASURE_MAPS.get(&u32::from(duty)) {
-                return Cow::from(name.text(&lang));
-            }
-        }
-        _ => {}
+    // unknown duties are counted when listings are contributed, see `crate::unknown_duties`
+    match listing_duty(duty_type, category, duty) {
+        Some(info) => Cow::from(info.name.text(langs)),
+        None => Cow::from(format!("{:?}", category)),
     }
-
-    eprintln!(
-        "unknown type/category/duty: {:?}/{:?}/{}",
-        duty_type, category, duty
-    );
-    Cow::from(format!("{:?}", category))
 }
 
 mod old {
@@ -237,7 +529,7 @@ mod old {
     use crate::ffxiv::{
         duties::{ContentKind, DutyInfo},
         roulettes::RouletteInfo,
-        LocalisedText,
+        LocalisedText, PartySize,
     };
 
     lazy_static::lazy_static! {
@@ -248,9 +540,16 @@ mod old {
                     ja: "リットアティン強襲戦",
                     de: "Kap Westwind",
                     fr: "Le Cap Vendouest",
+                    ..Default::default()
                 },
                 high_end: false,
                 content_kind: ContentKind::Trials,
+                level: None,
+                item_level: None,
+                party_size: PartySize::Unknown,
+                expansion: None,
+                icon: None,
+                image: None,
             },
             83 => DutyInfo {
                 name: LocalisedText {
@@ -258,9 +557,16 @@ mod old {
                     ja: "皇都イシュガルド防衛戦",
                     de: "Der Schicksalsweg",
                     fr: "Le Siège de la sainte Cité d'Ishgard",
+                    ..Default::default()
                 },
                 high_end: false,
                 content_kind: ContentKind::Trials,
+                level: None,
+                item_level: None,
+                party_size: PartySize::Unknown,
+                expansion: None,
+                icon: None,
+                image: None,
             },
             143 => DutyInfo {
                 name: LocalisedText {
@@ -268,9 +574,16 @@ mod old {
                     ja: "ザ・フィースト (4対4 / カジュアルマッチ)",
                     de: "The Feast (4 gegen 4, Übungskampf)",
                     fr: "The Feast (4x4/entraînement)",
+                    ..Default::default()
                 },
                 high_end: false,
                 content_kind: ContentKind::PvP,
+                level: None,
+                item_level: None,
+                party_size: PartySize::Unknown,
+                expansion: None,
+                icon: None,
+                image: None,
             },
             145 => DutyInfo {
                 name: LocalisedText {
@@ -278,9 +591,16 @@ mod old {
                     ja: "ザ・フィースト (4対4 / ランクマッチ)",
                     de: "The Feast (4 gegen 4, gewertet)",
                     fr: "The Feast (4x4/classé)",
+                    ..Default::default()
                 },
                 high_end: false,
                 content_kind: ContentKind::PvP,
+                level: None,
+                item_level: None,
+                party_size: PartySize::Unknown,
+                expansion: None,
+                icon: None,
+                image: None,
             },
             201 => DutyInfo {
                 name: LocalisedText {
@@ -288,9 +608,16 @@ mod old {
                     ja: "ザ・フィースト (ウルヴズジェイル演習場：カスタムマッチ）",
                     de: "The Feast (Wolfshöhle: Schaukampf)",
                     fr: "The Feast (personnalisé/Festin des loups)",
+                    ..Default::default()
                 },
                 high_end: false,
                 content_kind: ContentKind::PvP,
+                level: None,
+                item_level: None,
+                party_size: PartySize::Unknown,
+                expansion: None,
+                icon: None,
+                image: None,
             },
             228 => DutyInfo {
                 name: LocalisedText {
@@ -298,9 +625,16 @@ mod old {
                     ja: "ザ・フィースト (4対4 / カジュアルマッチ)",
                     de: "The Feast (4 gegen 4, Übungskampf)",
                     fr: "The Feast (4x4/entraînement)",
+                    ..Default::default()
                 },
                 high_end: false,
                 content_kind: ContentKind::PvP,
+                level: None,
+                item_level: None,
+                party_size: PartySize::Unknown,
+                expansion: None,
+                icon: None,
+                image: None,
             },
             230 => DutyInfo {
                 name: LocalisedText {
@@ -308,9 +642,16 @@ mod old {
                     ja: "ザ・フィースト (4対4 / ランクマッチ)",
                     de: "The Feast (4 gegen 4, gewertet)",
                     fr: "The Feast (4x4/classé)",
+                    ..Default::default()
                 },
                 high_end: false,
                 content_kind: ContentKind::PvP,
+                level: None,
+                item_level: None,
+                party_size: PartySize::Unknown,
+                expansion: None,
+                icon: None,
+                image: None,
             },
             233 => DutyInfo {
                 name: LocalisedText {
@@ -318,9 +659,16 @@ mod old {
                     ja: "ザ・フィースト (ライケンウィード演習場：カスタムマッチ）",
                     de: "The Feast (Flechtenhain: Schaukampf)",
                     fr: "The Feast (personnalisé/Pré-de-lichen)",
+                    ..Default::default()
                 },
                 high_end: false,
                 content_kind: ContentKind::PvP,
+                level: None,
+                item_level: None,
+                party_size: PartySize::Unknown,
+                expansion: None,
+                icon: None,
+                image: None,
             },
             476 => DutyInfo {
                 name: LocalisedText {
@@ -328,9 +676,16 @@ mod old {
                     ja: "ザ・フィースト (チーム用ランクマッチ)",
                     de: "The Feast (Team, gewertet)",
                     fr: "The Feast (classé/équipe JcJ)",
+                    ..Default::default()
                 },
                 high_end: false,
                 content_kind: ContentKind::PvP,
+                level: None,
+                item_level: None,
+                party_size: PartySize::Unknown,
+                expansion: None,
+                icon: None,
+                image: None,
             },
             478 => DutyInfo {
                 name: LocalisedText {
@@ -338,9 +693,16 @@ mod old {
                     ja: "ザ・フィースト (ランクマッチ)",
                     de: "The Feast (gewertet)",
                     fr: "The Feast (classé)",
+                    ..Default::default()
                 },
                 high_end: false,
                 content_kind: ContentKind::PvP,
+                level: None,
+                item_level: None,
+                party_size: PartySize::Unknown,
+                expansion: None,
+                icon: None,
+                image: None,
             },
             479 => DutyInfo {
                 name: LocalisedText {
@@ -348,9 +710,16 @@ mod old {
                     ja: "ザ・フィースト (カジュアルマッチ)",
                     de: "The Feast (Übungskampf)",
                     fr: "The Feast (entraînement)",
+                    ..Default::default()
                 },
                 high_end: false,
                 content_kind: ContentKind::PvP,
+                level: None,
+                item_level: None,
+                party_size: PartySize::Unknown,
+                expansion: None,
+                icon: None,
+                image: None,
             },
             480 => DutyInfo {
                 name: LocalisedText {
@@ -358,9 +727,16 @@ mod old {
                     ja: "ザ・フィースト (クリスタルタワー演習場：カスタムマッチ）",
                     de: "The Feast (Kristallturm-Arena: Schaukampf)",
                     fr: "The Feast (personnalisé/Tour de Cristal)",
+                    ..Default::default()
                 },
                 high_end: false,
                 content_kind: ContentKind::PvP,
+                level: None,
+                item_level: None,
+                party_size: PartySize::Unknown,
+                expansion: None,
+                icon: None,
+                image: None,
             },
             580 => DutyInfo {
                 name: LocalisedText {
@@ -368,9 +744,16 @@ mod old {
                     ja: "ザ・フィースト (クリスタルタワー演習場：チーム用カスタムマッチ)",
                     de: "The Feast (Kristallturm-Arena: Team-Schaukampf) ",
                     fr: "The Feast (personnalisé/équipe JcJ/Tour de Cristal)",
+                    ..Default::default()
                 },
                 high_end: false,
                 content_kind: ContentKind::PvP,
+                level: None,
+                item_level: None,
+                party_size: PartySize::Unknown,
+                expansion: None,
+                icon: None,
+                image: None,
             },
             776 => DutyInfo {
                 name: LocalisedText {
@@ -378,9 +761,16 @@ mod old {
                     ja: "幻リヴァイアサン討滅戦",
                     de: "Traumprüfung - Leviathan",
                     fr: "Le Briseur de marées (irréel)",
+                    ..Default::default()
                 },
                 high_end: true,
                 content_kind: ContentKind::Trials,
+                level: None,
+                item_level: None,
+                party_size: PartySize::Unknown,
+                expansion: None,
+                icon: None,
+                image: None,
             },
             821 => DutyInfo {
                 name: LocalisedText {
@@ -388,9 +778,16 @@ mod old {
                     ja: "幻アルテマウェポン破壊作戦",
                     de: "Traumprüfung - Ultima",
                     fr: "Le fléau d'Ultima (irréel)",
+                    ..Default::default()
                 },
                 high_end: true,
                 content_kind: ContentKind::Trials,
+                level: None,
+                item_level: None,
+                party_size: PartySize::Unknown,
+                expansion: None,
+                icon: None,
+                image: None,
             },
             875 => DutyInfo {
                 name: LocalisedText {
@@ -398,9 +795,16 @@ mod old {
                     ja: "幻魔神セフィロト討滅戦",
                     de: "Traumprüfung - Sephirot",
                     fr: "Unité de contention S1P7 (irréel)",
+                    ..Default::default()
                 },
                 high_end: true,
                 content_kind: ContentKind::Trials,
+                level: None,
+                item_level: None,
+                party_size: PartySize::Unknown,
+                expansion: None,
+                icon: None,
+                image: None,
             },
         };
 
@@ -411,6 +815,7 @@ mod old {
                     ja: "ザ・フィースト (カジュアルマッチ)",
                     de: "The Feast (Übungskampf)",
                     fr: "The Feast (entraînement)",
+                    ..Default::default()
                 },
                 pvp: true,
             },
@@ -420,6 +825,7 @@ mod old {
                     ja: "ザ・フィースト (ランクマッチ)",
                     de: "The Feast (gewertet)",
                     fr: "The Feast (classé)",
+                    ..Default::default()
                 },
                 pvp: true,
             },
@@ -429,6 +835,7 @@ mod old {
                     ja: "ザ・フィースト (チーム用ランクマッチ)",
                     de: "The Feast (Team, gewertet)",
                     fr: "The Feast (classé/équipe JcJ)",
+                    ..Default::default()
                 },
                 pvp: true,
             },
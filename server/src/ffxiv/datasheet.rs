@@ -0,0 +1,577 @@
+//! Reads the lookup tables in `ffxiv` from game datasheets exported as CSV.
+//!
+//! The data directory holds one file per sheet and language, named like
+//! `ContentFinderCondition.en.csv`, in the layout SaintCoinach's `allrawexd`
+//! produces: a row of column indices, a row of column names, a row of column
+//! types and then one line per row, starting with the row id. Links to other
+//! sheets must be raw row ids. `test-data/game-data` has a small example with
+//! every sheet that is read.
+//!
+//! The Korean and Chinese clients have their own datasheets. Their languages are
+//! optional, and text missing from them is left empty.
+//!
+//! This module only depends on `std`, as it is also compiled into `build.rs`.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::Path;
+
+pub const LANGUAGES: [&str; 7] = ["en", "ja", "de", "fr", "ko", "chs", "cht"];
+
+/// The languages of the global client, which every data directory must have.
+const GLOBAL_LANGUAGES: usize = 4;
+
+/// A name in every language of `LANGUAGES`, in the same order.
+pub type Names = [String; 7];
+
+#[derive(Debug, Default)]
+pub struct Sheet {
+    columns: HashMap<String, usize>,
+    rows: BTreeMap<u32, Vec<String>>,
+}
+
+impl Sheet {
+    pub fn load(dir: &Path, name: &str, lang: &str) -> Result<Self, String> {
+        let path = dir.join(format!("{}.{}.csv", name, lang));
+        let text = std::fs::read_to_string(&path)
+            .map_err(|e| format!("could not read {}: {}", path.display(), e))?;
+        Self::parse(&text).map_err(|e| format!("could not parse {}: {}", path.display(), e))
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut records = parse_csv(text).into_iter();
+        let indices = records.next().ok_or("missing column index header")?;
+        let names = records.next().ok_or("missing column name header")?;
+        // column types
+        records.next().ok_or("missing column type header")?;
+
+        // the first column is the row id. columns can be referred to by name or by index.
+        let mut columns = HashMap::new();
+        for (i, index) in indices.iter().enumerate().skip(1) {
+            columns.insert(index.clone(), i);
+        }
+        for (i, name) in names.iter().enumerate().skip(1) {
+            if !name.is_empty() {
+                columns.entry(name.clone()).or_insert(i);
+            }
+        }
+
+        let mut rows = BTreeMap::new();
+        for record in records {
+            let Some(id) = record.first() else {
+                continue;
+            };
+            // sub-rows are written as `id.sub`, which none of the tables use
+            let Ok(id) = id.parse::<u32>() else {
+                continue;
+            };
+            rows.insert(id, record);
+        }
+
+        Ok(Self { columns, rows })
+    }
+
+    pub fn has_row(&self, row: u32) -> bool {
+        self.rows.contains_key(&row)
+    }
+
+    pub fn row_ids(&self) -> impl Iterator<Item = u32> + '_ {
+        self.rows.keys().copied()
+    }
+
+    pub fn get(&self, row: u32, column: &str) -> Option<&str> {
+        let column = *self.columns.get(column)?;
+        self.rows.get(&row)?.get(column).map(String::as_str)
+    }
+
+    pub fn get_u32(&self, row: u32, column: &str) -> u32 {
+        self.get(row, column)
+            .and_then(|value| value.parse().ok())
+            .unwrap_or_default()
+    }
+
+    pub fn get_bool(&self, row: u32, column: &str) -> bool {
+        self.get(row, column)
+            .map(|value| value.eq_ignore_ascii_case("true"))
+            .unwrap_or_default()
+    }
+}
+
+/// Splits CSV text into records, handling quoted fields.
+fn parse_csv(text: &str) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.trim_start_matches('\u{feff}').chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes => {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    field.push('"');
+                } else {
+                    in_quotes = false;
+                }
+            }
+            '"' if field.is_empty() => in_quotes = true,
+            ',' if !in_quotes => record.push(std::mem::take(&mut field)),
+            '\r' if !in_quotes => {}
+            '\n' if !in_quotes => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            c => field.push(c),
+        }
+    }
+
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+
+    records
+}
+
+/// One sheet per language, in the order of `LANGUAGES`. Regional languages may be missing.
+struct LocalisedSheet([Option<Sheet>; 7]);
+
+impl LocalisedSheet {
+    fn load(dir: &Path, name: &str) -> Result<Self, String> {
+        let mut sheets: [Option<Sheet>; 7] = Default::default();
+        for (i, lang) in LANGUAGES.into_iter().enumerate() {
+            if i >= GLOBAL_LANGUAGES && !dir.join(format!("{}.{}.csv", name, lang)).exists() {
+                continue;
+            }
+
+            sheets[i] = Some(Sheet::load(dir, name, lang)?);
+        }
+
+        Ok(Self(sheets))
+    }
+
+    fn en(&self) -> &Sheet {
+        self.0[0].as_ref().unwrap()
+    }
+
+    /// Reads a localised string, falling back to English for global languages missing the row.
+    /// Returns `None` if there is no English text.
+    fn names(&self, row: u32, f: impl Fn(&Sheet, u32) -> Option<String>) -> Option<Names> {
+        let en = f(self.en(), row).filter(|name| !name.is_empty())?;
+        let mut names: Names = Default::default();
+        for (i, sheet) in self.0.iter().enumerate() {
+            names[i] = match sheet {
+                Some(sheet) if sheet.has_row(row) => clean(&f(sheet, row).unwrap_or_default()),
+                _ if i < GLOBAL_LANGUAGES => clean(&en),
+                _ => String::new(),
+            };
+        }
+
+        Some(names)
+    }
+}
+
+/// Removes characters that the game uses for layout only.
+fn clean(text: &str) -> String {
+    text.replace(['\u{a0}', '\u{ad}'], "")
+}
+
+fn capitalise(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[derive(Debug)]
+pub struct DutyRow {
+    pub name: Names,
+    pub high_end: bool,
+    pub content_type: u32,
+    pub level: u32,
+    pub item_level: u32,
+    pub members_per_party: u32,
+    pub alliance: bool,
+    /// A row of `ExVersion`, if the duty has a territory.
+    pub ex_version: Option<u32>,
+    pub icon: u32,
+    pub image: u32,
+}
+
+#[derive(Debug)]
+pub struct RouletteRow {
+    pub name: Names,
+    pub pvp: bool,
+}
+
+#[derive(Debug)]
+pub struct WorldRow {
+    pub name: String,
+    pub data_centre: String,
+    /// The `Region` of the world's `WorldDCGroupType`.
+    pub region: u32,
+    pub travel: bool,
+}
+
+/// `WorldDCGroupType` regions whose data centres players can travel between.
+/// The sheets have no column for it, so it's decided per world here and
+/// stored in the table.
+const TRAVEL_REGIONS: [u32; 4] = [1, 2, 3, 4];
+
+/// `TerritoryIntendedUse` rows of territories with FATEs: field areas, Eureka,
+/// Bozja and the Occult Crescent.
+const FATE_INTENDED_USES: [u32; 4] = [1, 41, 48, 61];
+
+#[derive(Debug)]
+pub struct ColourRow {
+    /// RGBA.
+    pub foreground: u32,
+    /// RGBA.
+    pub glow: u32,
+}
+
+#[derive(Debug, Default)]
+pub struct Tables {
+    pub duties: BTreeMap<u32, DutyRow>,
+    pub roulettes: BTreeMap<u32, RouletteRow>,
+    pub territory_names: BTreeMap<u32, Names>,
+    /// Territories with FATEs.
+    pub fate_territories: BTreeSet<u32>,
+    pub auto_translate: BTreeMap<(u32, u32), Names>,
+    pub treasure_maps: BTreeMap<u32, Names>,
+    pub worlds: BTreeMap<u32, WorldRow>,
+    pub ui_colours: BTreeMap<u32, ColourRow>,
+}
+
+impl Tables {
+    pub fn load(dir: &Path) -> Result<Self, String> {
+        Ok(Self {
+            duties: load_duties(dir)?,
+            roulettes: load_roulettes(dir)?,
+            territory_names: load_territory_names(dir)?,
+            fate_territories: load_fate_territories(dir)?,
+            auto_translate: load_auto_translate(dir)?,
+            treasure_maps: load_treasure_maps(dir)?,
+            worlds: load_worlds(dir)?,
+            ui_colours: load_ui_colours(dir)?,
+        })
+    }
+}
+
+pub fn load_duties(dir: &Path) -> Result<BTreeMap<u32, DutyRow>, String> {
+    let cfc = LocalisedSheet::load(dir, "ContentFinderCondition")?;
+    let member_types = Sheet::load(dir, "ContentMemberType", "en")?;
+    let territories = Sheet::load(dir, "TerritoryType", "en")?;
+
+    let mut duties = BTreeMap::new();
+    for row in cfc.en().row_ids().filter(|&row| row != 0) {
+        let Some(name) = cfc.names(row, |sheet, row| sheet.get(row, "Name").map(capitalise)) else {
+            continue;
+        };
+
+        let sheet = cfc.en();
+        let member_type = sheet.get_u32(row, "ContentMemberType");
+        let members_per_party = ["TanksPerParty", "HealersPerParty", "MeleesPerParty", "RangedPerParty"]
+            .into_iter()
+            .map(|column| member_types.get_u32(member_type, column))
+            .sum();
+        let territory = sheet.get_u32(row, "TerritoryType");
+        let ex_version = (territory != 0 && territories.has_row(territory))
+            .then(|| territories.get_u32(territory, "ExVersion"));
+
+        duties.insert(
+            row,
+            DutyRow {
+                name,
+                high_end: sheet.get_bool(row, "HighEndDuty"),
+                content_type: sheet.get_u32(row, "ContentType"),
+                level: sheet.get_u32(row, "ClassJobLevelRequired"),
+                item_level: sheet.get_u32(row, "ItemLevelSync"),
+                members_per_party,
+                // there is no party count in the sheets, but alliance raids are in their own roulette
+                alliance: sheet.get_bool(row, "AllianceRoulette"),
+                ex_version,
+                icon: sheet.get_u32(row, "Icon"),
+                image: sheet.get_u32(row, "Image"),
+            },
+        );
+    }
+
+    Ok(duties)
+}
+
+fn load_roulettes(dir: &Path) -> Result<BTreeMap<u32, RouletteRow>, String> {
+    let roulettes = LocalisedSheet::load(dir, "ContentRoulette")?;
+    let mut rows = BTreeMap::new();
+    for row in roulettes.en().row_ids().filter(|&row| row != 0) {
+        let Some(name) = roulettes.names(row, |sheet, row| sheet.get(row, "Name").map(str::to_string)) else {
+            continue;
+        };
+
+        rows.insert(
+            row,
+            RouletteRow {
+                name,
+                pvp: roulettes.en().get_bool(row, "IsPvP"),
+            },
+        );
+    }
+
+    Ok(rows)
+}
+
+fn load_territory_names(dir: &Path) -> Result<BTreeMap<u32, Names>, String> {
+    let territories = LocalisedSheet::load(dir, "TerritoryType")?;
+    let place_names = LocalisedSheet::load(dir, "PlaceName")?;
+    let mut names = BTreeMap::new();
+    for row in territories.en().row_ids().filter(|&row| row != 0) {
+        let place_name = territories.en().get_u32(row, "PlaceName");
+        if place_name == 0 {
+            continue;
+        }
+
+        if let Some(name) = place_names.names(place_name, |sheet, row| sheet.get(row, "Name").map(str::to_string)) {
+            names.insert(row, name);
+        }
+    }
+
+    Ok(names)
+}
+
+fn load_fate_territories(dir: &Path) -> Result<BTreeSet<u32>, String> {
+    let territories = Sheet::load(dir, "TerritoryType", "en")?;
+    Ok(territories
+        .row_ids()
+        .filter(|&row| FATE_INTENDED_USES.contains(&territories.get_u32(row, "TerritoryIntendedUse")))
+        .collect())
+}
+
+fn load_treasure_maps(dir: &Path) -> Result<BTreeMap<u32, Names>, String> {
+    let ranks = LocalisedSheet::load(dir, "TreasureHuntRank")?;
+    let event_items = LocalisedSheet::load(dir, "EventItem")?;
+    let items = LocalisedSheet::load(dir, "Item")?;
+
+    let mut maps = BTreeMap::new();
+    maps.insert(
+        0,
+        [
+            "All Levels".to_string(),
+            "レベルを指定しない".to_string(),
+            "Jede Stufe".to_string(),
+            "Tous niveaux".to_string(),
+            String::new(),
+            String::new(),
+            String::new(),
+        ],
+    );
+
+    // the party finder numbers maps in sheet order, skipping the ones that aren't maps
+    let mut i = 1;
+    for row in ranks.en().row_ids() {
+        if ranks.en().get_u32(row, "Icon") == 0 || ranks.en().get_u32(row, "TreasureHuntTexture") != 0 {
+            continue;
+        }
+
+        let key_item = ranks.en().get_u32(row, "KeyItemName");
+        let item = ranks.en().get_u32(row, "ItemName");
+        let name = event_items
+            .names(key_item, |sheet, row| sheet.get(row, "Name").map(str::to_string))
+            .or_else(|| items.names(item, |sheet, row| sheet.get(row, "Name").map(str::to_string)));
+        if let Some(name) = name {
+            maps.insert(i, name);
+            i += 1;
+        }
+    }
+
+    Ok(maps)
+}
+
+fn load_worlds(dir: &Path) -> Result<BTreeMap<u32, WorldRow>, String> {
+    let mut rows = BTreeMap::new();
+    // the regional clients have their own worlds, named in their language
+    for (i, lang) in LANGUAGES.into_iter().enumerate() {
+        if i >= GLOBAL_LANGUAGES && !dir.join(format!("World.{}.csv", lang)).exists() {
+            continue;
+        }
+
+        let worlds = Sheet::load(dir, "World", lang)?;
+        let data_centres = Sheet::load(dir, "WorldDCGroupType", lang)?;
+        for row in worlds.row_ids() {
+            let data_centre = worlds.get_u32(row, "DataCenter");
+            // 3000 and 3001 are public test worlds without a usable data centre
+            if rows.contains_key(&row)
+                || row == 0
+                || row == 3000
+                || row == 3001
+                || !worlds.get_bool(row, "IsPublic")
+                || worlds.get_u32(row, "UserType") == 0
+                || data_centre == 0
+            {
+                continue;
+            }
+
+            let name = worlds.get(row, "Name").unwrap_or_default();
+            let data_centre_name = data_centres.get(data_centre, "Name").unwrap_or_default();
+            if name.is_empty() || data_centre_name.is_empty() {
+                continue;
+            }
+
+            let region = data_centres.get_u32(data_centre, "Region");
+            rows.insert(
+                row,
+                WorldRow {
+                    name: name.to_string(),
+                    data_centre: data_centre_name.to_string(),
+                    region,
+                    travel: TRAVEL_REGIONS.contains(&region),
+                },
+            );
+        }
+    }
+
+    Ok(rows)
+}
+
+fn load_auto_translate(dir: &Path) -> Result<BTreeMap<(u32, u32), Names>, String> {
+    let completion = LocalisedSheet::load(dir, "Completion")?;
+    let mut sheets: HashMap<String, LocalisedSheet> = HashMap::new();
+    let mut entries = BTreeMap::new();
+
+    for row in completion.en().row_ids() {
+        let group = completion.en().get_u32(row, "Group");
+        // group 61 doesn't resolve to anything useful
+        if group == 61 {
+            continue;
+        }
+
+        let lookup = completion
+            .en()
+            .get(row, "LookupTable")
+            .unwrap_or_default()
+            .replace("<num(", "")
+            .replace(")>", "");
+
+        if lookup.is_empty() || lookup == "@" {
+            if let Some(text) = completion.names(row, |sheet, row| sheet.get(row, "Text").map(str::to_string)) {
+                entries.insert((group, row), text);
+            }
+            continue;
+        }
+
+        let Some(lookup) = Lookup::parse(&lookup) else {
+            eprintln!("could not parse auto-translate lookup {:?}", lookup);
+            continue;
+        };
+
+        if !sheets.contains_key(&lookup.sheet) {
+            let sheet = LocalisedSheet::load(dir, &lookup.sheet)?;
+            sheets.insert(lookup.sheet.clone(), sheet);
+        }
+        let sheet = &sheets[&lookup.sheet];
+
+        for key in lookup.rows(sheet.en()) {
+            let names = sheet.0.each_ref().map(|sheet| {
+                let sheet = sheet.as_ref()?;
+                lookup
+                    .columns
+                    .iter()
+                    .filter_map(|column| sheet.get(key, &column.to_string()))
+                    .find(|text| !text.is_empty())
+                    .map(clean)
+            });
+
+            // only keep entries that are translated into every global language
+            if names[..GLOBAL_LANGUAGES].iter().all(Option::is_some) {
+                entries.insert((group, key), names.map(Option::unwrap_or_default));
+            }
+        }
+    }
+
+    Ok(entries)
+}
+
+/// A `Completion` lookup table, like `Sheet[1-5,10,col-2]`.
+#[derive(Debug, PartialEq)]
+pub struct Lookup {
+    pub sheet: String,
+    /// Row ranges, inclusive. Empty means every row.
+    pub rows: Vec<(u32, u32)>,
+    pub columns: Vec<u32>,
+}
+
+impl Lookup {
+    pub fn parse(text: &str) -> Option<Self> {
+        let (sheet, selector) = match text.find('[') {
+            Some(idx) => (&text[..idx], Some(text[idx + 1..].strip_suffix(']')?)),
+            None => (text, None),
+        };
+        if sheet.is_empty() {
+            return None;
+        }
+
+        let mut rows = Vec::new();
+        let mut columns = Vec::new();
+        let items = selector
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty());
+        for item in items {
+            if item == "noun" {
+                continue;
+            }
+
+            if let Some(column) = item.strip_prefix("col-") {
+                columns.push(if column.is_empty() { 0 } else { column.parse().ok()? });
+            } else if let Some((start, end)) = item.split_once('-') {
+                rows.push((start.parse().ok()?, end.parse().ok()?));
+            } else {
+                let row = item.parse().ok()?;
+                rows.push((row, row));
+            }
+        }
+
+        if columns.is_empty() {
+            columns.push(0);
+        }
+
+        Some(Self {
+            sheet: sheet.to_string(),
+            rows,
+            columns,
+        })
+    }
+
+    fn rows<'a>(&'a self, sheet: &'a Sheet) -> Box<dyn Iterator<Item = u32> + 'a> {
+        if self.rows.is_empty() {
+            return Box::new(sheet.row_ids());
+        }
+
+        Box::new(
+            self.rows
+                .iter()
+                .flat_map(|&(start, end)| start..=end)
+                .filter(|&row| sheet.has_row(row)),
+        )
+    }
+}
+
+/// Colours are only used to render descriptions, so the sheet is optional.
+fn load_ui_colours(dir: &Path) -> Result<BTreeMap<u32, ColourRow>, String> {
+    if !dir.join("UIColor.en.csv").exists() {
+        return Ok(BTreeMap::new());
+    }
+
+    let sheet = Sheet::load(dir, "UIColor", "en")?;
+    Ok(sheet
+        .row_ids()
+        .map(|row| {
+            let colour = ColourRow {
+                foreground: sheet.get_u32(row, "UIForeground"),
+                glow: sheet.get_u32(row, "UIGlow"),
+            };
+            (row, colour)
+        })
+        .collect())
+}
@@ -1,15 +1,22 @@
 use std::collections::HashMap;
-use super::LocalisedText;
+use super::{Expansion, LocalisedText, PartySize};
 
 #[derive(Debug, Copy, Clone)]
 pub struct DutyInfo {
     pub name: LocalisedText,
     pub high_end: bool,
     pub content_kind: ContentKind,
+    pub level: Option<u8>,
+    /// `None` if unknown or not synced.
+    pub item_level: Option<u16>,
+    pub party_size: PartySize,
+    pub expansion: Option<Expansion>,
+    pub icon: Option<u32>,
+    pub image: Option<u32>,
 }
 
 #[derive(Debug, Clone, Copy)]
-#[allow(unused)]
+#[allow(unused, clippy::upper_case_acronyms)]
 #[repr(u32)]
 pub enum ContentKind {
     DutyRoulette = 1,
@@ -51,8 +58,7 @@ pub enum ContentKind {
 }
 
 impl ContentKind {
-    #[expect(unused)]
-    fn from_u32(kind: u32) -> Self {
+    pub fn from_u32(kind: u32) -> Self {
         match kind {
             1 => Self::DutyRoulette,
             2 => Self::Dungeons,
@@ -135,6 +141,10 @@ impl ContentKind {
     }
 }
 
+#[cfg(generated_tables)]
+include!(concat!(env!("OUT_DIR"), "/duties.rs"));
+
+#[cfg(not(generated_tables))]
 lazy_static::lazy_static! {
     pub static ref DUTIES: HashMap<u32, DutyInfo> = maplit::hashmap! {
         1 => DutyInfo {
@@ -143,9 +153,16 @@ lazy_static::lazy_static! {
                 ja: "監獄廃墟 トトラクの千獄",
                 de: "Tausend Löcher von Toto-Rak",
                 fr: "Les Mille Gueules de Toto-Rak",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         2 => DutyInfo {
             name: LocalisedText {
@@ -153,9 +170,16 @@ lazy_static::lazy_static! {
                 ja: "地下霊殿 タムタラの墓所",
                 de: "Totenacker Tam-Tara",
                 fr: "L'Hypogée de Tam-Tara",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         3 => DutyInfo {
             name: LocalisedText {
@@ -163,9 +187,16 @@ lazy_static::lazy_static! {
                 ja: "封鎖坑道 カッパーベル銅山",
                 de: "Kupferglocken-Mine",
                 fr: "Les Mines de Clochecuivre",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         4 => DutyInfo {
             name: LocalisedText {
@@ -173,9 +204,16 @@ lazy_static::lazy_static! {
                 ja: "天然要害 サスタシャ浸食洞",
                 de: "Sastasha",
                 fr: "Sastasha",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         5 => DutyInfo {
             name: LocalisedText {
@@ -183,9 +221,16 @@ lazy_static::lazy_static! {
                 ja: "霧中行軍 オーラムヴェイル",
                 de: "Goldklamm",
                 fr: "Le Val d'Aurum",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         6 => DutyInfo {
             name: LocalisedText {
@@ -193,9 +238,16 @@ lazy_static::lazy_static! {
                 ja: "名門屋敷 ハウケタ御用邸",
                 de: "Haukke-Herrenhaus",
                 fr: "Le Manoir des Haukke",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         7 => DutyInfo {
             name: LocalisedText {
@@ -203,9 +255,16 @@ lazy_static::lazy_static! {
                 ja: "魔獣領域 ハラタリ修練所",
                 de: "Halatali",
                 fr: "Halatali",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         8 => DutyInfo {
             name: LocalisedText {
@@ -213,9 +272,16 @@ lazy_static::lazy_static! {
                 ja: "奪還支援 ブレイフロクスの野営地",
                 de: "Brüllvolx' Langrast",
                 fr: "Le Bivouac de Brayflox",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         9 => DutyInfo {
             name: LocalisedText {
@@ -223,9 +289,16 @@ lazy_static::lazy_static! {
                 ja: "遺跡探索 カルン埋没寺院",
                 de: "Versunkener Tempel von Qarn",
                 fr: "Le Temple enseveli de Qarn",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         10 => DutyInfo {
             name: LocalisedText {
@@ -233,9 +306,16 @@ lazy_static::lazy_static! {
                 ja: "旅神聖域 ワンダラーパレス",
                 de: "Palast des Wanderers",
                 fr: "Le Palais du Vagabond",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         11 => DutyInfo {
             name: LocalisedText {
@@ -243,9 +323,16 @@ lazy_static::lazy_static! {
                 ja: "城塞攻略 ストーンヴィジル",
                 de: "Steinerne Wacht",
                 fr: "Le Vigile de Pierre",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         12 => DutyInfo {
             name: LocalisedText {
@@ -253,9 +340,16 @@ lazy_static::lazy_static! {
                 ja: "流砂迷宮 カッターズクライ",
                 de: "Sägerschrei",
                 fr: "Le Gouffre hurlant",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         13 => DutyInfo {
             name: LocalisedText {
@@ -263,9 +357,16 @@ lazy_static::lazy_static! {
                 ja: "掃討作戦 ゼーメル要塞",
                 de: "Die Feste Dzemael",
                 fr: "La Forteresse de Dzemael",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         14 => DutyInfo {
             name: LocalisedText {
@@ -273,9 +374,16 @@ lazy_static::lazy_static! {
                 ja: "邪教排撃 古城アムダプール",
                 de: "Die Ruinen von Amdapor",
                 fr: "Le Château d'Amdapor",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         15 => DutyInfo {
             name: LocalisedText {
@@ -283,9 +391,16 @@ lazy_static::lazy_static! {
                 ja: "外郭攻略 カストルム・メリディアヌム",
                 de: "Castrum Meridianum - Außenbereich",
                 fr: "Castrum Meridianum",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         16 => DutyInfo {
             name: LocalisedText {
@@ -293,9 +408,16 @@ lazy_static::lazy_static! {
                 ja: "最終決戦 魔導城プラエトリウム",
                 de: "Castrum Meridianum - Praetorium",
                 fr: "Le Praetorium",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         17 => DutyInfo {
             name: LocalisedText {
@@ -303,9 +425,16 @@ lazy_static::lazy_static! {
                 ja: "怪鳥巨塔 シリウス大灯台",
                 de: "Pharos Sirius",
                 fr: "Le Phare de Sirius",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         18 => DutyInfo {
             name: LocalisedText {
@@ -313,9 +442,16 @@ lazy_static::lazy_static! {
                 ja: "騒乱坑道 カッパーベル銅山 (Hard)",
                 de: "Kupferglocken-Mine (schwer)",
                 fr: "Les Mines de Clochecuivre (brutal)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         19 => DutyInfo {
             name: LocalisedText {
@@ -323,9 +459,16 @@ lazy_static::lazy_static! {
                 ja: "妖異屋敷 ハウケタ御用邸 (Hard)",
                 de: "Haukke-Herrenhaus (schwer)",
                 fr: "Le Manoir des Haukke (brutal)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         20 => DutyInfo {
             name: LocalisedText {
@@ -333,9 +476,16 @@ lazy_static::lazy_static! {
                 ja: "盟友支援 ブレイフロクスの野営地 (Hard)",
                 de: "Brüllvolx' Langrast (schwer)",
                 fr: "Le Bivouac de Brayflox (brutal)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         21 => DutyInfo {
             name: LocalisedText {
@@ -343,9 +493,16 @@ lazy_static::lazy_static! {
                 ja: "剣闘領域 ハラタリ修練所 (Hard)",
                 de: "Halatali (schwer)",
                 fr: "Halatali (brutal)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         22 => DutyInfo {
             name: LocalisedText {
@@ -353,9 +510,16 @@ lazy_static::lazy_static! {
                 ja: "腐敗遺跡 古アムダプール市街",
                 de: "Historisches Amdapor",
                 fr: "Les Vestiges de la cité d'Amdapor",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         23 => DutyInfo {
             name: LocalisedText {
@@ -363,9 +527,16 @@ lazy_static::lazy_static! {
                 ja: "財宝伝説 ハルブレーカー・アイル",
                 de: "Schiffbrecher-Insel",
                 fr: "L'Île de Crèvecarène",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         24 => DutyInfo {
             name: LocalisedText {
@@ -373,9 +544,16 @@ lazy_static::lazy_static! {
                 ja: "惨劇霊殿 タムタラの墓所 (Hard)",
                 de: "Totenacker Tam-Tara (schwer)",
                 fr: "L'Hypogée de Tam-Tara (brutal)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         25 => DutyInfo {
             name: LocalisedText {
@@ -383,9 +561,16 @@ lazy_static::lazy_static! {
                 ja: "城塞奪回 ストーンヴィジル (Hard)",
                 de: "Steinerne Wacht (schwer)",
                 fr: "Le Vigile de Pierre (brutal)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         26 => DutyInfo {
             name: LocalisedText {
@@ -393,9 +578,16 @@ lazy_static::lazy_static! {
                 ja: "遺跡救援 カルン埋没寺院 (Hard)",
                 de: "Versunkener Tempel von Qarn (schwer)",
                 fr: "Le Temple enseveli de Qarn (brutal)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         27 => DutyInfo {
             name: LocalisedText {
@@ -403,9 +595,16 @@ lazy_static::lazy_static! {
                 ja: "氷結潜窟 スノークローク大氷壁",
                 de: "Das Schneekleid",
                 fr: "Manteneige",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         28 => DutyInfo {
             name: LocalisedText {
@@ -413,9 +612,16 @@ lazy_static::lazy_static! {
                 ja: "逆襲要害 サスタシャ浸食洞 (Hard)",
                 de: "Sastasha (schwer)",
                 fr: "Sastasha (brutal)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         29 => DutyInfo {
             name: LocalisedText {
@@ -423,9 +629,16 @@ lazy_static::lazy_static! {
                 ja: "邪念排撃 古城アムダプール (Hard)",
                 de: "Die Ruinen von Amdapor (schwer)",
                 fr: "Le Château d'Amdapor (brutal)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         30 => DutyInfo {
             name: LocalisedText {
@@ -433,9 +646,16 @@ lazy_static::lazy_static! {
                 ja: "武装聖域 ワンダラーパレス (Hard)",
                 de: "Palast des Wanderers (schwer)",
                 fr: "Le Palais du Vagabond (brutal)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         31 => DutyInfo {
             name: LocalisedText {
@@ -443,9 +663,16 @@ lazy_static::lazy_static! {
                 ja: "禁書回収 グブラ幻想図書館",
                 de: "Große Gubal-Bibliothek",
                 fr: "La Grande bibliothèque de Gubal",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         32 => DutyInfo {
             name: LocalisedText {
@@ -453,9 +680,16 @@ lazy_static::lazy_static! {
                 ja: "幻龍残骸 黙約の塔",
                 de: "Hüter des Sees",
                 fr: "Le Gardien du lac",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         33 => DutyInfo {
             name: LocalisedText {
@@ -463,9 +697,16 @@ lazy_static::lazy_static! {
                 ja: "神域浮島 ネバーリープ",
                 de: "Nimmerreich",
                 fr: "Nalloncques",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         34 => DutyInfo {
             name: LocalisedText {
@@ -473,9 +714,16 @@ lazy_static::lazy_static! {
                 ja: "強硬突入 イシュガルド教皇庁",
                 de: "Erzbasilika",
                 fr: "La Voûte",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         35 => DutyInfo {
             name: LocalisedText {
@@ -483,9 +731,16 @@ lazy_static::lazy_static! {
                 ja: "博物戦艦 フラクタル・コンティニアム",
                 de: "Die Fraktal-Kontinuum",
                 fr: "Le Continuum fractal",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         36 => DutyInfo {
             name: LocalisedText {
@@ -493,9 +748,16 @@ lazy_static::lazy_static! {
                 ja: "廃砦捜索 ダスクヴィジル",
                 de: "Abendrot-Wacht",
                 fr: "Le Vigile du Crépuscule",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         37 => DutyInfo {
             name: LocalisedText {
@@ -503,9 +765,16 @@ lazy_static::lazy_static! {
                 ja: "霊峰踏破 ソーム・アル",
                 de: "Sohm Al",
                 fr: "Sohm Al",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         38 => DutyInfo {
             name: LocalisedText {
@@ -513,9 +782,16 @@ lazy_static::lazy_static! {
                 ja: "蒼天聖戦 魔科学研究所",
                 de: "Ätherochemisches Forschungslabor",
                 fr: "Le Laboratoire de magismologie",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         39 => DutyInfo {
             name: LocalisedText {
@@ -523,9 +799,16 @@ lazy_static::lazy_static! {
                 ja: "邪竜血戦 ドラゴンズエアリー",
                 de: "Nest des Drachen",
                 fr: "L'Aire",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         40 => DutyInfo {
             name: LocalisedText {
@@ -533,9 +816,16 @@ lazy_static::lazy_static! {
                 ja: "制圧巨塔 シリウス大灯台 (Hard)",
                 de: "Pharos Sirius (schwer)",
                 fr: "Le Phare de Sirius (brutal)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         41 => DutyInfo {
             name: LocalisedText {
@@ -543,9 +833,16 @@ lazy_static::lazy_static! {
                 ja: "草木庭園 聖モシャーヌ植物園",
                 de: "Sankt Mocianne-Arboretum",
                 fr: "L'Arboretum Sainte-Mocianne",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         42 => DutyInfo {
             name: LocalisedText {
@@ -553,9 +850,16 @@ lazy_static::lazy_static! {
                 ja: "集団戦訓練をくぐり抜けろ！",
                 de: "Einer für alle, alle für einen",
                 fr: "Entraînement: groupes d'ennemis",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Guildhests,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         43 => DutyInfo {
             name: LocalisedText {
@@ -563,9 +867,16 @@ lazy_static::lazy_static! {
                 ja: "彷徨う死霊を討て！",
                 de: "Bockmanns Gefolge",
                 fr: "Chasse au fantôme fantoche",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Guildhests,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         44 => DutyInfo {
             name: LocalisedText {
@@ -573,9 +884,16 @@ lazy_static::lazy_static! {
                 ja: "全関門を突破し、最深部の敵を討て！",
                 de: "Sturmkommando",
                 fr: "Entraînement: infiltration en base ennemie",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Guildhests,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         45 => DutyInfo {
             name: LocalisedText {
@@ -583,9 +901,16 @@ lazy_static::lazy_static! {
                 ja: "ギルガメを捕獲せよ！",
                 de: "Gil oder Leben",
                 fr: "Reconquête d'une carapace escamotée",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Guildhests,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         46 => DutyInfo {
             name: LocalisedText {
@@ -593,9 +918,16 @@ lazy_static::lazy_static! {
                 ja: "有毒妖花を駆除せよ！",
                 de: "Unkraut jäten",
                 fr: "Opération fleurs du mal",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Guildhests,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         47 => DutyInfo {
             name: LocalisedText {
@@ -603,9 +935,16 @@ lazy_static::lazy_static! {
                 ja: "無法者「似我蜂団」を撃滅せよ！",
                 de: "Ins Wespennest stechen",
                 fr: "Expédition punitive contre les Ventrerouge",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Guildhests,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         48 => DutyInfo {
             name: LocalisedText {
@@ -613,9 +952,16 @@ lazy_static::lazy_static! {
                 ja: "夢幻のブラキシオを討て！",
                 de: "Briaxio ausschalten",
                 fr: "Briaxio à bras raccourcis",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Guildhests,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         49 => DutyInfo {
             name: LocalisedText {
@@ -623,9 +969,16 @@ lazy_static::lazy_static! {
                 ja: "爆弾魔ゴブリン軍団を撃滅せよ！",
                 de: "Bombige Goblins",
                 fr: "Les Gobelins bombardiers",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Guildhests,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         50 => DutyInfo {
             name: LocalisedText {
@@ -633,9 +986,16 @@ lazy_static::lazy_static! {
                 ja: "汚染源モルボルを討て！",
                 de: "Tödliches Rankenspiel",
                 fr: "Sus au morbol pollueur",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Guildhests,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         51 => DutyInfo {
             name: LocalisedText {
@@ -643,9 +1003,16 @@ lazy_static::lazy_static! {
                 ja: "坑道に現れた妖異ブソを討て！",
                 de: "Gefahr aus dem Nichts",
                 fr: "Buso l'immolateur",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Guildhests,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         52 => DutyInfo {
             name: LocalisedText {
@@ -653,9 +1020,16 @@ lazy_static::lazy_static! {
                 ja: "無敵の眷属を従えし、大型妖異を討て！",
                 de: "Kampf gegen Schatten",
                 fr: "Ombres et griffes",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Guildhests,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         53 => DutyInfo {
             name: LocalisedText {
@@ -663,9 +1037,16 @@ lazy_static::lazy_static! {
                 ja: "ボムを率いる「ボムクイーン」を討て！",
                 de: "Miss Bombastic",
                 fr: "Longue vie à la Reine",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Guildhests,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         54 => DutyInfo {
             name: LocalisedText {
@@ -673,9 +1054,16 @@ lazy_static::lazy_static! {
                 ja: "不気味な陣形を組む妖異をせん滅せよ！",
                 de: "Unzertrennlich",
                 fr: "Quintettes infernaux",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Guildhests,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         55 => DutyInfo {
             name: LocalisedText {
@@ -683,9 +1071,16 @@ lazy_static::lazy_static! {
                 ja: "三つ巴の巨人族を制し、遺物を守れ！",
                 de: "Wuchtige Dreifaltigkeit",
                 fr: "Trinité sinistre",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Guildhests,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         56 => DutyInfo {
             name: LocalisedText {
@@ -693,9 +1088,16 @@ lazy_static::lazy_static! {
                 ja: "イフリート討伐戦",
                 de: "Das Grab der Lohe",
                 fr: "Le Cratère des tisons",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         57 => DutyInfo {
             name: LocalisedText {
@@ -703,9 +1105,16 @@ lazy_static::lazy_static! {
                 ja: "タイタン討伐戦",
                 de: "Der Nabel",
                 fr: "Le Nombril",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         58 => DutyInfo {
             name: LocalisedText {
@@ -713,9 +1122,16 @@ lazy_static::lazy_static! {
                 ja: "ガルーダ討伐戦",
                 de: "Das Tosende Auge",
                 fr: "Hurlœil",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         59 => DutyInfo {
             name: LocalisedText {
@@ -723,9 +1139,16 @@ lazy_static::lazy_static! {
                 ja: "真イフリート討滅戦",
                 de: "Götterdämmerung - Ifrit",
                 fr: "Le Cratère des tisons (brutal)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         60 => DutyInfo {
             name: LocalisedText {
@@ -733,9 +1156,16 @@ lazy_static::lazy_static! {
                 ja: "真タイタン討滅戦",
                 de: "Götterdämmerung - Titan",
                 fr: "Le Nombril (brutal)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         61 => DutyInfo {
             name: LocalisedText {
@@ -743,9 +1173,16 @@ lazy_static::lazy_static! {
                 ja: "真ガルーダ討滅戦",
                 de: "Götterdämmerung - Garuda",
                 fr: "Hurlœil (brutal)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         63 => DutyInfo {
             name: LocalisedText {
@@ -753,9 +1190,16 @@ lazy_static::lazy_static! {
                 ja: "極イフリート討滅戦",
                 de: "Zenit der Götter - Ifrit",
                 fr: "Le Cratère des tisons (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         64 => DutyInfo {
             name: LocalisedText {
@@ -763,9 +1207,16 @@ lazy_static::lazy_static! {
                 ja: "極タイタン討滅戦",
                 de: "Zenit der Götter - Titan",
                 fr: "Le Nombril (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         65 => DutyInfo {
             name: LocalisedText {
@@ -773,9 +1224,16 @@ lazy_static::lazy_static! {
                 ja: "極ガルーダ討滅戦",
                 de: "Zenit der Götter - Garuda",
                 fr: "Hurlœil (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         66 => DutyInfo {
             name: LocalisedText {
@@ -783,9 +1241,16 @@ lazy_static::lazy_static! {
                 ja: "善王モグル・モグXII世討滅戦",
                 de: "Königliche Konfrontation (schwer)",
                 fr: "La Lisière de ronces (brutal)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         67 => DutyInfo {
             name: LocalisedText {
@@ -793,9 +1258,16 @@ lazy_static::lazy_static! {
                 ja: "極王モグル・モグXII世討滅戦",
                 de: "Königliche Konfrontation (extrem)",
                 fr: "La Lisière de ronces (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         68 => DutyInfo {
             name: LocalisedText {
@@ -803,9 +1275,16 @@ lazy_static::lazy_static! {
                 ja: "究極幻想 アルテマウェポン破壊作戦",
                 de: "Heldenlied von Ultima",
                 fr: "Le fléau d'Ultima",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         69 => DutyInfo {
             name: LocalisedText {
@@ -813,9 +1292,16 @@ lazy_static::lazy_static! {
                 ja: "アスラ討滅戦",
                 de: "Prophetie - Asura",
                 fr: "Le temple doré d'Araya",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         70 => DutyInfo {
             name: LocalisedText {
@@ -823,9 +1309,16 @@ lazy_static::lazy_static! {
                 ja: "イベント用コンテンツ：1",
                 de: "Event-Inhalt 1",
                 fr: "Défi spécial I",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         71 => DutyInfo {
             name: LocalisedText {
@@ -833,9 +1326,16 @@ lazy_static::lazy_static! {
                 ja: "イベント用コンテンツ：2",
                 de: "Event-Inhalt 2",
                 fr: "Défi spécial II",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         72 => DutyInfo {
             name: LocalisedText {
@@ -843,9 +1343,16 @@ lazy_static::lazy_static! {
                 ja: "真リヴァイアサン討滅戦",
                 de: "Götterdämmerung - Leviathan",
                 fr: "Le Briseur de marées (brutal)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         73 => DutyInfo {
             name: LocalisedText {
@@ -853,9 +1360,16 @@ lazy_static::lazy_static! {
                 ja: "極リヴァイアサン討滅戦",
                 de: "Zenit der Götter - Leviathan",
                 fr: "Le Briseur de marées (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         74 => DutyInfo {
             name: LocalisedText {
@@ -863,9 +1377,16 @@ lazy_static::lazy_static! {
                 ja: "ドルムキマイラ討伐戦",
                 de: "Kampf gegen die Dhorme-Chimära",
                 fr: "La chimère dhorme du Coerthas",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         75 => DutyInfo {
             name: LocalisedText {
@@ -873,9 +1394,16 @@ lazy_static::lazy_static! {
                 ja: "ハイドラ討伐戦",
                 de: "Kampf gegen die Hydra",
                 fr: "L'hydre d'Halatali",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         76 => DutyInfo {
             name: LocalisedText {
@@ -883,9 +1411,16 @@ lazy_static::lazy_static! {
                 ja: "ギルガメッシュ討伐戦",
                 de: "Duell auf der großen Brücke",
                 fr: "Affrontement sur le grand pont",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         77 => DutyInfo {
             name: LocalisedText {
@@ -893,9 +1428,16 @@ lazy_static::lazy_static! {
                 ja: "真ラムウ討滅戦",
                 de: "Götterdämmerung - Ramuh",
                 fr: "L'Arbre du jugement (brutal)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         78 => DutyInfo {
             name: LocalisedText {
@@ -903,9 +1445,16 @@ lazy_static::lazy_static! {
                 ja: "極ラムウ討滅戦",
                 de: "Zenit der Götter - Ramuh",
                 fr: "L'Arbre du jugement (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         79 => DutyInfo {
             name: LocalisedText {
@@ -913,9 +1462,16 @@ lazy_static::lazy_static! {
                 ja: "真シヴァ討滅戦",
                 de: "Götterdämmerung - Shiva",
                 fr: "L'Amphithéâtre d'Akh Afah (brutal)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         80 => DutyInfo {
             name: LocalisedText {
@@ -923,9 +1479,16 @@ lazy_static::lazy_static! {
                 ja: "極シヴァ討滅戦",
                 de: "Zenit der Götter - Shiva",
                 fr: "L'Amphithéâtre d'Akh Afah (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         81 => DutyInfo {
             name: LocalisedText {
@@ -933,9 +1496,16 @@ lazy_static::lazy_static! {
                 ja: "アマジナ杯闘技会決勝戦",
                 de: "Das Drachenhals-Kolosseum",
                 fr: "Le Col du dragon",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         82 => DutyInfo {
             name: LocalisedText {
@@ -943,9 +1513,16 @@ lazy_static::lazy_static! {
                 ja: "闘神オーディン討滅戦",
                 de: "Jenseits Urths Quelle",
                 fr: "La Fontaine d'Urth",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         84 => DutyInfo {
             name: LocalisedText {
@@ -953,9 +1530,16 @@ lazy_static::lazy_static! {
                 ja: "アシエン・ナプリアレス討伐戦",
                 de: "Chrysalis",
                 fr: "La Chrysalide",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         85 => DutyInfo {
             name: LocalisedText {
@@ -963,9 +1547,16 @@ lazy_static::lazy_static! {
                 ja: "真ギルガメッシュ討滅戦",
                 de: "Revanche in den Ruinen",
                 fr: "Revanche au vieux château",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         86 => DutyInfo {
             name: LocalisedText {
@@ -973,9 +1564,16 @@ lazy_static::lazy_static! {
                 ja: "真ラーヴァナ討滅戦",
                 de: "Götterdämmerung - Ravana",
                 fr: "Thok ast Thok (brutal)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         87 => DutyInfo {
             name: LocalisedText {
@@ -983,9 +1581,16 @@ lazy_static::lazy_static! {
                 ja: "極ラーヴァナ討滅戦",
                 de: "Zenit der Götter - Ravana",
                 fr: "Thok ast Thok (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         88 => DutyInfo {
             name: LocalisedText {
@@ -993,9 +1598,16 @@ lazy_static::lazy_static! {
                 ja: "真ビスマルク討滅戦",
                 de: "Götterdämmerung - Bismarck",
                 fr: "L'Immensité bleue (brutal)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         89 => DutyInfo {
             name: LocalisedText {
@@ -1003,9 +1615,16 @@ lazy_static::lazy_static! {
                 ja: "極ビスマルク討滅戦",
                 de: "Zenit der Götter - Bismarck",
                 fr: "L'Immensité bleue (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         90 => DutyInfo {
             name: LocalisedText {
@@ -1013,9 +1632,16 @@ lazy_static::lazy_static! {
                 ja: "ナイツ・オブ・ラウンド討滅戦",
                 de: "Singularitäts-Reaktor",
                 fr: "Le Réacteur de singularité",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         91 => DutyInfo {
             name: LocalisedText {
@@ -1023,9 +1649,16 @@ lazy_static::lazy_static! {
                 ja: "蒼天幻想 ナイツ・オブ・ラウンド討滅戦",
                 de: "Heldenlied von Thordans Fall",
                 fr: "Le règne de Thordan",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         92 => DutyInfo {
             name: LocalisedText {
@@ -1033,9 +1666,16 @@ lazy_static::lazy_static! {
                 ja: "クリスタルタワー：古代の民の迷宮",
                 de: "Kristallturm - Das Labyrinth der Alten",
                 fr: "La Tour de Cristal - Dédale antique",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         93 => DutyInfo {
             name: LocalisedText {
@@ -1043,9 +1683,16 @@ lazy_static::lazy_static! {
                 ja: "大迷宮バハムート：邂逅編1",
                 de: "Verschlungene Schatten 1",
                 fr: "Le Labyrinthe de Bahamut I",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         94 => DutyInfo {
             name: LocalisedText {
@@ -1053,9 +1700,16 @@ lazy_static::lazy_static! {
                 ja: "大迷宮バハムート：邂逅編2",
                 de: "Verschlungene Schatten 2",
                 fr: "Le Labyrinthe de Bahamut II",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         95 => DutyInfo {
             name: LocalisedText {
@@ -1063,9 +1717,16 @@ lazy_static::lazy_static! {
                 ja: "大迷宮バハムート：邂逅編3",
                 de: "Verschlungene Schatten 3",
                 fr: "Le Labyrinthe de Bahamut III",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         96 => DutyInfo {
             name: LocalisedText {
@@ -1073,9 +1734,16 @@ lazy_static::lazy_static! {
                 ja: "大迷宮バハムート：邂逅編4",
                 de: "Verschlungene Schatten 4",
                 fr: "Le Labyrinthe de Bahamut IV",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         97 => DutyInfo {
             name: LocalisedText {
@@ -1083,9 +1751,16 @@ lazy_static::lazy_static! {
                 ja: "大迷宮バハムート：邂逅編5",
                 de: "Verschlungene Schatten 5",
                 fr: "Le Labyrinthe de Bahamut V",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         98 => DutyInfo {
             name: LocalisedText {
@@ -1093,9 +1768,16 @@ lazy_static::lazy_static! {
                 ja: "大迷宮バハムート：侵攻編1",
                 de: "Verschlungene Schatten 2 - 1",
                 fr: "Les Méandres de Bahamut I",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         99 => DutyInfo {
             name: LocalisedText {
@@ -1103,9 +1785,16 @@ lazy_static::lazy_static! {
                 ja: "大迷宮バハムート：侵攻編2",
                 de: "Verschlungene Schatten 2 - 2",
                 fr: "Les Méandres de Bahamut II",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         100 => DutyInfo {
             name: LocalisedText {
@@ -1113,9 +1802,16 @@ lazy_static::lazy_static! {
                 ja: "大迷宮バハムート：侵攻編3",
                 de: "Verschlungene Schatten 2 - 3",
                 fr: "Les Méandres de Bahamut III",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         101 => DutyInfo {
             name: LocalisedText {
@@ -1123,9 +1819,16 @@ lazy_static::lazy_static! {
                 ja: "大迷宮バハムート：侵攻編4",
                 de: "Verschlungene Schatten 2 - 4",
                 fr: "Les Méandres de Bahamut IV",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         102 => DutyInfo {
             name: LocalisedText {
@@ -1133,9 +1836,16 @@ lazy_static::lazy_static! {
                 ja: "クリスタルタワー：シルクスの塔",
                 de: "Kristallturm - Der Syrcus-Turm",
                 fr: "La Tour de Cristal - Tour de Syrcus",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         103 => DutyInfo {
             name: LocalisedText {
@@ -1143,9 +1853,16 @@ lazy_static::lazy_static! {
                 ja: "大迷宮バハムート零式：侵攻編1",
                 de: "Verschlungene Schatten 2 - 1 (episch)",
                 fr: "Les Méandres de Bahamut I (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         104 => DutyInfo {
             name: LocalisedText {
@@ -1153,9 +1870,16 @@ lazy_static::lazy_static! {
                 ja: "大迷宮バハムート零式：侵攻編2",
                 de: "Verschlungene Schatten 2 - 2 (episch)",
                 fr: "Les Méandres de Bahamut II (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         105 => DutyInfo {
             name: LocalisedText {
@@ -1163,9 +1887,16 @@ lazy_static::lazy_static! {
                 ja: "大迷宮バハムート零式：侵攻編3",
                 de: "Verschlungene Schatten 2 - 3 (episch)",
                 fr: "Les Méandres de Bahamut III (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         106 => DutyInfo {
             name: LocalisedText {
@@ -1173,9 +1904,16 @@ lazy_static::lazy_static! {
                 ja: "大迷宮バハムート零式：侵攻編4",
                 de: "Verschlungene Schatten 2 - 4 (episch)",
                 fr: "Les Méandres de Bahamut IV (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         107 => DutyInfo {
             name: LocalisedText {
@@ -1183,9 +1921,16 @@ lazy_static::lazy_static! {
                 ja: "大迷宮バハムート：真成編1",
                 de: "Verschlungene Schatten 3 - 1",
                 fr: "L'Abîme de Bahamut I",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         108 => DutyInfo {
             name: LocalisedText {
@@ -1193,9 +1938,16 @@ lazy_static::lazy_static! {
                 ja: "大迷宮バハムート：真成編2",
                 de: "Verschlungene Schatten 3 - 2",
                 fr: "L'Abîme de Bahamut II",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         109 => DutyInfo {
             name: LocalisedText {
@@ -1203,9 +1955,16 @@ lazy_static::lazy_static! {
                 ja: "大迷宮バハムート：真成編3",
                 de: "Verschlungene Schatten 3 - 3",
                 fr: "L'Abîme de Bahamut III",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         110 => DutyInfo {
             name: LocalisedText {
@@ -1213,9 +1972,16 @@ lazy_static::lazy_static! {
                 ja: "大迷宮バハムート：真成編4",
                 de: "Verschlungene Schatten 3 - 4",
                 fr: "L'Abîme de Bahamut IV",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         111 => DutyInfo {
             name: LocalisedText {
@@ -1223,9 +1989,16 @@ lazy_static::lazy_static! {
                 ja: "クリスタルタワー：闇の世界",
                 de: "Die Welt der Dunkelheit",
                 fr: "La Tour de Cristal - Monde des Ténèbres",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         112 => DutyInfo {
             name: LocalisedText {
@@ -1233,9 +2006,16 @@ lazy_static::lazy_static! {
                 ja: "機工城アレキサンダー：起動編1",
                 de: "Alexander - Faust des Vaters",
                 fr: "Alexander - Le Poing du Père",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         113 => DutyInfo {
             name: LocalisedText {
@@ -1243,9 +2023,16 @@ lazy_static::lazy_static! {
                 ja: "機工城アレキサンダー：起動編2",
                 de: "Alexander - Elle des Vaters",
                 fr: "Alexander - Le Poignet du Père",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         114 => DutyInfo {
             name: LocalisedText {
@@ -1253,9 +2040,16 @@ lazy_static::lazy_static! {
                 ja: "機工城アレキサンダー：起動編3",
                 de: "Alexander - Arm des Vaters",
                 fr: "Alexander - Le Bras du Père",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         115 => DutyInfo {
             name: LocalisedText {
@@ -1263,9 +2057,16 @@ lazy_static::lazy_static! {
                 ja: "機工城アレキサンダー：起動編4",
                 de: "Alexander - Last des Vaters",
                 fr: "Alexander - Le Fardeau du Père",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         116 => DutyInfo {
             name: LocalisedText {
@@ -1273,9 +2074,16 @@ lazy_static::lazy_static! {
                 ja: "機工城アレキサンダー零式：起動編1",
                 de: "Alexander - Faust des Vaters (episch)",
                 fr: "Alexander - Le Poing du Père (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         117 => DutyInfo {
             name: LocalisedText {
@@ -1283,9 +2091,16 @@ lazy_static::lazy_static! {
                 ja: "機工城アレキサンダー零式：起動編2",
                 de: "Alexander - Elle des Vaters (episch)",
                 fr: "Alexander - Le Poignet du Père (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         118 => DutyInfo {
             name: LocalisedText {
@@ -1293,9 +2108,16 @@ lazy_static::lazy_static! {
                 ja: "機工城アレキサンダー零式：起動編3",
                 de: "Alexander - Arm des Vaters (episch)",
                 fr: "Alexander - Le Bras du Père (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         119 => DutyInfo {
             name: LocalisedText {
@@ -1303,9 +2125,16 @@ lazy_static::lazy_static! {
                 ja: "機工城アレキサンダー零式：起動編4",
                 de: "Alexander - Last des Vaters (episch)",
                 fr: "Alexander - Le Fardeau du Père (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         120 => DutyInfo {
             name: LocalisedText {
@@ -1313,9 +2142,16 @@ lazy_static::lazy_static! {
                 ja: "魔航船ヴォイドアーク",
                 de: "Die Nichts-Arche",
                 fr: "L'Arche du néant",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         127 => DutyInfo {
             name: LocalisedText {
@@ -1323,9 +2159,16 @@ lazy_static::lazy_static! {
                 ja: "外縁遺跡群 (制圧戦)",
                 de: "Äußere Ruinen (Sicherung)",
                 fr: "Les Ruines frontalières (annexion)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         130 => DutyInfo {
             name: LocalisedText {
@@ -1333,9 +2176,16 @@ lazy_static::lazy_static! {
                 ja: "シールロック (争奪戦)",
                 de: "Robbenholm (Eroberung)",
                 fr: "Le Rocher des tréfonds (invasion)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         131 => DutyInfo {
             name: LocalisedText {
@@ -1343,9 +2193,16 @@ lazy_static::lazy_static! {
                 ja: "雲海探索 ディアデム諸島 (Easy)",
                 de: "Das Diadem (leicht)",
                 fr: "Le Diadème (facile)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(23),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         132 => DutyInfo {
             name: LocalisedText {
@@ -1353,9 +2210,16 @@ lazy_static::lazy_static! {
                 ja: "雲海探索 ディアデム諸島",
                 de: "Das Diadem",
                 fr: "Le Diadème",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(23),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         133 => DutyInfo {
             name: LocalisedText {
@@ -1363,9 +2227,16 @@ lazy_static::lazy_static! {
                 ja: "雲海探索 ディアデム諸島 (Hard)",
                 de: "Das Diadem (schwer)",
                 fr: "Le Diadème (brutal)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(23),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         134 => DutyInfo {
             name: LocalisedText {
@@ -1373,9 +2244,16 @@ lazy_static::lazy_static! {
                 ja: "魔神セフィロト討滅戦",
                 de: "Götterdämmerung - Sephirot",
                 fr: "Unité de contention S1P7",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         135 => DutyInfo {
             name: LocalisedText {
@@ -1383,9 +2261,16 @@ lazy_static::lazy_static! {
                 ja: "極魔神セフィロト討滅戦",
                 de: "Zenit der Götter - Sephirot",
                 fr: "Unité de contention S1P7 (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         136 => DutyInfo {
             name: LocalisedText {
@@ -1393,9 +2278,16 @@ lazy_static::lazy_static! {
                 ja: "機工城アレキサンダー：律動編1",
                 de: "Alexander - Faust des Sohnes",
                 fr: "Alexander - Le Poing du Fils",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         137 => DutyInfo {
             name: LocalisedText {
@@ -1403,9 +2295,16 @@ lazy_static::lazy_static! {
                 ja: "機工城アレキサンダー：律動編2",
                 de: "Alexander - Elle des Sohnes",
                 fr: "Alexander - Le Poignet du Fils",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         138 => DutyInfo {
             name: LocalisedText {
@@ -1413,9 +2312,16 @@ lazy_static::lazy_static! {
                 ja: "機工城アレキサンダー：律動編3",
                 de: "Alexander - Arm des Sohnes",
                 fr: "Alexander - Le Bras du Fils",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         139 => DutyInfo {
             name: LocalisedText {
@@ -1423,9 +2329,16 @@ lazy_static::lazy_static! {
                 ja: "機工城アレキサンダー：律動編4",
                 de: "Alexander - Last des Sohnes",
                 fr: "Alexander - Le Fardeau du Fils",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         140 => DutyInfo {
             name: LocalisedText {
@@ -1433,9 +2346,16 @@ lazy_static::lazy_static! {
                 ja: "神聖遺跡 古アムダプール市街 (Hard)",
                 de: "Historisches Amdapor (schwer)",
                 fr: "Les Vestiges de la cité d'Amdapor (brutal)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         141 => DutyInfo {
             name: LocalisedText {
@@ -1443,9 +2363,16 @@ lazy_static::lazy_static! {
                 ja: "星海観測 逆さの塔",
                 de: "Antiturm",
                 fr: "L'Antitour",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         147 => DutyInfo {
             name: LocalisedText {
@@ -1453,9 +2380,16 @@ lazy_static::lazy_static! {
                 ja: "機工城アレキサンダー零式：律動編1",
                 de: "Alexander - Faust des Sohnes (episch)",
                 fr: "Alexander - Le Poing du Fils (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         148 => DutyInfo {
             name: LocalisedText {
@@ -1463,9 +2397,16 @@ lazy_static::lazy_static! {
                 ja: "機工城アレキサンダー零式：律動編2",
                 de: "Alexander - Elle des Sohnes (episch)",
                 fr: "Alexander - Le Poignet du Fils (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         149 => DutyInfo {
             name: LocalisedText {
@@ -1473,9 +2414,16 @@ lazy_static::lazy_static! {
                 ja: "機工城アレキサンダー零式：律動編3",
                 de: "Alexander - Arm des Sohnes (episch)",
                 fr: "Alexander - Le Bras du Fils (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         150 => DutyInfo {
             name: LocalisedText {
@@ -1483,9 +2431,16 @@ lazy_static::lazy_static! {
                 ja: "機工城アレキサンダー零式：律動編4",
                 de: "Alexander - Last des Sohnes (episch)",
                 fr: "Alexander - Le Fardeau du Fils (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         151 => DutyInfo {
             name: LocalisedText {
@@ -1493,9 +2448,16 @@ lazy_static::lazy_static! {
                 ja: "範囲攻撃を避けよう！",
                 de: "Flächenangriffen ausweichen",
                 fr: "Éviter les attaques à aire d'effet",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(20),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         152 => DutyInfo {
             name: LocalisedText {
@@ -1503,9 +2465,16 @@ lazy_static::lazy_static! {
                 ja: "コンボで敵視を集めよう！",
                 de: "Mit Kombos Feindseligkeit auf sich ziehen",
                 fr: "Générer de l'inimitié avec un combo",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(20),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         153 => DutyInfo {
             name: LocalisedText {
@@ -1513,9 +2482,16 @@ lazy_static::lazy_static! {
                 ja: "実戦でコンボに挑戦しよう！",
                 de: "Kombos im Kampf einsetzen",
                 fr: "Effectuer le combo en combat",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(20),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         154 => DutyInfo {
             name: LocalisedText {
@@ -1523,9 +2499,16 @@ lazy_static::lazy_static! {
                 ja: "複数の敵から敵視を集めよう！",
                 de: "Feindseligkeit mehrerer Gegner auf sich ziehen",
                 fr: "Attirer l'inimitié de plusieurs ennemis sur soi",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(20),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         155 => DutyInfo {
             name: LocalisedText {
@@ -1533,9 +2516,16 @@ lazy_static::lazy_static! {
                 ja: "実戦で複数の敵と戦ってみよう！",
                 de: "Gegen mehrere Gegner auf einmal kämpfen",
                 fr: "Affronter plusieurs ennemis",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(20),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         156 => DutyInfo {
             name: LocalisedText {
@@ -1543,9 +2533,16 @@ lazy_static::lazy_static! {
                 ja: "遠距離から敵視を集めよう！",
                 de: "Aus der Ferne Feindseligkeit auf sich ziehen",
                 fr: "Générer de l'inimitié à distance",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(20),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         157 => DutyInfo {
             name: LocalisedText {
@@ -1553,9 +2550,16 @@ lazy_static::lazy_static! {
                 ja: "敵の増援に対応しよう！",
                 de: "Feindliche Verstärkung aufhalten",
                 fr: "Faire face à des renforts ennemis",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(20),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         158 => DutyInfo {
             name: LocalisedText {
@@ -1563,9 +2567,16 @@ lazy_static::lazy_static! {
                 ja: "味方と協力して敵を倒そう！",
                 de: "Gegner gemeinsam besiegen",
                 fr: "Vaincre un ennemi en assistant des alliés",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(20),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         159 => DutyInfo {
             name: LocalisedText {
@@ -1573,9 +2584,16 @@ lazy_static::lazy_static! {
                 ja: "味方が引きつけている敵を倒そう！",
                 de: "Den Gegner eines Verbündeten besiegen",
                 fr: "Vaincre un ennemi occupé par un allié",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(20),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         160 => DutyInfo {
             name: LocalisedText {
@@ -1583,9 +2601,16 @@ lazy_static::lazy_static! {
                 ja: "敵の攻撃を避けながら戦おう！",
                 de: "Angriffen ausweichen",
                 fr: "Combattre en évitant les attaques ennemies",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(20),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         161 => DutyInfo {
             name: LocalisedText {
@@ -1593,9 +2618,16 @@ lazy_static::lazy_static! {
                 ja: "敵の増援に対応しよう！",
                 de: "Feindliche Verstärkung aufhalten",
                 fr: "Éliminer les renforts ennemis",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(20),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         162 => DutyInfo {
             name: LocalisedText {
@@ -1603,9 +2635,16 @@ lazy_static::lazy_static! {
                 ja: "ギミックを活用して戦おう！",
                 de: "Mit dem Gelände interagieren",
                 fr: "Interagir avec le décor en combat",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(20),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         163 => DutyInfo {
             name: LocalisedText {
@@ -1613,9 +2652,16 @@ lazy_static::lazy_static! {
                 ja: "味方を回復しよう！",
                 de: "Verbündete heilen",
                 fr: "Soigner un allié",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(20),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         164 => DutyInfo {
             name: LocalisedText {
@@ -1623,9 +2669,16 @@ lazy_static::lazy_static! {
                 ja: "複数の味方を回復しよう！",
                 de: "Mehrere Verbündete heilen",
                 fr: "Soigner plusieurs alliés",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(20),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         165 => DutyInfo {
             name: LocalisedText {
@@ -1633,9 +2686,16 @@ lazy_static::lazy_static! {
                 ja: "敵の攻撃を避けながら戦おう！",
                 de: "Angriffen ausweichen",
                 fr: "Combattre en évitant les attaques ennemies",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(20),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         166 => DutyInfo {
             name: LocalisedText {
@@ -1643,9 +2703,16 @@ lazy_static::lazy_static! {
                 ja: "最終訓練！",
                 de: "Letzte Übung",
                 fr: "Exercice final",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(20),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         167 => DutyInfo {
             name: LocalisedText {
@@ -1653,9 +2720,16 @@ lazy_static::lazy_static! {
                 ja: "四国合同演習",
                 de: "Truppenübung der Eorzäischen Allianz",
                 fr: "La grande manœuvre éorzéenne",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         168 => DutyInfo {
             name: LocalisedText {
@@ -1663,9 +2737,16 @@ lazy_static::lazy_static! {
                 ja: "禁忌都市マハ",
                 de: "Die Stadt der Tränen",
                 fr: "La Cité défendue de Mhach",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         169 => DutyInfo {
             name: LocalisedText {
@@ -1673,9 +2754,16 @@ lazy_static::lazy_static! {
                 ja: "ニーズヘッグ征竜戦",
                 de: "Der letzte Schicksalsweg",
                 fr: "La Dernière avancée de la Foi",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         170 => DutyInfo {
             name: LocalisedText {
@@ -1683,9 +2771,16 @@ lazy_static::lazy_static! {
                 ja: "極ニーズヘッグ征竜戦",
                 de: "Das Lied von Nidhoggs letztem Ruf",
                 fr: "L'ire de Nidhogg",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         171 => DutyInfo {
             name: LocalisedText {
@@ -1693,9 +2788,16 @@ lazy_static::lazy_static! {
                 ja: "天竜宮殿 ソール・カイ",
                 de: "Sohr Khai",
                 fr: "Sohr Khai",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         172 => DutyInfo {
             name: LocalisedText {
@@ -1703,9 +2805,16 @@ lazy_static::lazy_static! {
                 ja: "黒渦伝説 ハルブレーカー・アイル (Hard)",
                 de: "Schiffbrecher-Insel (schwer)",
                 fr: "L'Île de Crèvecarène (brutal)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         173 => DutyInfo {
             name: LocalisedText {
@@ -1713,9 +2822,16 @@ lazy_static::lazy_static! {
                 ja: "レグラ・ヴァン・ヒュドルス追撃戦",
                 de: "Blutiges Wiedersehen",
                 fr: "Course-poursuite dans le laboratoire",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         174 => DutyInfo {
             name: LocalisedText {
@@ -1723,9 +2839,16 @@ lazy_static::lazy_static! {
                 ja: "死者の宮殿 B1～B10",
                 de: "Palast der Toten (Ebenen 1-10)",
                 fr: "Le Palais des morts (sous-sols 1-10)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         175 => DutyInfo {
             name: LocalisedText {
@@ -1733,9 +2856,16 @@ lazy_static::lazy_static! {
                 ja: "死者の宮殿 B11～B20",
                 de: "Palast der Toten (Ebenen 11-20)",
                 fr: "Le Palais des morts (sous-sols 11-20)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         176 => DutyInfo {
             name: LocalisedText {
@@ -1743,9 +2873,16 @@ lazy_static::lazy_static! {
                 ja: "死者の宮殿 B21～B30",
                 de: "Palast der Toten (Ebenen 21-30)",
                 fr: "Le Palais des morts (sous-sols 21-30)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         177 => DutyInfo {
             name: LocalisedText {
@@ -1753,9 +2890,16 @@ lazy_static::lazy_static! {
                 ja: "死者の宮殿 B31～B40",
                 de: "Palast der Toten (Ebenen 31-40)",
                 fr: "Le Palais des morts (sous-sols 31-40)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         178 => DutyInfo {
             name: LocalisedText {
@@ -1763,9 +2907,16 @@ lazy_static::lazy_static! {
                 ja: "死者の宮殿 B41～B50",
                 de: "Palast der Toten (Ebenen 41-50)",
                 fr: "Le Palais des morts (sous-sols 41-50)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         179 => DutyInfo {
             name: LocalisedText {
@@ -1773,9 +2924,16 @@ lazy_static::lazy_static! {
                 ja: "宝物庫 アクアポリス",
                 de: "Aquapolis",
                 fr: "L'Aquapole",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TreasureHunt,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         180 => DutyInfo {
             name: LocalisedText {
@@ -1783,9 +2941,16 @@ lazy_static::lazy_static! {
                 ja: "フィールド・オブ・グローリー (砕氷戦)",
                 de: "Feld der Ehre (Zersplitterung)",
                 fr: "Les Champs de la Gloire (brise-glace)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         181 => DutyInfo {
             name: LocalisedText {
@@ -1793,9 +2958,16 @@ lazy_static::lazy_static! {
                 ja: "亡霊屋敷 ホーンテッドマナー",
                 de: "Das Geisterschloss",
                 fr: "Le Manoir hanté",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(22),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         182 => DutyInfo {
             name: LocalisedText {
@@ -1803,9 +2975,16 @@ lazy_static::lazy_static! {
                 ja: "峻厳渓谷 ゼルファトル",
                 de: "Xelphatol",
                 fr: "Xelphatol",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         183 => DutyInfo {
             name: LocalisedText {
@@ -1813,9 +2992,16 @@ lazy_static::lazy_static! {
                 ja: "女神ソフィア討滅戦",
                 de: "Götterdämmerung - Sophia",
                 fr: "Unité de contention P1P6",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         184 => DutyInfo {
             name: LocalisedText {
@@ -1823,9 +3009,16 @@ lazy_static::lazy_static! {
                 ja: "極女神ソフィア討滅戦",
                 de: "Zenit der Götter - Sophia",
                 fr: "Unité de contention P1P6 (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         186 => DutyInfo {
             name: LocalisedText {
@@ -1833,9 +3026,16 @@ lazy_static::lazy_static! {
                 ja: "機工城アレキサンダー：天動編1",
                 de: "Alexander - Augen des Schöpfers",
                 fr: "Alexander - Les Yeux du Créateur",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         187 => DutyInfo {
             name: LocalisedText {
@@ -1843,9 +3043,16 @@ lazy_static::lazy_static! {
                 ja: "機工城アレキサンダー：天動編2",
                 de: "Alexander - Atem des Schöpfers",
                 fr: "Alexander - Le Souffle du Créateur",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         188 => DutyInfo {
             name: LocalisedText {
@@ -1853,9 +3060,16 @@ lazy_static::lazy_static! {
                 ja: "機工城アレキサンダー：天動編3",
                 de: "Alexander - Herz des Schöpfers",
                 fr: "Alexander - Le Cœur du Créateur",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         189 => DutyInfo {
             name: LocalisedText {
@@ -1863,9 +3077,16 @@ lazy_static::lazy_static! {
                 ja: "機工城アレキサンダー：天動編4",
                 de: "Alexander - Seele des Schöpfers",
                 fr: "Alexander - L'Âme du Créateur",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         190 => DutyInfo {
             name: LocalisedText {
@@ -1873,9 +3094,16 @@ lazy_static::lazy_static! {
                 ja: "機工城アレキサンダー零式：天動編1",
                 de: "Alexander - Augen des Schöpfers (episch)",
                 fr: "Alexander - Les Yeux du Créateur (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         191 => DutyInfo {
             name: LocalisedText {
@@ -1883,9 +3111,16 @@ lazy_static::lazy_static! {
                 ja: "機工城アレキサンダー零式：天動編2",
                 de: "Alexander - Atem des Schöpfers (episch)",
                 fr: "Alexander - Le Souffle du Créateur (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         192 => DutyInfo {
             name: LocalisedText {
@@ -1893,9 +3128,16 @@ lazy_static::lazy_static! {
                 ja: "機工城アレキサンダー零式：天動編3",
                 de: "Alexander - Herz des Schöpfers (episch)",
                 fr: "Alexander - Le Cœur du Créateur (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         193 => DutyInfo {
             name: LocalisedText {
@@ -1903,9 +3145,16 @@ lazy_static::lazy_static! {
                 ja: "機工城アレキサンダー零式：天動編4",
                 de: "Alexander - Seele des Schöpfers (episch)",
                 fr: "Alexander - L'Âme du Créateur (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         194 => DutyInfo {
             name: LocalisedText {
@@ -1913,9 +3162,16 @@ lazy_static::lazy_static! {
                 ja: "絡み合う宿命",
                 de: "Weltenübergreifendes Schicksal",
                 fr: "Destins entrecroisés",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         195 => DutyInfo {
             name: LocalisedText {
@@ -1923,9 +3179,16 @@ lazy_static::lazy_static! {
                 ja: "トリプルトライアド：カードバトルルーム",
                 de: "Triple Triad: Weltensalon",
                 fr: "Arène Triple Triade",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         196 => DutyInfo {
             name: LocalisedText {
@@ -1933,9 +3196,16 @@ lazy_static::lazy_static! {
                 ja: "稀書回収 グブラ幻想図書館 (Hard)",
                 de: "Große Gubal-Bibliothek (schwer)",
                 fr: "La Grande bibliothèque de Gubal (brutal)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         197 => DutyInfo {
             name: LocalisedText {
@@ -1943,9 +3213,16 @@ lazy_static::lazy_static! {
                 ja: "LoVM：プレイヤー対戦 (RP変動あり)",
                 de: "Kampf der Trabanten: Gegen Spieler (um RP)",
                 fr: "Bataille simple contre un joueur (avec PR)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         198 => DutyInfo {
             name: LocalisedText {
@@ -1953,9 +3230,16 @@ lazy_static::lazy_static! {
                 ja: "LoVM：大会対戦 (プレイヤー対戦）",
                 de: "Kampf der Trabanten: Turnier (gegen Spieler)",
                 fr: "Bataille de tournoi contre des joueurs",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         199 => DutyInfo {
             name: LocalisedText {
@@ -1963,9 +3247,16 @@ lazy_static::lazy_static! {
                 ja: "LoVM：プレイヤー対戦 (RP変動なし)",
                 de: "Kampf der Trabanten: Gegen Spieler (ohne RP)",
                 fr: "Bataille simple contre un joueur (sans PR)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         202 => DutyInfo {
             name: LocalisedText {
@@ -1973,9 +3264,16 @@ lazy_static::lazy_static! {
                 ja: "雲海探索 ディアデム諸島：狩猟限定 (Easy)",
                 de: "Das Diadem - Jagdgründe (leicht)",
                 fr: "Le Diadème: terrains de chasse (facile)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(23),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         203 => DutyInfo {
             name: LocalisedText {
@@ -1983,9 +3281,16 @@ lazy_static::lazy_static! {
                 ja: "雲海探索 ディアデム諸島：狩猟限定",
                 de: "Das Diadem - Jagdgründe",
                 fr: "Le Diadème: terrains de chasse",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(23),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         204 => DutyInfo {
             name: LocalisedText {
@@ -1993,9 +3298,16 @@ lazy_static::lazy_static! {
                 ja: "死者の宮殿 B51～B60",
                 de: "Palast der Toten (Ebenen 51 - 60)",
                 fr: "Le Palais des morts (sous-sols 51-60)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         205 => DutyInfo {
             name: LocalisedText {
@@ -2003,9 +3315,16 @@ lazy_static::lazy_static! {
                 ja: "死者の宮殿 B61～B70",
                 de: "Palast der Toten (Ebenen 61 - 70)",
                 fr: "Le Palais des morts (sous-sols 61-70)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         206 => DutyInfo {
             name: LocalisedText {
@@ -2013,9 +3332,16 @@ lazy_static::lazy_static! {
                 ja: "死者の宮殿 B71～B80",
                 de: "Palast der Toten (Ebenen 71 - 80)",
                 fr: "Le Palais des morts (sous-sols 71-80)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         207 => DutyInfo {
             name: LocalisedText {
@@ -2023,9 +3349,16 @@ lazy_static::lazy_static! {
                 ja: "死者の宮殿 B81～B90",
                 de: "Palast der Toten (Ebenen 81 - 90)",
                 fr: "Le Palais des morts (sous-sols 81-90)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         208 => DutyInfo {
             name: LocalisedText {
@@ -2033,9 +3366,16 @@ lazy_static::lazy_static! {
                 ja: "死者の宮殿 B91～B100",
                 de: "Palast der Toten (Ebenen 91 - 100)",
                 fr: "Le Palais des morts (sous-sols 91-100)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         209 => DutyInfo {
             name: LocalisedText {
@@ -2043,9 +3383,16 @@ lazy_static::lazy_static! {
                 ja: "死者の宮殿 B101～B110",
                 de: "Palast der Toten (Ebenen 101 - 110)",
                 fr: "Le Palais des morts (sous-sols 101-110)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         210 => DutyInfo {
             name: LocalisedText {
@@ -2053,9 +3400,16 @@ lazy_static::lazy_static! {
                 ja: "死者の宮殿 B111～B120",
                 de: "Palast der Toten (Ebenen 111 - 120)",
                 fr: "Le Palais des morts (sous-sols 111-120)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         211 => DutyInfo {
             name: LocalisedText {
@@ -2063,9 +3417,16 @@ lazy_static::lazy_static! {
                 ja: "死者の宮殿 B121～B130",
                 de: "Palast der Toten (Ebenen 121 - 130)",
                 fr: "Le Palais des morts (sous-sols 121-130)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         212 => DutyInfo {
             name: LocalisedText {
@@ -2073,9 +3434,16 @@ lazy_static::lazy_static! {
                 ja: "死者の宮殿 B131～B140",
                 de: "Palast der Toten (Ebenen 131 - 140)",
                 fr: "Le Palais des morts (sous-sols 131-140)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         213 => DutyInfo {
             name: LocalisedText {
@@ -2083,9 +3451,16 @@ lazy_static::lazy_static! {
                 ja: "死者の宮殿 B141～B150",
                 de: "Palast der Toten (Ebenen 141 - 150)",
                 fr: "Le Palais des morts (sous-sols 141-150)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         214 => DutyInfo {
             name: LocalisedText {
@@ -2093,9 +3468,16 @@ lazy_static::lazy_static! {
                 ja: "死者の宮殿 B151～B160",
                 de: "Palast der Toten (Ebenen 151 - 160)",
                 fr: "Le Palais des morts (sous-sols 151-160)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         215 => DutyInfo {
             name: LocalisedText {
@@ -2103,9 +3485,16 @@ lazy_static::lazy_static! {
                 ja: "死者の宮殿 B161～B170",
                 de: "Palast der Toten (Ebenen 161 - 170)",
                 fr: "Le Palais des morts (sous-sols 161-170)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         216 => DutyInfo {
             name: LocalisedText {
@@ -2113,9 +3502,16 @@ lazy_static::lazy_static! {
                 ja: "死者の宮殿 B171～B180",
                 de: "Palast der Toten (Ebenen 171 - 180)",
                 fr: "Le Palais des morts (sous-sols 171-180)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         217 => DutyInfo {
             name: LocalisedText {
@@ -2123,9 +3519,16 @@ lazy_static::lazy_static! {
                 ja: "死者の宮殿 B181～B190",
                 de: "Palast der Toten (Ebenen 181 - 190)",
                 fr: "Le Palais des morts (sous-sols 181-190)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         218 => DutyInfo {
             name: LocalisedText {
@@ -2133,9 +3536,16 @@ lazy_static::lazy_static! {
                 ja: "死者の宮殿 B191～B200",
                 de: "Palast der Toten (Ebenen 191 - 200)",
                 fr: "Le Palais des morts (sous-sols 191-200)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         219 => DutyInfo {
             name: LocalisedText {
@@ -2143,9 +3553,16 @@ lazy_static::lazy_static! {
                 ja: "巨大防壁 バエサルの長城",
                 de: "Baelsar-Wall",
                 fr: "La Muraille de Baelsar",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         220 => DutyInfo {
             name: LocalisedText {
@@ -2153,9 +3570,16 @@ lazy_static::lazy_static! {
                 ja: "影の国ダン・スカー",
                 de: "Dun Scaith",
                 fr: "Dun Scaith",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         221 => DutyInfo {
             name: LocalisedText {
@@ -2163,9 +3587,16 @@ lazy_static::lazy_static! {
                 ja: "霊峰浄化 ソーム・アル (Hard)",
                 de: "Sohm Al (schwer)",
                 fr: "Sohm Al (brutal)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         222 => DutyInfo {
             name: LocalisedText {
@@ -2173,9 +3604,16 @@ lazy_static::lazy_static! {
                 ja: "カルテノー平原遭遇戦",
                 de: "Heliodrom",
                 fr: "Rixe à l'Héliodrome",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         223 => DutyInfo {
             name: LocalisedText {
@@ -2183,9 +3621,16 @@ lazy_static::lazy_static! {
                 ja: "鬼神ズルワーン討滅戦",
                 de: "Götterdämmerung - Zurvan",
                 fr: "Unité de contention Z1P9",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         224 => DutyInfo {
             name: LocalisedText {
@@ -2193,9 +3638,16 @@ lazy_static::lazy_static! {
                 ja: "極鬼神ズルワーン討滅戦",
                 de: "Zenit der Götter - Zurvan",
                 fr: "Unité de contention Z1P9 (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         225 => DutyInfo {
             name: LocalisedText {
@@ -2203,9 +3655,16 @@ lazy_static::lazy_static! {
                 ja: "雲海探索 ディアデム諸島 (狩猟)",
                 de: "Das Diadem - Halones Prüfung",
                 fr: "Le Diadème - Épreuves de Halone",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(23),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         234 => DutyInfo {
             name: LocalisedText {
@@ -2213,9 +3672,16 @@ lazy_static::lazy_static! {
                 ja: "雲海探索 ディアデム諸島 (採集)",
                 de: "Das Diadem - Nophicas Prüfung",
                 fr: "Le Diadème - Épreuves de Nophica",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(23),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         235 => DutyInfo {
             name: LocalisedText {
@@ -2223,9 +3689,16 @@ lazy_static::lazy_static! {
                 ja: "海底宮殿 紫水宮",
                 de: "Shisui",
                 fr: "Le Palais aux Marées violettes",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         236 => DutyInfo {
             name: LocalisedText {
@@ -2233,9 +3706,16 @@ lazy_static::lazy_static! {
                 ja: "壊神修行 星導山寺院",
                 de: "Tempel der Faust",
                 fr: "Le Temple du Poing",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         237 => DutyInfo {
             name: LocalisedText {
@@ -2243,9 +3723,16 @@ lazy_static::lazy_static! {
                 ja: "ギョドウ現る！",
                 de: "Ein zweifelhaftes Angebot",
                 fr: "Un drôle de Namazu",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         238 => DutyInfo {
             name: LocalisedText {
@@ -2253,9 +3740,16 @@ lazy_static::lazy_static! {
                 ja: "漂流海域 セイレーン海",
                 de: "Sirenen-See",
                 fr: "La Mer du Chant des sirènes",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         239 => DutyInfo {
             name: LocalisedText {
@@ -2263,9 +3757,16 @@ lazy_static::lazy_static! {
                 ja: "神龍討滅戦",
                 de: "Königliche Menagerie",
                 fr: "La Ménagerie royale",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         240 => DutyInfo {
             name: LocalisedText {
@@ -2273,9 +3774,16 @@ lazy_static::lazy_static! {
                 ja: "伝統試練 バルダム覇道",
                 de: "Bardams Probe",
                 fr: "La Force de Bardam",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         241 => DutyInfo {
             name: LocalisedText {
@@ -2283,9 +3791,16 @@ lazy_static::lazy_static! {
                 ja: "解放決戦 ドマ城",
                 de: "Burg Doma",
                 fr: "Le Château de Doma",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         242 => DutyInfo {
             name: LocalisedText {
@@ -2293,9 +3808,16 @@ lazy_static::lazy_static! {
                 ja: "巨砲要塞 カストルム・アバニア",
                 de: "Castrum Abania",
                 fr: "Castrum Abania",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         243 => DutyInfo {
             name: LocalisedText {
@@ -2303,9 +3825,16 @@ lazy_static::lazy_static! {
                 ja: "スサノオ討滅戦",
                 de: "Götterdämmerung - Susano",
                 fr: "La Crique aux tributs",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         244 => DutyInfo {
             name: LocalisedText {
@@ -2313,9 +3842,16 @@ lazy_static::lazy_static! {
                 ja: "極スサノオ討滅戦",
                 de: "Zenit der Götter - Susano",
                 fr: "La Crique aux tributs (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         245 => DutyInfo {
             name: LocalisedText {
@@ -2323,9 +3859,16 @@ lazy_static::lazy_static! {
                 ja: "抗う力",
                 de: "Die Kraft des Widerstands",
                 fr: "Transmigration démoniaque",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         246 => DutyInfo {
             name: LocalisedText {
@@ -2333,9 +3876,16 @@ lazy_static::lazy_static! {
                 ja: "終節の合戦",
                 de: "Naadam",
                 fr: "La grande bataille du Naadam",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         247 => DutyInfo {
             name: LocalisedText {
@@ -2343,9 +3893,16 @@ lazy_static::lazy_static! {
                 ja: "紅蓮決戦 アラミゴ",
                 de: "Ala Mhigo",
                 fr: "Ala Mhigo",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         248 => DutyInfo {
             name: LocalisedText {
@@ -2353,9 +3910,16 @@ lazy_static::lazy_static! {
                 ja: "海都を震わす人斬りの宴！",
                 de: "Mord ist sein Hobby",
                 fr: "La légende de Musosai: l'assassin de Limsa Lominsa",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         249 => DutyInfo {
             name: LocalisedText {
@@ -2363,9 +3927,16 @@ lazy_static::lazy_static! {
                 ja: "極悪人コガラシ",
                 de: "Der Inbegriff des Bösen",
                 fr: "L'abominable Kogarashi",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         250 => DutyInfo {
             name: LocalisedText {
@@ -2373,9 +3944,16 @@ lazy_static::lazy_static! {
                 ja: "松葉門外の変",
                 de: "Vorfall auf dem Matsuba-Platz",
                 fr: "Règlement de compte au square Matsuba",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         251 => DutyInfo {
             name: LocalisedText {
@@ -2383,9 +3961,16 @@ lazy_static::lazy_static! {
                 ja: "ベッコウ島の決闘",
                 de: "Entscheidungsschlacht auf Bekko",
                 fr: "L'affrontement de deux justices",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         252 => DutyInfo {
             name: LocalisedText {
@@ -2393,9 +3978,16 @@ lazy_static::lazy_static! {
                 ja: "次元の狭間オメガ：デルタ編1",
                 de: "Deltametrie 1.0",
                 fr: "Deltastice v1.0",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         253 => DutyInfo {
             name: LocalisedText {
@@ -2403,9 +3995,16 @@ lazy_static::lazy_static! {
                 ja: "次元の狭間オメガ：デルタ編2",
                 de: "Deltametrie 2.0",
                 fr: "Deltastice v2.0",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         254 => DutyInfo {
             name: LocalisedText {
@@ -2413,9 +4012,16 @@ lazy_static::lazy_static! {
                 ja: "次元の狭間オメガ：デルタ編3",
                 de: "Deltametrie 3.0",
                 fr: "Deltastice v3.0",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         255 => DutyInfo {
             name: LocalisedText {
@@ -2423,9 +4029,16 @@ lazy_static::lazy_static! {
                 ja: "次元の狭間オメガ：デルタ編4",
                 de: "Deltametrie 4.0",
                 fr: "Deltastice v4.0",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         256 => DutyInfo {
             name: LocalisedText {
@@ -2433,9 +4046,16 @@ lazy_static::lazy_static! {
                 ja: "次元の狭間オメガ零式：デルタ編1",
                 de: "Deltametrie 1.0 (episch)",
                 fr: "Deltastice v1.0 (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         257 => DutyInfo {
             name: LocalisedText {
@@ -2443,9 +4063,16 @@ lazy_static::lazy_static! {
                 ja: "次元の狭間オメガ零式：デルタ編2",
                 de: "Deltametrie 2.0 (episch)",
                 fr: "Deltastice v2.0 (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         258 => DutyInfo {
             name: LocalisedText {
@@ -2453,9 +4080,16 @@ lazy_static::lazy_static! {
                 ja: "次元の狭間オメガ零式：デルタ編3",
                 de: "Deltametrie 3.0 (episch)",
                 fr: "Deltastice v3.0 (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         259 => DutyInfo {
             name: LocalisedText {
@@ -2463,9 +4097,16 @@ lazy_static::lazy_static! {
                 ja: "次元の狭間オメガ零式：デルタ編4",
                 de: "Deltametrie 4.0 (episch)",
                 fr: "Deltastice v4.0 (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         260 => DutyInfo {
             name: LocalisedText {
@@ -2473,9 +4114,16 @@ lazy_static::lazy_static! {
                 ja: "原初的な彼女",
                 de: "Die Urkraft in ihr",
                 fr: "L'épreuve de force",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         261 => DutyInfo {
             name: LocalisedText {
@@ -2483,9 +4131,16 @@ lazy_static::lazy_static! {
                 ja: "ウル王杯闘技会の始まり",
                 de: "Thal zu Ehren",
                 fr: "Le tournoi commémoratif du sultanat",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         262 => DutyInfo {
             name: LocalisedText {
@@ -2493,9 +4148,16 @@ lazy_static::lazy_static! {
                 ja: "悪党成敗 クガネ城",
                 de: "Schloss Kugane",
                 fr: "Le Château de Kugane",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         263 => DutyInfo {
             name: LocalisedText {
@@ -2503,9 +4165,16 @@ lazy_static::lazy_static! {
                 ja: "ラクシュミ討滅戦",
                 de: "Götterdämmerung - Lakshmi",
                 fr: "Émanation",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         264 => DutyInfo {
             name: LocalisedText {
@@ -2513,9 +4182,16 @@ lazy_static::lazy_static! {
                 ja: "極ラクシュミ討滅戦",
                 de: "Zenit der Götter - Lakshmi",
                 fr: "Émanation (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         265 => DutyInfo {
             name: LocalisedText {
@@ -2523,9 +4199,16 @@ lazy_static::lazy_static! {
                 ja: "時をかける願い",
                 de: "Ein Wunsch aus alten Zeiten",
                 fr: "L'espoir en héritage",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         266 => DutyInfo {
             name: LocalisedText {
@@ -2533,9 +4216,16 @@ lazy_static::lazy_static! {
                 ja: "燃えよゴージ！",
                 de: "Kriegerische Leidenschaft",
                 fr: "Passion guerrière",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         267 => DutyInfo {
             name: LocalisedText {
@@ -2543,9 +4233,16 @@ lazy_static::lazy_static! {
                 ja: "漆黒の巨竜",
                 de: "Der tobende Drache",
                 fr: "Aussi sombre que la nuit",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         268 => DutyInfo {
             name: LocalisedText {
@@ -2553,9 +4250,16 @@ lazy_static::lazy_static! {
                 ja: "宝物庫 ウズネアカナル",
                 de: "Kanäle von Uznair",
                 fr: "Les Canaux perdus d'Uznair",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TreasureHunt,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         269 => DutyInfo {
             name: LocalisedText {
@@ -2563,9 +4267,16 @@ lazy_static::lazy_static! {
                 ja: "ウリエンジェの秘策",
                 de: "Wege zur Transzendenz",
                 fr: "La ruse d'Urianger",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         270 => DutyInfo {
             name: LocalisedText {
@@ -2573,9 +4284,16 @@ lazy_static::lazy_static! {
                 ja: "さらなる剣術の高みへ",
                 de: "Die hohe Kunst des Schwertkampfs",
                 fr: "La finale des champions",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         271 => DutyInfo {
             name: LocalisedText {
@@ -2583,9 +4301,16 @@ lazy_static::lazy_static! {
                 ja: "あと三度、遥かな憧憬",
                 de: "Probe des Meisters",
                 fr: "L'aspiration refoulée",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         272 => DutyInfo {
             name: LocalisedText {
@@ -2593,9 +4318,16 @@ lazy_static::lazy_static! {
                 ja: "あと一度、君に会えたら",
                 de: "Aus der Tiefe des Herzens",
                 fr: "La dernière séparation",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         273 => DutyInfo {
             name: LocalisedText {
@@ -2603,9 +4335,16 @@ lazy_static::lazy_static! {
                 ja: "紅の竜騎士",
                 de: "Der Rubin-Drachenreiter",
                 fr: "Le Dragon écarlate",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         274 => DutyInfo {
             name: LocalisedText {
@@ -2613,9 +4352,16 @@ lazy_static::lazy_static! {
                 ja: "影隠忍法帖",
                 de: "Aus dem Verborgenen",
                 fr: "La bataille des clans",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         275 => DutyInfo {
             name: LocalisedText {
@@ -2623,9 +4369,16 @@ lazy_static::lazy_static! {
                 ja: "次元の狭間：外縁",
                 de: "Interdimensionaler Riss",
                 fr: "Fissure interdimensionnelle",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         276 => DutyInfo {
             name: LocalisedText {
@@ -2633,9 +4386,16 @@ lazy_static::lazy_static! {
                 ja: "宝物庫 ウズネアカナル深層",
                 de: "Vergessene Kanäle von Uznair",
                 fr: "Les Canaux cachés d'Uznair",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TreasureHunt,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         277 => DutyInfo {
             name: LocalisedText {
@@ -2643,9 +4403,16 @@ lazy_static::lazy_static! {
                 ja: "アストラガロス (機工戦)",
                 de: "Astragalos",
                 fr: "Astragalos (machinerie)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         278 => DutyInfo {
             name: LocalisedText {
@@ -2653,9 +4420,16 @@ lazy_static::lazy_static! {
                 ja: "極神龍討滅戦",
                 de: "Heldenlied von Shinryu",
                 fr: "Le domaine de Shinryu",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         279 => DutyInfo {
             name: LocalisedText {
@@ -2663,9 +4437,16 @@ lazy_static::lazy_static! {
                 ja: "水没遺構 スカラ",
                 de: "Die versunkene Stadt Skalla",
                 fr: "La Cité engloutie de Skalla",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         280 => DutyInfo {
             name: LocalisedText {
@@ -2673,9 +4454,16 @@ lazy_static::lazy_static! {
                 ja: "絶バハムート討滅戦",
                 de: "Endlose Schatten von Bahamut (fatal)",
                 fr: "L'Abîme infini de Bahamut (fatal)",
+                ..Default::default()
             },
             high_end: true,
             content_kind: ContentKind::UltimateRaids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         281 => DutyInfo {
             name: LocalisedText {
@@ -2683,9 +4471,16 @@ lazy_static::lazy_static! {
                 ja: "失われた都 ラバナスタ",
                 de: "Rabanastre",
                 fr: "La Cité royale de Rabanastre",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         282 => DutyInfo {
             name: LocalisedText {
@@ -2693,9 +4488,16 @@ lazy_static::lazy_static! {
                 ja: "英雄の帰還",
                 de: "Verrat der Qalyana",
                 fr: "Retour au bercail",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         283 => DutyInfo {
             name: LocalisedText {
@@ -2703,9 +4505,16 @@ lazy_static::lazy_static! {
                 ja: "禁断の地 エウレカ：アネモス編",
                 de: "Eureka Anemos",
                 fr: "Eurêka Anemos",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Eureka,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         284 => DutyInfo {
             name: LocalisedText {
@@ -2713,9 +4522,16 @@ lazy_static::lazy_static! {
                 ja: "紅玉火山 獄之蓋",
                 de: "Höllenspund",
                 fr: "Le Couvercle des enfers",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         285 => DutyInfo {
             name: LocalisedText {
@@ -2723,9 +4539,16 @@ lazy_static::lazy_static! {
                 ja: "暴走戦艦 フラクタル・コンティニアム (Hard)",
                 de: "Die Fraktal-Kontinuum (schwer)",
                 fr: "Le Continuum fractal (brutal)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         286 => DutyInfo {
             name: LocalisedText {
@@ -2733,9 +4556,16 @@ lazy_static::lazy_static! {
                 ja: "次元の狭間オメガ：シグマ編1",
                 de: "Sigmametrie 1.0",
                 fr: "Sigmastice v1.0",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         287 => DutyInfo {
             name: LocalisedText {
@@ -2743,9 +4573,16 @@ lazy_static::lazy_static! {
                 ja: "次元の狭間オメガ：シグマ編2",
                 de: "Sigmametrie 2.0",
                 fr: "Sigmastice v2.0",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         288 => DutyInfo {
             name: LocalisedText {
@@ -2753,9 +4590,16 @@ lazy_static::lazy_static! {
                 ja: "次元の狭間オメガ：シグマ編3",
                 de: "Sigmametrie 3.0",
                 fr: "Sigmastice v3.0",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         289 => DutyInfo {
             name: LocalisedText {
@@ -2763,9 +4607,16 @@ lazy_static::lazy_static! {
                 ja: "次元の狭間オメガ：シグマ編4",
                 de: "Sigmametrie 4.0",
                 fr: "Sigmastice v4.0",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         290 => DutyInfo {
             name: LocalisedText {
@@ -2773,9 +4624,16 @@ lazy_static::lazy_static! {
                 ja: "白虎征魂戦",
                 de: "Seelentanz - Byakko",
                 fr: "La Clairière de Jade",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         291 => DutyInfo {
             name: LocalisedText {
@@ -2783,9 +4641,16 @@ lazy_static::lazy_static! {
                 ja: "極白虎征魂戦",
                 de: "Seelensturm - Byakko",
                 fr: "La Clairière de Jade (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         292 => DutyInfo {
             name: LocalisedText {
@@ -2793,9 +4658,16 @@ lazy_static::lazy_static! {
                 ja: "次元の狭間オメガ零式：シグマ編1",
                 de: "Sigmametrie 1.0 (episch)",
                 fr: "Sigmastice v1.0 (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         293 => DutyInfo {
             name: LocalisedText {
@@ -2803,9 +4675,16 @@ lazy_static::lazy_static! {
                 ja: "次元の狭間オメガ零式：シグマ編2",
                 de: "Sigmametrie 2.0 (episch)",
                 fr: "Sigmastice v2.0 (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         294 => DutyInfo {
             name: LocalisedText {
@@ -2813,9 +4692,16 @@ lazy_static::lazy_static! {
                 ja: "次元の狭間オメガ零式：シグマ編3",
                 de: "Sigmametrie 3.0 (episch)",
                 fr: "Sigmastice v3.0 (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         295 => DutyInfo {
             name: LocalisedText {
@@ -2823,9 +4709,16 @@ lazy_static::lazy_static! {
                 ja: "次元の狭間オメガ零式：シグマ編4",
                 de: "Sigmametrie 4.0 (episch)",
                 fr: "Sigmastice v4.0 (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         473 => DutyInfo {
             name: LocalisedText {
@@ -2833,9 +4726,16 @@ lazy_static::lazy_static! {
                 ja: "ヴァレンティオンセレモニー",
                 de: "Valentionzeremonie",
                 fr: "La Cérémonie de la Valention",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(22),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         474 => DutyInfo {
             name: LocalisedText {
@@ -2843,9 +4743,16 @@ lazy_static::lazy_static! {
                 ja: "リオレウス狩猟戦",
                 de: "Jagd auf Rathalos",
                 fr: "Chasse au Rathalos",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         475 => DutyInfo {
             name: LocalisedText {
@@ -2853,9 +4760,16 @@ lazy_static::lazy_static! {
                 ja: "極リオレウス狩猟戦",
                 de: "Jagd auf Rathalos (schwer)",
                 fr: "Chasse au Rathalos (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         481 => DutyInfo {
             name: LocalisedText {
@@ -2863,9 +4777,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：チュートリアル",
                 de: "Chocobo-Rennen: Übungsbahn",
                 fr: "Course d'appentissage",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         482 => DutyInfo {
             name: LocalisedText {
@@ -2873,9 +4794,16 @@ lazy_static::lazy_static! {
                 ja: "第1節：インコースを狙え",
                 de: "1: Immer schön innen",
                 fr: "CP1 - Toujours à la corde!",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         483 => DutyInfo {
             name: LocalisedText {
@@ -2883,9 +4811,16 @@ lazy_static::lazy_static! {
                 ja: "第2節：範囲攻撃を避けろ",
                 de: "2: Vorsicht, Flächenangriff",
                 fr: "CP2 - Gare aux attaques à aires d'effet!",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         484 => DutyInfo {
             name: LocalisedText {
@@ -2893,9 +4828,16 @@ lazy_static::lazy_static! {
                 ja: "第3節：アビリティに頼るな",
                 de: "3: Fähigkeiten sind nicht alles",
                 fr: "CP3 - En avant même sans aptitudes!",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         485 => DutyInfo {
             name: LocalisedText {
@@ -2903,9 +4845,16 @@ lazy_static::lazy_static! {
                 ja: "第4節：ヘヴィなレース",
                 de: "4: Ein gewichtiges Rennen",
                 fr: "CP4 - Une course très très pesante!",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         486 => DutyInfo {
             name: LocalisedText {
@@ -2913,9 +4862,16 @@ lazy_static::lazy_static! {
                 ja: "第5節：とんずらを阻止せよ",
                 de: "5: Hiergeblieben!",
                 fr: "CP5 - Attention aux départs rapides!",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         487 => DutyInfo {
             name: LocalisedText {
@@ -2923,9 +4879,16 @@ lazy_static::lazy_static! {
                 ja: "第6節：ライバルを叩け",
                 de: "6: Rivale auf Trab",
                 fr: "CP6 - Pas de pitié pour les rivaux!",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         488 => DutyInfo {
             name: LocalisedText {
@@ -2933,9 +4896,16 @@ lazy_static::lazy_static! {
                 ja: "第7節：フィールド・オブ・ドリームズ",
                 de: "7: Feld der Träume",
                 fr: "CP7 - Prendre du champ avec les champs!",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         489 => DutyInfo {
             name: LocalisedText {
@@ -2943,9 +4913,16 @@ lazy_static::lazy_static! {
                 ja: "第8節：漁夫の利を狙え",
                 de: "8: Der lachende Dritte",
                 fr: "CP8 - Laissons les rivaux s'éliminer!",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         490 => DutyInfo {
             name: LocalisedText {
@@ -2953,9 +4930,16 @@ lazy_static::lazy_static! {
                 ja: "第9節：スタミナの戦い",
                 de: "9: Der längere Atem",
                 fr: "CP9 - Une bataille d'endurance!",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         491 => DutyInfo {
             name: LocalisedText {
@@ -2963,9 +4947,16 @@ lazy_static::lazy_static! {
                 ja: "第10節：逃げる者と追う者と",
                 de: "10: Zwei Temperamente",
                 fr: "CP10 - Course poursuite!",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         492 => DutyInfo {
             name: LocalisedText {
@@ -2973,9 +4964,16 @@ lazy_static::lazy_static! {
                 ja: "第11節：全速で駆け抜けろ",
                 de: "11: Volle Kraft voraus",
                 fr: "CP11 - À bride abattue!",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         493 => DutyInfo {
             name: LocalisedText {
@@ -2983,9 +4981,16 @@ lazy_static::lazy_static! {
                 ja: "第12節：アビリティを駆使せよ",
                 de: "12: Fähigkeiten ohne Wenn und Extra",
                 fr: "CP12 - Une épreuve d'aptitude!",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         494 => DutyInfo {
             name: LocalisedText {
@@ -2993,9 +4998,16 @@ lazy_static::lazy_static! {
                 ja: "第13節：連携プレイに勝利せよ",
                 de: "13: Ein unschlagbares Gespann",
                 fr: "CP13 - Un travail d'équipe!",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         495 => DutyInfo {
             name: LocalisedText {
@@ -3003,9 +5015,16 @@ lazy_static::lazy_static! {
                 ja: "第14節：続ヘヴィなレース",
                 de: "14: Ein äußerst gewichtiges Rennen",
                 fr: "CP14 - Une course encore plus pesante!",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         496 => DutyInfo {
             name: LocalisedText {
@@ -3013,9 +5032,16 @@ lazy_static::lazy_static! {
                 ja: "第15節：究極のレース",
                 de: "15: Rennen der Superlative",
                 fr: "CP15 - La course des extrêmes!",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         497 => DutyInfo {
             name: LocalisedText {
@@ -3023,9 +5049,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：サゴリーロード",
                 de: "Chocobo-Rennen: Sagolii-Straße",
                 fr: "Course de chocobos: Route de Sagolii",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         498 => DutyInfo {
             name: LocalisedText {
@@ -3033,9 +5066,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：コスタ・デル・ソル",
                 de: "Chocobo-Rennen: Sonnenküste",
                 fr: "Course de chocobos: Costa del Sol",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         499 => DutyInfo {
             name: LocalisedText {
@@ -3043,9 +5083,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：トランキルパス",
                 de: "Chocobo-Rennen: Pfad der Seelenruhe",
                 fr: "Course de chocobos: Sentes tranquilles",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         500 => DutyInfo {
             name: LocalisedText {
@@ -3053,9 +5100,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：サゴリーロード",
                 de: "Chocobo-Rennen: Sagolii-Straße",
                 fr: "Course de chocobos: Route de Sagolii",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         501 => DutyInfo {
             name: LocalisedText {
@@ -3063,9 +5117,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：コスタ・デル・ソル",
                 de: "Chocobo-Rennen: Sonnenküste",
                 fr: "Course de chocobos: Costa del Sol",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         502 => DutyInfo {
             name: LocalisedText {
@@ -3073,9 +5134,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：トランキルパス",
                 de: "Chocobo-Rennen: Pfad der Seelenruhe",
                 fr: "Course de chocobos: Sentes tranquilles",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         503 => DutyInfo {
             name: LocalisedText {
@@ -3083,9 +5151,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：サゴリーロード",
                 de: "Chocobo-Rennen: Sagolii-Straße",
                 fr: "Course de chocobos: Route de Sagolii",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         504 => DutyInfo {
             name: LocalisedText {
@@ -3093,9 +5168,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：コスタ・デル・ソル",
                 de: "Chocobo-Rennen: Sonnenküste",
                 fr: "Course de chocobos: Costa del Sol",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         505 => DutyInfo {
             name: LocalisedText {
@@ -3103,9 +5185,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：トランキルパス",
                 de: "Chocobo-Rennen: Pfad der Seelenruhe",
                 fr: "Course de chocobos: Sentes tranquilles",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         506 => DutyInfo {
             name: LocalisedText {
@@ -3113,9 +5202,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：サゴリーロード",
                 de: "Chocobo-Rennen: Sagolii-Straße",
                 fr: "Course de chocobos: Route de Sagolii",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         507 => DutyInfo {
             name: LocalisedText {
@@ -3123,9 +5219,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：コスタ・デル・ソル",
                 de: "Chocobo-Rennen: Sonnenküste",
                 fr: "Course de chocobos: Costa del Sol",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         508 => DutyInfo {
             name: LocalisedText {
@@ -3133,9 +5236,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：トランキルパス",
                 de: "Chocobo-Rennen: Pfad der Seelenruhe",
                 fr: "Course de chocobos: Sentes tranquilles",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         509 => DutyInfo {
             name: LocalisedText {
@@ -3143,9 +5253,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：サゴリーロード",
                 de: "Chocobo-Rennen: Sagolii-Straße",
                 fr: "Course de chocobos: Route de Sagolii",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         510 => DutyInfo {
             name: LocalisedText {
@@ -3153,9 +5270,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：コスタ・デル・ソル",
                 de: "Chocobo-Rennen: Sonnenküste",
                 fr: "Course de chocobos: Costa del Sol",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         511 => DutyInfo {
             name: LocalisedText {
@@ -3163,9 +5287,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：トランキルパス",
                 de: "Chocobo-Rennen: Pfad der Seelenruhe",
                 fr: "Course de chocobos: Sentes tranquilles",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         512 => DutyInfo {
             name: LocalisedText {
@@ -3173,9 +5304,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：サゴリーロード",
                 de: "Chocobo-Rennen: Sagolii-Straße",
                 fr: "Course de chocobos: Route de Sagolii",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         513 => DutyInfo {
             name: LocalisedText {
@@ -3183,9 +5321,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：コスタ・デル・ソル",
                 de: "Chocobo-Rennen: Sonnenküste",
                 fr: "Course de chocobos: Costa del Sol",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         514 => DutyInfo {
             name: LocalisedText {
@@ -3193,9 +5338,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：トランキルパス",
                 de: "Chocobo-Rennen: Pfad der Seelenruhe",
                 fr: "Course de chocobos: Sentes tranquilles",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         515 => DutyInfo {
             name: LocalisedText {
@@ -3203,9 +5355,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：サゴリーロード",
                 de: "Chocobo-Rennen: Sagolii-Straße",
                 fr: "Course de chocobos: Route de Sagolii",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         516 => DutyInfo {
             name: LocalisedText {
@@ -3213,9 +5372,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：コスタ・デル・ソル",
                 de: "Chocobo-Rennen: Sonnenküste",
                 fr: "Course de chocobos: Costa del Sol",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         517 => DutyInfo {
             name: LocalisedText {
@@ -3223,9 +5389,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：トランキルパス",
                 de: "Chocobo-Rennen: Pfad der Seelenruhe",
                 fr: "Course de chocobos: Sentes tranquilles",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         518 => DutyInfo {
             name: LocalisedText {
@@ -3233,9 +5406,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：サゴリーロード",
                 de: "Chocobo-Rennen: Sagolii-Straße",
                 fr: "Course de chocobos: Route de Sagolii",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         519 => DutyInfo {
             name: LocalisedText {
@@ -3243,9 +5423,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：コスタ・デル・ソル",
                 de: "Chocobo-Rennen: Sonnenküste",
                 fr: "Course de chocobos: Costa del Sol",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         520 => DutyInfo {
             name: LocalisedText {
@@ -3253,9 +5440,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：トランキルパス",
                 de: "Chocobo-Rennen: Pfad der Seelenruhe",
                 fr: "Course de chocobos: Sentes tranquilles",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         521 => DutyInfo {
             name: LocalisedText {
@@ -3263,9 +5457,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：サゴリーロード",
                 de: "Chocobo-Rennen: Sagolii-Straße",
                 fr: "Course de chocobos: Route de Sagolii",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         522 => DutyInfo {
             name: LocalisedText {
@@ -3273,9 +5474,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：コスタ・デル・ソル",
                 de: "Chocobo-Rennen: Sonnenküste",
                 fr: "Course de chocobos: Costa del Sol",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         523 => DutyInfo {
             name: LocalisedText {
@@ -3283,9 +5491,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：トランキルパス",
                 de: "Chocobo-Rennen: Pfad der Seelenruhe",
                 fr: "Course de chocobos: Sentes tranquilles",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         524 => DutyInfo {
             name: LocalisedText {
@@ -3293,9 +5508,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：サゴリーロード",
                 de: "Chocobo-Rennen: Sagolii-Straße",
                 fr: "Course de chocobos: Route de Sagolii",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         525 => DutyInfo {
             name: LocalisedText {
@@ -3303,9 +5525,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：コスタ・デル・ソル",
                 de: "Chocobo-Rennen: Sonnenküste",
                 fr: "Course de chocobos: Costa del Sol",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         526 => DutyInfo {
             name: LocalisedText {
@@ -3313,9 +5542,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：トランキルパス",
                 de: "Chocobo-Rennen: Pfad der Seelenruhe",
                 fr: "Course de chocobos: Sentes tranquilles",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         527 => DutyInfo {
             name: LocalisedText {
@@ -3323,9 +5559,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：サゴリーロード",
                 de: "Chocobo-Rennen: Sagolii-Straße",
                 fr: "Course de chocobos: Route de Sagolii",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         528 => DutyInfo {
             name: LocalisedText {
@@ -3333,9 +5576,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：コスタ・デル・ソル",
                 de: "Chocobo-Rennen: Sonnenküste",
                 fr: "Course de chocobos: Costa del Sol",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         529 => DutyInfo {
             name: LocalisedText {
@@ -3343,9 +5593,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：トランキルパス",
                 de: "Chocobo-Rennen: Pfad der Seelenruhe",
                 fr: "Course de chocobos: Sentes tranquilles",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         530 => DutyInfo {
             name: LocalisedText {
@@ -3353,9 +5610,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：サゴリーロード",
                 de: "Chocobo-Rennen: Sagolii-Straße",
                 fr: "Course de chocobos: Route de Sagolii",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         531 => DutyInfo {
             name: LocalisedText {
@@ -3363,9 +5627,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：コスタ・デル・ソル",
                 de: "Chocobo-Rennen: Sonnenküste",
                 fr: "Course de chocobos: Costa del Sol",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         532 => DutyInfo {
             name: LocalisedText {
@@ -3373,9 +5644,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：トランキルパス",
                 de: "Chocobo-Rennen: Pfad der Seelenruhe",
                 fr: "Course de chocobos: Sentes tranquilles",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         533 => DutyInfo {
             name: LocalisedText {
@@ -3383,9 +5661,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：サゴリーロード",
                 de: "Chocobo-Rennen: Sagolii-Straße",
                 fr: "Course de chocobos: Route de Sagolii",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         534 => DutyInfo {
             name: LocalisedText {
@@ -3393,9 +5678,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：コスタ・デル・ソル",
                 de: "Chocobo-Rennen: Sonnenküste",
                 fr: "Course de chocobos: Costa del Sol",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         535 => DutyInfo {
             name: LocalisedText {
@@ -3403,9 +5695,16 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：トランキルパス",
                 de: "Chocobo-Rennen: Pfad der Seelenruhe",
                 fr: "Course de chocobos: Sentes tranquilles",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         536 => DutyInfo {
             name: LocalisedText {
@@ -3413,9 +5712,16 @@ lazy_static::lazy_static! {
                 ja: "風水霊殿 ガンエン廟",
                 de: "Kompass der Schwalbe",
                 fr: "Le Compas de l'Hirondelle",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         537 => DutyInfo {
             name: LocalisedText {
@@ -3423,9 +5729,16 @@ lazy_static::lazy_static! {
                 ja: "ツクヨミ討滅戦",
                 de: "Götterdämmerung - Tsukuyomi",
                 fr: "Castrum Fluminis",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         538 => DutyInfo {
             name: LocalisedText {
@@ -3433,9 +5746,16 @@ lazy_static::lazy_static! {
                 ja: "極ツクヨミ討滅戦",
                 de: "Zenit der Götter - Tsukuyomi",
                 fr: "Castrum Fluminis (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         539 => DutyInfo {
             name: LocalisedText {
@@ -3443,9 +5763,16 @@ lazy_static::lazy_static! {
                 ja: "絶アルテマウェポン破壊作戦",
                 de: "Heldenlied von Ultima (fatal)",
                 fr: "La Fantasmagorie d'Ultima (fatal)",
+                ..Default::default()
             },
             high_end: true,
             content_kind: ContentKind::UltimateRaids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         540 => DutyInfo {
             name: LocalisedText {
@@ -3453,9 +5780,16 @@ lazy_static::lazy_static! {
                 ja: "アメノミハシラ 1～10層",
                 de: "Himmelssäule (Ebenen 1-10)",
                 fr: "Le Pilier des Cieux (étages 1-10)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         541 => DutyInfo {
             name: LocalisedText {
@@ -3463,9 +5797,16 @@ lazy_static::lazy_static! {
                 ja: "アメノミハシラ 11～20層",
                 de: "Himmelssäule (Ebenen 11-20)",
                 fr: "Le Pilier des Cieux (étages 11-20)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         542 => DutyInfo {
             name: LocalisedText {
@@ -3473,9 +5814,16 @@ lazy_static::lazy_static! {
                 ja: "アメノミハシラ 21～30層",
                 de: "Himmelssäule (Ebenen 21-30)",
                 fr: "Le Pilier des Cieux (étages 21-30)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         543 => DutyInfo {
             name: LocalisedText {
@@ -3483,9 +5831,16 @@ lazy_static::lazy_static! {
                 ja: "アメノミハシラ 31～40層",
                 de: "Himmelssäule (Ebenen 31-40)",
                 fr: "Le Pilier des Cieux (étages 31-40)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         544 => DutyInfo {
             name: LocalisedText {
@@ -3493,9 +5848,16 @@ lazy_static::lazy_static! {
                 ja: "アメノミハシラ 41～50層",
                 de: "Himmelssäule (Ebenen 41-50)",
                 fr: "Le Pilier des Cieux (étages 41-50)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         545 => DutyInfo {
             name: LocalisedText {
@@ -3503,9 +5865,16 @@ lazy_static::lazy_static! {
                 ja: "アメノミハシラ 51～60層",
                 de: "Himmelssäule (Ebenen 51-60)",
                 fr: "Le Pilier des Cieux (étages 51-60)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         546 => DutyInfo {
             name: LocalisedText {
@@ -3513,9 +5882,16 @@ lazy_static::lazy_static! {
                 ja: "アメノミハシラ 61～70層",
                 de: "Himmelssäule (Ebenen 61-70)",
                 fr: "Le Pilier des Cieux (étages 61-70)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         547 => DutyInfo {
             name: LocalisedText {
@@ -3523,9 +5899,16 @@ lazy_static::lazy_static! {
                 ja: "アメノミハシラ 71～80層",
                 de: "Himmelssäule (Ebenen 71-80)",
                 fr: "Le Pilier des Cieux (étages 71-80)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         548 => DutyInfo {
             name: LocalisedText {
@@ -3533,9 +5916,16 @@ lazy_static::lazy_static! {
                 ja: "アメノミハシラ 81～90層",
                 de: "Himmelssäule (Ebenen 81-90)",
                 fr: "Le Pilier des Cieux (étages 81-90)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         549 => DutyInfo {
             name: LocalisedText {
@@ -3543,9 +5933,16 @@ lazy_static::lazy_static! {
                 ja: "アメノミハシラ 91～100層",
                 de: "Himmelssäule (Ebenen 91-100)",
                 fr: "Le Pilier des Cieux (étages 91-100)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         550 => DutyInfo {
             name: LocalisedText {
@@ -3553,9 +5950,16 @@ lazy_static::lazy_static! {
                 ja: "封じられた聖塔 リドルアナ",
                 de: "Richtfeuer von Ridorana",
                 fr: "Le Phare de Ridorana",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         552 => DutyInfo {
             name: LocalisedText {
@@ -3563,9 +5967,16 @@ lazy_static::lazy_static! {
                 ja: "第1節：チュートリアル",
                 de: "Stufe 1: Einführung",
                 fr: "Bataille 1: Tutoriel",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         553 => DutyInfo {
             name: LocalisedText {
@@ -3573,9 +5984,16 @@ lazy_static::lazy_static! {
                 ja: "第2節：チョコチョコチョコチョコボ",
                 de: "Stufe 2: Federn lassen",
                 fr: "Bataille 2: Ô beaux chocobos",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         554 => DutyInfo {
             name: LocalisedText {
@@ -3583,9 +6001,16 @@ lazy_static::lazy_static! {
                 ja: "第3節：序盤にありそうな戦い",
                 de: "Stufe 3: Angriff der Ungeziefer",
                 fr: "Bataille 3: Des ouvertures",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         555 => DutyInfo {
             name: LocalisedText {
@@ -3593,9 +6018,16 @@ lazy_static::lazy_static! {
                 ja: "第4節：小さな巨獣",
                 de: "Stufe 4: Riesenbaby-Goobbue",
                 fr: "Bataille 4: Grosses petites bestioles",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         556 => DutyInfo {
             name: LocalisedText {
@@ -3603,9 +6035,16 @@ lazy_static::lazy_static! {
                 ja: "第5節：蛮族たちの反乱",
                 de: "Stufe 5: Aufstand der Wilden",
                 fr: "Bataille 5: Hommes-bêtes révoltés",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         557 => DutyInfo {
             name: LocalisedText {
@@ -3613,9 +6052,16 @@ lazy_static::lazy_static! {
                 ja: "第6節：恐怖、タラタラの墓所",
                 de: "Stufe 6: Gedankenspiele",
                 fr: "Bataille 6: Hypogée de Tam-Tam",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         558 => DutyInfo {
             name: LocalisedText {
@@ -3623,9 +6069,16 @@ lazy_static::lazy_static! {
                 ja: "第7節：ライバルたちの共闘",
                 de: "Stufe 7: Vierbeinige Feinde",
                 fr: "Bataille 7: Comme chien et chat",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         559 => DutyInfo {
             name: LocalisedText {
@@ -3633,9 +6086,16 @@ lazy_static::lazy_static! {
                 ja: "第8節：暁は光と闇とを分かつ",
                 de: "Stufe 8: Das Bündchen der Morgenröte",
                 fr: "Bataille 8: Héritiers miniatures",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         560 => DutyInfo {
             name: LocalisedText {
@@ -3643,9 +6103,16 @@ lazy_static::lazy_static! {
                 ja: "第9節：爆破、カパカパベル銅山",
                 de: "Stufe 9: Auf den Schleim gegangen",
                 fr: "Bataille 9: Déflagration à Clochecuivre",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         561 => DutyInfo {
             name: LocalisedText {
@@ -3653,9 +6120,16 @@ lazy_static::lazy_static! {
                 ja: "第10節：マンドラ帝国の逆襲",
                 de: "Stufe 10: Mandragora-Salat",
                 fr: "Bataille 10: Invasion de mandragores",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         562 => DutyInfo {
             name: LocalisedText {
@@ -3663,9 +6137,16 @@ lazy_static::lazy_static! {
                 ja: "第11節：女王陛下万歳！",
                 de: "Stufe 11: Die trabantäische Allianz",
                 fr: "Bataille 11: Longue vie aux Sultanes!",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         563 => DutyInfo {
             name: LocalisedText {
@@ -3673,9 +6154,16 @@ lazy_static::lazy_static! {
                 ja: "第12節：デモンズブロック崩し",
                 de: "Stufe 12: Alea iacta est",
                 fr: "Bataille 12: Château d'Amdapeur",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         564 => DutyInfo {
             name: LocalisedText {
@@ -3683,9 +6171,16 @@ lazy_static::lazy_static! {
                 ja: "第13節：筆頭機工師ガーロンド",
                 de: "Stufe 13: Luftadmiral Garlond",
                 fr: "Bataille 13: Épreuve des Forges",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         565 => DutyInfo {
             name: LocalisedText {
@@ -3693,9 +6188,16 @@ lazy_static::lazy_static! {
                 ja: "第14節：ナッツの味にはもう飽きた",
                 de: "Stufe 14: Eins auf die Nuss",
                 fr: "Bataille 14: Casse-noisette",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         566 => DutyInfo {
             name: LocalisedText {
@@ -3703,9 +6205,16 @@ lazy_static::lazy_static! {
                 ja: "第15節：極小オーディン討滅戦",
                 de: "Stufe 15: Der Dunkle Reiter",
                 fr: "Bataille 15: Mini-fontaine d'Urth",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         567 => DutyInfo {
             name: LocalisedText {
@@ -3713,9 +6222,16 @@ lazy_static::lazy_static! {
                 ja: "第16節：チョコボ大行進",
                 de: "Stufe 16: Chocobo-Mania",
                 fr: "Bataille 16: Parade des chocobos",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         568 => DutyInfo {
             name: LocalisedText {
@@ -3723,9 +6239,16 @@ lazy_static::lazy_static! {
                 ja: "第17節：融解、スノークローク小氷壁",
                 de: "Stufe 17: Jenseits des Schneekleids",
                 fr: "Bataille 17: Bonhommes de Manteneige",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         569 => DutyInfo {
             name: LocalisedText {
@@ -3733,9 +6256,16 @@ lazy_static::lazy_static! {
                 ja: "第18節：小さなモブハント",
                 de: "Stufe 18: Die Trabanten-Jagd",
                 fr: "Bataille 18: Mini-contrat de chasse",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         570 => DutyInfo {
             name: LocalisedText {
@@ -3743,9 +6273,16 @@ lazy_static::lazy_static! {
                 ja: "第19節：極小ギルガメッシュ討滅戦",
                 de: "Stufe 19: Gilgameschs Hühnerstall",
                 fr: "Bataille 19: Revanche de Gilgamini",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         571 => DutyInfo {
             name: LocalisedText {
@@ -3753,9 +6290,16 @@ lazy_static::lazy_static! {
                 ja: "第20節：汝にクリスタルの導きあれ",
                 de: "Stufe 20: Trabanten des Lichts",
                 fr: "Bataille 20: Pouvoir de la Lumière",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         572 => DutyInfo {
             name: LocalisedText {
@@ -3763,9 +6307,16 @@ lazy_static::lazy_static! {
                 ja: "第21節：賢人ルイゾワの試練",
                 de: "Stufe 21: Die Leveilleurs",
                 fr: "Bataille 21: Louisoix et petits fils",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         573 => DutyInfo {
             name: LocalisedText {
@@ -3773,9 +6324,16 @@ lazy_static::lazy_static! {
                 ja: "第22節：薄闇の世界",
                 de: "Stufe 22: Kleine Welt der Dunkelheit",
                 fr: "Bataille 22: Monde des Quasi-ténèbres",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         574 => DutyInfo {
             name: LocalisedText {
@@ -3783,9 +6341,16 @@ lazy_static::lazy_static! {
                 ja: "第23節：大迷惑バハムート邂逅編",
                 de: "Stufe 23: In die Verschlungenen Schatten",
                 fr: "Bataille 23: Labyrinthe de Basta-mut",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         575 => DutyInfo {
             name: LocalisedText {
@@ -3793,9 +6358,16 @@ lazy_static::lazy_static! {
                 ja: "第24節：大迷惑バハムート真成編",
                 de: "Stufe 24: Bahamut, der Aufzieh-Primae",
                 fr: "Bataille 24: Abîme de Basta-mut",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         576 => DutyInfo {
             name: LocalisedText {
@@ -3803,9 +6375,16 @@ lazy_static::lazy_static! {
                 ja: "LoVM：ミニオンクラス (Easy)",
                 de: "Kampf der Trabanten: Trabantenkampf",
                 fr: "Bataille simple contre l'ordinateur (facile)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         577 => DutyInfo {
             name: LocalisedText {
@@ -3813,9 +6392,16 @@ lazy_static::lazy_static! {
                 ja: "LoVM：真ミニオンクラス (Normal)",
                 de: "Kampf der Trabanten: Trabantendämmerung",
                 fr: "Bataille simple contre l'ordinateur (normal)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         578 => DutyInfo {
             name: LocalisedText {
@@ -3823,9 +6409,16 @@ lazy_static::lazy_static! {
                 ja: "LoVM：極ミニオンクラス (Hard)",
                 de: "Kampf der Trabanten: Zenit der Trabanten",
                 fr: "Bataille simple contre l'ordinateur (brutal)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         579 => DutyInfo {
             name: LocalisedText {
@@ -3833,9 +6426,16 @@ lazy_static::lazy_static! {
                 ja: "LoVM：大会対戦 (CPU対戦)",
                 de: "Kampf der Trabanten: Turnier (gegen Arenameister)",
                 fr: "Bataille de tournoi contre l'ordinateur",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         581 => DutyInfo {
             name: LocalisedText {
@@ -3843,9 +6443,16 @@ lazy_static::lazy_static! {
                 ja: "禁断の地 エウレカ：パゴス編",
                 de: "Eureka Pagos",
                 fr: "Eurêka Pagos",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Eureka,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         582 => DutyInfo {
             name: LocalisedText {
@@ -3853,9 +6460,16 @@ lazy_static::lazy_static! {
                 ja: "「暁」の少年",
                 de: "Der Knabe der Morgenröte",
                 fr: "Voyage en terre hostile",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         583 => DutyInfo {
             name: LocalisedText {
@@ -3863,9 +6477,16 @@ lazy_static::lazy_static! {
                 ja: "新生祭軍事演習",
                 de: "Gedenkschlacht der Eorzäischen Allianz",
                 fr: "Les grandes manœuvres commémoratives",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(22),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         584 => DutyInfo {
             name: LocalisedText {
@@ -3873,9 +6494,16 @@ lazy_static::lazy_static! {
                 ja: "草木汚染 聖モシャーヌ植物園 (Hard)",
                 de: "Sankt Mocianne-Arboretum (schwer)",
                 fr: "L'Arboretum Sainte-Mocianne (brutal)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         585 => DutyInfo {
             name: LocalisedText {
@@ -3883,9 +6511,16 @@ lazy_static::lazy_static! {
                 ja: "永久焦土 ザ・バーン",
                 de: "Das Kargland",
                 fr: "L'Escarre",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         586 => DutyInfo {
             name: LocalisedText {
@@ -3893,9 +6528,16 @@ lazy_static::lazy_static! {
                 ja: "宝物庫 ウズネアカナル祭殿",
                 de: "Glücksaltäre von Uznair",
                 fr: "Le Temple sacré d'Uznair",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TreasureHunt,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         587 => DutyInfo {
             name: LocalisedText {
@@ -3903,9 +6545,16 @@ lazy_static::lazy_static! {
                 ja: "次元の狭間オメガ：アルファ編1",
                 de: "Alphametrie 1.0",
                 fr: "Alphastice v1.0",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         588 => DutyInfo {
             name: LocalisedText {
@@ -3913,9 +6562,16 @@ lazy_static::lazy_static! {
                 ja: "次元の狭間オメガ：アルファ編2",
                 de: "Alphametrie 2.0",
                 fr: "Alphastice v2.0",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         589 => DutyInfo {
             name: LocalisedText {
@@ -3923,9 +6579,16 @@ lazy_static::lazy_static! {
                 ja: "次元の狭間オメガ：アルファ編3",
                 de: "Alphametrie 3.0",
                 fr: "Alphastice v3.0",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         590 => DutyInfo {
             name: LocalisedText {
@@ -3933,9 +6596,16 @@ lazy_static::lazy_static! {
                 ja: "次元の狭間オメガ：アルファ編4",
                 de: "Alphametrie 4.0",
                 fr: "Alphastice v4.0",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         591 => DutyInfo {
             name: LocalisedText {
@@ -3943,9 +6613,16 @@ lazy_static::lazy_static! {
                 ja: "次元の狭間オメガ零式：アルファ編1",
                 de: "Alphametrie 1.0 (episch)",
                 fr: "Alphastice v1.0 (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         592 => DutyInfo {
             name: LocalisedText {
@@ -3953,9 +6630,16 @@ lazy_static::lazy_static! {
                 ja: "次元の狭間オメガ零式：アルファ編2",
                 de: "Alphametrie 2.0 (episch)",
                 fr: "Alphastice v2.0 (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         593 => DutyInfo {
             name: LocalisedText {
@@ -3963,9 +6647,16 @@ lazy_static::lazy_static! {
                 ja: "次元の狭間オメガ零式：アルファ編3",
                 de: "Alphametrie 3.0 (episch)",
                 fr: "Alphastice v3.0 (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         594 => DutyInfo {
             name: LocalisedText {
@@ -3973,9 +6664,16 @@ lazy_static::lazy_static! {
                 ja: "次元の狭間オメガ零式：アルファ編4",
                 de: "Alphametrie 4.0 (episch)",
                 fr: "Alphastice v4.0 (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         595 => DutyInfo {
             name: LocalisedText {
@@ -3983,9 +6681,16 @@ lazy_static::lazy_static! {
                 ja: "真ヨウジンボウ討滅戦",
                 de: "Duell auf der Kugane-Brücke",
                 fr: "Le Pont Ohashi",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         596 => DutyInfo {
             name: LocalisedText {
@@ -3993,9 +6698,16 @@ lazy_static::lazy_static! {
                 ja: "朱雀征魂戦",
                 de: "Seelentanz - Suzaku",
                 fr: "Le Nid des Lamentations",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         597 => DutyInfo {
             name: LocalisedText {
@@ -4003,9 +6715,16 @@ lazy_static::lazy_static! {
                 ja: "極朱雀征魂戦",
                 de: "Seelensturm - Suzaku",
                 fr: "Le Nid des Lamentations (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         598 => DutyInfo {
             name: LocalisedText {
@@ -4013,9 +6732,16 @@ lazy_static::lazy_static! {
                 ja: "禁断の地 エウレカ：ピューロス編",
                 de: "Eureka Pyros",
                 fr: "Eurêka Pyros",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Eureka,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         599 => DutyInfo {
             name: LocalisedText {
@@ -4023,9 +6749,16 @@ lazy_static::lazy_static! {
                 ja: "ヒドゥンゴージ (機工戦)",
                 de: "Verborgene Schlucht",
                 fr: "Gorge dérobée (machinerie)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         600 => DutyInfo {
             name: LocalisedText {
@@ -4033,9 +6766,16 @@ lazy_static::lazy_static! {
                 ja: "挑戦！ ジャンピングアスレチック",
                 de: "Kaktor-Kletterwand",
                 fr: "Haute voltige",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         601 => DutyInfo {
             name: LocalisedText {
@@ -4043,9 +6783,16 @@ lazy_static::lazy_static! {
                 ja: "挑戦！ ジャンピングアスレチック",
                 de: "Kaktor-Kletterwand",
                 fr: "Haute voltige",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         602 => DutyInfo {
             name: LocalisedText {
@@ -4053,9 +6800,16 @@ lazy_static::lazy_static! {
                 ja: "挑戦！ ジャンピングアスレチック",
                 de: "Kaktor-Kletterwand",
                 fr: "Haute voltige",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         603 => DutyInfo {
             name: LocalisedText {
@@ -4063,9 +6817,16 @@ lazy_static::lazy_static! {
                 ja: "挑戦！ ジャンピングアスレチック",
                 de: "Kaktor-Kletterwand",
                 fr: "Haute voltige",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         604 => DutyInfo {
             name: LocalisedText {
@@ -4073,9 +6834,16 @@ lazy_static::lazy_static! {
                 ja: "挑戦！ ジャンピングアスレチック",
                 de: "Kaktor-Kletterwand",
                 fr: "Haute voltige",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         605 => DutyInfo {
             name: LocalisedText {
@@ -4083,9 +6851,16 @@ lazy_static::lazy_static! {
                 ja: "挑戦！ ジャンピングアスレチック",
                 de: "Kaktor-Kletterwand",
                 fr: "Haute voltige",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         606 => DutyInfo {
             name: LocalisedText {
@@ -4093,9 +6868,16 @@ lazy_static::lazy_static! {
                 ja: "挑戦！ ジャンピングアスレチック",
                 de: "Kaktor-Kletterwand",
                 fr: "Haute voltige",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         607 => DutyInfo {
             name: LocalisedText {
@@ -4103,9 +6885,16 @@ lazy_static::lazy_static! {
                 ja: "挑戦！ ジャンピングアスレチック",
                 de: "Kaktor-Kletterwand",
                 fr: "Haute voltige",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         608 => DutyInfo {
             name: LocalisedText {
@@ -4113,9 +6902,16 @@ lazy_static::lazy_static! {
                 ja: "挑戦！ ジャンピングアスレチック",
                 de: "Kaktor-Kletterwand",
                 fr: "Haute voltige",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         609 => DutyInfo {
             name: LocalisedText {
@@ -4123,9 +6919,16 @@ lazy_static::lazy_static! {
                 ja: "楔石の虚",
                 de: "Der Wille der Mondgöttin",
                 fr: "Ralliement dans la steppe",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         610 => DutyInfo {
             name: LocalisedText {
@@ -4133,9 +6936,16 @@ lazy_static::lazy_static! {
                 ja: "デビューマッチ",
                 de: "Debüt in der Himmlischen Arena",
                 fr: "Début du spectacle",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TheMaskedCarnivale,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         611 => DutyInfo {
             name: LocalisedText {
@@ -4143,9 +6953,16 @@ lazy_static::lazy_static! {
                 ja: "境界戦線 ギムリトダーク",
                 de: "Die Ghimlyt-Finsternis",
                 fr: "Les Ténèbres de Ghimlyt",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         612 => DutyInfo {
             name: LocalisedText {
@@ -4153,9 +6970,16 @@ lazy_static::lazy_static! {
                 ja: "プリン・アラモード",
                 de: "Pudding nach Art des Hauses",
                 fr: "Puddings à la mode",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TheMaskedCarnivale,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         613 => DutyInfo {
             name: LocalisedText {
@@ -4163,9 +6987,16 @@ lazy_static::lazy_static! {
                 ja: "最初の岩壁「シパクナー」",
                 de: "Zipacna - Aller Anfang ist schwer",
                 fr: "Zipacna, le premier obstacle",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TheMaskedCarnivale,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         614 => DutyInfo {
             name: LocalisedText {
@@ -4173,9 +7004,16 @@ lazy_static::lazy_static! {
                 ja: "怪力の鉄巨人「クレイオス」",
                 de: "Kreios - Der Mann aus Stahl",
                 fr: "Kreios, le destructeur d'acier",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TheMaskedCarnivale,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         615 => DutyInfo {
             name: LocalisedText {
@@ -4183,9 +7021,16 @@ lazy_static::lazy_static! {
                 ja: "ギルガメブラザーズ",
                 de: "Die Bruderschaft der Kröten",
                 fr: "La fratrie des gilkhélones",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TheMaskedCarnivale,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         616 => DutyInfo {
             name: LocalisedText {
@@ -4193,9 +7038,16 @@ lazy_static::lazy_static! {
                 ja: "ブラインド・フューリー",
                 de: "Den Tod im Auge",
                 fr: "Vengeance aveugle",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TheMaskedCarnivale,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         617 => DutyInfo {
             name: LocalisedText {
@@ -4203,9 +7055,16 @@ lazy_static::lazy_static! {
                 ja: "シアーハートアタック",
                 de: "Heißkalte Schauer",
                 fr: "Pure attaque cardiaque",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TheMaskedCarnivale,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         618 => DutyInfo {
             name: LocalisedText {
@@ -4213,9 +7072,16 @@ lazy_static::lazy_static! {
                 ja: "青い牙、赤い牙",
                 de: "Explosiv - Vorhut in Blau und Rot",
                 fr: "Crocs bleus et crocs rouges",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TheMaskedCarnivale,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         619 => DutyInfo {
             name: LocalisedText {
@@ -4223,9 +7089,16 @@ lazy_static::lazy_static! {
                 ja: "七色の甘味「ギモーヴ」",
                 de: "Guimauve und die sieben Gesichter des Todes",
                 fr: "Guimauve, le goût de l'arc-en-ciel",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TheMaskedCarnivale,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         620 => DutyInfo {
             name: LocalisedText {
@@ -4233,9 +7106,16 @@ lazy_static::lazy_static! {
                 ja: "偶像の王者「クロムドゥーブ」",
                 de: "Crom Dubh - König der Götzen",
                 fr: "Crom Dubh, roi des idoles",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TheMaskedCarnivale,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         621 => DutyInfo {
             name: LocalisedText {
@@ -4243,9 +7123,16 @@ lazy_static::lazy_static! {
                 ja: "爆ボム・ファーストアタック",
                 de: "Explosiv - Die zweite Welle",
                 fr: "Les courtes mèches",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TheMaskedCarnivale,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         622 => DutyInfo {
             name: LocalisedText {
@@ -4253,9 +7140,16 @@ lazy_static::lazy_static! {
                 ja: "寄生植物「ヒドノラ」",
                 de: "Hydnora - Der Parasit, der Paras sieht",
                 fr: "Hydnora, la plante parasite",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TheMaskedCarnivale,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         623 => DutyInfo {
             name: LocalisedText {
@@ -4263,9 +7157,16 @@ lazy_static::lazy_static! {
                 ja: "紅の死妖姫「カーミラ」",
                 de: "Carmilla - Prinzessin des Todes",
                 fr: "La mystérieuse Carmilla",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TheMaskedCarnivale,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         624 => DutyInfo {
             name: LocalisedText {
@@ -4273,9 +7174,16 @@ lazy_static::lazy_static! {
                 ja: "死なばもろともーッ！",
                 de: "Der Tod kommt süß gekleidet",
                 fr: "La mort n'a pas d'ami",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TheMaskedCarnivale,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         625 => DutyInfo {
             name: LocalisedText {
@@ -4283,9 +7191,16 @@ lazy_static::lazy_static! {
                 ja: "アラグの脅威「闘獣システム」",
                 de: "Die Bestie steckt im System",
                 fr: "La sphère bestiale, une menace allagoise",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TheMaskedCarnivale,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         626 => DutyInfo {
             name: LocalisedText {
@@ -4293,9 +7208,16 @@ lazy_static::lazy_static! {
                 ja: "豪腕の獣王「ティクバラン」",
                 de: "Tikbalang - Der rechte Arm des Verderbens",
                 fr: "Tikbalang, bras tout-puissant",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TheMaskedCarnivale,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         627 => DutyInfo {
             name: LocalisedText {
@@ -4303,9 +7225,16 @@ lazy_static::lazy_static! {
                 ja: "剛柔の鉄巨人「クレイオス改」",
                 de: "Kreios - Neues vom Mann aus Stahl",
                 fr: "Kreios plie, mais ne rompt pas",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TheMaskedCarnivale,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         628 => DutyInfo {
             name: LocalisedText {
@@ -4313,9 +7242,16 @@ lazy_static::lazy_static! {
                 ja: "爆破デスマッチ",
                 de: "Explosiv - Revanche mit Wumms",
                 fr: "Rencontre explosive",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TheMaskedCarnivale,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         629 => DutyInfo {
             name: LocalisedText {
@@ -4323,9 +7259,16 @@ lazy_static::lazy_static! {
                 ja: "魅惑の芳香「リフレクティブ・レベッカ」",
                 de: "Rebekkha - Verführerische Gerüche",
                 fr: "Miroir, mon beau miroir",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TheMaskedCarnivale,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         630 => DutyInfo {
             name: LocalisedText {
@@ -4333,9 +7276,16 @@ lazy_static::lazy_static! {
                 ja: "名コンビ「オルトロス＆テュポーン」",
                 de: "Ultros & Typhon - Zwei wie Rotz und Wasser",
                 fr: "Duo de choc: Orthros et maître Typhon",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TheMaskedCarnivale,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         631 => DutyInfo {
             name: LocalisedText {
@@ -4343,9 +7293,16 @@ lazy_static::lazy_static! {
                 ja: "憤怒の合成獣「アペデマク」",
                 de: "Apademak - Die cholerische Chimära",
                 fr: "La chimèrique colère d'Apademak",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TheMaskedCarnivale,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         632 => DutyInfo {
             name: LocalisedText {
@@ -4353,9 +7310,16 @@ lazy_static::lazy_static! {
                 ja: "爆発の対消滅「グランパボム」",
                 de: "Explosiv - Urgroßbomber lässt es krachen",
                 fr: "L'histoire détonante de Papi bombo",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TheMaskedCarnivale,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         633 => DutyInfo {
             name: LocalisedText {
@@ -4363,9 +7327,16 @@ lazy_static::lazy_static! {
                 ja: "魔獣の皇太子「クロンプリンツ・ベヒーモス」",
                 de: "Kronprinz-Behemoth - Vom Teufel besessen",
                 fr: "Kronprinz béhémoth, le prince héritier",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TheMaskedCarnivale,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         634 => DutyInfo {
             name: LocalisedText {
@@ -4373,9 +7344,16 @@ lazy_static::lazy_static! {
                 ja: "異形の人形師「エペロギ」",
                 de: "Epilogi - Das Ende kommt immer zuletzt",
                 fr: "Epilogi, l'étrange marionnettiste",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TheMaskedCarnivale,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         635 => DutyInfo {
             name: LocalisedText {
@@ -4383,9 +7361,16 @@ lazy_static::lazy_static! {
                 ja: "悪の青魔道士「アポカリョープス」",
                 de: "Azulmagia - Der Blaumagier des Bösen",
                 fr: "L'abominable Azulmagia",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TheMaskedCarnivale,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         636 => DutyInfo {
             name: LocalisedText {
@@ -4393,9 +7378,16 @@ lazy_static::lazy_static! {
                 ja: "楽欲の僧院 オーボンヌ",
                 de: "Kloster von Orbonne",
                 fr: "Le Monastère d'Orbonne",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         637 => DutyInfo {
             name: LocalisedText {
@@ -4403,9 +7395,16 @@ lazy_static::lazy_static! {
                 ja: "青龍征魂戦",
                 de: "Seelentanz - Seiryu",
                 fr: "L'Îlot des Amertumes",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         638 => DutyInfo {
             name: LocalisedText {
@@ -4413,9 +7412,16 @@ lazy_static::lazy_static! {
                 ja: "極青龍征魂戦",
                 de: "Seelensturm - Seiryu",
                 fr: "L'Îlot des Amertumes (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         639 => DutyInfo {
             name: LocalisedText {
@@ -4423,9 +7429,16 @@ lazy_static::lazy_static! {
                 ja: "禁断の地 エウレカ：ヒュダトス編",
                 de: "Eureka Hydatos",
                 fr: "Eurêka Hydatos",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Eureka,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         640 => DutyInfo {
             name: LocalisedText {
@@ -4433,9 +7446,16 @@ lazy_static::lazy_static! {
                 ja: "出撃！ エアフォースパイロット",
                 de: "Luftwaffe, Feuer frei!",
                 fr: "As de l'air",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         641 => DutyInfo {
             name: LocalisedText {
@@ -4443,9 +7463,16 @@ lazy_static::lazy_static! {
                 ja: "出撃！ エアフォースパイロット",
                 de: "Luftwaffe, Feuer frei!",
                 fr: "As de l'air",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         642 => DutyInfo {
             name: LocalisedText {
@@ -4453,9 +7480,16 @@ lazy_static::lazy_static! {
                 ja: "出撃！ エアフォースパイロット",
                 de: "Luftwaffe, Feuer frei!",
                 fr: "As de l'air",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         643 => DutyInfo {
             name: LocalisedText {
@@ -4463,9 +7497,16 @@ lazy_static::lazy_static! {
                 ja: "ドマ式麻雀：半荘戦一般卓 (段位変動有り)",
                 de: "Anfänger-Mahjong (komplette Partie, gewertet)",
                 fr: "Mahjong domien: tous rangs (partie classée)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         644 => DutyInfo {
             name: LocalisedText {
@@ -4473,9 +7514,16 @@ lazy_static::lazy_static! {
                 ja: "ドマ式麻雀：半荘戦有段卓 (段位変動有り)",
                 de: "Fortgeschrittenen-Mahjong (komplette Partie, gewertet)",
                 fr: "Mahjong domien: dan seulement (partie classée)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         645 => DutyInfo {
             name: LocalisedText {
@@ -4483,9 +7531,16 @@ lazy_static::lazy_static! {
                 ja: "ドマ式麻雀：半荘戦4人セット卓 (クイタン有り)",
                 de: "4-Spieler-Mahjong (komplette Partie, Kuitan aktiviert)",
                 fr: "Mahjong domien: 4 joueurs (partie avec kuitan)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         646 => DutyInfo {
             name: LocalisedText {
@@ -4493,9 +7548,16 @@ lazy_static::lazy_static! {
                 ja: "来訪せし風の御使",
                 de: "Durch den Sturm und zurück",
                 fr: "La Messagère du vent",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         648 => DutyInfo {
             name: LocalisedText {
@@ -4503,9 +7565,16 @@ lazy_static::lazy_static! {
                 ja: "英雄への鎮魂歌",
                 de: "Requiem der Helden",
                 fr: "Un requiem pour les héros",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         649 => DutyInfo {
             name: LocalisedText {
@@ -4513,9 +7582,16 @@ lazy_static::lazy_static! {
                 ja: "水妖幻園 ドォーヌ・メグ",
                 de: "Dohn Mheg",
                 fr: "Dohn Mheg",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         650 => DutyInfo {
             name: LocalisedText {
@@ -4523,9 +7599,16 @@ lazy_static::lazy_static! {
                 ja: "ドマ式麻雀：半荘戦4人セット卓 (クイタン無し)",
                 de: "4-Spieler-Mahjong (komplette Partie, Kuitan deaktiviert)",
                 fr: "Mahjong domien: 4 joueurs (partie sans kuitan)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         651 => DutyInfo {
             name: LocalisedText {
@@ -4533,9 +7616,16 @@ lazy_static::lazy_static! {
                 ja: "古跡探索 キタンナ神影洞",
                 de: "Irrungen der Qitari",
                 fr: "L'Enchevêtrement des Qitari",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         652 => DutyInfo {
             name: LocalisedText {
@@ -4543,9 +7633,16 @@ lazy_static::lazy_static! {
                 ja: "終末幻想 アーモロート",
                 de: "Amaurot",
                 fr: "Amaurote",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         653 => DutyInfo {
             name: LocalisedText {
@@ -4553,9 +7650,16 @@ lazy_static::lazy_static! {
                 ja: "希望の園エデン：覚醒編1",
                 de: "Edens Erwachen - Auferstehung",
                 fr: "L'Éveil d'Éden - Résurrection",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         654 => DutyInfo {
             name: LocalisedText {
@@ -4563,9 +7667,16 @@ lazy_static::lazy_static! {
                 ja: "希望の園エデン零式：覚醒編1",
                 de: "Edens Erwachen - Auferstehung (episch)",
                 fr: "L'Éveil d'Éden - Résurrection (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         655 => DutyInfo {
             name: LocalisedText {
@@ -4573,9 +7684,16 @@ lazy_static::lazy_static! {
                 ja: "異界遺構 シルクス・ツイニング",
                 de: "Der Kristallzwilling",
                 fr: "La Macle de Syrcus",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         656 => DutyInfo {
             name: LocalisedText {
@@ -4583,9 +7701,16 @@ lazy_static::lazy_static! {
                 ja: "爽涼離宮 マリカの大井戸",
                 de: "Malikahs Brunnen",
                 fr: "Le Puits de Malikah",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         657 => DutyInfo {
             name: LocalisedText {
@@ -4593,9 +7718,16 @@ lazy_static::lazy_static! {
                 ja: "ティターニア討滅戦",
                 de: "Offenbarung - Titania",
                 fr: "La Valse du Monarque",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         658 => DutyInfo {
             name: LocalisedText {
@@ -4603,9 +7735,16 @@ lazy_static::lazy_static! {
                 ja: "極ティターニア討滅戦",
                 de: "Letzte Läuterung - Titania",
                 fr: "La Valse du Monarque (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         659 => DutyInfo {
             name: LocalisedText {
@@ -4613,9 +7752,16 @@ lazy_static::lazy_static! {
                 ja: "偽造天界 グルグ火山",
                 de: "Der Gulg",
                 fr: "Mont Gulg",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         661 => DutyInfo {
             name: LocalisedText {
@@ -4623,9 +7769,16 @@ lazy_static::lazy_static! {
                 ja: "創造機関 アナイダアカデミア",
                 de: "Akadaemia Anyder",
                 fr: "Akadaemia Anydre",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         666 => DutyInfo {
             name: LocalisedText {
@@ -4633,9 +7786,16 @@ lazy_static::lazy_static! {
                 ja: "イノセンス討滅戦",
                 de: "Offenbarung - Innozenz",
                 fr: "La Couronne de l'Immaculé",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         667 => DutyInfo {
             name: LocalisedText {
@@ -4643,9 +7803,16 @@ lazy_static::lazy_static! {
                 ja: "極イノセンス討滅戦",
                 de: "Letzte Läuterung - Innozenz",
                 fr: "La Couronne de l'Immaculé (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         676 => DutyInfo {
             name: LocalisedText {
@@ -4653,9 +7820,16 @@ lazy_static::lazy_static! {
                 ja: "殺戮郷村 ホルミンスター",
                 de: "Holminster",
                 fr: "Holminster",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         678 => DutyInfo {
             name: LocalisedText {
@@ -4663,9 +7837,16 @@ lazy_static::lazy_static! {
                 ja: "揺れる天秤",
                 de: "Ob Mitleid oder Hass",
                 fr: "Naissance d'un bourreau",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         679 => DutyInfo {
             name: LocalisedText {
@@ -4673,9 +7854,16 @@ lazy_static::lazy_static! {
                 ja: "古の大再生魔法",
                 de: "Alter Zauber",
                 fr: "Magie ancestrale",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         680 => DutyInfo {
             name: LocalisedText {
@@ -4683,9 +7871,16 @@ lazy_static::lazy_static! {
                 ja: "廃都ナバスアレン",
                 de: "Vater und Bruder",
                 fr: "Sur les rails de Nabaath Areng",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         681 => DutyInfo {
             name: LocalisedText {
@@ -4693,9 +7888,16 @@ lazy_static::lazy_static! {
                 ja: "仕掛けと呪いと毒と",
                 de: "Der Beichtstuhl von Toupasa dem Älteren",
                 fr: "Le Confessionnal de Toupasa l'ancien",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         682 => DutyInfo {
             name: LocalisedText {
@@ -4703,9 +7905,16 @@ lazy_static::lazy_static! {
                 ja: "希望の園エデン：覚醒編3",
                 de: "Edens Erwachen - Überflutung",
                 fr: "L'Éveil d'Éden - Déluge",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         683 => DutyInfo {
             name: LocalisedText {
@@ -4713,9 +7922,16 @@ lazy_static::lazy_static! {
                 ja: "希望の園エデン零式：覚醒編3",
                 de: "Edens Erwachen - Überflutung (episch)",
                 fr: "L'Éveil d'Éden - Déluge (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         684 => DutyInfo {
             name: LocalisedText {
@@ -4723,9 +7939,16 @@ lazy_static::lazy_static! {
                 ja: "希望の園エデン：覚醒編2",
                 de: "Edens Erwachen - Niederkunft",
                 fr: "L'Éveil d'Éden - Descente",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         685 => DutyInfo {
             name: LocalisedText {
@@ -4733,9 +7956,16 @@ lazy_static::lazy_static! {
                 ja: "希望の園エデン零式：覚醒編2",
                 de: "Edens Erwachen - Niederkunft (episch)",
                 fr: "L'Éveil d'Éden - Descente (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         686 => DutyInfo {
             name: LocalisedText {
@@ -4743,9 +7973,16 @@ lazy_static::lazy_static! {
                 ja: "ナイルベルトの後悔",
                 de: "Ein großes Opfer",
                 fr: "Une cupidité bien généreuse",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         687 => DutyInfo {
             name: LocalisedText {
@@ -4753,9 +7990,16 @@ lazy_static::lazy_static! {
                 ja: "ハーデス討滅戦",
                 de: "Offenbarung - Hades",
                 fr: "Le Râle de l'Agonie",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         688 => DutyInfo {
             name: LocalisedText {
@@ -4763,9 +8007,16 @@ lazy_static::lazy_static! {
                 ja: "宝物庫 リェー・ギア・ダンジョン",
                 de: "Verliese von Lyhe Ghiah",
                 fr: "Le Donjon hypogéen du Lyhe Ghiah",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TreasureHunt,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         689 => DutyInfo {
             name: LocalisedText {
@@ -4773,9 +8024,16 @@ lazy_static::lazy_static! {
                 ja: "希望の園エデン：覚醒編4",
                 de: "Edens Erwachen - Beerdigung",
                 fr: "L'Éveil d'Éden - Inhumation",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         690 => DutyInfo {
             name: LocalisedText {
@@ -4783,9 +8041,16 @@ lazy_static::lazy_static! {
                 ja: "希望の園エデン零式：覚醒編4",
                 de: "Edens Erwachen - Beerdigung (episch)",
                 fr: "L'Éveil d'Éden - Inhumation (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         691 => DutyInfo {
             name: LocalisedText {
@@ -4793,9 +8058,16 @@ lazy_static::lazy_static! {
                 ja: "勇気の狩人",
                 de: "Der Legende auf der Spur",
                 fr: "La chasseuse de légende",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         692 => DutyInfo {
             name: LocalisedText {
@@ -4803,9 +8075,16 @@ lazy_static::lazy_static! {
                 ja: "魔法宮殿 グラン・コスモス",
                 de: "Chateau Cosmea",
                 fr: "Le Cosmos coruscant",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         693 => DutyInfo {
             name: LocalisedText {
@@ -4813,9 +8092,16 @@ lazy_static::lazy_static! {
                 ja: "極ハーデス討滅戦",
                 de: "Letzte Läuterung - Hades",
                 fr: "Le Râle de l'Agonie (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         694 => DutyInfo {
             name: LocalisedText {
@@ -4823,9 +8109,16 @@ lazy_static::lazy_static! {
                 ja: "絶アレキサンダー討滅戦",
                 de: "Alexander (fatal)",
                 fr: "L'Odyssée d'Alexander (fatal)",
+                ..Default::default()
             },
             high_end: true,
             content_kind: ContentKind::UltimateRaids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         695 => DutyInfo {
             name: LocalisedText {
@@ -4833,9 +8126,16 @@ lazy_static::lazy_static! {
                 ja: "豪腕の父親「フンババ・パパ」",
                 de: "Papa Mia",
                 fr: "Papa Humbaba, le paternel aux gros bras",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TheMaskedCarnivale,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         696 => DutyInfo {
             name: LocalisedText {
@@ -4843,9 +8143,16 @@ lazy_static::lazy_static! {
                 ja: "はないき爆破デスマッチ",
                 de: "Explosives Schnaufen",
                 fr: "Fungaaah! Et boum!",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TheMaskedCarnivale,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         697 => DutyInfo {
             name: LocalisedText {
@@ -4853,9 +8160,16 @@ lazy_static::lazy_static! {
                 ja: "至妙の傀儡子「ドゥリン」",
                 de: "Begnadeter Puppenspieler",
                 fr: "Durinn, marionnettiste d'outre-tombe",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TheMaskedCarnivale,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         698 => DutyInfo {
             name: LocalisedText {
@@ -4863,9 +8177,16 @@ lazy_static::lazy_static! {
                 ja: "水と炎の歌",
                 de: "Die Melodie von Feuer und Wasser",
                 fr: "La mélodie du feu et de l'eau",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TheMaskedCarnivale,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         699 => DutyInfo {
             name: LocalisedText {
@@ -4873,9 +8194,16 @@ lazy_static::lazy_static! {
                 ja: "世界一の剣士「ジークフリード」",
                 de: "Siegfried, der beste Schwertkämpfer der Welt!",
                 fr: "Siegfried, le plus grand bretteur du monde",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TheMaskedCarnivale,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         700 => DutyInfo {
             name: LocalisedText {
@@ -4883,9 +8211,16 @@ lazy_static::lazy_static! {
                 ja: "複製サレタ工場廃墟",
                 de: "Die kopierte Fabrik",
                 fr: "La réplique de l'usine désaffectée",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         701 => DutyInfo {
             name: LocalisedText {
@@ -4893,9 +8228,16 @@ lazy_static::lazy_static! {
                 ja: "オンサル・ハカイル (終節戦)",
                 de: "Onsal Hakair (Danshig Naadam)",
                 fr: "Onsal Hakair (Danshig Naadam)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         702 => DutyInfo {
             name: LocalisedText {
@@ -4903,9 +8245,16 @@ lazy_static::lazy_static! {
                 ja: "白き誓約、黒き密約",
                 de: "Der Wolf und der Drachenreiter",
                 fr: "Vœux de vertu, actes de cruauté",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         703 => DutyInfo {
             name: LocalisedText {
@@ -4913,9 +8262,16 @@ lazy_static::lazy_static! {
                 ja: "この心が望むがままに",
                 de: "Trubel im Traumland",
                 fr: "À l'écoute de soi",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         705 => DutyInfo {
             name: LocalisedText {
@@ -4923,9 +8279,16 @@ lazy_static::lazy_static! {
                 ja: "挑戦！ ジャンピングアスレチック",
                 de: "Kaktor-Kletterwand",
                 fr: "Haute voltige",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         706 => DutyInfo {
             name: LocalisedText {
@@ -4933,9 +8296,16 @@ lazy_static::lazy_static! {
                 ja: "挑戦！ ジャンピングアスレチック",
                 de: "Kaktor-Kletterwand",
                 fr: "Haute voltige",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         707 => DutyInfo {
             name: LocalisedText {
@@ -4943,9 +8313,16 @@ lazy_static::lazy_static! {
                 ja: "挑戦！ ジャンピングアスレチック",
                 de: "Kaktor-Kletterwand",
                 fr: "Haute voltige",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         708 => DutyInfo {
             name: LocalisedText {
@@ -4953,9 +8330,16 @@ lazy_static::lazy_static! {
                 ja: "挑戦！ ジャンピングアスレチック",
                 de: "Kaktor-Kletterwand",
                 fr: "Haute voltige",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         709 => DutyInfo {
             name: LocalisedText {
@@ -4963,9 +8347,16 @@ lazy_static::lazy_static! {
                 ja: "挑戦！ ジャンピングアスレチック",
                 de: "Kaktor-Kletterwand",
                 fr: "Haute voltige",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         710 => DutyInfo {
             name: LocalisedText {
@@ -4973,9 +8364,16 @@ lazy_static::lazy_static! {
                 ja: "挑戦！ ジャンピングアスレチック",
                 de: "Kaktor-Kletterwand",
                 fr: "Haute voltige",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         711 => DutyInfo {
             name: LocalisedText {
@@ -4983,9 +8381,16 @@ lazy_static::lazy_static! {
                 ja: "挑戦！ ジャンピングアスレチック",
                 de: "Kaktor-Kletterwand",
                 fr: "Haute voltige",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         712 => DutyInfo {
             name: LocalisedText {
@@ -4993,9 +8398,16 @@ lazy_static::lazy_static! {
                 ja: "挑戦！ ジャンピングアスレチック",
                 de: "Kaktor-Kletterwand",
                 fr: "Haute voltige",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         713 => DutyInfo {
             name: LocalisedText {
@@ -5003,9 +8415,16 @@ lazy_static::lazy_static! {
                 ja: "挑戦！ ジャンピングアスレチック",
                 de: "Kaktor-Kletterwand",
                 fr: "Haute voltige",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         714 => DutyInfo {
             name: LocalisedText {
@@ -5013,9 +8432,16 @@ lazy_static::lazy_static! {
                 ja: "黒風海底 アニドラス・アナムネーシス",
                 de: "Anamnesis Anyder",
                 fr: "Anamnesis Anydre",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         715 => DutyInfo {
             name: LocalisedText {
@@ -5023,9 +8449,16 @@ lazy_static::lazy_static! {
                 ja: "希望の園エデン：共鳴編1",
                 de: "Edens Resonanz - Entladung",
                 fr: "Les Accords d'Éden - Fulmination",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         716 => DutyInfo {
             name: LocalisedText {
@@ -5033,9 +8466,16 @@ lazy_static::lazy_static! {
                 ja: "希望の園エデン零式：共鳴編1",
                 de: "Edens Resonanz - Entladung (episch)",
                 fr: "Les Accords d'Éden - Fulmination (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         717 => DutyInfo {
             name: LocalisedText {
@@ -5043,9 +8483,16 @@ lazy_static::lazy_static! {
                 ja: "ルビーウェポン破壊作戦",
                 de: "Rubinfeuer - Entfesselung",
                 fr: "Les Nuées de Brandons",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         718 => DutyInfo {
             name: LocalisedText {
@@ -5053,9 +8500,16 @@ lazy_static::lazy_static! {
                 ja: "極ルビーウェポン破壊作戦",
                 de: "Rubinfeuer - Trauma",
                 fr: "Les Nuées de Brandons (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         719 => DutyInfo {
             name: LocalisedText {
@@ -5063,9 +8517,16 @@ lazy_static::lazy_static! {
                 ja: "希望の園エデン：共鳴編2",
                 de: "Edens Resonanz - Raserei",
                 fr: "Les Accords d'Éden - Fureur",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         720 => DutyInfo {
             name: LocalisedText {
@@ -5073,9 +8534,16 @@ lazy_static::lazy_static! {
                 ja: "希望の園エデン零式：共鳴編2",
                 de: "Edens Resonanz - Raserei (episch)",
                 fr: "Les Accords d'Éden - Fureur (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         721 => DutyInfo {
             name: LocalisedText {
@@ -5083,9 +8551,16 @@ lazy_static::lazy_static! {
                 ja: "オーシャンフィッシング",
                 de: "Auf großer Fahrt",
                 fr: "Pêche en mer",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DisciplesoftheLand,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         722 => DutyInfo {
             name: LocalisedText {
@@ -5093,9 +8568,16 @@ lazy_static::lazy_static! {
                 ja: "雲海採集 ディアデム諸島",
                 de: "Das Diadem - Erschließung",
                 fr: "Le Diadème",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DisciplesoftheLand,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         723 => DutyInfo {
             name: LocalisedText {
@@ -5103,9 +8585,16 @@ lazy_static::lazy_static! {
                 ja: "シタデル・ボズヤ蒸発事変",
                 de: "Der Bozja-Vorfall",
                 fr: "Prélude à la catastrophe",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         724 => DutyInfo {
             name: LocalisedText {
@@ -5113,9 +8602,16 @@ lazy_static::lazy_static! {
                 ja: "汝、英雄の眠り妨げるは",
                 de: "Von schlafenden Helden",
                 fr: "L'épreuve ronka",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         725 => DutyInfo {
             name: LocalisedText {
@@ -5123,9 +8619,16 @@ lazy_static::lazy_static! {
                 ja: "極シタデル・ボズヤ追憶戦",
                 de: "Memoria Misera (extrem)",
                 fr: "Memoria Misera (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         726 => DutyInfo {
             name: LocalisedText {
@@ -5133,9 +8636,16 @@ lazy_static::lazy_static! {
                 ja: "希望の園エデン：共鳴編3",
                 de: "Edens Resonanz - Bildersturm",
                 fr: "Les Accords d'Éden - Iconoclasme",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         727 => DutyInfo {
             name: LocalisedText {
@@ -5143,9 +8653,16 @@ lazy_static::lazy_static! {
                 ja: "希望の園エデン零式：共鳴編3",
                 de: "Edens Resonanz - Bildersturm (episch)",
                 fr: "Les Accords d'Éden - Iconoclasme (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         728 => DutyInfo {
             name: LocalisedText {
@@ -5153,9 +8670,16 @@ lazy_static::lazy_static! {
                 ja: "希望の園エデン：共鳴編4",
                 de: "Edens Resonanz - Erstarrung",
                 fr: "Les Accords d'Éden - Éclat",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         729 => DutyInfo {
             name: LocalisedText {
@@ -5163,9 +8687,16 @@ lazy_static::lazy_static! {
                 ja: "希望の園エデン零式：共鳴編4",
                 de: "Edens Resonanz - Erstarrung (episch)",
                 fr: "Les Accords d'Éden - Éclat (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         730 => DutyInfo {
             name: LocalisedText {
@@ -5173,9 +8704,16 @@ lazy_static::lazy_static! {
                 ja: "オーシャンフィッシング",
                 de: "Auf großer Fahrt",
                 fr: "Pêche en mer",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DisciplesoftheLand,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         731 => DutyInfo {
             name: LocalisedText {
@@ -5183,9 +8721,16 @@ lazy_static::lazy_static! {
                 ja: "オーシャンフィッシング",
                 de: "Auf großer Fahrt",
                 fr: "Pêche en mer",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DisciplesoftheLand,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         732 => DutyInfo {
             name: LocalisedText {
@@ -5193,9 +8738,16 @@ lazy_static::lazy_static! {
                 ja: "オーシャンフィッシング",
                 de: "Auf großer Fahrt",
                 fr: "Pêche en mer",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DisciplesoftheLand,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         733 => DutyInfo {
             name: LocalisedText {
@@ -5203,9 +8755,16 @@ lazy_static::lazy_static! {
                 ja: "オーシャンフィッシング",
                 de: "Auf großer Fahrt",
                 fr: "Pêche en mer",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DisciplesoftheLand,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         734 => DutyInfo {
             name: LocalisedText {
@@ -5213,9 +8772,16 @@ lazy_static::lazy_static! {
                 ja: "オーシャンフィッシング",
                 de: "Auf großer Fahrt",
                 fr: "Pêche en mer",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DisciplesoftheLand,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         735 => DutyInfo {
             name: LocalisedText {
@@ -5223,9 +8789,16 @@ lazy_static::lazy_static! {
                 ja: "南方ボズヤ戦線",
                 de: "Bozja-Südfront",
                 fr: "Front sud de Bozja",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::SavetheQueen,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         736 => DutyInfo {
             name: LocalisedText {
@@ -5233,9 +8806,16 @@ lazy_static::lazy_static! {
                 ja: "人形タチノ軍事基地",
                 de: "Die Puppenfestung",
                 fr: "La base militaire des Pantins",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         737 => DutyInfo {
             name: LocalisedText {
@@ -5243,9 +8823,16 @@ lazy_static::lazy_static! {
                 ja: "漆黒決戦 ノルヴラント",
                 de: "Schlacht um Norvrandt",
                 fr: "La Traversée de Norvrandt",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         738 => DutyInfo {
             name: LocalisedText {
@@ -5253,9 +8840,16 @@ lazy_static::lazy_static! {
                 ja: "ウォーリア・オブ・ライト討滅戦",
                 de: "Krieger des Lichts",
                 fr: "Le Trône du Sacrifice",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         739 => DutyInfo {
             name: LocalisedText {
@@ -5263,9 +8857,16 @@ lazy_static::lazy_static! {
                 ja: "極ウォーリア・オブ・ライト討滅戦",
                 de: "Krieger des Lichts (extrem)",
                 fr: "Le Trône du Sacrifice (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         740 => DutyInfo {
             name: LocalisedText {
@@ -5273,9 +8874,16 @@ lazy_static::lazy_static! {
                 ja: "飛べ！ ウェルリトへ ",
                 de: "Luftangriff auf Werlyt",
                 fr: "Sur la mer de saphir",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         741 => DutyInfo {
             name: LocalisedText {
@@ -5283,9 +8891,16 @@ lazy_static::lazy_static! {
                 ja: "飛べ！ ウェルリトへ ",
                 de: "Luftangriff auf Werlyt",
                 fr: "Sur la mer de saphir",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         742 => DutyInfo {
             name: LocalisedText {
@@ -5293,9 +8908,16 @@ lazy_static::lazy_static! {
                 ja: "雲海採集 ディアデム諸島",
                 de: "Das Diadem - Erschließung",
                 fr: "Le Diadème",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DisciplesoftheLand,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         743 => DutyInfo {
             name: LocalisedText {
@@ -5303,9 +8925,16 @@ lazy_static::lazy_static! {
                 ja: "色あせた記憶",
                 de: "Verblasste Erinnerungen",
                 fr: "Souvenir périssable",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         745 => DutyInfo {
             name: LocalisedText {
@@ -5313,9 +8942,16 @@ lazy_static::lazy_static! {
                 ja: "宝物庫 リェー・ギア・ダンジョン祭殿",
                 de: "Das Karussell von Lyhe Ghiah",
                 fr: "Le Jardin secret du Lyhe Ghiah",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TreasureHunt,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         746 => DutyInfo {
             name: LocalisedText {
@@ -5323,9 +8959,16 @@ lazy_static::lazy_static! {
                 ja: "魔術工房 マトーヤのアトリエ",
                 de: "Matoyas Atelier",
                 fr: "L'Atelier abandonné de Matoya",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         747 => DutyInfo {
             name: LocalisedText {
@@ -5333,9 +8976,16 @@ lazy_static::lazy_static! {
                 ja: "希望の園エデン：再生編2",
                 de: "Edens Verheißung - Litanei",
                 fr: "La Promesse d'Éden - Litanie",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         748 => DutyInfo {
             name: LocalisedText {
@@ -5343,9 +8993,16 @@ lazy_static::lazy_static! {
                 ja: "希望の園エデン零式：再生編2",
                 de: "Edens Verheißung - Litanei (episch)",
                 fr: "La Promesse d'Éden - Litanie (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         749 => DutyInfo {
             name: LocalisedText {
@@ -5353,9 +9010,16 @@ lazy_static::lazy_static! {
                 ja: "希望の園エデン：再生編1",
                 de: "Edens Verheißung - Umbra",
                 fr: "La Promesse d'Éden - Nuée",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         750 => DutyInfo {
             name: LocalisedText {
@@ -5363,9 +9027,16 @@ lazy_static::lazy_static! {
                 ja: "希望の園エデン零式：再生編1",
                 de: "Edens Verheißung - Umbra (episch)",
                 fr: "La Promesse d'Éden - Nuée (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         751 => DutyInfo {
             name: LocalisedText {
@@ -5373,9 +9044,16 @@ lazy_static::lazy_static! {
                 ja: "希望の園エデン：再生編3",
                 de: "Edens Verheißung - Anamorphose",
                 fr: "La Promesse d'Éden - Anamorphose",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         752 => DutyInfo {
             name: LocalisedText {
@@ -5383,9 +9061,16 @@ lazy_static::lazy_static! {
                 ja: "希望の園エデン零式：再生編3",
                 de: "Edens Verheißung - Anamorphose (episch)",
                 fr: "La Promesse d'Éden - Anamorphose (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         753 => DutyInfo {
             name: LocalisedText {
@@ -5393,9 +9078,16 @@ lazy_static::lazy_static! {
                 ja: "雲海採集 ディアデム諸島",
                 de: "Das Diadem - Erschließung",
                 fr: "Le Diadème",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DisciplesoftheLand,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         754 => DutyInfo {
             name: LocalisedText {
@@ -5403,9 +9095,16 @@ lazy_static::lazy_static! {
                 ja: "黄金頭巾「ものまね士ゴゴ」",
                 de: "Gogo der Mime",
                 fr: "Gogo le mime",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TheMaskedCarnivale,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         755 => DutyInfo {
             name: LocalisedText {
@@ -5413,9 +9112,16 @@ lazy_static::lazy_static! {
                 ja: "トリプルトライアド：オフィシャルトーナメント",
                 de: "Triple Triad: Manderville-Turnier",
                 fr: "Tournoi officiel de Triple Triade",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         756 => DutyInfo {
             name: LocalisedText {
@@ -5423,9 +9129,16 @@ lazy_static::lazy_static! {
                 ja: "トリプルトライアド：カスタムトーナメントルーム",
                 de: "Triple Triad: Privatturnier",
                 fr: "Salle de tournoi libre de Triple Triade",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         758 => DutyInfo {
             name: LocalisedText {
@@ -5433,9 +9146,16 @@ lazy_static::lazy_static! {
                 ja: "希望の園エデン：再生編4",
                 de: "Edens Verheißung - Ewigkeit",
                 fr: "La Promesse d'Éden - Éternité",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         759 => DutyInfo {
             name: LocalisedText {
@@ -5443,9 +9163,16 @@ lazy_static::lazy_static! {
                 ja: "希望の園エデン零式：再生編4",
                 de: "Edens Verheißung - Ewigkeit (episch)",
                 fr: "La Promesse d'Éden - Éternité (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         760 => DutyInfo {
             name: LocalisedText {
@@ -5453,9 +9180,16 @@ lazy_static::lazy_static! {
                 ja: "グンヒルド・ディルーブラム",
                 de: "Delubrum Reginae",
                 fr: "Delubrum Reginae",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::SavetheQueen,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         761 => DutyInfo {
             name: LocalisedText {
@@ -5463,9 +9197,16 @@ lazy_static::lazy_static! {
                 ja: "グンヒルド・ディルーブラム零式",
                 de: "Delubrum Reginae (episch)",
                 fr: "Delubrum Reginae (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::SavetheQueen,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         762 => DutyInfo {
             name: LocalisedText {
@@ -5473,9 +9214,16 @@ lazy_static::lazy_static! {
                 ja: "エメラルドウェポン破壊作戦",
                 de: "Smaragdsturm - Entfesselung",
                 fr: "Castrum Marinum",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         763 => DutyInfo {
             name: LocalisedText {
@@ -5483,9 +9231,16 @@ lazy_static::lazy_static! {
                 ja: "極エメラルドウェポン破壊作戦",
                 de: "Smaragdsturm - Trauma",
                 fr: "Castrum Marinum (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         764 => DutyInfo {
             name: LocalisedText {
@@ -5493,9 +9248,16 @@ lazy_static::lazy_static! {
                 ja: "バイルブランドの船出",
                 de: "Gute Winde für Vylbrand",
                 fr: "Un navire nommé Vylbrand",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         765 => DutyInfo {
             name: LocalisedText {
@@ -5503,9 +9265,16 @@ lazy_static::lazy_static! {
                 ja: "ゴッド・セイブ・ザ・クイーン",
                 de: "Hinab in die Ruinen",
                 fr: "Que les Dieux gardent la Reine",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         766 => DutyInfo {
             name: LocalisedText {
@@ -5513,9 +9282,16 @@ lazy_static::lazy_static! {
                 ja: "ドマ式麻雀：東風戦一般卓 (段位変動有り)",
                 de: "Anfänger-Mahjong (schnelle Partie, gewertet)",
                 fr: "Mahjong domien: tous rangs (partie rapide classée)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         767 => DutyInfo {
             name: LocalisedText {
@@ -5523,9 +9299,16 @@ lazy_static::lazy_static! {
                 ja: "ドマ式麻雀：東風戦有段卓 (段位変動有り)",
                 de: "Fortgeschrittenen-Mahjong (schnelle Partie, gewertet)",
                 fr: "Mahjong domien: dan seulement (partie rapide classée)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         768 => DutyInfo {
             name: LocalisedText {
@@ -5533,9 +9316,16 @@ lazy_static::lazy_static! {
                 ja: "ドマ式麻雀：東風戦4人セット卓 (クイタン有り)",
                 de: "4-Spieler-Mahjong (schnelle Partie, Kuitan aktiviert)",
                 fr: "Mahjong domien: 4 joueurs (partie rapide avec kuitan)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         769 => DutyInfo {
             name: LocalisedText {
@@ -5543,9 +9333,16 @@ lazy_static::lazy_static! {
                 ja: "ドマ式麻雀：東風戦4人セット卓 (クイタン無し)",
                 de: "4-Spieler-Mahjong (schnelle Partie, Kuitan deaktiviert)",
                 fr: "Mahjong domien: 4 joueurs (partie rapide sans kuitan)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         770 => DutyInfo {
             name: LocalisedText {
@@ -5553,9 +9350,16 @@ lazy_static::lazy_static! {
                 ja: "オーシャンフィッシング",
                 de: "Auf großer Fahrt",
                 fr: "Pêche en mer",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DisciplesoftheLand,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         771 => DutyInfo {
             name: LocalisedText {
@@ -5563,9 +9367,16 @@ lazy_static::lazy_static! {
                 ja: "オーシャンフィッシング",
                 de: "Auf großer Fahrt",
                 fr: "Pêche en mer",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DisciplesoftheLand,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         772 => DutyInfo {
             name: LocalisedText {
@@ -5573,9 +9384,16 @@ lazy_static::lazy_static! {
                 ja: "オーシャンフィッシング",
                 de: "Auf großer Fahrt",
                 fr: "Pêche en mer",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DisciplesoftheLand,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         773 => DutyInfo {
             name: LocalisedText {
@@ -5583,9 +9401,16 @@ lazy_static::lazy_static! {
                 ja: "オーシャンフィッシング",
                 de: "Auf großer Fahrt",
                 fr: "Pêche en mer",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DisciplesoftheLand,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         774 => DutyInfo {
             name: LocalisedText {
@@ -5593,9 +9418,16 @@ lazy_static::lazy_static! {
                 ja: "オーシャンフィッシング",
                 de: "Auf großer Fahrt",
                 fr: "Pêche en mer",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DisciplesoftheLand,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         775 => DutyInfo {
             name: LocalisedText {
@@ -5603,9 +9435,16 @@ lazy_static::lazy_static! {
                 ja: "オーシャンフィッシング",
                 de: "Auf großer Fahrt",
                 fr: "Pêche en mer",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DisciplesoftheLand,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         777 => DutyInfo {
             name: LocalisedText {
@@ -5613,9 +9452,16 @@ lazy_static::lazy_static! {
                 ja: "黄金平原 パガルザン",
                 de: "Die Goldene Ebene von Paglth'an",
                 fr: "La grande prairie de Paglth'an",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         778 => DutyInfo {
             name: LocalisedText {
@@ -5623,9 +9469,16 @@ lazy_static::lazy_static! {
                 ja: "ザトゥノル高原",
                 de: "Zadnor-Hochebene",
                 fr: "Hauts plateaux de Zadnor",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::SavetheQueen,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         779 => DutyInfo {
             name: LocalisedText {
@@ -5633,9 +9486,16 @@ lazy_static::lazy_static! {
                 ja: "希望ノ砲台：「塔」",
                 de: "Der Turm, Paradigmenbrecher",
                 fr: "La tour de la Contingence",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         780 => DutyInfo {
             name: LocalisedText {
@@ -5643,9 +9503,16 @@ lazy_static::lazy_static! {
                 ja: "黎明の死闘",
                 de: "Kampf im Morgengrauen",
                 fr: "Aube meurtrière",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         781 => DutyInfo {
             name: LocalisedText {
@@ -5653,9 +9520,16 @@ lazy_static::lazy_static! {
                 ja: "ダイヤウェポン捕獲作戦",
                 de: "Diamantblitz - Entfesselung",
                 fr: "Le Tillac des Cirrus",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         782 => DutyInfo {
             name: LocalisedText {
@@ -5663,9 +9537,16 @@ lazy_static::lazy_static! {
                 ja: "極ダイヤウェポン捕獲作戦",
                 de: "Diamantblitz - Trauma",
                 fr: "Le Tillac des Cirrus (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         783 => DutyInfo {
             name: LocalisedText {
@@ -5673,9 +9554,16 @@ lazy_static::lazy_static! {
                 ja: "異形楼閣 ゾットの塔",
                 de: "Der Turm von Zot",
                 fr: "La tour de Zott",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         784 => DutyInfo {
             name: LocalisedText {
@@ -5683,9 +9571,16 @@ lazy_static::lazy_static! {
                 ja: "電脳夢想 スティグマ・フォー",
                 de: "Stigma-Holometrie",
                 fr: "Rêve électrique de Stigma-4",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         785 => DutyInfo {
             name: LocalisedText {
@@ -5693,9 +9588,16 @@ lazy_static::lazy_static! {
                 ja: "魔導神門 バブイルの塔",
                 de: "Der Turm von Babil",
                 fr: "La tour de Babil",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         786 => DutyInfo {
             name: LocalisedText {
@@ -5703,9 +9605,16 @@ lazy_static::lazy_static! {
                 ja: "星海潜航 アイティオン星晶鏡",
                 de: "Das Aitiaskop",
                 fr: "Le Prisme de l'Aitia",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         787 => DutyInfo {
             name: LocalisedText {
@@ -5713,9 +9622,16 @@ lazy_static::lazy_static! {
                 ja: "創造環境 ヒュペルボレア造物院",
                 de: "Ktisis Hyperboreia",
                 fr: "L'Hyperborée",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         788 => DutyInfo {
             name: LocalisedText {
@@ -5723,9 +9639,16 @@ lazy_static::lazy_static! {
                 ja: "絶竜詩戦争",
                 de: "Drachenkrieg (fatal)",
                 fr: "La Guerre du chant des dragons (fatal)",
+                ..Default::default()
             },
             high_end: true,
             content_kind: ContentKind::UltimateRaids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         789 => DutyInfo {
             name: LocalisedText {
@@ -5733,9 +9656,16 @@ lazy_static::lazy_static! {
                 ja: "終末樹海 ヴァナスパティ",
                 de: "Vanaspati",
                 fr: "Vanaspati",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         790 => DutyInfo {
             name: LocalisedText {
@@ -5743,9 +9673,16 @@ lazy_static::lazy_static! {
                 ja: "ハイデリン討滅戦",
                 de: "Prophetie - Hydaelyn",
                 fr: "Le Cristal-mère",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         791 => DutyInfo {
             name: LocalisedText {
@@ -5753,9 +9690,16 @@ lazy_static::lazy_static! {
                 ja: "極ハイデリン討滅戦",
                 de: "Eschatos - Hydaelyn",
                 fr: "Le Cristal-mère (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         792 => DutyInfo {
             name: LocalisedText {
@@ -5763,9 +9707,16 @@ lazy_static::lazy_static! {
                 ja: "最終幻想 レムナント",
                 de: "Das Sternengrab",
                 fr: "L'Issue aux Impasses",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         793 => DutyInfo {
             name: LocalisedText {
@@ -5773,9 +9724,16 @@ lazy_static::lazy_static! {
                 ja: "寒夜のこと",
                 de: "In fremder Haut",
                 fr: "Le voleur de corps",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         794 => DutyInfo {
             name: LocalisedText {
@@ -5783,9 +9741,16 @@ lazy_static::lazy_static! {
                 ja: "楽園都市 スマイルトン",
                 de: "Smileton",
                 fr: "Risette-sur-lune",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         795 => DutyInfo {
             name: LocalisedText {
@@ -5793,9 +9758,16 @@ lazy_static::lazy_static! {
                 ja: "前代アゼムの手ほどき",
                 de: " Es reimt sich auf Gebell",
                 fr: "Le défi de l'ancienne Azem",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         796 => DutyInfo {
             name: LocalisedText {
@@ -5803,9 +9775,16 @@ lazy_static::lazy_static! {
                 ja: "終焉の戦い",
                 de: "Prophetie - Endsängerin",
                 fr: "Le Répons final",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         797 => DutyInfo {
             name: LocalisedText {
@@ -5813,9 +9792,16 @@ lazy_static::lazy_static! {
                 ja: "道化饗宴 ホーンテッドフィースト",
                 de: "Lustiges Bankett",
                 fr: "Le banquet cauchemardesque",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(22),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         798 => DutyInfo {
             name: LocalisedText {
@@ -5823,9 +9809,16 @@ lazy_static::lazy_static! {
                 ja: "暁月のフィナーレ",
                 de: "Endschreiter",
                 fr: "Arpenteur des finitudes",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         799 => DutyInfo {
             name: LocalisedText {
@@ -5833,9 +9826,16 @@ lazy_static::lazy_static! {
                 ja: "融和への船出",
                 de: "Im Hafen des Friedens",
                 fr: "Cap sur la paix",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         800 => DutyInfo {
             name: LocalisedText {
@@ -5843,9 +9843,16 @@ lazy_static::lazy_static! {
                 ja: "万魔殿パンデモニウム：辺獄編4",
                 de: "Asphodelos - Vierter Kreis",
                 fr: "Les Limbes du Pandæmonium - Abîme",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         801 => DutyInfo {
             name: LocalisedText {
@@ -5853,9 +9860,16 @@ lazy_static::lazy_static! {
                 ja: "万魔殿パンデモニウム零式：辺獄編4",
                 de: "Asphodelos - Vierter Kreis (episch)",
                 fr: "Les Limbes du Pandæmonium - Abîme (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         802 => DutyInfo {
             name: LocalisedText {
@@ -5863,9 +9877,16 @@ lazy_static::lazy_static! {
                 ja: "ゾディアーク討滅戦",
                 de: "Prophetie - Zodiark",
                 fr: "Le Cratère des Martyrs",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         803 => DutyInfo {
             name: LocalisedText {
@@ -5873,9 +9894,16 @@ lazy_static::lazy_static! {
                 ja: "極ゾディアーク討滅戦",
                 de: "Eschatos - Zodiark",
                 fr: "Le Cratère des Martyrs (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         804 => DutyInfo {
             name: LocalisedText {
@@ -5883,9 +9911,16 @@ lazy_static::lazy_static! {
                 ja: "拡がる終末",
                 de: "Rote Himmel, roter Schnee",
                 fr: "L'arène des neiges",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         805 => DutyInfo {
             name: LocalisedText {
@@ -5893,9 +9928,16 @@ lazy_static::lazy_static! {
                 ja: "開かれた道の先へ",
                 de: "Offen für neue Wege",
                 fr: "Des esprits et des hommes",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         806 => DutyInfo {
             name: LocalisedText {
@@ -5903,9 +9945,16 @@ lazy_static::lazy_static! {
                 ja: "万魔殿パンデモニウム：辺獄編3",
                 de: "Asphodelos - Dritter Kreis",
                 fr: "Les Limbes du Pandæmonium - Fournaise",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         807 => DutyInfo {
             name: LocalisedText {
@@ -5913,9 +9962,16 @@ lazy_static::lazy_static! {
                 ja: "万魔殿パンデモニウム零式：辺獄編3",
                 de: "Asphodelos - Dritter Kreis (episch)",
                 fr: "Les Limbes du Pandæmonium - Fournaise (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         808 => DutyInfo {
             name: LocalisedText {
@@ -5923,9 +9979,16 @@ lazy_static::lazy_static! {
                 ja: "万魔殿パンデモニウム：辺獄編1",
                 de: "Asphodelos - Erster Kreis",
                 fr: "Les Limbes du Pandæmonium - Parvis",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         809 => DutyInfo {
             name: LocalisedText {
@@ -5933,9 +9996,16 @@ lazy_static::lazy_static! {
                 ja: "万魔殿パンデモニウム零式：辺獄編1",
                 de: "Asphodelos - Erster Kreis (episch)",
                 fr: "Les Limbes du Pandæmonium - Parvis (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         810 => DutyInfo {
             name: LocalisedText {
@@ -5943,9 +10013,16 @@ lazy_static::lazy_static! {
                 ja: "万魔殿パンデモニウム：辺獄編2",
                 de: "Asphodelos - Zweiter Kreis",
                 fr: "Les Limbes du Pandæmonium - Cloaque",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         811 => DutyInfo {
             name: LocalisedText {
@@ -5953,9 +10030,16 @@ lazy_static::lazy_static! {
                 ja: "万魔殿パンデモニウム零式：辺獄編2",
                 de: "Asphodelos - Zweiter Kreis (episch)",
                 fr: "Les Limbes du Pandæmonium - Cloaque (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         812 => DutyInfo {
             name: LocalisedText {
@@ -5963,9 +10047,16 @@ lazy_static::lazy_static! {
                 ja: "霜雪を踏みしめて",
                 de: "Ein frostiger Empfang",
                 fr: "Un accueil glacial",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         813 => DutyInfo {
             name: LocalisedText {
@@ -5973,9 +10064,16 @@ lazy_static::lazy_static! {
                 ja: "賢者の短杖",
                 de: "Des Weisen wundersames Werkzeug",
                 fr: "Les armes du sage",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         814 => DutyInfo {
             name: LocalisedText {
@@ -5983,9 +10081,16 @@ lazy_static::lazy_static! {
                 ja: "大鎌の意味",
                 de: "Die Bedeutung der Sense",
                 fr: "La vraie puissance de la faux",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         815 => DutyInfo {
             name: LocalisedText {
@@ -5993,9 +10098,16 @@ lazy_static::lazy_static! {
                 ja: "蒼天を仰ぎ、歩み続ける",
                 de: "Der Weg zur Erneuerung",
                 fr: "La voie du renouveau",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         816 => DutyInfo {
             name: LocalisedText {
@@ -6003,9 +10115,16 @@ lazy_static::lazy_static! {
                 ja: "暗殺道",
                 de: "Die Kunst des Tötens",
                 fr: "La voie du néant",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         817 => DutyInfo {
             name: LocalisedText {
@@ -6013,9 +10132,16 @@ lazy_static::lazy_static! {
                 ja: "人命は儚く、術のみちは永久に",
                 de: "Das Leben ist kurz, die Kunst ist lang",
                 fr: "Existences éphémères et savoir éternel",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         818 => DutyInfo {
             name: LocalisedText {
@@ -6023,9 +10149,16 @@ lazy_static::lazy_static! {
                 ja: "ドマの弔い",
                 de: "Domanisches Begräbnis",
                 fr: "Des adieux domiens",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         819 => DutyInfo {
             name: LocalisedText {
@@ -6033,9 +10166,16 @@ lazy_static::lazy_static! {
                 ja: "宝物庫 エキサイトロン",
                 de: "Euphoratron",
                 fr: "Le Ludodrome",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TreasureHunt,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         820 => DutyInfo {
             name: LocalisedText {
@@ -6043,9 +10183,16 @@ lazy_static::lazy_static! {
                 ja: "僕たちは還り、君を見送ろう",
                 de: "Trauer und Hoffnung",
                 fr: "Acceptation",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         822 => DutyInfo {
             name: LocalisedText {
@@ -6053,9 +10200,16 @@ lazy_static::lazy_static! {
                 ja: "星霊間欠 ハーム島",
                 de: "Ätherborn",
                 fr: "L'île de Haam",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         823 => DutyInfo {
             name: LocalisedText {
@@ -6063,9 +10217,16 @@ lazy_static::lazy_static! {
                 ja: "深淵潜行 月の地下渓谷",
                 de: "Monduntergrund",
                 fr: "Le Souterrain lunaire",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         824 => DutyInfo {
             name: LocalisedText {
@@ -6073,9 +10234,16 @@ lazy_static::lazy_static! {
                 ja: "山嶺登頂 ウォーコー・ゾーモー",
                 de: "Worqor Zormor",
                 fr: "Worqor Zormor",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         825 => DutyInfo {
             name: LocalisedText {
@@ -6083,9 +10251,16 @@ lazy_static::lazy_static! {
                 ja: "魂魄工廠 オリジェニクス",
                 de: "Origenik",
                 fr: "L'Origenèse",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         826 => DutyInfo {
             name: LocalisedText {
@@ -6093,9 +10268,16 @@ lazy_static::lazy_static! {
                 ja: "濁流遡上 イフイカ・トゥム",
                 de: "Ihuykatumu",
                 fr: "La Remontée de l'Ihuykatumu",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         827 => DutyInfo {
             name: LocalisedText {
@@ -6103,9 +10285,16 @@ lazy_static::lazy_static! {
                 ja: "記憶幻想 アレクサンドリア",
                 de: "Alexandria",
                 fr: "Alexandrie",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         828 => DutyInfo {
             name: LocalisedText {
@@ -6113,9 +10302,16 @@ lazy_static::lazy_static! {
                 ja: "王として、父として",
                 de: "Des Vaters viele Sorgen",
                 fr: "L'Aurarque et le père",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         829 => DutyInfo {
             name: LocalisedText {
@@ -6123,9 +10319,16 @@ lazy_static::lazy_static! {
                 ja: "遺産踏査 天深きセノーテ",
                 de: "Himmelstiefer Cenote",
                 fr: "Le cénote des Cieux infinis",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         830 => DutyInfo {
             name: LocalisedText {
@@ -6133,9 +10336,16 @@ lazy_static::lazy_static! {
                 ja: "アルテマウェポン破壊作戦",
                 de: "Porta Decumana",
                 fr: "Porta Decumana",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         831 => DutyInfo {
             name: LocalisedText {
@@ -6143,9 +10353,16 @@ lazy_static::lazy_static! {
                 ja: "外征前哨 ヴァンガード",
                 de: "Der Außenposten",
                 fr: "L'Avant-garde",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         832 => DutyInfo {
             name: LocalisedText {
@@ -6153,9 +10370,16 @@ lazy_static::lazy_static! {
                 ja: "ヴァリガルマンダ討滅戦",
                 de: "Zel Tajaal - Valigarmanda",
                 fr: "Worqor Lar Dor",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         833 => DutyInfo {
             name: LocalisedText {
@@ -6163,9 +10387,16 @@ lazy_static::lazy_static! {
                 ja: "極ヴァリガルマンダ討滅戦",
                 de: "Gok Tajaal - Valigarmanda",
                 fr: "Worqor Lar Dor (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         834 => DutyInfo {
             name: LocalisedText {
@@ -6173,9 +10404,16 @@ lazy_static::lazy_static! {
                 ja: "荒野秘境 サボテンダーバレー",
                 de: "Kaktorsenke",
                 fr: "La Vallée des Pampas",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         835 => DutyInfo {
             name: LocalisedText {
@@ -6183,9 +10421,16 @@ lazy_static::lazy_static! {
                 ja: "パライストラ",
                 de: "Die Palästra",
                 fr: "Le Palestre",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         836 => DutyInfo {
             name: LocalisedText {
@@ -6193,9 +10438,16 @@ lazy_static::lazy_static! {
                 ja: "ヴォルカニック・ハート",
                 de: "Das Herz des Vulkans",
                 fr: "Le Cœur volcanique",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         837 => DutyInfo {
             name: LocalisedText {
@@ -6203,9 +10455,16 @@ lazy_static::lazy_static! {
                 ja: "クラウドナイン",
                 de: "Wolke Sieben",
                 fr: "Le Petit Nuage",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         838 => DutyInfo {
             name: LocalisedText {
@@ -6213,9 +10472,16 @@ lazy_static::lazy_static! {
                 ja: "パライストラ",
                 de: "Die Palästra",
                 fr: "Le Palestre",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         839 => DutyInfo {
             name: LocalisedText {
@@ -6223,9 +10489,16 @@ lazy_static::lazy_static! {
                 ja: "ヴォルカニック・ハート",
                 de: "Das Herz des Vulkans",
                 fr: "Le Cœur volcanique",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         840 => DutyInfo {
             name: LocalisedText {
@@ -6233,9 +10506,16 @@ lazy_static::lazy_static! {
                 ja: "クラウドナイン",
                 de: "Wolke Sieben",
                 fr: "Le Petit Nuage",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         841 => DutyInfo {
             name: LocalisedText {
@@ -6243,9 +10523,16 @@ lazy_static::lazy_static! {
                 ja: "パライストラ",
                 de: "Die Palästra",
                 fr: "Le Palestre",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         842 => DutyInfo {
             name: LocalisedText {
@@ -6253,9 +10540,16 @@ lazy_static::lazy_static! {
                 ja: "ヴォルカニック・ハート",
                 de: "Das Herz des Vulkans",
                 fr: "Le Cœur volcanique",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         843 => DutyInfo {
             name: LocalisedText {
@@ -6263,9 +10557,16 @@ lazy_static::lazy_static! {
                 ja: "クラウドナイン",
                 de: "Wolke Sieben",
                 fr: "Le Petit Nuage",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         844 => DutyInfo {
             name: LocalisedText {
@@ -6273,9 +10574,16 @@ lazy_static::lazy_static! {
                 ja: "近東秘宝 アルザダール海底遺跡群",
                 de: "Alzadaals Vermächtnis",
                 fr: "Le legs d'Alzadaal",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         845 => DutyInfo {
             name: LocalisedText {
@@ -6283,9 +10591,16 @@ lazy_static::lazy_static! {
                 ja: "ネルウァの帝国",
                 de: "Nervas Reich",
                 fr: "Nerva, le patriote",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         846 => DutyInfo {
             name: LocalisedText {
@@ -6293,9 +10608,16 @@ lazy_static::lazy_static! {
                 ja: "終極の戦い",
                 de: "Eschatos - Endsängerin",
                 fr: "Le Répons final (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         847 => DutyInfo {
             name: LocalisedText {
@@ -6303,9 +10625,16 @@ lazy_static::lazy_static! {
                 ja: "パライストラ",
                 de: "Die Palästra",
                 fr: "Le Palestre",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         848 => DutyInfo {
             name: LocalisedText {
@@ -6313,9 +10642,16 @@ lazy_static::lazy_static! {
                 ja: "ヴォルカニック・ハート",
                 de: "Das Herz des Vulkans",
                 fr: "Le Cœur volcanique",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         849 => DutyInfo {
             name: LocalisedText {
@@ -6323,9 +10659,16 @@ lazy_static::lazy_static! {
                 ja: "クラウドナイン",
                 de: "Wolke Sieben",
                 fr: "Le Petit Nuage",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         850 => DutyInfo {
             name: LocalisedText {
@@ -6333,9 +10676,16 @@ lazy_static::lazy_static! {
                 ja: "パライストラ",
                 de: "Die Palästra",
                 fr: "Le Palestre",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         851 => DutyInfo {
             name: LocalisedText {
@@ -6343,9 +10693,16 @@ lazy_static::lazy_static! {
                 ja: "ヴォルカニック・ハート",
                 de: "Das Herz des Vulkans",
                 fr: "Le Cœur volcanique",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         852 => DutyInfo {
             name: LocalisedText {
@@ -6353,9 +10710,16 @@ lazy_static::lazy_static! {
                 ja: "クラウドナイン",
                 de: "Wolke Sieben",
                 fr: "Le Petit Nuage",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         853 => DutyInfo {
             name: LocalisedText {
@@ -6363,9 +10727,16 @@ lazy_static::lazy_static! {
                 ja: "パライストラ",
                 de: "Die Palästra",
                 fr: "Le Palestre",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         854 => DutyInfo {
             name: LocalisedText {
@@ -6373,9 +10744,16 @@ lazy_static::lazy_static! {
                 ja: "ヴォルカニック・ハート",
                 de: "Das Herz des Vulkans",
                 fr: "Le Cœur volcanique",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         855 => DutyInfo {
             name: LocalisedText {
@@ -6383,9 +10761,16 @@ lazy_static::lazy_static! {
                 ja: "クラウドナイン",
                 de: "Wolke Sieben",
                 fr: "Le Petit Nuage",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         856 => DutyInfo {
             name: LocalisedText {
@@ -6393,9 +10778,16 @@ lazy_static::lazy_static! {
                 ja: "パライストラ",
                 de: "Die Palästra",
                 fr: "Le Palestre",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         857 => DutyInfo {
             name: LocalisedText {
@@ -6403,9 +10795,16 @@ lazy_static::lazy_static! {
                 ja: "ヴォルカニック・ハート",
                 de: "Das Herz des Vulkans",
                 fr: "Le Cœur volcanique",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         858 => DutyInfo {
             name: LocalisedText {
@@ -6413,9 +10812,16 @@ lazy_static::lazy_static! {
                 ja: "クラウドナイン",
                 de: "Wolke Sieben",
                 fr: "Le Petit Nuage",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         859 => DutyInfo {
             name: LocalisedText {
@@ -6423,9 +10829,16 @@ lazy_static::lazy_static! {
                 ja: "パライストラ",
                 de: "Die Palästra",
                 fr: "Le Palestre",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         860 => DutyInfo {
             name: LocalisedText {
@@ -6433,9 +10846,16 @@ lazy_static::lazy_static! {
                 ja: "ヴォルカニック・ハート",
                 de: "Das Herz des Vulkans",
                 fr: "Le Cœur volcanique",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         861 => DutyInfo {
             name: LocalisedText {
@@ -6443,9 +10863,16 @@ lazy_static::lazy_static! {
                 ja: "クラウドナイン",
                 de: "Wolke Sieben",
                 fr: "Le Petit Nuage",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         862 => DutyInfo {
             name: LocalisedText {
@@ -6453,9 +10880,16 @@ lazy_static::lazy_static! {
                 ja: "クリスタルコンフリクト(パライストラ：カスタムマッチ)",
                 de: "Crystalline Conflict: Die Palästra (Schaukampf)",
                 fr: "Crystalline Conflict (partie personnalisée - Le Palestre)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         863 => DutyInfo {
             name: LocalisedText {
@@ -6463,9 +10897,16 @@ lazy_static::lazy_static! {
                 ja: "クリスタルコンフリクト(ヴォルカニック・ハート：カスタムマッチ)",
                 de: "Crystalline Conflict: Das Herz des Vulkans (Schaukampf)",
                 fr: "Crystalline Conflict (partie personnalisée - Le Cœur volcanique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         864 => DutyInfo {
             name: LocalisedText {
@@ -6473,9 +10914,16 @@ lazy_static::lazy_static! {
                 ja: "クリスタルコンフリクト(クラウドナイン：カスタムマッチ)",
                 de: "Crystalline Conflict: Wolke Sieben (Schaukampf)",
                 fr: "Crystalline Conflict (partie personnalisée - Le Petit Nuage)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         865 => DutyInfo {
             name: LocalisedText {
@@ -6483,9 +10931,16 @@ lazy_static::lazy_static! {
                 ja: "リットアティン強襲戦",
                 de: "Kap Westwind",
                 fr: "Le Cap Vendouest",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         866 => DutyInfo {
             name: LocalisedText {
@@ -6493,9 +10948,16 @@ lazy_static::lazy_static! {
                 ja: "輝ける神域 アグライア",
                 de: "Aglaia",
                 fr: "Domaine divin - Aglaé",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         867 => DutyInfo {
             name: LocalisedText {
@@ -6503,9 +10965,16 @@ lazy_static::lazy_static! {
                 ja: "アシエン・ラハブレア討伐戦",
                 de: "Das Antlitz der Kaltblütigkeit",
                 fr: "Le Praetorium en flammes",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         868 => DutyInfo {
             name: LocalisedText {
@@ -6513,9 +10982,16 @@ lazy_static::lazy_static! {
                 ja: "ヴァリアントダンジョン シラディハ水道",
                 de: "Die Unterstadt von Sil'dih",
                 fr: "Les canalisations sildiennes - Donjon à embranchements",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::VCDungeonFinder,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         869 => DutyInfo {
             name: LocalisedText {
@@ -6523,9 +10999,16 @@ lazy_static::lazy_static! {
                 ja: "異界孤城 トロイアコート",
                 de: "Der Schwarze Hof von Troia",
                 fr: "Le Château de Troïa",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         870 => DutyInfo {
             name: LocalisedText {
@@ -6533,9 +11016,16 @@ lazy_static::lazy_static! {
                 ja: "バルバリシア討滅戦",
                 de: "Prophetie - Barbarizia",
                 fr: "La Toison des tempêtes",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         871 => DutyInfo {
             name: LocalisedText {
@@ -6543,9 +11033,16 @@ lazy_static::lazy_static! {
                 ja: "極バルバリシア討滅戦",
                 de: "Eschatos - Barbarizia",
                 fr: "La Toison des tempêtes (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         872 => DutyInfo {
             name: LocalisedText {
@@ -6553,9 +11050,16 @@ lazy_static::lazy_static! {
                 ja: "万魔殿パンデモニウム：煉獄編1",
                 de: "Abyssos - Fünfter Kreis",
                 fr: "Le Purgatoire du Pandæmonium - Cages",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         873 => DutyInfo {
             name: LocalisedText {
@@ -6563,9 +11067,16 @@ lazy_static::lazy_static! {
                 ja: "万魔殿パンデモニウム零式：煉獄編1",
                 de: "Abyssos - Fünfter Kreis (episch)",
                 fr: "Le Purgatoire du Pandæmonium - Cages (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         874 => DutyInfo {
             name: LocalisedText {
@@ -6573,9 +11084,16 @@ lazy_static::lazy_static! {
                 ja: "失われた力",
                 de: "Wo alles seinen Anfang nimmt",
                 fr: "Au commencement était Zero",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         876 => DutyInfo {
             name: LocalisedText {
@@ -6583,9 +11101,16 @@ lazy_static::lazy_static! {
                 ja: "万魔殿パンデモニウム：煉獄編3",
                 de: "Abyssos - Siebter Kreis",
                 fr: "Le Purgatoire du Pandæmonium - Racines",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         877 => DutyInfo {
             name: LocalisedText {
@@ -6593,9 +11118,16 @@ lazy_static::lazy_static! {
                 ja: "万魔殿パンデモニウム零式：煉獄編3",
                 de: "Abyssos - Siebter Kreis (episch)",
                 fr: "Le Purgatoire du Pandæmonium - Racines (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         878 => DutyInfo {
             name: LocalisedText {
@@ -6603,9 +11135,16 @@ lazy_static::lazy_static! {
                 ja: "アナザーダンジョン 異聞シラディハ水道",
                 de: "Die kuriose Unterstadt von Sil'dih",
                 fr: "Les canalisations sildiennes annexes - Donjon alternatif",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::VCDungeonFinder,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         879 => DutyInfo {
             name: LocalisedText {
@@ -6613,9 +11152,16 @@ lazy_static::lazy_static! {
                 ja: "アナザーダンジョン 異聞シラディハ水道 零式",
                 de: "Die kuriose Unterstadt von Sil'dih (episch)",
                 fr: "Les canalisations sildiennes annexes - Donjon alternatif (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::VCDungeonFinder,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         880 => DutyInfo {
             name: LocalisedText {
@@ -6623,9 +11169,16 @@ lazy_static::lazy_static! {
                 ja: "万魔殿パンデモニウム：煉獄編2",
                 de: "Abyssos - Sechster Kreis",
                 fr: "Le Purgatoire du Pandæmonium - Croisements",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         881 => DutyInfo {
             name: LocalisedText {
@@ -6633,9 +11186,16 @@ lazy_static::lazy_static! {
                 ja: "万魔殿パンデモニウム零式：煉獄編2",
                 de: "Abyssos - Sechster Kreis (episch)",
                 fr: "Le Purgatoire du Pandæmonium - Croisements (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         883 => DutyInfo {
             name: LocalisedText {
@@ -6643,9 +11203,16 @@ lazy_static::lazy_static! {
                 ja: "万魔殿パンデモニウム：煉獄編4",
                 de: "Abyssos - Achter Kreis",
                 fr: "Le Purgatoire du Pandæmonium - Hérédité",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         884 => DutyInfo {
             name: LocalisedText {
@@ -6653,9 +11220,16 @@ lazy_static::lazy_static! {
                 ja: "万魔殿パンデモニウム零式：煉獄編4",
                 de: "Abyssos - Achter Kreis (episch)",
                 fr: "Le Purgatoire du Pandæmonium - Hérédité (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         885 => DutyInfo {
             name: LocalisedText {
@@ -6663,9 +11237,16 @@ lazy_static::lazy_static! {
                 ja: "皇都イシュガルド防衛戦",
                 de: "Der Schicksalsweg",
                 fr: "Le Siège de la sainte Cité d'Ishgard",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         886 => DutyInfo {
             name: LocalisedText {
@@ -6673,9 +11254,16 @@ lazy_static::lazy_static! {
                 ja: "ルビカンテ討滅戦",
                 de: "Prophetie - Rubicante",
                 fr: "Le Mont du Supplice",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         896 => DutyInfo {
             name: LocalisedText {
@@ -6683,9 +11271,16 @@ lazy_static::lazy_static! {
                 ja: "雪山冥洞 ラピス・マナリス",
                 de: "Lapis Manalis",
                 fr: "Lapis Manalis",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         897 => DutyInfo {
             name: LocalisedText {
@@ -6693,9 +11288,16 @@ lazy_static::lazy_static! {
                 ja: "オルト・エウレカ B1～B10",
                 de: "Eureka Orthos (Ebenen 1-10)",
                 fr: "Eurêka Orthos (sous-sols 1-10)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         898 => DutyInfo {
             name: LocalisedText {
@@ -6703,9 +11305,16 @@ lazy_static::lazy_static! {
                 ja: "オルト・エウレカ B11～B20",
                 de: "Eureka Orthos (Ebenen 11-20)",
                 fr: "Eurêka Orthos (sous-sols 11-20)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         899 => DutyInfo {
             name: LocalisedText {
@@ -6713,9 +11322,16 @@ lazy_static::lazy_static! {
                 ja: "オルト・エウレカ B21～B30",
                 de: "Eureka Orthos (Ebenen 21-30)",
                 fr: "Eurêka Orthos (sous-sols 21-30)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         900 => DutyInfo {
             name: LocalisedText {
@@ -6723,9 +11339,16 @@ lazy_static::lazy_static! {
                 ja: "オルト・エウレカ B31～B40",
                 de: "Eureka Orthos (Ebenen 31-40)",
                 fr: "Eurêka Orthos (sous-sols 31-40)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         901 => DutyInfo {
             name: LocalisedText {
@@ -6733,9 +11356,16 @@ lazy_static::lazy_static! {
                 ja: "オルト・エウレカ B41～B50",
                 de: "Eureka Orthos (Ebenen 41-50)",
                 fr: "Eurêka Orthos (sous-sols 41-50)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         902 => DutyInfo {
             name: LocalisedText {
@@ -6743,9 +11373,16 @@ lazy_static::lazy_static! {
                 ja: "オルト・エウレカ B51～B60",
                 de: "Eureka Orthos (Ebenen 51-60)",
                 fr: "Eurêka Orthos (sous-sols 51-60)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         903 => DutyInfo {
             name: LocalisedText {
@@ -6753,9 +11390,16 @@ lazy_static::lazy_static! {
                 ja: "オルト・エウレカ B61～B70",
                 de: "Eureka Orthos (Ebenen 61-70)",
                 fr: "Eurêka Orthos (sous-sols 61-70)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         904 => DutyInfo {
             name: LocalisedText {
@@ -6763,9 +11407,16 @@ lazy_static::lazy_static! {
                 ja: "オルト・エウレカ B71～B80",
                 de: "Eureka Orthos (Ebenen 71-80)",
                 fr: "Eurêka Orthos (sous-sols 71-80)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         905 => DutyInfo {
             name: LocalisedText {
@@ -6773,9 +11424,16 @@ lazy_static::lazy_static! {
                 ja: "オルト・エウレカ B81～B90",
                 de: "Eureka Orthos (Ebenen 81-90)",
                 fr: "Eurêka Orthos (sous-sols 81-90)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         906 => DutyInfo {
             name: LocalisedText {
@@ -6783,9 +11441,16 @@ lazy_static::lazy_static! {
                 ja: "オルト・エウレカ B91～B100",
                 de: "Eureka Orthos (Ebenen 91-100)",
                 fr: "Eurêka Orthos (sous-sols 91-100)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         907 => DutyInfo {
             name: LocalisedText {
@@ -6793,9 +11458,16 @@ lazy_static::lazy_static! {
                 ja: "はつらつとした親子",
                 de: "Väterliche Liebe",
                 fr: "Tel gentilhomme, tel gentilhomme",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         908 => DutyInfo {
             name: LocalisedText {
@@ -6803,9 +11475,16 @@ lazy_static::lazy_static! {
                 ja: "絶オメガ検証戦",
                 de: "Omega (fatal)",
                 fr: "Le Protocole Oméga (fatal)",
+                ..Default::default()
             },
             high_end: true,
             content_kind: ContentKind::UltimateRaids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         909 => DutyInfo {
             name: LocalisedText {
@@ -6813,9 +11492,16 @@ lazy_static::lazy_static! {
                 ja: "宝物庫 エルピス・ギュムナシオン祭殿",
                 de: "Gymnasion Agonon",
                 fr: "Elpis Gymnasion",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TreasureHunt,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         910 => DutyInfo {
             name: LocalisedText {
@@ -6823,9 +11509,16 @@ lazy_static::lazy_static! {
                 ja: "パンひとつと引き換えに",
                 de: "Der Wert einer Buuds",
                 fr: "Pour une bouchée de pain",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         911 => DutyInfo {
             name: LocalisedText {
@@ -6833,9 +11526,16 @@ lazy_static::lazy_static! {
                 ja: "喜びの神域 エウプロシュネ",
                 de: "Euphrosyne",
                 fr: "Domaine divin - Euphrosyne",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         912 => DutyInfo {
             name: LocalisedText {
@@ -6843,9 +11543,16 @@ lazy_static::lazy_static! {
                 ja: "東方絡繰御殿",
                 de: "Die Mechanische Menagerie",
                 fr: "Le Traquenard oriental",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         913 => DutyInfo {
             name: LocalisedText {
@@ -6853,9 +11560,16 @@ lazy_static::lazy_static! {
                 ja: "東方絡繰御殿",
                 de: "Die Mechanische Menagerie",
                 fr: "Le Traquenard oriental",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         914 => DutyInfo {
             name: LocalisedText {
@@ -6863,9 +11577,16 @@ lazy_static::lazy_static! {
                 ja: "東方絡繰御殿",
                 de: "Die Mechanische Menagerie",
                 fr: "Le Traquenard oriental",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         915 => DutyInfo {
             name: LocalisedText {
@@ -6873,9 +11594,16 @@ lazy_static::lazy_static! {
                 ja: "東方絡繰御殿",
                 de: "Die Mechanische Menagerie",
                 fr: "Le Traquenard oriental",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         916 => DutyInfo {
             name: LocalisedText {
@@ -6883,9 +11611,16 @@ lazy_static::lazy_static! {
                 ja: "東方絡繰御殿",
                 de: "Die Mechanische Menagerie",
                 fr: "Le Traquenard oriental",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         917 => DutyInfo {
             name: LocalisedText {
@@ -6893,9 +11628,16 @@ lazy_static::lazy_static! {
                 ja: "東方絡繰御殿",
                 de: "Die Mechanische Menagerie",
                 fr: "Le Traquenard oriental",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         918 => DutyInfo {
             name: LocalisedText {
@@ -6903,9 +11645,16 @@ lazy_static::lazy_static! {
                 ja: "東方絡繰御殿",
                 de: "Die Mechanische Menagerie",
                 fr: "Le Traquenard oriental",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         919 => DutyInfo {
             name: LocalisedText {
@@ -6913,9 +11662,16 @@ lazy_static::lazy_static! {
                 ja: "東方絡繰御殿",
                 de: "Die Mechanische Menagerie",
                 fr: "Le Traquenard oriental",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         920 => DutyInfo {
             name: LocalisedText {
@@ -6923,9 +11679,16 @@ lazy_static::lazy_static! {
                 ja: "東方絡繰御殿",
                 de: "Die Mechanische Menagerie",
                 fr: "Le Traquenard oriental",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         921 => DutyInfo {
             name: LocalisedText {
@@ -6933,9 +11696,16 @@ lazy_static::lazy_static! {
                 ja: "東方絡繰御殿",
                 de: "Die Mechanische Menagerie",
                 fr: "Le Traquenard oriental",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         922 => DutyInfo {
             name: LocalisedText {
@@ -6943,9 +11713,16 @@ lazy_static::lazy_static! {
                 ja: "東方絡繰御殿",
                 de: "Die Mechanische Menagerie",
                 fr: "Le Traquenard oriental",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         923 => DutyInfo {
             name: LocalisedText {
@@ -6953,9 +11730,16 @@ lazy_static::lazy_static! {
                 ja: "クリスタルコンフリクト(東方絡繰御殿：カスタムマッチ)",
                 de: "Crystalline Conflict: Die Mechanische Menagerie (Schaukampf)",
                 fr: "Crys. Conflict (partie perso. - Le Traquenard oriental)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         924 => DutyInfo {
             name: LocalisedText {
@@ -6963,9 +11747,16 @@ lazy_static::lazy_static! {
                 ja: "極ルビカンテ討滅戦",
                 de: "Eschatos - Rubicante",
                 fr: "Le Mont du Supplice (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         925 => DutyInfo {
             name: LocalisedText {
@@ -6973,9 +11764,16 @@ lazy_static::lazy_static! {
                 ja: "ヴァイパーの使命",
                 de: "Einführung in die Viperologie",
                 fr: "Le devoir des rôdeurs vipère",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         927 => DutyInfo {
             name: LocalisedText {
@@ -6983,9 +11781,16 @@ lazy_static::lazy_static! {
                 ja: "挑戦！ ジャンピングアスレチック",
                 de: "Kaktor-Kletterwand",
                 fr: "Haute voltige",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         928 => DutyInfo {
             name: LocalisedText {
@@ -6993,9 +11798,16 @@ lazy_static::lazy_static! {
                 ja: "挑戦！ ジャンピングアスレチック",
                 de: "Kaktor-Kletterwand",
                 fr: "Haute voltige",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         929 => DutyInfo {
             name: LocalisedText {
@@ -7003,9 +11815,16 @@ lazy_static::lazy_static! {
                 ja: "挑戦！ ジャンピングアスレチック",
                 de: "Kaktor-Kletterwand",
                 fr: "Haute voltige",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         930 => DutyInfo {
             name: LocalisedText {
@@ -7013,9 +11832,16 @@ lazy_static::lazy_static! {
                 ja: "挑戦！ ジャンピングアスレチック",
                 de: "Kaktor-Kletterwand",
                 fr: "Haute voltige",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         931 => DutyInfo {
             name: LocalisedText {
@@ -7023,9 +11849,16 @@ lazy_static::lazy_static! {
                 ja: "挑戦！ ジャンピングアスレチック",
                 de: "Kaktor-Kletterwand",
                 fr: "Haute voltige",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         932 => DutyInfo {
             name: LocalisedText {
@@ -7033,9 +11866,16 @@ lazy_static::lazy_static! {
                 ja: "挑戦！ ジャンピングアスレチック",
                 de: "Kaktor-Kletterwand",
                 fr: "Haute voltige",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         933 => DutyInfo {
             name: LocalisedText {
@@ -7043,9 +11883,16 @@ lazy_static::lazy_static! {
                 ja: "挑戦！ ジャンピングアスレチック",
                 de: "Kaktor-Kletterwand",
                 fr: "Haute voltige",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         934 => DutyInfo {
             name: LocalisedText {
@@ -7053,9 +11900,16 @@ lazy_static::lazy_static! {
                 ja: "挑戦！ ジャンピングアスレチック",
                 de: "Kaktor-Kletterwand",
                 fr: "Haute voltige",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         935 => DutyInfo {
             name: LocalisedText {
@@ -7063,9 +11917,16 @@ lazy_static::lazy_static! {
                 ja: "挑戦！ ジャンピングアスレチック",
                 de: "Kaktor-Kletterwand",
                 fr: "Haute voltige",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         936 => DutyInfo {
             name: LocalisedText {
@@ -7073,9 +11934,16 @@ lazy_static::lazy_static! {
                 ja: "万魔殿パンデモニウム：天獄編1",
                 de: "Anabaseios - Neunter Kreis",
                 fr: "Le Paradis du Pandæmonium - Métempsycose",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         937 => DutyInfo {
             name: LocalisedText {
@@ -7083,9 +11951,16 @@ lazy_static::lazy_static! {
                 ja: "万魔殿パンデモニウム零式：天獄編1",
                 de: "Anabaseios - Neunter Kreis (episch)",
                 fr: "Le Paradis du Pandæmonium - Métempsycose (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         938 => DutyInfo {
             name: LocalisedText {
@@ -7093,9 +11968,16 @@ lazy_static::lazy_static! {
                 ja: "万魔殿パンデモニウム：天獄編2",
                 de: "Anabaseios - Zehnter Kreis",
                 fr: "Le Paradis du Pandæmonium - Monochrome",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         939 => DutyInfo {
             name: LocalisedText {
@@ -7103,9 +11985,16 @@ lazy_static::lazy_static! {
                 ja: "万魔殿パンデモニウム零式：天獄編2",
                 de: "Anabaseios - Zehnter Kreis (episch)",
                 fr: "Le Paradis du Pandæmonium - Monochrome (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         940 => DutyInfo {
             name: LocalisedText {
@@ -7113,9 +12002,16 @@ lazy_static::lazy_static! {
                 ja: "万魔殿パンデモニウム：天獄編3",
                 de: "Anabaseios - Elfter Kreis",
                 fr: "Le Paradis du Pandæmonium - Tribunal",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         941 => DutyInfo {
             name: LocalisedText {
@@ -7123,9 +12019,16 @@ lazy_static::lazy_static! {
                 ja: "万魔殿パンデモニウム零式：天獄編3",
                 de: "Anabaseios - Elfter Kreis (episch)",
                 fr: "Le Paradis du Pandæmonium - Tribunal (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         942 => DutyInfo {
             name: LocalisedText {
@@ -7133,9 +12036,16 @@ lazy_static::lazy_static! {
                 ja: "万魔殿パンデモニウム：天獄編4",
                 de: "Anabaseios - Zwölfter Kreis",
                 fr: "Le Paradis du Pandæmonium - Apothéose",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         943 => DutyInfo {
             name: LocalisedText {
@@ -7143,9 +12053,16 @@ lazy_static::lazy_static! {
                 ja: "万魔殿パンデモニウム零式：天獄編4",
                 de: "Anabaseios - Zwölfter Kreis (episch)",
                 fr: "Le Paradis du Pandæmonium - Apothéose (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         944 => DutyInfo {
             name: LocalisedText {
@@ -7153,9 +12070,16 @@ lazy_static::lazy_static! {
                 ja: "アスラ討滅戦",
                 de: "Prophetie - Asura",
                 fr: "Le temple doré d'Araya",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         945 => DutyInfo {
             name: LocalisedText {
@@ -7163,9 +12087,16 @@ lazy_static::lazy_static! {
                 ja: "ヴァリアントダンジョン 六根山",
                 de: "Der Rokkon",
                 fr: "Le mont Rokkon - Donjon à embranchements",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::VCDungeonFinder,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         946 => DutyInfo {
             name: LocalisedText {
@@ -7173,9 +12104,16 @@ lazy_static::lazy_static! {
                 ja: "アナザーダンジョン 異聞六根山",
                 de: "Der kuriose Rokkon",
                 fr: "Le mont Rokkon annexe - Donjon alternatif",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::VCDungeonFinder,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         947 => DutyInfo {
             name: LocalisedText {
@@ -7183,9 +12121,16 @@ lazy_static::lazy_static! {
                 ja: "アナザーダンジョン 異聞六根山 零式",
                 de: "Der kuriose Rokkon (episch)",
                 fr: "Le mont Rokkon annexe - Donjon alternatif (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::VCDungeonFinder,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         948 => DutyInfo {
             name: LocalisedText {
@@ -7193,9 +12138,16 @@ lazy_static::lazy_static! {
                 ja: "黄金闘士「ゴールドル」",
                 de: "Goldor der Güldene",
                 fr: "Goldor, le mage doré",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TheMaskedCarnivale,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         949 => DutyInfo {
             name: LocalisedText {
@@ -7203,9 +12155,16 @@ lazy_static::lazy_static! {
                 ja: "ゴルベーザ討滅戦",
                 de: "Prophetie - Golbez",
                 fr: "La Chaire de l'Exilée",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         950 => DutyInfo {
             name: LocalisedText {
@@ -7213,9 +12172,16 @@ lazy_static::lazy_static! {
                 ja: "極ゴルベーザ討滅戦",
                 de: "Eschatos - Golbez",
                 fr: "La Chaire de l'Exilée (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         952 => DutyInfo {
             name: LocalisedText {
@@ -7223,9 +12189,16 @@ lazy_static::lazy_static! {
                 ja: "オーシャンフィッシング",
                 de: "Auf großer Fahrt",
                 fr: "Pêche en mer",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DisciplesoftheLand,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         953 => DutyInfo {
             name: LocalisedText {
@@ -7233,9 +12206,16 @@ lazy_static::lazy_static! {
                 ja: "オーシャンフィッシング",
                 de: "Auf großer Fahrt",
                 fr: "Pêche en mer",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DisciplesoftheLand,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         954 => DutyInfo {
             name: LocalisedText {
@@ -7243,9 +12223,16 @@ lazy_static::lazy_static! {
                 ja: "オーシャンフィッシング",
                 de: "Auf großer Fahrt",
                 fr: "Pêche en mer",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DisciplesoftheLand,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         955 => DutyInfo {
             name: LocalisedText {
@@ -7253,9 +12240,16 @@ lazy_static::lazy_static! {
                 ja: "オーシャンフィッシング",
                 de: "Auf großer Fahrt",
                 fr: "Pêche en mer",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DisciplesoftheLand,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         956 => DutyInfo {
             name: LocalisedText {
@@ -7263,9 +12257,16 @@ lazy_static::lazy_static! {
                 ja: "オーシャンフィッシング",
                 de: "Auf großer Fahrt",
                 fr: "Pêche en mer",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DisciplesoftheLand,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         957 => DutyInfo {
             name: LocalisedText {
@@ -7273,9 +12274,16 @@ lazy_static::lazy_static! {
                 ja: "オーシャンフィッシング",
                 de: "Auf großer Fahrt",
                 fr: "Pêche en mer",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DisciplesoftheLand,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         958 => DutyInfo {
             name: LocalisedText {
@@ -7283,9 +12291,16 @@ lazy_static::lazy_static! {
                 ja: "ブランダーヴィル",
                 de: "Stolperville",
                 fr: "Blunderville",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         959 => DutyInfo {
             name: LocalisedText {
@@ -7293,9 +12308,16 @@ lazy_static::lazy_static! {
                 ja: "炎影の旅路",
                 de: "Der Pfad der Flammen",
                 fr: "Flammes primordiales",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         960 => DutyInfo {
             name: LocalisedText {
@@ -7303,9 +12325,16 @@ lazy_static::lazy_static! {
                 ja: "その瞳が見据えるもの",
                 de: "Die Jagd ist eröffnet",
                 fr: "Par-delà l'horizon",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         961 => DutyInfo {
             name: LocalisedText {
@@ -7313,9 +12342,16 @@ lazy_static::lazy_static! {
                 ja: "ヴァリアントダンジョン アロアロ島",
                 de: "Aloalo",
                 fr: "L'île d'Aloalo - Donjon à embranchements",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::VCDungeonFinder,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         962 => DutyInfo {
             name: LocalisedText {
@@ -7323,9 +12359,16 @@ lazy_static::lazy_static! {
                 ja: "華めく神域 タレイア",
                 de: "Thaleia",
                 fr: "Domaine divin - Thalie",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         964 => DutyInfo {
             name: LocalisedText {
@@ -7333,9 +12376,16 @@ lazy_static::lazy_static! {
                 ja: "ゼロムス討滅戦",
                 de: "Prophetie - Zeromus",
                 fr: "La Fracture abyssale",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         965 => DutyInfo {
             name: LocalisedText {
@@ -7343,9 +12393,16 @@ lazy_static::lazy_static! {
                 ja: "極ゼロムス討滅戦",
                 de: "Eschatos - Zeromus",
                 fr: "La Fracture abyssale (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         966 => DutyInfo {
             name: LocalisedText {
@@ -7353,9 +12410,16 @@ lazy_static::lazy_static! {
                 ja: "旅する画家は幻想を征く",
                 de: "Begegnungen und Abschiede",
                 fr: "L'œuvre de la maturité",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         967 => DutyInfo {
             name: LocalisedText {
@@ -7363,9 +12427,16 @@ lazy_static::lazy_static! {
                 ja: "レッド・サンズ",
                 de: "Die Roten Sande",
                 fr: "Les Sables sanglants",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         968 => DutyInfo {
             name: LocalisedText {
@@ -7373,9 +12444,16 @@ lazy_static::lazy_static! {
                 ja: "レッド・サンズ",
                 de: "Die Roten Sande",
                 fr: "Les Sables sanglants",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         969 => DutyInfo {
             name: LocalisedText {
@@ -7383,9 +12461,16 @@ lazy_static::lazy_static! {
                 ja: "レッド・サンズ",
                 de: "Die Roten Sande",
                 fr: "Les Sables sanglants",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         970 => DutyInfo {
             name: LocalisedText {
@@ -7393,9 +12478,16 @@ lazy_static::lazy_static! {
                 ja: "レッド・サンズ",
                 de: "Die Roten Sande",
                 fr: "Les Sables sanglants",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         971 => DutyInfo {
             name: LocalisedText {
@@ -7403,9 +12495,16 @@ lazy_static::lazy_static! {
                 ja: "レッド・サンズ",
                 de: "Die Roten Sande",
                 fr: "Les Sables sanglants",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         972 => DutyInfo {
             name: LocalisedText {
@@ -7413,9 +12512,16 @@ lazy_static::lazy_static! {
                 ja: "レッド・サンズ",
                 de: "Die Roten Sande",
                 fr: "Les Sables sanglants",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         973 => DutyInfo {
             name: LocalisedText {
@@ -7423,9 +12529,16 @@ lazy_static::lazy_static! {
                 ja: "レッド・サンズ",
                 de: "Die Roten Sande",
                 fr: "Les Sables sanglants",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         974 => DutyInfo {
             name: LocalisedText {
@@ -7433,9 +12546,16 @@ lazy_static::lazy_static! {
                 ja: "レッド・サンズ",
                 de: "Die Roten Sande",
                 fr: "Les Sables sanglants",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         975 => DutyInfo {
             name: LocalisedText {
@@ -7443,9 +12563,16 @@ lazy_static::lazy_static! {
                 ja: "レッド・サンズ",
                 de: "Die Roten Sande",
                 fr: "Les Sables sanglants",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         976 => DutyInfo {
             name: LocalisedText {
@@ -7453,9 +12580,16 @@ lazy_static::lazy_static! {
                 ja: "レッド・サンズ",
                 de: "Die Roten Sande",
                 fr: "Les Sables sanglants",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         977 => DutyInfo {
             name: LocalisedText {
@@ -7463,9 +12597,16 @@ lazy_static::lazy_static! {
                 ja: "レッド・サンズ",
                 de: "Die Roten Sande",
                 fr: "Les Sables sanglants",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         978 => DutyInfo {
             name: LocalisedText {
@@ -7473,9 +12614,16 @@ lazy_static::lazy_static! {
                 ja: "クリスタルコンフリクト(レッド・サンズ：カスタムマッチ)",
                 de: "Crystalline Conflict: Die Roten Sande (Schaukampf)",
                 fr: "Crystalline Conflict (partie personnalisée - Les Sables sanglants)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         979 => DutyInfo {
             name: LocalisedText {
@@ -7483,9 +12631,16 @@ lazy_static::lazy_static! {
                 ja: "アナザーダンジョン 異聞アロアロ島",
                 de: "Kurioses Aloalo",
                 fr: "L'île d'Aloalo annexe - Donjon alternatif",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::VCDungeonFinder,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         980 => DutyInfo {
             name: LocalisedText {
@@ -7493,9 +12648,16 @@ lazy_static::lazy_static! {
                 ja: "アナザーダンジョン 異聞アロアロ島 零式",
                 de: "Kurioses Aloalo (episch)",
                 fr: "L'île d'Aloalo annexe - Donjon alternatif (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::VCDungeonFinder,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         981 => DutyInfo {
             name: LocalisedText {
@@ -7503,9 +12665,16 @@ lazy_static::lazy_static! {
                 ja: "悪夢遊園 ストレイバロー",
                 de: "Mahrlingen-Promenade",
                 fr: "Le Parc-aux-Errants",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         982 => DutyInfo {
             name: LocalisedText {
@@ -7513,9 +12682,16 @@ lazy_static::lazy_static! {
                 ja: "友の試練",
                 de: "Von Schuld und Sühne",
                 fr: "L'épreuve de l'amitié",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         983 => DutyInfo {
             name: LocalisedText {
@@ -7523,9 +12699,16 @@ lazy_static::lazy_static! {
                 ja: "笑顔を護るための戦い",
                 de: "Mit scharfer Axt und leichtem Lächeln",
                 fr: "Préserver la paix",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         984 => DutyInfo {
             name: LocalisedText {
@@ -7533,9 +12716,16 @@ lazy_static::lazy_static! {
                 ja: "エターナルクイーン討滅戦",
                 de: "Zel Tajaal - Ewige Königin",
                 fr: "Interphos",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         985 => DutyInfo {
             name: LocalisedText {
@@ -7543,9 +12733,16 @@ lazy_static::lazy_static! {
                 ja: "至天の座アルカディア：ライトヘビー級1",
                 de: "Arkadion - Halbschwergewicht R1",
                 fr: "Poids mi-lourds CCA - match 1",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         986 => DutyInfo {
             name: LocalisedText {
@@ -7553,9 +12750,16 @@ lazy_static::lazy_static! {
                 ja: "至天の座アルカディア零式：ライトヘビー級1",
                 de: "Arkadion - Halbschwergewicht R1 (episch)",
                 fr: "Poids mi-lourds CCA - match 1 (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         987 => DutyInfo {
             name: LocalisedText {
@@ -7563,9 +12767,16 @@ lazy_static::lazy_static! {
                 ja: "至天の座アルカディア：ライトヘビー級2",
                 de: "Arkadion - Halbschwergewicht R2",
                 fr: "Poids mi-lourds CCA - match 2",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         988 => DutyInfo {
             name: LocalisedText {
@@ -7573,9 +12784,16 @@ lazy_static::lazy_static! {
                 ja: "至天の座アルカディア零式：ライトヘビー級2",
                 de: "Arkadion - Halbschwergewicht R2 (episch)",
                 fr: "Poids mi-lourds CCA - match 2 (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         989 => DutyInfo {
             name: LocalisedText {
@@ -7583,9 +12801,16 @@ lazy_static::lazy_static! {
                 ja: "至天の座アルカディア：ライトヘビー級3",
                 de: "Arkadion - Halbschwergewicht R3",
                 fr: "Poids mi-lourds CCA - match 3",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         990 => DutyInfo {
             name: LocalisedText {
@@ -7593,9 +12818,16 @@ lazy_static::lazy_static! {
                 ja: "至天の座アルカディア零式：ライトヘビー級3",
                 de: "Arkadion - Halbschwergewicht R3 (episch)",
                 fr: "Poids mi-lourds CCA - match 3 (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         991 => DutyInfo {
             name: LocalisedText {
@@ -7603,9 +12835,16 @@ lazy_static::lazy_static! {
                 ja: "至天の座アルカディア：ライトヘビー級4",
                 de: "Arkadion - Halbschwergewicht R4",
                 fr: "Poids mi-lourds CCA - match 4",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         992 => DutyInfo {
             name: LocalisedText {
@@ -7613,9 +12852,16 @@ lazy_static::lazy_static! {
                 ja: "至天の座アルカディア零式：ライトヘビー級4",
                 de: "Arkadion - Halbschwergewicht R4 (episch)",
                 fr: "Poids mi-lourds CCA - match 4 (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         993 => DutyInfo {
             name: LocalisedText {
@@ -7623,9 +12869,16 @@ lazy_static::lazy_static! {
                 ja: "宝物庫 セノーテ・ジャジャグラル",
                 de: "Cenote Ja Ja Gural",
                 fr: "Cénote Ja Ja Gural",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TreasureHunt,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         994 => DutyInfo {
             name: LocalisedText {
@@ -7633,9 +12886,16 @@ lazy_static::lazy_static! {
                 ja: "毒と癒やしの頂上決戦",
                 de: "Heilkunst gegen Hexerei",
                 fr: "Les deux faces du scorpion",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         995 => DutyInfo {
             name: LocalisedText {
@@ -7643,9 +12903,16 @@ lazy_static::lazy_static! {
                 ja: "ゾラージャ討滅戦",
                 de: "Zel Tajaal - Zoraal Ja",
                 fr: "Le Pinacle de l'Éternité",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         996 => DutyInfo {
             name: LocalisedText {
@@ -7653,9 +12920,16 @@ lazy_static::lazy_static! {
                 ja: "極ゾラージャ討滅戦",
                 de: "Gok Tajaal - Zoraal Ja",
                 fr: "Le Pinacle de l'Éternité (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         997 => DutyInfo {
             name: LocalisedText {
@@ -7663,9 +12937,16 @@ lazy_static::lazy_static! {
                 ja: "一端の狩人",
                 de: "Krönender Abschuss",
                 fr: "Chasseuse à lunettes... chasseuse honnête",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         998 => DutyInfo {
             name: LocalisedText {
@@ -7673,9 +12954,16 @@ lazy_static::lazy_static! {
                 ja: "護る者、壊す者",
                 de: "Schützer des Volkes, Schlächter des Volkes",
                 fr: "Protecteurs et destructeurs",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         999 => DutyInfo {
             name: LocalisedText {
@@ -7683,9 +12971,16 @@ lazy_static::lazy_static! {
                 ja: "打倒！ シャルトフィット盗賊団",
                 de: "Der mächtigste Schild",
                 fr: "Mauvais cru pour Chalteaufite",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1000 => DutyInfo {
             name: LocalisedText {
@@ -7693,9 +12988,16 @@ lazy_static::lazy_static! {
                 ja: "旅する画家は森都を征く",
                 de: "Nächtliche Ruhestörung",
                 fr: "Tapage nocturne",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1001 => DutyInfo {
             name: LocalisedText {
@@ -7703,9 +13005,16 @@ lazy_static::lazy_static! {
                 ja: "祭器と作られた虚像",
                 de: "Ein Mythos in Trümmern",
                 fr: "Le château de cartes s'effondre",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1002 => DutyInfo {
             name: LocalisedText {
@@ -7713,9 +13022,16 @@ lazy_static::lazy_static! {
                 ja: "トラルヴィドラールを狩る者",
                 de: "Der Wille der Natur",
                 fr: "Le fléau des Tural vidraal",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1003 => DutyInfo {
             name: LocalisedText {
@@ -7723,9 +13039,16 @@ lazy_static::lazy_static! {
                 ja: "寝ても覚めても",
                 de: "Neue Bande der Freundschaft",
                 fr: "Rêves éveillés",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1006 => DutyInfo {
             name: LocalisedText {
@@ -7733,9 +13056,16 @@ lazy_static::lazy_static! {
                 ja: "絶もうひとつの未来",
                 de: "Eine zweite Zukunft (fatal)",
                 fr: "Avenirs réécrits (fatal)",
+                ..Default::default()
             },
             high_end: true,
             content_kind: ContentKind::UltimateRaids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1008 => DutyInfo {
             name: LocalisedText {
@@ -7743,9 +13073,16 @@ lazy_static::lazy_static! {
                 ja: "廃地討究 ユウェヤーワータ",
                 de: "Forschungsstation Yuweyawata",
                 fr: "Le centre de recherche de Yuweyawata",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1009 => DutyInfo {
             name: LocalisedText {
@@ -7753,9 +13090,16 @@ lazy_static::lazy_static! {
                 ja: "王の家族",
                 de: "Ein Land, eine Familie",
                 fr: "La famille de l'Aurarque",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1010 => DutyInfo {
             name: LocalisedText {
@@ -7763,9 +13107,16 @@ lazy_static::lazy_static! {
                 ja: "滅暗闇の雲激闘戦",
                 de: "Die Wolke der Dunkelheit (chaotisch)",
                 fr: "La Tour de Ténèbres (chaotique)",
+                ..Default::default()
             },
             high_end: true,
             content_kind: ContentKind::ChaoticAllianceRaid,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1012 => DutyInfo {
             name: LocalisedText {
@@ -7773,9 +13124,16 @@ lazy_static::lazy_static! {
                 ja: "マーカーが出る攻撃に対処しよう！",
                 de: "Achte auf Attackenmarkierungen!",
                 fr: "Réagir à des marquages",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(20),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1013 => DutyInfo {
             name: LocalisedText {
@@ -7783,9 +13141,16 @@ lazy_static::lazy_static! {
                 ja: "地面に予兆が出る攻撃に対処しよう！",
                 de: "Achte auf Geländemarkierungen!",
                 fr: "Réagir à des indications au sol",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(20),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1014 => DutyInfo {
             name: LocalisedText {
@@ -7793,9 +13158,16 @@ lazy_static::lazy_static! {
                 ja: "さまざまな攻撃に対処しよう！",
                 de: "Stelle dich allerlei Angriffsmanövern!",
                 fr: "Réagir à des mécaniques variées",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(20),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1015 => DutyInfo {
             name: LocalisedText {
@@ -7803,9 +13175,16 @@ lazy_static::lazy_static! {
                 ja: "ジュノ：ザ・ファーストウォーク",
                 de: "Jeuno: Die erste Etappe",
                 fr: "Jeuno - La première perambulation",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1016 => DutyInfo {
             name: LocalisedText {
@@ -7813,9 +13192,16 @@ lazy_static::lazy_static! {
                 ja: "決戦、ゾーゴー永結橋",
                 de: "Kampf an Zorgor der Weiten",
                 fr: "Pour s'affranchir de la tyrannie, tout s'appelle vertu",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1017 => DutyInfo {
             name: LocalisedText {
@@ -7823,9 +13209,16 @@ lazy_static::lazy_static! {
                 ja: "極エターナルクイーン討滅戦",
                 de: "Gok Tajaal - Ewige Königin",
                 fr: "Interphos (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1018 => DutyInfo {
             name: LocalisedText {
@@ -7833,9 +13226,16 @@ lazy_static::lazy_static! {
                 ja: "蜃気楼の島 クレセントアイル：南征編",
                 de: "Das südliche Kreszentia",
                 fr: "Île de Lunule méridionale",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::OccultCrescent,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1019 => DutyInfo {
             name: LocalisedText {
@@ -7843,9 +13243,16 @@ lazy_static::lazy_static! {
                 ja: "至天の座アルカディア：クルーザー級1",
                 de: "Arkadion - Schwergewicht R1",
                 fr: "Poids lourds-légers CCA - match 1",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1020 => DutyInfo {
             name: LocalisedText {
@@ -7853,9 +13260,16 @@ lazy_static::lazy_static! {
                 ja: "至天の座アルカディア零式：クルーザー級1",
                 de: "Arkadion - Schwergewicht R1 (episch)",
                 fr: "Poids lourds-légers CCA - match 1 (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1021 => DutyInfo {
             name: LocalisedText {
@@ -7863,9 +13277,16 @@ lazy_static::lazy_static! {
                 ja: "至天の座アルカディア：クルーザー級2",
                 de: "Arkadion - Schwergewicht R2",
                 fr: "Poids lourds-légers CCA - match 2",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1022 => DutyInfo {
             name: LocalisedText {
@@ -7873,9 +13294,16 @@ lazy_static::lazy_static! {
                 ja: "至天の座アルカディア零式：クルーザー級2",
                 de: "Arkadion - Schwergewicht R2 (episch)",
                 fr: "Poids lourds-légers CCA - match 2 (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1023 => DutyInfo {
             name: LocalisedText {
@@ -7883,9 +13311,16 @@ lazy_static::lazy_static! {
                 ja: "至天の座アルカディア：クルーザー級3",
                 de: "Arkadion - Schwergewicht R3",
                 fr: "Poids lourds-légers CCA - match 3",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1024 => DutyInfo {
             name: LocalisedText {
@@ -7893,9 +13328,16 @@ lazy_static::lazy_static! {
                 ja: "至天の座アルカディア零式：クルーザー級3",
                 de: "Arkadion - Schwergewicht R3 (episch)",
                 fr: "Poids lourds-légers CCA - match 3 (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1025 => DutyInfo {
             name: LocalisedText {
@@ -7903,9 +13345,16 @@ lazy_static::lazy_static! {
                 ja: "至天の座アルカディア：クルーザー級4",
                 de: "Arkadion - Schwergewicht R4",
                 fr: "Poids lourds-légers CCA - match 4",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1026 => DutyInfo {
             name: LocalisedText {
@@ -7913,9 +13362,16 @@ lazy_static::lazy_static! {
                 ja: "至天の座アルカディア零式：クルーザー級4",
                 de: "Arkadion - Schwergewicht R4 (episch)",
                 fr: "Poids lourds-légers CCA - match 4 (sadique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1027 => DutyInfo {
             name: LocalisedText {
@@ -7923,9 +13379,16 @@ lazy_static::lazy_static! {
                 ja: "王城旧跡 アンダーキープ",
                 de: "Unterfried-Ruinen",
                 fr: "La Gardienne de l'Immémorial",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1028 => DutyInfo {
             name: LocalisedText {
@@ -7933,9 +13396,16 @@ lazy_static::lazy_static! {
                 ja: "永久幽界 メインターミナル",
                 de: "Das Meso-System",
                 fr: "Le Mésoterminal",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1030 => DutyInfo {
             name: LocalisedText {
@@ -7943,9 +13413,16 @@ lazy_static::lazy_static! {
                 ja: "ゼレニア討滅戦",
                 de: "Zel Tajaal - Zelenia",
                 fr: "Le Sanctuaire du Serment",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1031 => DutyInfo {
             name: LocalisedText {
@@ -7953,9 +13430,16 @@ lazy_static::lazy_static! {
                 ja: "極ゼレニア討滅戦",
                 de: "Gok Tajaal - Zelenia",
                 fr: "Le Sanctuaire du Serment (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1032 => DutyInfo {
             name: LocalisedText {
@@ -7963,9 +13447,16 @@ lazy_static::lazy_static! {
                 ja: "ピルグリム・トラバース 第1～10巡礼路",
                 de: "Pilgers Pfad (Ebenen 1-10)",
                 fr: "Le Sanctuaire des pèlerins (zones 1-10)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1033 => DutyInfo {
             name: LocalisedText {
@@ -7973,9 +13464,16 @@ lazy_static::lazy_static! {
                 ja: "ピルグリム・トラバース 第11～20巡礼路",
                 de: "Pilgers Pfad (Ebenen 11-20)",
                 fr: "Le Sanctuaire des pèlerins (zones 11-20)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1034 => DutyInfo {
             name: LocalisedText {
@@ -7983,9 +13481,16 @@ lazy_static::lazy_static! {
                 ja: "ピルグリム・トラバース 第21～30巡礼路",
                 de: "Pilgers Pfad (Ebenen 21-30)",
                 fr: "Le Sanctuaire des pèlerins (zones 21-30)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1035 => DutyInfo {
             name: LocalisedText {
@@ -7993,9 +13498,16 @@ lazy_static::lazy_static! {
                 ja: "ピルグリム・トラバース 第31～40巡礼路",
                 de: "Pilgers Pfad (Ebenen 31-40)",
                 fr: "Le Sanctuaire des pèlerins (zones 31-40)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1036 => DutyInfo {
             name: LocalisedText {
@@ -8003,9 +13515,16 @@ lazy_static::lazy_static! {
                 ja: "ピルグリム・トラバース 第41～50巡礼路",
                 de: "Pilgers Pfad (Ebenen 41-50)",
                 fr: "Le Sanctuaire des pèlerins (zones 41-50)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1037 => DutyInfo {
             name: LocalisedText {
@@ -8013,9 +13532,16 @@ lazy_static::lazy_static! {
                 ja: "ピルグリム・トラバース 第51～60巡礼路",
                 de: "Pilgers Pfad (Ebenen 51-60)",
                 fr: "Le Sanctuaire des pèlerins (zones 51-60)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1038 => DutyInfo {
             name: LocalisedText {
@@ -8023,9 +13549,16 @@ lazy_static::lazy_static! {
                 ja: "ピルグリム・トラバース 第61～70巡礼路",
                 de: "Pilgers Pfad (Ebenen 61-70)",
                 fr: "Le Sanctuaire des pèlerins (zones 61-70)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1039 => DutyInfo {
             name: LocalisedText {
@@ -8033,9 +13566,16 @@ lazy_static::lazy_static! {
                 ja: "ピルグリム・トラバース 第71～80巡礼路",
                 de: "Pilgers Pfad (Ebenen 71-80)",
                 fr: "Le Sanctuaire des pèlerins (zones 71-80)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1040 => DutyInfo {
             name: LocalisedText {
@@ -8043,9 +13583,16 @@ lazy_static::lazy_static! {
                 ja: "ピルグリム・トラバース 第81～90巡礼路",
                 de: "Pilgers Pfad (Ebenen 81-90)",
                 fr: "Le Sanctuaire des pèlerins (zones 81-90)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1041 => DutyInfo {
             name: LocalisedText {
@@ -8053,9 +13600,16 @@ lazy_static::lazy_static! {
                 ja: "ピルグリム・トラバース 第91～100巡礼路",
                 de: "Pilgers Pfad (Ebenen 91-100)",
                 fr: "Le Sanctuaire des pèlerins (zones 91-100)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::DeepDungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1043 => DutyInfo {
             name: LocalisedText {
@@ -8063,9 +13617,16 @@ lazy_static::lazy_static! {
                 ja: "護竜アルシュベルド狩猟戦",
                 de: "Jagd auf Wächter-Arkveld",
                 fr: "Chasse à l'Arkveld Gardien",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1044 => DutyInfo {
             name: LocalisedText {
@@ -8073,9 +13634,16 @@ lazy_static::lazy_static! {
                 ja: "極護竜アルシュベルド狩猟戦",
                 de: "Jagd auf Wächter-Arkveld (schwer)",
                 fr: "Chasse à l'Arkveld Gardien (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1045 => DutyInfo {
             name: LocalisedText {
@@ -8083,9 +13651,16 @@ lazy_static::lazy_static! {
                 ja: "ヴァレンティオンパティスリー",
                 de: "Die Pâtisserie",
                 fr: "La Pâtisserie de la Valention",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(22),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1046 => DutyInfo {
             name: LocalisedText {
@@ -8093,9 +13668,16 @@ lazy_static::lazy_static! {
                 ja: "ベイサイド・バトルグラウンド",
                 de: "Der Küstenkampf",
                 fr: "Le Rivage redoutable",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1047 => DutyInfo {
             name: LocalisedText {
@@ -8103,9 +13685,16 @@ lazy_static::lazy_static! {
                 ja: "ベイサイド・バトルグラウンド",
                 de: "Der Küstenkampf",
                 fr: "Le Rivage redoutable",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1048 => DutyInfo {
             name: LocalisedText {
@@ -8113,9 +13702,16 @@ lazy_static::lazy_static! {
                 ja: "ベイサイド・バトルグラウンド",
                 de: "Der Küstenkampf",
                 fr: "Le Rivage redoutable",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1049 => DutyInfo {
             name: LocalisedText {
@@ -8123,9 +13719,16 @@ lazy_static::lazy_static! {
                 ja: "ベイサイド・バトルグラウンド",
                 de: "Der Küstenkampf",
                 fr: "Le Rivage redoutable",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1050 => DutyInfo {
             name: LocalisedText {
@@ -8133,9 +13736,16 @@ lazy_static::lazy_static! {
                 ja: "ベイサイド・バトルグラウンド",
                 de: "Der Küstenkampf",
                 fr: "Le Rivage redoutable",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1051 => DutyInfo {
             name: LocalisedText {
@@ -8143,9 +13753,16 @@ lazy_static::lazy_static! {
                 ja: "ベイサイド・バトルグラウンド",
                 de: "Der Küstenkampf",
                 fr: "Le Rivage redoutable",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1052 => DutyInfo {
             name: LocalisedText {
@@ -8153,9 +13770,16 @@ lazy_static::lazy_static! {
                 ja: "ベイサイド・バトルグラウンド",
                 de: "Der Küstenkampf",
                 fr: "Le Rivage redoutable",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1053 => DutyInfo {
             name: LocalisedText {
@@ -8163,9 +13787,16 @@ lazy_static::lazy_static! {
                 ja: "ベイサイド・バトルグラウンド",
                 de: "Der Küstenkampf",
                 fr: "Le Rivage redoutable",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1054 => DutyInfo {
             name: LocalisedText {
@@ -8173,9 +13804,16 @@ lazy_static::lazy_static! {
                 ja: "ベイサイド・バトルグラウンド",
                 de: "Der Küstenkampf",
                 fr: "Le Rivage redoutable",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1055 => DutyInfo {
             name: LocalisedText {
@@ -8183,9 +13821,16 @@ lazy_static::lazy_static! {
                 ja: "ベイサイド・バトルグラウンド",
                 de: "Der Küstenkampf",
                 fr: "Le Rivage redoutable",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1056 => DutyInfo {
             name: LocalisedText {
@@ -8193,9 +13838,16 @@ lazy_static::lazy_static! {
                 ja: "ベイサイド・バトルグラウンド",
                 de: "Der Küstenkampf",
                 fr: "Le Rivage redoutable",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1057 => DutyInfo {
             name: LocalisedText {
@@ -8203,9 +13855,16 @@ lazy_static::lazy_static! {
                 ja: "クリスタルコンフリクト(ベイサイド・バトルグラウンド：カスタムマッチ)",
                 de: "Crystalline Conflict: Der Küstenkampf (Schaukampf)",
                 fr: "Crys. Conflict (partie perso. - Le Rivage redoutable)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1058 => DutyInfo {
             name: LocalisedText {
@@ -8213,9 +13872,16 @@ lazy_static::lazy_static! {
                 ja: "サンドリア：ザ・セカンドウォーク",
                 de: "San d'Oria: Die zweite Etappe",
                 fr: "San d'Oria - La deuxième perambulation",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1060 => DutyInfo {
             name: LocalisedText {
@@ -8223,9 +13889,16 @@ lazy_static::lazy_static! {
                 ja: "宝物庫 ボルト・オネイロン",
                 de: "Tresor Oneiron",
                 fr: "Le coffre d'Oneiron",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::TreasureHunt,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1061 => DutyInfo {
             name: LocalisedText {
@@ -8233,9 +13906,16 @@ lazy_static::lazy_static! {
                 ja: "永遠の闇討滅戦",
                 de: "Zel Tajaal - Das Ewige Dunkel",
                 fr: "La Nécropole intemporelle",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1062 => DutyInfo {
             name: LocalisedText {
@@ -8243,9 +13923,16 @@ lazy_static::lazy_static! {
                 ja: "極永遠の闇討滅戦",
                 de: "Gok Tajaal - Das Ewige Dunkel",
                 fr: "La Nécropole intemporelle (extrême)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1063 => DutyInfo {
             name: LocalisedText {
@@ -8253,9 +13940,16 @@ lazy_static::lazy_static! {
                 ja: "詩想エミネントグリーフ討滅戦",
                 de: "Eminente Trauer (variabel)",
                 fr: "Le Verset final (quantique)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(39),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1064 => DutyInfo {
             name: LocalisedText {
@@ -8263,9 +13957,16 @@ lazy_static::lazy_static! {
                 ja: "遺忘行路 ミストウェイク",
                 de: "Die Nebeltrift",
                 fr: "Le Sillage brumeux",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Dungeons,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1065 => DutyInfo {
             name: LocalisedText {
@@ -8273,9 +13974,16 @@ lazy_static::lazy_static! {
                 ja: "エミネントグリーフ討滅戦",
                 de: "Eminente Trauer",
                 fr: "Le Verset final",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Other(39),
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1067 => DutyInfo {
             name: LocalisedText {
@@ -8283,9 +13991,16 @@ lazy_static::lazy_static! {
                 ja: "幻ツクヨミ討滅戦",
                 de: "Traumprüfung - Tsukuyomi",
                 fr: "Castrum Fluminis (irréel)",
+                ..Default::default()
             },
             high_end: true,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1068 => DutyInfo {
             name: LocalisedText {
@@ -8293,9 +14008,16 @@ lazy_static::lazy_static! {
                 ja: "至天の座アルカディア：ヘビー級1",
                 de: "Arkadion - Superschwergewicht R1",
                 fr: "Poids lourds CCA - match 1",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1069 => DutyInfo {
             name: LocalisedText {
@@ -8303,9 +14025,16 @@ lazy_static::lazy_static! {
                 ja: "至天の座アルカディア零式：ヘビー級1",
                 de: "Arkadion - Superschwergewicht R1 (episch)",
                 fr: "Poids lourds CCA - match 1 (sadique)",
+                ..Default::default()
             },
             high_end: true,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1070 => DutyInfo {
             name: LocalisedText {
@@ -8313,9 +14042,16 @@ lazy_static::lazy_static! {
                 ja: "至天の座アルカディア：ヘビー級2",
                 de: "Arkadion - Superschwergewicht R2",
                 fr: "Poids lourds CCA - match 2",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1071 => DutyInfo {
             name: LocalisedText {
@@ -8323,9 +14059,16 @@ lazy_static::lazy_static! {
                 ja: "至天の座アルカディア零式：ヘビー級2",
                 de: "Arkadion - Superschwergewicht R2 (episch)",
                 fr: "Poids lourds CCA - match 2 (sadique)",
+                ..Default::default()
             },
             high_end: true,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1072 => DutyInfo {
             name: LocalisedText {
@@ -8333,9 +14076,16 @@ lazy_static::lazy_static! {
                 ja: "至天の座アルカディア：ヘビー級3",
                 de: "Arkadion - Superschwergewicht R3",
                 fr: "Poids lourds CCA - match 3",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1073 => DutyInfo {
             name: LocalisedText {
@@ -8343,9 +14093,16 @@ lazy_static::lazy_static! {
                 ja: "至天の座アルカディア零式：ヘビー級3",
                 de: "Arkadion - Superschwergewicht R3 (episch)",
                 fr: "Poids lourds CCA - match 3 (sadique)",
+                ..Default::default()
             },
             high_end: true,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1074 => DutyInfo {
             name: LocalisedText {
@@ -8353,9 +14110,16 @@ lazy_static::lazy_static! {
                 ja: "至天の座アルカディア：ヘビー級4",
                 de: "Arkadion - Superschwergewicht R4",
                 fr: "Poids lourds CCA - match 4",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1075 => DutyInfo {
             name: LocalisedText {
@@ -8363,9 +14127,16 @@ lazy_static::lazy_static! {
                 ja: "至天の座アルカディア零式：ヘビー級4",
                 de: "Arkadion - Superschwergewicht R4 (episch)",
                 fr: "Poids lourds CCA - match 4 (sadique)",
+                ..Default::default()
             },
             high_end: true,
             content_kind: ContentKind::Raids,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1076 => DutyInfo {
             name: LocalisedText {
@@ -8373,9 +14144,16 @@ lazy_static::lazy_static! {
                 ja: "グラシャラボラス討滅戦",
                 de: "Zel Tajaal - Doomtrain",
                 fr: "Le Convoi maléfique",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1077 => DutyInfo {
             name: LocalisedText {
@@ -8383,9 +14161,16 @@ lazy_static::lazy_static! {
                 ja: "極グラシャラボラス討滅戦",
                 de: "Gok Tajaal - Doomtrain",
                 fr: "Le Convoi maléfique (extrême)",
+                ..Default::default()
             },
             high_end: true,
             content_kind: ContentKind::Trials,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1080 => DutyInfo {
             name: LocalisedText {
@@ -8393,9 +14178,16 @@ lazy_static::lazy_static! {
                 ja: "ウォーコー・チーテ (演習戦)",
                 de: "Worqor Chirteh (Triumph)",
                 fr: "Worqor Chirteh (manœuvre)",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::PvP,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1081 => DutyInfo {
             name: LocalisedText {
@@ -8403,9 +14195,16 @@ lazy_static::lazy_static! {
                 ja: "出撃！ エアフォースパイロット",
                 de: "Luftwaffe, Feuer frei!",
                 fr: "As de l'air",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1082 => DutyInfo {
             name: LocalisedText {
@@ -8413,9 +14212,16 @@ lazy_static::lazy_static! {
                 ja: "出撃！ エアフォースパイロット",
                 de: "Luftwaffe, Feuer frei!",
                 fr: "As de l'air",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1083 => DutyInfo {
             name: LocalisedText {
@@ -8423,9 +14229,16 @@ lazy_static::lazy_static! {
                 ja: "出撃！ エアフォースパイロット",
                 de: "Luftwaffe, Feuer frei!",
                 fr: "As de l'air",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::GoldSaucer,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
         1085 => DutyInfo {
             name: LocalisedText {
@@ -8433,9 +14246,16 @@ lazy_static::lazy_static! {
                 ja: "隣人と手を取り合いて",
                 de: "Die Hüterin Trenos",
                 fr: "Solidarité entre voisins",
+                ..Default::default()
             },
             high_end: false,
             content_kind: ContentKind::QuestBattles,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
         },
     };
 }
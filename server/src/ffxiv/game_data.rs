@@ -0,0 +1,166 @@
+//! Game data loaded from datasheet CSVs at runtime.
+//!
+//! Loaded tables take precedence over the built-in ones, so new duties can be
+//! picked up on patch day by updating the data directory and sending `SIGHUP`.
+
+use super::datasheet::{DutyRow, Names, Tables};
+use super::duties::{ContentKind, DutyInfo};
+use super::roulettes::RouletteInfo;
+use super::{Expansion, LocalisedText, PartySize, Region, UiColour, WorldInfo};
+use anyhow::Result;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+pub struct GameData {
+    pub duties: HashMap<u32, DutyInfo>,
+    pub roulettes: HashMap<u32, RouletteInfo>,
+    pub territory_names: HashMap<u32, LocalisedText>,
+    pub fate_territories: HashSet<u32>,
+    pub auto_translate: HashMap<(u32, u32), LocalisedText>,
+    pub treasure_maps: HashMap<u32, LocalisedText>,
+    pub worlds: HashMap<u32, WorldInfo>,
+    /// Only available from loaded game data.
+    pub ui_colours: HashMap<u32, UiColour>,
+}
+
+lazy_static::lazy_static! {
+    static ref LOADED: RwLock<Option<&'static GameData>> = RwLock::new(None);
+}
+
+/// The loaded game data, if any.
+pub fn get() -> Option<&'static GameData> {
+    *LOADED.read().unwrap()
+}
+
+/// Loads the game data in `dir`, replacing any previously loaded data.
+/// On error, the previous data (or the built-in tables) stay in use.
+pub async fn load(dir: PathBuf) -> Result<()> {
+    let data = tokio::task::spawn_blocking(move || load_blocking(&dir)).await??;
+    println!(
+        "loaded game data: {} duties, {} roulettes, {} territories, {} auto-translate entries, {} treasure maps, {} worlds, {} colours",
+        data.duties.len(),
+        data.roulettes.len(),
+        data.territory_names.len(),
+        data.auto_translate.len(),
+        data.treasure_maps.len(),
+        data.worlds.len(),
+        data.ui_colours.len(),
+    );
+
+    // lookups hand out `'static` references, so replaced data is leaked. this
+    // only happens on reload, which is expected about once per patch.
+    *LOADED.write().unwrap() = Some(Box::leak(Box::new(data)));
+    super::search::invalidate();
+    Ok(())
+}
+
+fn load_blocking(dir: &Path) -> Result<GameData> {
+    let tables = Tables::load(dir).map_err(anyhow::Error::msg)?;
+
+    Ok(GameData {
+        duties: tables
+            .duties
+            .into_iter()
+            .map(|(id, duty)| (id, duty_info(duty)))
+            .collect(),
+        roulettes: tables
+            .roulettes
+            .into_iter()
+            .map(|(id, roulette)| {
+                let info = RouletteInfo {
+                    name: localised_text(roulette.name),
+                    pvp: roulette.pvp,
+                };
+                (id, info)
+            })
+            .collect(),
+        territory_names: localised_table(tables.territory_names),
+        fate_territories: tables.fate_territories.into_iter().collect(),
+        auto_translate: localised_table(tables.auto_translate),
+        treasure_maps: localised_table(tables.treasure_maps),
+        worlds: tables
+            .worlds
+            .into_iter()
+            .map(|(id, world)| {
+                let info = WorldInfo {
+                    id,
+                    name: leak(world.name),
+                    data_centre: leak(world.data_centre),
+                    region: Region::from_u32(world.region),
+                    travel: world.travel,
+                };
+                (id, info)
+            })
+            .collect(),
+        ui_colours: tables
+            .ui_colours
+            .into_iter()
+            .map(|(id, colour)| {
+                let colour = UiColour {
+                    foreground: colour.foreground,
+                    glow: colour.glow,
+                };
+                (id, colour)
+            })
+            .collect(),
+    })
+}
+
+/// Reloads the game data in `dir` whenever the process receives `SIGHUP`.
+#[cfg(unix)]
+pub fn reload_on_sighup(dir: PathBuf) -> Result<()> {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    let mut hangups = signal(SignalKind::hangup())?;
+    tokio::task::spawn(async move {
+        while hangups.recv().await.is_some() {
+            println!("reloading game data from {}", dir.display());
+            if let Err(e) = load(dir.clone()).await {
+                eprintln!("could not reload game data: {:#?}", e);
+            }
+        }
+    });
+
+    Ok(())
+}
+
+pub fn duty_info(duty: DutyRow) -> DutyInfo {
+    DutyInfo {
+        name: localised_text(duty.name),
+        high_end: duty.high_end,
+        content_kind: ContentKind::from_u32(duty.content_type),
+        level: u8::try_from(duty.level).ok().filter(|&level| level != 0),
+        item_level: u16::try_from(duty.item_level).ok().filter(|&item_level| item_level != 0),
+        party_size: PartySize::from_members(duty.members_per_party, duty.alliance),
+        expansion: duty.ex_version.and_then(Expansion::from_u32),
+        icon: Some(duty.icon).filter(|&icon| icon != 0),
+        image: Some(duty.image).filter(|&image| image != 0),
+    }
+}
+
+fn localised_table<K: std::hash::Hash + Eq>(
+    table: impl IntoIterator<Item = (K, Names)>,
+) -> HashMap<K, LocalisedText> {
+    table
+        .into_iter()
+        .map(|(key, names)| (key, localised_text(names)))
+        .collect()
+}
+
+fn localised_text(names: Names) -> LocalisedText {
+    let [en, ja, de, fr, ko, zh_hans, zh_hant] = names.map(leak);
+    LocalisedText {
+        en,
+        ja,
+        de,
+        fr,
+        ko,
+        zh_hans,
+        zh_hant,
+    }
+}
+
+fn leak(text: String) -> &'static str {
+    Box::leak(text.into_boxed_str())
+}
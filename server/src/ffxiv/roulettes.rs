@@ -4,10 +4,13 @@ use super::LocalisedText;
 #[derive(Debug)]
 pub struct RouletteInfo {
     pub name: LocalisedText,
-    #[expect(unused)]
     pub pvp: bool,
 }
 
+#[cfg(generated_tables)]
+include!(concat!(env!("OUT_DIR"), "/roulettes.rs"));
+
+#[cfg(not(generated_tables))]
 lazy_static::lazy_static! {
     pub static ref ROULETTES: HashMap<u32, RouletteInfo> = maplit::hashmap! {
         1 => RouletteInfo {
@@ -16,6 +19,7 @@ lazy_static::lazy_static! {
                 ja: "コンテンツルーレット：レべリング",
                 de: "Zufallsinhalt: Stufensteigerung",
                 fr: "Mission aléatoire : gain de niveaux",
+                ..Default::default()
             },
             pvp: false,
         },
@@ -25,6 +29,7 @@ lazy_static::lazy_static! {
                 ja: "コンテンツルーレット：ハイレベリング",
                 de: "Zufallsinhalt: Hohe Stufen",
                 fr: "Mission aléatoire : donjons avancés",
+                ..Default::default()
             },
             pvp: false,
         },
@@ -34,6 +39,7 @@ lazy_static::lazy_static! {
                 ja: "コンテンツルーレット：メインクエスト",
                 de: "Zufallsinhalt: Hauptszenario",
                 fr: "Mission aléatoire : épopée",
+                ..Default::default()
             },
             pvp: false,
         },
@@ -43,6 +49,7 @@ lazy_static::lazy_static! {
                 ja: "コンテンツルーレット：ギルドオーダー",
                 de: "Zufallsinhalt: Gildengeheiß",
                 fr: "Mission aléatoire : opérations de guilde",
+                ..Default::default()
             },
             pvp: false,
         },
@@ -52,6 +59,7 @@ lazy_static::lazy_static! {
                 ja: "コンテンツルーレット：エキスパート",
                 de: "Zufallsinhalt: Experte",
                 fr: "Mission aléatoire : expert",
+                ..Default::default()
             },
             pvp: false,
         },
@@ -61,6 +69,7 @@ lazy_static::lazy_static! {
                 ja: "コンテンツルーレット：討伐・討滅戦",
                 de: "Zufallsinhalt: Prüfung",
                 fr: "Mission aléatoire : défis",
+                ..Default::default()
             },
             pvp: false,
         },
@@ -70,6 +79,7 @@ lazy_static::lazy_static! {
                 ja: "デイリーチャレンジ：フロントライン",
                 de: "Tagesherausforderung: PvP-Front",
                 fr: "Challenge quotidien : Front",
+                ..Default::default()
             },
             pvp: true,
         },
@@ -79,6 +89,7 @@ lazy_static::lazy_static! {
                 ja: "コンテンツルーレット：レベルキャップダンジョン",
                 de: "Zufallsinhalt: Höchststufe",
                 fr: "Mission aléatoire : donjons supérieurs",
+                ..Default::default()
             },
             pvp: false,
         },
@@ -88,6 +99,7 @@ lazy_static::lazy_static! {
                 ja: "コンテンツルーレット：メンター",
                 de: "Zufallsinhalt: Mentor",
                 fr: "Mission aléatoire : mentor",
+                ..Default::default()
             },
             pvp: false,
         },
@@ -97,6 +109,7 @@ lazy_static::lazy_static! {
                 ja: "コンテンツルーレット：アライアンスレイド",
                 de: "Zufallsinhalt: Allianz-Raid",
                 fr: "Mission aléatoire : raids en alliance",
+                ..Default::default()
             },
             pvp: false,
         },
@@ -106,6 +119,7 @@ lazy_static::lazy_static! {
                 ja: "コンテンツルーレット：ノーマルレイド",
                 de: "Zufallsinhalt: Normaler Raid",
                 fr: "Mission aléatoire : raids normaux",
+                ..Default::default()
             },
             pvp: false,
         },
@@ -115,6 +129,7 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：サゴリーロード",
                 de: "Chocobo-Rennen: Sagolii-Straße",
                 fr: "Course de chocobos : Route de Sagolii",
+                ..Default::default()
             },
             pvp: false,
         },
@@ -124,6 +139,7 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：コスタ・デル・ソル",
                 de: "Chocobo-Rennen: Sonnenküste",
                 fr: "Course de chocobos : Costa del Sol",
+                ..Default::default()
             },
             pvp: false,
         },
@@ -133,6 +149,7 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：トランキルパス",
                 de: "Chocobo-Rennen: Pfad der Seelenruhe",
                 fr: "Course de chocobos : Sentes tranquilles",
+                ..Default::default()
             },
             pvp: false,
         },
@@ -142,6 +159,7 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：コースルーレット",
                 de: "Chocobo-Rennen: Zufallsstrecke",
                 fr: "Course de chocobos : aléatoire",
+                ..Default::default()
             },
             pvp: false,
         },
@@ -151,6 +169,7 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：サゴリーロード (報酬なし)",
                 de: "Chocobo-Rennen: Sagolii-Straße (keine Belohnung)",
                 fr: "Course de chocobos : Route de Sagolii (sans récompense)",
+                ..Default::default()
             },
             pvp: false,
         },
@@ -160,6 +179,7 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：コスタ・デル・ソル (報酬なし)",
                 de: "Chocobo-Rennen: Sonnenküste (keine Belohnung)",
                 fr: "Course de chocobos : Costa del Sol (sans récompense)",
+                ..Default::default()
             },
             pvp: false,
         },
@@ -169,6 +189,7 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：トランキルパス (報酬なし)",
                 de: "Chocobo-Rennen: Pfad der Seelenruhe (keine Belohnung)",
                 fr: "Course de chocobos : Sentes tranquilles (sans récompense)",
+                ..Default::default()
             },
             pvp: false,
         },
@@ -178,6 +199,7 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：コースルーレット (報酬なし)",
                 de: "Chocobo-Rennen: Zufallsstrecke (keine Belohnung)",
                 fr: "Course de chocobos : aléatoire (sans récompense)",
+                ..Default::default()
             },
             pvp: false,
         },
@@ -187,6 +209,7 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：コースルーレット",
                 de: "Chocobo-Rennen: Zufallsstrecke",
                 fr: "Course de chocobos : aléatoire",
+                ..Default::default()
             },
             pvp: false,
         },
@@ -196,6 +219,7 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：コースルーレット",
                 de: "Chocobo-Rennen: Zufallsstrecke",
                 fr: "Course de chocobos : aléatoire",
+                ..Default::default()
             },
             pvp: false,
         },
@@ -205,6 +229,7 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：コースルーレット",
                 de: "Chocobo-Rennen: Zufallsstrecke",
                 fr: "Course de chocobos : aléatoire",
+                ..Default::default()
             },
             pvp: false,
         },
@@ -214,6 +239,7 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：コースルーレット",
                 de: "Chocobo-Rennen: Zufallsstrecke",
                 fr: "Course de chocobos : aléatoire",
+                ..Default::default()
             },
             pvp: false,
         },
@@ -223,6 +249,7 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：コースルーレット",
                 de: "Chocobo-Rennen: Zufallsstrecke",
                 fr: "Course de chocobos : aléatoire",
+                ..Default::default()
             },
             pvp: false,
         },
@@ -232,6 +259,7 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：コースルーレット",
                 de: "Chocobo-Rennen: Zufallsstrecke",
                 fr: "Course de chocobos : aléatoire",
+                ..Default::default()
             },
             pvp: false,
         },
@@ -241,6 +269,7 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：コースルーレット",
                 de: "Chocobo-Rennen: Zufallsstrecke",
                 fr: "Course de chocobos : aléatoire",
+                ..Default::default()
             },
             pvp: false,
         },
@@ -250,6 +279,7 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：コースルーレット",
                 de: "Chocobo-Rennen: Zufallsstrecke",
                 fr: "Course de chocobos : aléatoire",
+                ..Default::default()
             },
             pvp: false,
         },
@@ -259,6 +289,7 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：コースルーレット",
                 de: "Chocobo-Rennen: Zufallsstrecke",
                 fr: "Course de chocobos : aléatoire",
+                ..Default::default()
             },
             pvp: false,
         },
@@ -268,6 +299,7 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：コースルーレット",
                 de: "Chocobo-Rennen: Zufallsstrecke",
                 fr: "Course de chocobos : aléatoire",
+                ..Default::default()
             },
             pvp: false,
         },
@@ -277,6 +309,7 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：コースルーレット",
                 de: "Chocobo-Rennen: Zufallsstrecke",
                 fr: "Course de chocobos : aléatoire",
+                ..Default::default()
             },
             pvp: false,
         },
@@ -286,6 +319,7 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：コースルーレット",
                 de: "Chocobo-Rennen: Zufallsstrecke",
                 fr: "Course de chocobos : aléatoire",
+                ..Default::default()
             },
             pvp: false,
         },
@@ -295,6 +329,7 @@ lazy_static::lazy_static! {
                 ja: "チョコボレース：コースルーレット",
                 de: "Chocobo-Rennen: Zufallsstrecke",
                 fr: "Course de chocobos : aléatoire",
+                ..Default::default()
             },
             pvp: false,
         },
@@ -304,6 +339,7 @@ lazy_static::lazy_static! {
                 ja: "クリスタルコンフリクト(カジュアルマッチ)",
                 de: "Crystalline Conflict: Freies Spiel",
                 fr: "Crystalline Conflict (partie non classée)",
+                ..Default::default()
             },
             pvp: true,
         },
@@ -313,6 +349,7 @@ lazy_static::lazy_static! {
                 ja: "クリスタルコンフリクト(ランクマッチ)",
                 de: "Crystalline Conflict: Gewertetes Spiel",
                 fr: "Crystalline Conflict (partie classée)",
+                ..Default::default()
             },
             pvp: true,
         },
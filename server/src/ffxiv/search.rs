@@ -0,0 +1,348 @@
+//! Searching duties by name in every language.
+//!
+//! Listings only carry ids, so this turns what players type ("top",
+//! "絶アルテマ") back into the ids a listing would have.
+
+use super::special_duties::{self, Source};
+use super::{game_data, LocalisedText};
+use crate::listing::{DutyCategory, DutyType};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+#[derive(Debug, Clone)]
+pub struct SearchResult {
+    pub duty_type: DutyType,
+    /// `None` for duties and roulettes, which are identified by id alone.
+    pub category: Option<DutyCategory>,
+    /// Every id with this name, e.g. all the territories of a FATE zone.
+    pub ids: Vec<u16>,
+    pub name: LocalisedText,
+    pub score: u32,
+}
+
+struct Entry {
+    duty_type: DutyType,
+    category: Option<DutyCategory>,
+    ids: Vec<u16>,
+    name: LocalisedText,
+    keys: Vec<Key>,
+}
+
+/// A normalised name in one language.
+struct Key {
+    text: String,
+    /// Byte offsets of the start of each word in `text`.
+    words: Vec<usize>,
+    /// The first letter of each word.
+    acronym: String,
+    /// The acronym without any parenthesised suffix.
+    short_acronym: String,
+}
+
+fn word_starts(text: &str) -> Vec<usize> {
+    text.char_indices()
+        .filter(|&(i, _)| i == 0 || text[..i].ends_with(' '))
+        .map(|(i, _)| i)
+        .collect()
+}
+
+fn acronym(text: &str, words: &[usize]) -> String {
+    words
+        .iter()
+        .filter_map(|&i| text[i..].chars().next())
+        .collect()
+}
+
+fn acronym_of(text: &str) -> String {
+    acronym(text, &word_starts(text))
+}
+
+impl Key {
+    fn new(name: &str) -> Self {
+        let text = normalise(name);
+        let words = word_starts(&text);
+        let acronym = acronym(&text, &words);
+
+        // "The Omega Protocol (Ultimate)" is "top"
+        let base = normalise(name.split('(').next().unwrap_or_default());
+        let short_acronym = acronym_of(&base);
+
+        Self {
+            text,
+            words,
+            acronym,
+            short_acronym,
+        }
+    }
+
+    fn score(&self, query: &str) -> Option<u32> {
+        if self.text == query {
+            return Some(100);
+        }
+
+        if query.chars().count() >= 2 && (self.acronym == query || self.short_acronym == query) {
+            return Some(95);
+        }
+
+        if self.text.starts_with(query) {
+            return Some(90);
+        }
+
+        let len = query.chars().count();
+        if len >= 2 && self.acronym.starts_with(query) {
+            return Some(80);
+        }
+
+        if self.words.iter().any(|&i| self.text[i..].starts_with(query)) {
+            return Some(70);
+        }
+
+        if self.text.contains(query) {
+            return Some(60);
+        }
+
+        // allow a typo or two in longer queries, anchored at a word
+        let allowed = match len {
+            0..=3 => return None,
+            4..=7 => 1,
+            _ => 2,
+        };
+        let query: Vec<char> = query.chars().collect();
+        self.words
+            .iter()
+            .filter_map(|&i| {
+                let word: Vec<char> = self.text[i..].chars().take(len + allowed).collect();
+                (len.saturating_sub(allowed)..=word.len())
+                    .map(|end| edit_distance(&query, &word[..end]))
+                    .min()
+            })
+            .min()
+            .filter(|&distance| distance <= allowed)
+            .map(|distance| 50 - 10 * distance as u32)
+    }
+}
+
+#[derive(Default)]
+struct Index {
+    entries: Vec<Entry>,
+    /// Entries by type, category and English name, to merge ids with the same name.
+    by_name: HashMap<(u8, Option<u32>, &'static str), usize>,
+}
+
+impl Index {
+    fn build() -> Self {
+        let mut index = Self::default();
+
+        for (id, info) in super::all_duties() {
+            index.add(DutyType::Normal, None, id, info.name);
+        }
+
+        for (id, info) in super::all_roulettes() {
+            index.add(DutyType::Roulette, None, id, info.name);
+        }
+
+        let treasure_maps = super::merged([
+            Some(&*super::TREASURE_MAPS),
+            game_data::get().map(|data| &data.treasure_maps),
+        ]);
+        for (id, name) in treasure_maps {
+            index.add(DutyType::Other, Some(DutyCategory::TreasureHunt), id, *name);
+        }
+
+        let territories = super::merged([
+            Some(&*super::TERRITORY_NAMES),
+            game_data::get().map(|data| &data.territory_names),
+        ]);
+        // FATE listings can only be made in territories that have FATEs
+        let fate_territories = match game_data::get() {
+            Some(data) => &data.fate_territories,
+            None => &*super::FATE_TERRITORIES,
+        };
+        for (id, name) in territories {
+            if !fate_territories.contains(&id) {
+                continue;
+            }
+
+            index.add(DutyType::Other, Some(DutyCategory::Fate), id, *name);
+        }
+
+        // deep dungeons, gold saucer content and the hunt
+        for special in special_duties::SPECIAL_DUTIES {
+            // territories and treasure maps are indexed above
+            let fixed = special.duty.is_some() || matches!(special.source, Source::Name(_));
+            if !fixed || special.category == DutyCategory::None {
+                continue;
+            }
+
+            // hunt listings don't have a duty
+            let duty = special.duty.unwrap_or(0);
+            let Some(info) = special.info(duty) else {
+                continue;
+            };
+
+            let duty_type = special.duty_type.unwrap_or(DutyType::Other);
+            index.add(duty_type, Some(special.category), u32::from(duty), info.name);
+        }
+
+        index
+    }
+
+    fn add(&mut self, duty_type: DutyType, category: Option<DutyCategory>, id: u32, name: LocalisedText) {
+        let Ok(id) = u16::try_from(id) else {
+            return;
+        };
+
+        if name.en.is_empty() {
+            return;
+        }
+
+        let key = (duty_type.as_u8(), category.map(|category| category as u32), name.en);
+        if let Some(&i) = self.by_name.get(&key) {
+            self.entries[i].ids.push(id);
+            return;
+        }
+
+        let mut keys: Vec<Key> = Vec::new();
+        for lang in super::Language::ALL {
+            let text = name.get(lang);
+            if text.is_empty() {
+                continue;
+            }
+
+            let key = Key::new(text);
+            if !key.text.is_empty() && keys.iter().all(|other| other.text != key.text) {
+                keys.push(key);
+            }
+        }
+
+        self.by_name.insert(key, self.entries.len());
+        self.entries.push(Entry {
+            duty_type,
+            category,
+            ids: vec![id],
+            name,
+            keys,
+        });
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref INDEX: RwLock<Option<Arc<Index>>> = RwLock::new(None);
+}
+
+fn index() -> Arc<Index> {
+    if let Some(index) = &*INDEX.read().unwrap() {
+        return Arc::clone(index);
+    }
+
+    let index = Arc::new(Index::build());
+    *INDEX.write().unwrap() = Some(Arc::clone(&index));
+    index
+}
+
+/// Drops the index so that it is rebuilt from newly loaded game data.
+pub fn invalidate() {
+    *INDEX.write().unwrap() = None;
+}
+
+/// Longer queries are cut short. Every name is shorter than this, and the
+/// typo matching is quadratic in the query's length.
+pub const MAX_QUERY_CHARS: usize = 64;
+
+/// Results scoring at least this matched a whole name or its acronym.
+pub const EXACT_SCORE: u32 = 95;
+
+/// Finds duties, roulettes, treasure maps, FATE zones and deep dungeons
+/// whose name in any language matches `query`, best matches first.
+///
+/// This scans every name, so it shouldn't run on an async worker for
+/// untrusted input.
+pub fn search(query: &str, limit: usize) -> Vec<SearchResult> {
+    let query = match query.char_indices().nth(MAX_QUERY_CHARS) {
+        Some((end, _)) => &query[..end],
+        None => query,
+    };
+    let query = normalise(query);
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let index = index();
+    let mut results: Vec<(&Entry, u32)> = index
+        .entries
+        .iter()
+        .filter_map(|entry| {
+            let score = entry.keys.iter().filter_map(|key| key.score(&query)).max()?;
+            Some((entry, score))
+        })
+        .collect();
+
+    // prefer actual duties when a FATE zone or roulette has the same score
+    results.sort_by_key(|(entry, score)| {
+        (
+            std::cmp::Reverse(*score),
+            std::cmp::Reverse(entry.duty_type.as_u8()),
+            entry.name.en.len(),
+            entry.ids[0],
+        )
+    });
+
+    results
+        .into_iter()
+        .take(limit)
+        .map(|(entry, score)| SearchResult {
+            duty_type: entry.duty_type,
+            category: entry.category,
+            ids: entry.ids.clone(),
+            name: entry.name,
+            score,
+        })
+        .collect()
+}
+
+/// Folds case, full-width characters and katakana, and turns punctuation
+/// into word breaks, so that names match however they were typed.
+pub fn normalise(text: &str) -> String {
+    let mut normalised = String::with_capacity(text.len());
+    for c in text.chars() {
+        let c = match c {
+            // full-width ascii
+            '\u{ff01}'..='\u{ff5e}' => char::from_u32(c as u32 - 0xfee0).unwrap_or(c),
+            '\u{3000}' => ' ',
+            // katakana to hiragana
+            '\u{30a1}'..='\u{30f6}' => char::from_u32(c as u32 - 0x60).unwrap_or(c),
+            _ => c,
+        };
+
+        if c == '\'' || c == '’' {
+            continue;
+        }
+
+        if c.is_alphanumeric() {
+            normalised.extend(c.to_lowercase());
+        } else if !normalised.is_empty() && !normalised.ends_with(' ') {
+            normalised.push(' ');
+        }
+    }
+
+    if normalised.ends_with(' ') {
+        normalised.pop();
+    }
+
+    normalised
+}
+
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous + usize::from(ca != cb);
+            previous = row[j + 1];
+            row[j + 1] = substitution.min(previous + 1).min(row[j] + 1);
+        }
+    }
+
+    row[b.len()]
+}
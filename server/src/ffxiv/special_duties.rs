@@ -0,0 +1,227 @@
+//! Party finder duty ids that don't map directly onto a row of
+//! `ContentFinderCondition` or `ContentRoulette`.
+
+use super::duties::{ContentKind, DutyInfo};
+use super::{LocalisedText, PartySize};
+use crate::listing::{DutyCategory, DutyType};
+
+pub enum Source {
+    /// A fixed name, for content without a row of its own.
+    Name(LocalisedText),
+    /// A row of `ContentFinderCondition`.
+    Duty(u32),
+    /// A row of `ContentRoulette`.
+    Roulette(u32),
+    /// The territory given by the listing's duty id.
+    Territory,
+    /// The treasure map given by the listing's duty id.
+    TreasureMap,
+}
+
+pub struct SpecialDuty {
+    /// `None` matches any duty type.
+    pub duty_type: Option<DutyType>,
+    pub category: DutyCategory,
+    /// `None` matches any duty id.
+    pub duty: Option<u16>,
+    pub source: Source,
+    pub content_kind: ContentKind,
+    pub high_end: bool,
+}
+
+impl SpecialDuty {
+    pub fn matches(&self, duty_type: DutyType, category: DutyCategory, duty: u16) -> bool {
+        self.duty_type.is_none_or(|ty| ty == duty_type)
+            && self.category == category
+            && self.duty.is_none_or(|id| id == duty)
+    }
+
+    /// The duty info for the listing duty id `duty`.
+    pub fn info(&self, duty: u16) -> Option<DutyInfo> {
+        let name = match self.source {
+            Source::Name(name) => name,
+            Source::Duty(row) => {
+                // the row's kind is how the party finder files it, not the duty's own
+                return super::duty(row).map(|info| DutyInfo {
+                    content_kind: self.content_kind,
+                    high_end: self.high_end,
+                    ..*info
+                });
+            }
+            Source::Roulette(row) => super::roulette(row)?.name,
+            Source::Territory => super::territory_name(u32::from(duty))
+                .copied()
+                .unwrap_or(FATES),
+            Source::TreasureMap => *super::treasure_map(u32::from(duty))?,
+        };
+
+        Some(DutyInfo {
+            name,
+            high_end: self.high_end,
+            content_kind: self.content_kind,
+            level: None,
+            item_level: None,
+            party_size: PartySize::Unknown,
+            expansion: None,
+            icon: None,
+            image: None,
+        })
+    }
+}
+
+/// The special duty for a listing, checked in order.
+pub fn find(duty_type: DutyType, category: DutyCategory, duty: u16) -> Option<&'static SpecialDuty> {
+    SPECIAL_DUTIES
+        .iter()
+        .find(|special| special.matches(duty_type, category, duty))
+}
+
+const fn other(category: DutyCategory, duty: u16, source: Source, content_kind: ContentKind) -> SpecialDuty {
+    SpecialDuty {
+        duty_type: Some(DutyType::Other),
+        category,
+        duty: Some(duty),
+        source,
+        content_kind,
+        high_end: false,
+    }
+}
+
+// gold saucer duties appear to be all over the place
+const fn gold_saucer(duty: u16, source: Source, content_kind: ContentKind) -> SpecialDuty {
+    SpecialDuty {
+        duty_type: None,
+        category: DutyCategory::GoldSaucer,
+        duty: Some(duty),
+        source,
+        content_kind,
+        high_end: false,
+    }
+}
+
+pub const SPECIAL_DUTIES: &[SpecialDuty] = &[
+    SpecialDuty {
+        duty_type: Some(DutyType::Other),
+        category: DutyCategory::Fate,
+        duty: None,
+        source: Source::Territory,
+        content_kind: ContentKind::FATEs,
+        high_end: false,
+    },
+    SpecialDuty {
+        duty_type: Some(DutyType::Other),
+        category: DutyCategory::TheHunt,
+        duty: None,
+        source: Source::Name(THE_HUNT),
+        content_kind: ContentKind::TheHunt,
+        high_end: false,
+    },
+    SpecialDuty {
+        duty_type: None,
+        category: DutyCategory::None,
+        duty: Some(0),
+        source: Source::Name(NONE),
+        content_kind: ContentKind::Other(0),
+        high_end: false,
+    },
+    other(DutyCategory::DeepDungeon, 1, Source::Name(PALACE_OF_THE_DEAD), ContentKind::DeepDungeons),
+    other(DutyCategory::DeepDungeon, 2, Source::Name(HEAVEN_ON_HIGH), ContentKind::DeepDungeons),
+    other(DutyCategory::DeepDungeon, 3, Source::Name(EUREKA_ORTHOS), ContentKind::DeepDungeons),
+    // Addon 2308
+    gold_saucer(11, Source::Name(GATES), ContentKind::GATE),
+    // chocobo races. in the sheet, the order is sagolii, del sol, tranquil,
+    // random. in PF, random comes first
+    gold_saucer(12, Source::Roulette(21), ContentKind::GoldSaucer),
+    gold_saucer(13, Source::Roulette(18), ContentKind::GoldSaucer),
+    gold_saucer(14, Source::Roulette(19), ContentKind::GoldSaucer),
+    gold_saucer(15, Source::Roulette(20), ContentKind::GoldSaucer),
+    gold_saucer(16, Source::Roulette(25), ContentKind::GoldSaucer),
+    gold_saucer(17, Source::Roulette(22), ContentKind::GoldSaucer),
+    gold_saucer(18, Source::Roulette(23), ContentKind::GoldSaucer),
+    gold_saucer(19, Source::Roulette(24), ContentKind::GoldSaucer),
+    gold_saucer(20, Source::Duty(195), ContentKind::GoldSaucer),
+    gold_saucer(21, Source::Duty(756), ContentKind::GoldSaucer),
+    gold_saucer(22, Source::Duty(199), ContentKind::GoldSaucer),
+    gold_saucer(23, Source::Duty(645), ContentKind::GoldSaucer),
+    gold_saucer(24, Source::Duty(650), ContentKind::GoldSaucer),
+    gold_saucer(25, Source::Duty(768), ContentKind::GoldSaucer),
+    gold_saucer(26, Source::Duty(769), ContentKind::GoldSaucer),
+    SpecialDuty {
+        duty_type: None,
+        category: DutyCategory::TreasureHunt,
+        duty: None,
+        source: Source::TreasureMap,
+        content_kind: ContentKind::TreasureHunt,
+        high_end: false,
+    },
+];
+
+const FATES: LocalisedText = LocalisedText {
+    en: "FATEs",
+    ja: "F.A.T.E.",
+    de: "FATEs",
+    fr: "ALÉA",
+    ko: "돌발 임무",
+    zh_hans: "危命任务",
+    zh_hant: "危命任務",
+};
+
+const THE_HUNT: LocalisedText = LocalisedText {
+    en: "The Hunt",
+    ja: "モブハント",
+    de: "Hohe Jagd",
+    fr: "Contrats de chasse",
+    ko: "마물 사냥",
+    zh_hans: "怪物狩猎",
+    zh_hant: "怪物狩獵",
+};
+
+const NONE: LocalisedText = LocalisedText {
+    en: "None",
+    ja: "設定なし",
+    de: "Nicht festgelegt",
+    fr: "Non spécifiée",
+    ko: "설정 안 함",
+    zh_hans: "不限",
+    zh_hant: "不限",
+};
+
+const PALACE_OF_THE_DEAD: LocalisedText = LocalisedText {
+    en: "The Palace of the Dead",
+    ja: "死者の宮殿",
+    de: "Palast der Toten",
+    fr: "Palais des morts",
+    ko: "망자의 궁전",
+    zh_hans: "死者宫殿",
+    zh_hant: "死者宮殿",
+};
+
+const HEAVEN_ON_HIGH: LocalisedText = LocalisedText {
+    en: "Heaven-on-High",
+    ja: "アメノミハシラ",
+    de: "Himmelssäule",
+    fr: "Pilier des Cieux",
+    ko: "천궁탑",
+    zh_hans: "天之御柱",
+    zh_hant: "天之御柱",
+};
+
+const EUREKA_ORTHOS: LocalisedText = LocalisedText {
+    en: "Eureka Orthos",
+    ja: "オルト・エウレカ",
+    de: "Eureka Orthos",
+    fr: "Eurêka Orthos",
+    ko: "오르토 에우레카",
+    zh_hans: "正统优雷卡",
+    zh_hant: "正統優雷卡",
+};
+
+const GATES: LocalisedText = LocalisedText {
+    en: "GATEs",
+    ja: "G.A.T.E.",
+    de: "GATEs",
+    fr: "JACTA",
+    ko: "G.A.T.E.",
+    zh_hans: "G.A.T.E.",
+    zh_hant: "G.A.T.E.",
+};
@@ -1,6 +1,10 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use super::LocalisedText;
 
+#[cfg(generated_tables)]
+include!(concat!(env!("OUT_DIR"), "/territory_names.rs"));
+
+#[cfg(not(generated_tables))]
 lazy_static::lazy_static! {
     pub static ref TERRITORY_NAMES: HashMap<u32, LocalisedText> = maplit::hashmap! {
         128 => LocalisedText {
@@ -8,6582 +12,7739 @@ lazy_static::lazy_static! {
             ja: "リムサ・ロミンサ：上甲板層",
             de: "Obere Decks",
             fr: "Limsa Lominsa - Le Tillac",
+            ..Default::default()
         },
         129 => LocalisedText {
             en: "Limsa Lominsa Lower Decks",
             ja: "リムサ・ロミンサ：下甲板層",
             de: "Untere Decks",
             fr: "Limsa Lominsa - L'Entrepont",
+            ..Default::default()
         },
         130 => LocalisedText {
             en: "Ul'dah - Steps of Nald",
             ja: "ウルダハ：ナル回廊",
             de: "Nald-Kreuzgang",
             fr: "Ul'dah - Faubourg de Nald",
+            ..Default::default()
         },
         131 => LocalisedText {
             en: "Ul'dah - Steps of Thal",
             ja: "ウルダハ：ザル回廊",
             de: "Thal-Kreuzgang",
             fr: "Ul'dah - Faubourg de Thal",
+            ..Default::default()
         },
         132 => LocalisedText {
             en: "New Gridania",
             ja: "グリダニア：新市街",
             de: "Neu-Gridania",
             fr: "Nouvelle Gridania",
+            ..Default::default()
         },
         133 => LocalisedText {
             en: "Old Gridania",
             ja: "グリダニア：旧市街",
             de: "Alt-Gridania",
             fr: "Vieille Gridania",
+            ..Default::default()
         },
         134 => LocalisedText {
             en: "Middle La Noscea",
             ja: "中央ラノシア",
             de: "Zentrales La Noscea",
             fr: "Noscea centrale",
+            ..Default::default()
         },
         135 => LocalisedText {
             en: "Lower La Noscea",
             ja: "低地ラノシア",
             de: "Unteres La Noscea",
             fr: "Basse-Noscea",
+            ..Default::default()
         },
         136 => LocalisedText {
             en: "Mist",
             ja: "ミスト・ヴィレッジ",
             de: "Dorf des Nebels",
             fr: "Brumée",
+            ..Default::default()
         },
         137 => LocalisedText {
             en: "Eastern La Noscea",
             ja: "東ラノシア",
             de: "Östliches La Noscea",
             fr: "Noscea orientale",
+            ..Default::default()
         },
         138 => LocalisedText {
             en: "Western La Noscea",
             ja: "西ラノシア",
             de: "Westliches La Noscea",
             fr: "Noscea occidentale",
+            ..Default::default()
         },
         139 => LocalisedText {
             en: "Upper La Noscea",
             ja: "高地ラノシア",
             de: "Oberes La Noscea",
             fr: "Haute-Noscea",
+            ..Default::default()
         },
         140 => LocalisedText {
             en: "Western Thanalan",
             ja: "西ザナラーン",
             de: "Westliches Thanalan",
             fr: "Thanalan occidental",
+            ..Default::default()
         },
         141 => LocalisedText {
             en: "Central Thanalan",
             ja: "中央ザナラーン",
             de: "Zentrales Thanalan",
             fr: "Thanalan central",
+            ..Default::default()
         },
         142 => LocalisedText {
             en: "Halatali",
             ja: "ハラタリ修練所",
             de: "Halatali",
             fr: "Halatali",
+            ..Default::default()
         },
         144 => LocalisedText {
             en: "The Gold Saucer",
             ja: "ゴールドソーサー",
             de: "Gold Saucer",
             fr: "Gold Saucer",
+            ..Default::default()
         },
         145 => LocalisedText {
             en: "Eastern Thanalan",
             ja: "東ザナラーン",
             de: "Östliches Thanalan",
             fr: "Thanalan oriental",
+            ..Default::default()
         },
         146 => LocalisedText {
             en: "Southern Thanalan",
             ja: "南ザナラーン",
             de: "Südliches Thanalan",
             fr: "Thanalan méridional",
+            ..Default::default()
         },
         147 => LocalisedText {
             en: "Northern Thanalan",
             ja: "北ザナラーン",
             de: "Nördliches Thanalan",
             fr: "Thanalan septentrional",
+            ..Default::default()
         },
         148 => LocalisedText {
             en: "Central Shroud",
             ja: "黒衣森：中央森林",
             de: "Tiefer Wald",
             fr: "Forêt centrale",
+            ..Default::default()
         },
         149 => LocalisedText {
             en: "The Feasting Grounds",
             ja: "ウルヴズジェイル演習場",
             de: "Kampfplatz",
             fr: "Festin des loups",
+            ..Default::default()
         },
         151 => LocalisedText {
             en: "The World of Darkness",
             ja: "闇の世界",
             de: "Welt der Dunkelheit",
             fr: "Monde des Ténèbres",
+            ..Default::default()
         },
         152 => LocalisedText {
             en: "East Shroud",
             ja: "黒衣森：東部森林",
             de: "Ostwald",
             fr: "Forêt de l'est",
+            ..Default::default()
         },
         153 => LocalisedText {
             en: "South Shroud",
             ja: "黒衣森：南部森林",
             de: "Südwald",
             fr: "Forêt du sud",
+            ..Default::default()
         },
         154 => LocalisedText {
             en: "North Shroud",
             ja: "黒衣森：北部森林",
             de: "Nordwald",
             fr: "Forêt du nord",
+            ..Default::default()
         },
         155 => LocalisedText {
             en: "Coerthas Central Highlands",
             ja: "クルザス中央高地",
             de: "Zentrales Hochland von Coerthas",
             fr: "Hautes terres du Coerthas central",
+            ..Default::default()
         },
         156 => LocalisedText {
             en: "Mor Dhona",
             ja: "モードゥナ",
             de: "Mor Dhona",
             fr: "Mor Dhona",
+            ..Default::default()
         },
         159 => LocalisedText {
             en: "The Wanderer's Palace",
             ja: "ワンダラーパレス",
             de: "Palast des Wanderers",
             fr: "Palais du Vagabond",
+            ..Default::default()
         },
         160 => LocalisedText {
             en: "Pharos Sirius",
             ja: "シリウス大灯台",
             de: "Pharos Sirius",
             fr: "Phare de Sirius",
+            ..Default::default()
         },
         167 => LocalisedText {
             en: "Amdapor Keep",
             ja: "古城アムダプール",
             de: "Ruinen von Amdapor",
             fr: "Château d'Amdapor",
+            ..Default::default()
         },
         171 => LocalisedText {
             en: "Dzemael Darkhold",
             ja: "ゼーメル要塞",
             de: "Feste Dzemael",
             fr: "Forteresse de Dzemael",
+            ..Default::default()
         },
         172 => LocalisedText {
             en: "Aurum Vale",
             ja: "オーラムヴェイル",
             de: "Goldklamm",
             fr: "Val d'Aurum",
+            ..Default::default()
         },
         174 => LocalisedText {
             en: "Labyrinth of the Ancients",
             ja: "古代の民の迷宮",
             de: "Labyrinth der Alten",
             fr: "Dédale antique",
+            ..Default::default()
         },
         176 => LocalisedText {
             en: "Mordion Gaol",
             ja: "モルディオン監獄",
             de: "Mordion-Kerker",
             fr: "Geôle de Mordion",
+            ..Default::default()
         },
         177 => LocalisedText {
             en: "Mizzenmast Inn",
             ja: "宿屋「ミズンマスト」",
             de: "Gasthaus Gaffelschoner",
             fr: "Auberge de L'Artimon",
+            ..Default::default()
         },
         178 => LocalisedText {
             en: "The Hourglass",
             ja: "宿屋「砂時計亭」",
             de: "Die Sanduhr",
             fr: "Le Sablier",
+            ..Default::default()
         },
         179 => LocalisedText {
             en: "The Roost",
             ja: "旅館「とまり木」",
             de: "Der Traumbaum",
             fr: "Le Perchoir",
+            ..Default::default()
         },
         180 => LocalisedText {
             en: "Outer La Noscea",
             ja: "外地ラノシア",
             de: "Äußeres La Noscea",
             fr: "Noscea extérieure",
+            ..Default::default()
         },
         181 => LocalisedText {
             en: "Limsa Lominsa",
             ja: "リムサ・ロミンサ",
             de: "Limsa Lominsa",
             fr: "Limsa Lominsa",
+            ..Default::default()
         },
         182 => LocalisedText {
             en: "Ul'dah - Steps of Nald",
             ja: "ウルダハ：ナル回廊",
             de: "Nald-Kreuzgang",
             fr: "Ul'dah - Faubourg de Nald",
+            ..Default::default()
         },
         183 => LocalisedText {
             en: "New Gridania",
             ja: "グリダニア：新市街",
             de: "Neu-Gridania",
             fr: "Nouvelle Gridania",
+            ..Default::default()
         },
         188 => LocalisedText {
             en: "The Wanderer's Palace",
             ja: "ワンダラーパレス",
             de: "Palast des Wanderers",
             fr: "Palais du Vagabond",
+            ..Default::default()
         },
         189 => LocalisedText {
             en: "Amdapor Keep",
             ja: "古城アムダプール",
             de: "Ruinen von Amdapor",
             fr: "Château d'Amdapor",
+            ..Default::default()
         },
         190 => LocalisedText {
             en: "Central Shroud",
             ja: "黒衣森：中央森林",
             de: "Tiefer Wald",
             fr: "Forêt centrale",
+            ..Default::default()
         },
         191 => LocalisedText {
             en: "East Shroud",
             ja: "黒衣森：東部森林",
             de: "Ostwald",
             fr: "Forêt de l'est",
+            ..Default::default()
         },
         192 => LocalisedText {
             en: "South Shroud",
             ja: "黒衣森：南部森林",
             de: "Südwald",
             fr: "Forêt du sud",
+            ..Default::default()
         },
         193 => LocalisedText {
             en: "IC-06 Central Decks",
             ja: "ラグナロク級六番艦：艦体中央部",
             de: "Zentrale Decks RVH-06",
             fr: "Secteur central RCI-06",
+            ..Default::default()
         },
         194 => LocalisedText {
             en: "IC-06 Regeneration Grid",
             ja: "ラグナロク級六番艦：再生制御区",
             de: "Regenerationssektor RVH-06",
             fr: "Réseau de régénération RCI-06",
+            ..Default::default()
         },
         195 => LocalisedText {
             en: "IC-06 Main Bridge",
             ja: "ラグナロク級六番艦：第一艦橋",
             de: "Kommandobrücke RVH-06",
             fr: "Pont principal RCI-06",
+            ..Default::default()
         },
         196 => LocalisedText {
             en: "The Burning Heart",
             ja: "バハムートコア",
             de: "Bahamuts Herz",
             fr: "Cœur de Bahamut",
+            ..Default::default()
         },
         198 => LocalisedText {
             en: "Command Room",
             ja: "アドミラルブリッジ：提督室",
             de: "Admiralsbrücke",
             fr: "Salon de l'Amiral",
+            ..Default::default()
         },
         204 => LocalisedText {
             en: "Seat of the First Bow",
             ja: "神勇隊司令室",
             de: "Kommandozimmer von Nophicas Schar ",
             fr: "Salle de commandement du Carquois",
+            ..Default::default()
         },
         205 => LocalisedText {
             en: "Lotus Stand",
             ja: "不語仙の座卓",
             de: "Wasserrosentisch",
             fr: "Chaire du Lotus",
+            ..Default::default()
         },
         210 => LocalisedText {
             en: "Heart of the Sworn",
             ja: "銀冑団総長室",
             de: "Hauptquartier der Palastwache",
             fr: "Hall d'argent",
+            ..Default::default()
         },
         212 => LocalisedText {
             en: "The Waking Sands",
             ja: "砂の家",
             de: "Sonnenwind",
             fr: "Refuge des sables",
+            ..Default::default()
         },
         214 => LocalisedText {
             en: "Middle La Noscea",
             ja: "中央ラノシア",
             de: "Zentrales La Noscea",
             fr: "Noscea centrale",
+            ..Default::default()
         },
         215 => LocalisedText {
             en: "Western Thanalan",
             ja: "西ザナラーン",
             de: "Westliches Thanalan",
             fr: "Thanalan occidental",
+            ..Default::default()
         },
         216 => LocalisedText {
             en: "Central Thanalan",
             ja: "中央ザナラーン",
             de: "Zentrales Thanalan",
             fr: "Thanalan central",
+            ..Default::default()
         },
         219 => LocalisedText {
             en: "Central Shroud",
             ja: "黒衣森：中央森林",
             de: "Tiefer Wald",
             fr: "Forêt centrale",
+            ..Default::default()
         },
         220 => LocalisedText {
             en: "South Shroud",
             ja: "黒衣森：南部森林",
             de: "Südwald",
             fr: "Forêt du sud",
+            ..Default::default()
         },
         221 => LocalisedText {
             en: "Upper La Noscea",
             ja: "高地ラノシア",
             de: "Oberes La Noscea",
             fr: "Haute-Noscea",
+            ..Default::default()
         },
         222 => LocalisedText {
             en: "Lower La Noscea",
             ja: "低地ラノシア",
             de: "Unteres La Noscea",
             fr: "Basse-Noscea",
+            ..Default::default()
         },
         223 => LocalisedText {
             en: "Coerthas Central Highlands",
             ja: "クルザス中央高地",
             de: "Zentrales Hochland von Coerthas",
             fr: "Hautes terres du Coerthas central",
+            ..Default::default()
         },
         225 => LocalisedText {
             en: "Central Shroud",
             ja: "黒衣森：中央森林",
             de: "Tiefer Wald",
             fr: "Forêt centrale",
+            ..Default::default()
         },
         226 => LocalisedText {
             en: "Central Shroud",
             ja: "黒衣森：中央森林",
             de: "Tiefer Wald",
             fr: "Forêt centrale",
+            ..Default::default()
         },
         227 => LocalisedText {
             en: "Central Shroud",
             ja: "黒衣森：中央森林",
             de: "Tiefer Wald",
             fr: "Forêt centrale",
+            ..Default::default()
         },
         228 => LocalisedText {
             en: "North Shroud",
             ja: "黒衣森：北部森林",
             de: "Nordwald",
             fr: "Forêt du nord",
+            ..Default::default()
         },
         229 => LocalisedText {
             en: "South Shroud",
             ja: "黒衣森：南部森林",
             de: "Südwald",
             fr: "Forêt du sud",
+            ..Default::default()
         },
         230 => LocalisedText {
             en: "Central Shroud",
             ja: "黒衣森：中央森林",
             de: "Tiefer Wald",
             fr: "Forêt centrale",
+            ..Default::default()
         },
         231 => LocalisedText {
             en: "South Shroud",
             ja: "黒衣森：南部森林",
             de: "Südwald",
             fr: "Forêt du sud",
+            ..Default::default()
         },
         232 => LocalisedText {
             en: "South Shroud",
             ja: "黒衣森：南部森林",
             de: "Südwald",
             fr: "Forêt du sud",
+            ..Default::default()
         },
         233 => LocalisedText {
             en: "Central Shroud",
             ja: "黒衣森：中央森林",
             de: "Tiefer Wald",
             fr: "Forêt centrale",
+            ..Default::default()
         },
         234 => LocalisedText {
             en: "East Shroud",
             ja: "黒衣森：東部森林",
             de: "Ostwald",
             fr: "Forêt de l'est",
+            ..Default::default()
         },
         235 => LocalisedText {
             en: "South Shroud",
             ja: "黒衣森：南部森林",
             de: "Südwald",
             fr: "Forêt du sud",
+            ..Default::default()
         },
         236 => LocalisedText {
             en: "South Shroud",
             ja: "黒衣森：南部森林",
             de: "Südwald",
             fr: "Forêt du sud",
+            ..Default::default()
         },
         237 => LocalisedText {
             en: "Central Shroud",
             ja: "黒衣森：中央森林",
             de: "Tiefer Wald",
             fr: "Forêt centrale",
+            ..Default::default()
         },
         238 => LocalisedText {
             en: "Old Gridania",
             ja: "グリダニア：旧市街",
             de: "Alt-Gridania",
             fr: "Vieille Gridania",
+            ..Default::default()
         },
         239 => LocalisedText {
             en: "Central Shroud",
             ja: "黒衣森：中央森林",
             de: "Tiefer Wald",
             fr: "Forêt centrale",
+            ..Default::default()
         },
         240 => LocalisedText {
             en: "North Shroud",
             ja: "黒衣森：北部森林",
             de: "Nordwald",
             fr: "Forêt du nord",
+            ..Default::default()
         },
         241 => LocalisedText {
             en: "Upper Aetheroacoustic Exploratory Site",
             ja: "メテオ探査坑浅部",
             de: "Obere ätheroakustische Grabung",
             fr: "Site impérial d'exploration supérieur",
+            ..Default::default()
         },
         242 => LocalisedText {
             en: "Lower Aetheroacoustic Exploratory Site",
             ja: "メテオ探査坑深部",
             de: "Untere ätheroakustische Grabung",
             fr: "Site impérial d'exploration inférieur",
+            ..Default::default()
         },
         243 => LocalisedText {
             en: "The Ragnarok",
             ja: "ラグナロク級拘束艦",
             de: "Die Ragnarök",
             fr: "Le Ragnarok",
+            ..Default::default()
         },
         244 => LocalisedText {
             en: "Ragnarok Drive Cylinder",
             ja: "稼働隔壁",
             de: "Antriebszylinder der Ragnarök",
             fr: "Cylindre propulseur du Ragnarok",
+            ..Default::default()
         },
         245 => LocalisedText {
             en: "Ragnarok Central Core",
             ja: "中枢区画",
             de: "Kernsektor der Ragnarök",
             fr: "Noyau central du Ragnarok",
+            ..Default::default()
         },
         246 => LocalisedText {
             en: "IC-04 Main Bridge",
             ja: "ラグナロク級四番艦：第一艦橋",
             de: "Kommandobrücke RVH-04",
             fr: "Pont principal RCI-04",
+            ..Default::default()
         },
         247 => LocalisedText {
             en: "Ragnarok Main Bridge",
             ja: "第一艦橋",
             de: "Kommandobrücke der Ragnarök",
             fr: "Pont principal du Ragnarok",
+            ..Default::default()
         },
         248 => LocalisedText {
             en: "Central Thanalan",
             ja: "中央ザナラーン",
             de: "Zentrales Thanalan",
             fr: "Thanalan central",
+            ..Default::default()
         },
         249 => LocalisedText {
             en: "Lower La Noscea",
             ja: "低地ラノシア",
             de: "Unteres La Noscea",
             fr: "Basse-Noscea",
+            ..Default::default()
         },
         250 => LocalisedText {
             en: "Wolves' Den Pier",
             ja: "ウルヴズジェイル係船場",
             de: "Wolfshöhlen-Pier",
             fr: "Jetée de l'Antre des loups",
+            ..Default::default()
         },
         251 => LocalisedText {
             en: "Ul'dah - Steps of Nald",
             ja: "ウルダハ：ナル回廊",
             de: "Nald-Kreuzgang",
             fr: "Ul'dah - Faubourg de Nald",
+            ..Default::default()
         },
         252 => LocalisedText {
             en: "Middle La Noscea",
             ja: "中央ラノシア",
             de: "Zentrales La Noscea",
             fr: "Noscea centrale",
+            ..Default::default()
         },
         253 => LocalisedText {
             en: "Central Thanalan",
             ja: "中央ザナラーン",
             de: "Zentrales Thanalan",
             fr: "Thanalan central",
+            ..Default::default()
         },
         254 => LocalisedText {
             en: "Ul'dah - Steps of Nald",
             ja: "ウルダハ：ナル回廊",
             de: "Nald-Kreuzgang",
             fr: "Ul'dah - Faubourg de Nald",
+            ..Default::default()
         },
         255 => LocalisedText {
             en: "Western Thanalan",
             ja: "西ザナラーン",
             de: "Westliches Thanalan",
             fr: "Thanalan occidental",
+            ..Default::default()
         },
         256 => LocalisedText {
             en: "Eastern Thanalan",
             ja: "東ザナラーン",
             de: "Östliches Thanalan",
             fr: "Thanalan oriental",
+            ..Default::default()
         },
         257 => LocalisedText {
             en: "Eastern Thanalan",
             ja: "東ザナラーン",
             de: "Östliches Thanalan",
             fr: "Thanalan oriental",
+            ..Default::default()
         },
         258 => LocalisedText {
             en: "Central Thanalan",
             ja: "中央ザナラーン",
             de: "Zentrales Thanalan",
             fr: "Thanalan central",
+            ..Default::default()
         },
         259 => LocalisedText {
             en: "Ul'dah - Steps of Nald",
             ja: "ウルダハ：ナル回廊",
             de: "Nald-Kreuzgang",
             fr: "Ul'dah - Faubourg de Nald",
+            ..Default::default()
         },
         260 => LocalisedText {
             en: "Southern Thanalan",
             ja: "南ザナラーン",
             de: "Südliches Thanalan",
             fr: "Thanalan méridional",
+            ..Default::default()
         },
         261 => LocalisedText {
             en: "Southern Thanalan",
             ja: "南ザナラーン",
             de: "Südliches Thanalan",
             fr: "Thanalan méridional",
+            ..Default::default()
         },
         262 => LocalisedText {
             en: "Lower La Noscea",
             ja: "低地ラノシア",
             de: "Unteres La Noscea",
             fr: "Basse-Noscea",
+            ..Default::default()
         },
         263 => LocalisedText {
             en: "Western La Noscea",
             ja: "西ラノシア",
             de: "Westliches La Noscea",
             fr: "Noscea occidentale",
+            ..Default::default()
         },
         264 => LocalisedText {
             en: "Lower La Noscea",
             ja: "低地ラノシア",
             de: "Unteres La Noscea",
             fr: "Basse-Noscea",
+            ..Default::default()
         },
         265 => LocalisedText {
             en: "Lower La Noscea",
             ja: "低地ラノシア",
             de: "Unteres La Noscea",
             fr: "Basse-Noscea",
+            ..Default::default()
         },
         266 => LocalisedText {
             en: "Eastern Thanalan",
             ja: "東ザナラーン",
             de: "Östliches Thanalan",
             fr: "Thanalan oriental",
+            ..Default::default()
         },
         267 => LocalisedText {
             en: "Western Thanalan",
             ja: "西ザナラーン",
             de: "Westliches Thanalan",
             fr: "Thanalan occidental",
+            ..Default::default()
         },
         268 => LocalisedText {
             en: "Eastern Thanalan",
             ja: "東ザナラーン",
             de: "Östliches Thanalan",
             fr: "Thanalan oriental",
+            ..Default::default()
         },
         269 => LocalisedText {
             en: "Western Thanalan",
             ja: "西ザナラーン",
             de: "Westliches Thanalan",
             fr: "Thanalan occidental",
+            ..Default::default()
         },
         270 => LocalisedText {
             en: "Central Thanalan",
             ja: "中央ザナラーン",
             de: "Zentrales Thanalan",
             fr: "Thanalan central",
+            ..Default::default()
         },
         271 => LocalisedText {
             en: "Central Thanalan",
             ja: "中央ザナラーン",
             de: "Zentrales Thanalan",
             fr: "Thanalan central",
+            ..Default::default()
         },
         272 => LocalisedText {
             en: "Middle La Noscea",
             ja: "中央ラノシア",
             de: "Zentrales La Noscea",
             fr: "Noscea centrale",
+            ..Default::default()
         },
         273 => LocalisedText {
             en: "Western Thanalan",
             ja: "西ザナラーン",
             de: "Westliches Thanalan",
             fr: "Thanalan occidental",
+            ..Default::default()
         },
         274 => LocalisedText {
             en: "Ul'dah - Steps of Nald",
             ja: "ウルダハ：ナル回廊",
             de: "Nald-Kreuzgang",
             fr: "Ul'dah - Faubourg de Nald",
+            ..Default::default()
         },
         275 => LocalisedText {
             en: "Eastern Thanalan",
             ja: "東ザナラーン",
             de: "Östliches Thanalan",
             fr: "Thanalan oriental",
+            ..Default::default()
         },
         276 => LocalisedText {
             en: "Hall of Summoning",
             ja: "次元の狭間：迎賓室",
             de: "Halle der Beschwörung",
             fr: "Salle des convocations",
+            ..Default::default()
         },
         277 => LocalisedText {
             en: "East Shroud",
             ja: "黒衣森：東部森林",
             de: "Ostwald",
             fr: "Forêt de l'est",
+            ..Default::default()
         },
         278 => LocalisedText {
             en: "Western Thanalan",
             ja: "西ザナラーン",
             de: "Westliches Thanalan",
             fr: "Thanalan occidental",
+            ..Default::default()
         },
         279 => LocalisedText {
             en: "Lower La Noscea",
             ja: "低地ラノシア",
             de: "Unteres La Noscea",
             fr: "Basse-Noscea",
+            ..Default::default()
         },
         280 => LocalisedText {
             en: "Western La Noscea",
             ja: "西ラノシア",
             de: "Westliches La Noscea",
             fr: "Noscea occidentale",
+            ..Default::default()
         },
         281 => LocalisedText {
             en: "The Whorleater",
             ja: "対リヴァイアサン双胴船",
             de: "Der Wogenbrecher",
             fr: "Briseur de marées",
+            ..Default::default()
         },
         282 => LocalisedText {
             en: "Private Cottage - Mist",
             ja: "ミスト・ヴィレッジ：コテージ",
             de: "Privathütte (Dorf des Nebels)",
             fr: "Maisonnette - Brumée",
+            ..Default::default()
         },
         283 => LocalisedText {
             en: "Private House - Mist",
             ja: "ミスト・ヴィレッジ：ハウス",
             de: "Privathaus (Dorf des Nebels)",
             fr: "Pavillon - Brumée",
+            ..Default::default()
         },
         284 => LocalisedText {
             en: "Private Mansion - Mist",
             ja: "ミスト・ヴィレッジ：レジデンス",
             de: "Privatresidenz (Dorf des Nebels)",
             fr: "Villa - Brumée",
+            ..Default::default()
         },
         285 => LocalisedText {
             en: "Middle La Noscea",
             ja: "中央ラノシア",
             de: "Zentrales La Noscea",
             fr: "Noscea centrale",
+            ..Default::default()
         },
         286 => LocalisedText {
             en: "Rhotano Sea",
             ja: "ロータノ海",
             de: "Rhotano-See",
             fr: "Mer de Rhotano",
+            ..Default::default()
         },
         287 => LocalisedText {
             en: "Lower La Noscea",
             ja: "低地ラノシア",
             de: "Unteres La Noscea",
             fr: "Basse-Noscea",
+            ..Default::default()
         },
         288 => LocalisedText {
             en: "Rhotano Sea",
             ja: "ロータノ海",
             de: "Rhotano-See",
             fr: "Mer de Rhotano",
+            ..Default::default()
         },
         289 => LocalisedText {
             en: "East Shroud",
             ja: "黒衣森：東部森林",
             de: "Ostwald",
             fr: "Forêt de l'est",
+            ..Default::default()
         },
         290 => LocalisedText {
             en: "East Shroud",
             ja: "黒衣森：東部森林",
             de: "Ostwald",
             fr: "Forêt de l'est",
+            ..Default::default()
         },
         291 => LocalisedText {
             en: "South Shroud",
             ja: "黒衣森：南部森林",
             de: "Südwald",
             fr: "Forêt du sud",
+            ..Default::default()
         },
         292 => LocalisedText {
             en: "Bowl of Embers",
             ja: "炎帝祭跡",
             de: "Das Grab der Lohe",
             fr: "Cratère des tisons",
+            ..Default::default()
         },
         293 => LocalisedText {
             en: "The Navel",
             ja: "オ・ゴモロ火口神殿",
             de: "Der Nabel",
             fr: "Le Nombril",
+            ..Default::default()
         },
         294 => LocalisedText {
             en: "The Howling Eye",
             ja: "ハウリングアイ石塔群",
             de: "Das Tosende Auge",
             fr: "Hurlœil",
+            ..Default::default()
         },
         295 => LocalisedText {
             en: "Bowl of Embers",
             ja: "炎帝祭跡",
             de: "Das Grab der Lohe",
             fr: "Cratère des tisons",
+            ..Default::default()
         },
         296 => LocalisedText {
             en: "The Navel",
             ja: "オ・ゴモロ火口神殿",
             de: "Der Nabel",
             fr: "Le Nombril",
+            ..Default::default()
         },
         297 => LocalisedText {
             en: "The Howling Eye",
             ja: "ハウリングアイ石塔群",
             de: "Das Tosende Auge",
             fr: "Hurlœil",
+            ..Default::default()
         },
         298 => LocalisedText {
             en: "Coerthas Central Highlands",
             ja: "クルザス中央高地",
             de: "Zentrales Hochland von Coerthas",
             fr: "Hautes terres du Coerthas central",
+            ..Default::default()
         },
         299 => LocalisedText {
             en: "Mor Dhona",
             ja: "モードゥナ",
             de: "Mor Dhona",
             fr: "Mor Dhona",
+            ..Default::default()
         },
         300 => LocalisedText {
             en: "Mor Dhona",
             ja: "モードゥナ",
             de: "Mor Dhona",
             fr: "Mor Dhona",
+            ..Default::default()
         },
         301 => LocalisedText {
             en: "Coerthas Central Highlands",
             ja: "クルザス中央高地",
             de: "Zentrales Hochland von Coerthas",
             fr: "Hautes terres du Coerthas central",
+            ..Default::default()
         },
         302 => LocalisedText {
             en: "Coerthas Central Highlands",
             ja: "クルザス中央高地",
             de: "Zentrales Hochland von Coerthas",
             fr: "Hautes terres du Coerthas central",
+            ..Default::default()
         },
         303 => LocalisedText {
             en: "East Shroud",
             ja: "黒衣森：東部森林",
             de: "Ostwald",
             fr: "Forêt de l'est",
+            ..Default::default()
         },
         304 => LocalisedText {
             en: "Coerthas Central Highlands",
             ja: "クルザス中央高地",
             de: "Zentrales Hochland von Coerthas",
             fr: "Hautes terres du Coerthas central",
+            ..Default::default()
         },
         305 => LocalisedText {
             en: "Mor Dhona",
             ja: "モードゥナ",
             de: "Mor Dhona",
             fr: "Mor Dhona",
+            ..Default::default()
         },
         306 => LocalisedText {
             en: "Southern Thanalan",
             ja: "南ザナラーン",
             de: "Südliches Thanalan",
             fr: "Thanalan méridional",
+            ..Default::default()
         },
         307 => LocalisedText {
             en: "Lower La Noscea",
             ja: "低地ラノシア",
             de: "Unteres La Noscea",
             fr: "Basse-Noscea",
+            ..Default::default()
         },
         308 => LocalisedText {
             en: "Mor Dhona",
             ja: "モードゥナ",
             de: "Mor Dhona",
             fr: "Mor Dhona",
+            ..Default::default()
         },
         309 => LocalisedText {
             en: "Mor Dhona",
             ja: "モードゥナ",
             de: "Mor Dhona",
             fr: "Mor Dhona",
+            ..Default::default()
         },
         310 => LocalisedText {
             en: "Eastern La Noscea",
             ja: "東ラノシア",
             de: "Östliches La Noscea",
             fr: "Noscea orientale",
+            ..Default::default()
         },
         311 => LocalisedText {
             en: "Eastern La Noscea",
             ja: "東ラノシア",
             de: "Östliches La Noscea",
             fr: "Noscea orientale",
+            ..Default::default()
         },
         312 => LocalisedText {
             en: "Southern Thanalan",
             ja: "南ザナラーン",
             de: "Südliches Thanalan",
             fr: "Thanalan méridional",
+            ..Default::default()
         },
         313 => LocalisedText {
             en: "Coerthas Central Highlands",
             ja: "クルザス中央高地",
             de: "Zentrales Hochland von Coerthas",
             fr: "Hautes terres du Coerthas central",
+            ..Default::default()
         },
         314 => LocalisedText {
             en: "Central Thanalan",
             ja: "中央ザナラーン",
             de: "Zentrales Thanalan",
             fr: "Thanalan central",
+            ..Default::default()
         },
         315 => LocalisedText {
             en: "Mor Dhona",
             ja: "モードゥナ",
             de: "Mor Dhona",
             fr: "Mor Dhona",
+            ..Default::default()
         },
         316 => LocalisedText {
             en: "Coerthas Central Highlands",
             ja: "クルザス中央高地",
             de: "Zentrales Hochland von Coerthas",
             fr: "Hautes terres du Coerthas central",
+            ..Default::default()
         },
         317 => LocalisedText {
             en: "South Shroud",
             ja: "黒衣森：南部森林",
             de: "Südwald",
             fr: "Forêt du sud",
+            ..Default::default()
         },
         318 => LocalisedText {
             en: "Southern Thanalan",
             ja: "南ザナラーン",
             de: "Südliches Thanalan",
             fr: "Thanalan méridional",
+            ..Default::default()
         },
         319 => LocalisedText {
             en: "Central Shroud",
             ja: "黒衣森：中央森林",
             de: "Tiefer Wald",
             fr: "Forêt centrale",
+            ..Default::default()
         },
         320 => LocalisedText {
             en: "Central Shroud",
             ja: "黒衣森：中央森林",
             de: "Tiefer Wald",
             fr: "Forêt centrale",
+            ..Default::default()
         },
         321 => LocalisedText {
             en: "North Shroud",
             ja: "黒衣森：北部森林",
             de: "Nordwald",
             fr: "Forêt du nord",
+            ..Default::default()
         },
         322 => LocalisedText {
             en: "Coerthas Central Highlands",
             ja: "クルザス中央高地",
             de: "Zentrales Hochland von Coerthas",
             fr: "Hautes terres du Coerthas central",
+            ..Default::default()
         },
         323 => LocalisedText {
             en: "Southern Thanalan",
             ja: "南ザナラーン",
             de: "Südliches Thanalan",
             fr: "Thanalan méridional",
+            ..Default::default()
         },
         324 => LocalisedText {
             en: "North Shroud",
             ja: "黒衣森：北部森林",
             de: "Nordwald",
             fr: "Forêt du nord",
+            ..Default::default()
         },
         325 => LocalisedText {
             en: "Outer La Noscea",
             ja: "外地ラノシア",
             de: "Äußeres La Noscea",
             fr: "Noscea extérieure",
+            ..Default::default()
         },
         326 => LocalisedText {
             en: "Mor Dhona",
             ja: "モードゥナ",
             de: "Mor Dhona",
             fr: "Mor Dhona",
+            ..Default::default()
         },
         327 => LocalisedText {
             en: "Eastern La Noscea",
             ja: "東ラノシア",
             de: "Östliches La Noscea",
             fr: "Noscea orientale",
+            ..Default::default()
         },
         328 => LocalisedText {
             en: "Upper La Noscea",
             ja: "高地ラノシア",
             de: "Oberes La Noscea",
             fr: "Haute-Noscea",
+            ..Default::default()
         },
         329 => LocalisedText {
             en: "The Wanderer's Palace",
             ja: "ワンダラーパレス",
             de: "Palast des Wanderers",
             fr: "Palais du Vagabond",
+            ..Default::default()
         },
         330 => LocalisedText {
             en: "Western La Noscea",
             ja: "西ラノシア",
             de: "Westliches La Noscea",
             fr: "Noscea occidentale",
+            ..Default::default()
         },
         331 => LocalisedText {
             en: "The Howling Eye",
             ja: "ハウリングアイ外縁",
             de: "Das Tosende Auge",
             fr: "Hurlœil (abord)",
+            ..Default::default()
         },
         335 => LocalisedText {
             en: "Mor Dhona",
             ja: "モードゥナ",
             de: "Mor Dhona",
             fr: "Mor Dhona",
+            ..Default::default()
         },
         338 => LocalisedText {
             en: "Eorzean Subterrane",
             ja: "エオルゼア地下空間",
             de: "Tiefstes Inneres",
             fr: "Sous-sol éorzéen",
+            ..Default::default()
         },
         339 => LocalisedText {
             en: "Mist",
             ja: "ミスト・ヴィレッジ",
             de: "Dorf des Nebels",
             fr: "Brumée",
+            ..Default::default()
         },
         340 => LocalisedText {
             en: "The Lavender Beds",
             ja: "ラベンダーベッド",
             de: "Lavendelbeete",
             fr: "Lavandière",
+            ..Default::default()
         },
         341 => LocalisedText {
             en: "The Goblet",
             ja: "ゴブレットビュート",
             de: "Kelchkuppe",
             fr: "La Coupe",
+            ..Default::default()
         },
         342 => LocalisedText {
             en: "Private Cottage - The Lavender Beds",
             ja: "ラベンダーベッド：コテージ",
             de: "Privathütte (Lavendelbeete)",
             fr: "Maisonnette - Lavandière",
+            ..Default::default()
         },
         343 => LocalisedText {
             en: "Private House - The Lavender Beds",
             ja: "ラベンダーベッド：ハウス",
             de: "Privathaus (Lavendelbeete)",
             fr: "Pavillon - Lavandière",
+            ..Default::default()
         },
         344 => LocalisedText {
             en: "Private Mansion - The Lavender Beds",
             ja: "ラベンダーベッド：レジデンス",
             de: "Privatresidenz (Lavendelbeete)",
             fr: "Villa - Lavandière",
+            ..Default::default()
         },
         345 => LocalisedText {
             en: "Private Cottage - The Goblet",
             ja: "ゴブレットビュート：コテージ",
             de: "Privathütte (Kelchkuppe)",
             fr: "Maisonnette - La Coupe",
+            ..Default::default()
         },
         346 => LocalisedText {
             en: "Private House - The Goblet",
             ja: "ゴブレットビュート：ハウス",
             de: "Privathaus (Kelchkuppe)",
             fr: "Pavillon - La Coupe",
+            ..Default::default()
         },
         347 => LocalisedText {
             en: "Private Mansion - The Goblet",
             ja: "ゴブレットビュート：レジデンス",
             de: "Privatresidenz (Kelchkuppe)",
             fr: "Villa - La Coupe",
+            ..Default::default()
         },
         348 => LocalisedText {
             en: "Porta Decumana",
             ja: "ポルタ・デクマーナ",
             de: "Porta Decumana",
             fr: "Porta Decumana",
+            ..Default::default()
         },
         349 => LocalisedText {
             en: "Copperbell Mines",
             ja: "カッパーベル銅山",
             de: "Kupferglocken-Mine",
             fr: "Mines de Clochecuivre",
+            ..Default::default()
         },
         350 => LocalisedText {
             en: "Haukke Manor",
             ja: "ハウケタ御用邸",
             de: "Haukke-Herrenhaus",
             fr: "Manoir des Haukke",
+            ..Default::default()
         },
         351 => LocalisedText {
             en: "The Rising Stones",
             ja: "石の家",
             de: "Sonnenstein",
             fr: "Refuge des roches",
+            ..Default::default()
         },
         353 => LocalisedText {
             en: "Kugane Ohashi",
             ja: "クガネ大橋",
             de: "Kugane-Brücke",
             fr: "Pont Ohashi",
+            ..Default::default()
         },
         354 => LocalisedText {
             en: "The Dancing Plague",
             ja: "妖精王の舞踏場",
             de: "Königlicher Tanzgarten",
             fr: "Salle de bal de Titania",
+            ..Default::default()
         },
         355 => LocalisedText {
             en: "Dalamud's Shadow",
             ja: "メテオの陰地",
             de: "Meteoritenkrater",
             fr: "Ombrage du Météore",
+            ..Default::default()
         },
         356 => LocalisedText {
             en: "The Outer Coil",
             ja: "バインディングコイル",
             de: "Äußere Schächte",
             fr: "L'Écheveau",
+            ..Default::default()
         },
         357 => LocalisedText {
             en: "Central Decks",
             ja: "ラグナロク級三番艦：艦体中央部",
             de: "Zentrale Decks",
             fr: "Secteur central RCI-03",
+            ..Default::default()
         },
         358 => LocalisedText {
             en: "The Holocharts",
             ja: "ラグナロク級三番艦：作戦室",
             de: "Holosphäre",
             fr: "Salle de contrôle RCI-03",
+            ..Default::default()
         },
         359 => LocalisedText {
             en: "The Whorleater",
             ja: "対リヴァイアサン双胴船",
             de: "Der Wogenbrecher",
             fr: "Briseur de marées",
+            ..Default::default()
         },
         360 => LocalisedText {
             en: "Halatali",
             ja: "ハラタリ修練所",
             de: "Halatali",
             fr: "Halatali",
+            ..Default::default()
         },
         361 => LocalisedText {
             en: "Hullbreaker Isle",
             ja: "ハルブレーカー・アイル",
             de: "Schiffbrecher-Insel",
             fr: "Île de Crèvecarène",
+            ..Default::default()
         },
         362 => LocalisedText {
             en: "Brayflox's Longstop",
             ja: "ブレイフロクスの野営地",
             de: "Brüllvolx' Langrast",
             fr: "Bivouac de Brayflox",
+            ..Default::default()
         },
         363 => LocalisedText {
             en: "The Lost City of Amdapor",
             ja: "古アムダプール市街",
             de: "Historisches Amdapor",
             fr: "Vestiges de la cité d'Amdapor",
+            ..Default::default()
         },
         364 => LocalisedText {
             en: "Thornmarch",
             ja: "茨の園",
             de: "Dornmarsch",
             fr: "Lisière de ronces",
+            ..Default::default()
         },
         365 => LocalisedText {
             en: "Stone Vigil",
             ja: "ストーンヴィジル",
             de: "Steinerne Wacht",
             fr: "Vigile de Pierre",
+            ..Default::default()
         },
         366 => LocalisedText {
             en: "Griffin Crossing",
             ja: "グリフィン大橋",
             de: "Greifenbrücke",
             fr: "Traversée du Griffon",
+            ..Default::default()
         },
         367 => LocalisedText {
             en: "The Sunken Temple of Qarn",
             ja: "カルン埋没寺院",
             de: "Versunkener Tempel von Qarn",
             fr: "Temple enseveli de Qarn",
+            ..Default::default()
         },
         368 => LocalisedText {
             en: "The Weeping Saint",
             ja: "聖ダナフェンの落涙",
             de: "Der Trauernde Heilige ",
             fr: "Le Saint affligé",
+            ..Default::default()
         },
         369 => LocalisedText {
             en: "Hall of the Bestiarii",
             ja: "獣闘の間",
             de: "Halle der Bestie",
             fr: "Hall des Bestiarii",
+            ..Default::default()
         },
         370 => LocalisedText {
             en: "Main Bridge",
             ja: "ラグナロク級三番艦：第一艦橋",
             de: "Kommandobrücke RVH-03",
             fr: "Premier pont RCI-03",
+            ..Default::default()
         },
         372 => LocalisedText {
             en: "Syrcus Tower",
             ja: "シルクスの塔",
             de: "Syrcus-Turm",
             fr: "Tour de Syrcus",
+            ..Default::default()
         },
         373 => LocalisedText {
             en: "The Tam-Tara Deepcroft",
             ja: "タムタラの墓所",
             de: "Totenacker Tam-Tara",
             fr: "Hypogée de Tam-Tara",
+            ..Default::default()
         },
         374 => LocalisedText {
             en: "The Striking Tree",
             ja: "古木の神判地",
             de: "Baum des Urteils",
             fr: "Arbre du jugement",
+            ..Default::default()
         },
         375 => LocalisedText {
             en: "The Striking Tree",
             ja: "古木の神判地",
             de: "Baum des Urteils",
             fr: "Arbre du jugement",
+            ..Default::default()
         },
         376 => LocalisedText {
             en: "Carteneau Flats: Borderland Ruins",
             ja: "カルテノー平原：外縁遺跡群",
             de: "Äußere Ruinen",
             fr: "Ruines frontalières",
+            ..Default::default()
         },
         377 => LocalisedText {
             en: "Akh Afah Amphitheatre",
             ja: "アク・アファー円形劇場",
             de: "Akh Afah",
             fr: "Amphithéâtre d'Akh Afah",
+            ..Default::default()
         },
         378 => LocalisedText {
             en: "Akh Afah Amphitheatre",
             ja: "アク・アファー円形劇場",
             de: "Akh Afah",
             fr: "Amphithéâtre d'Akh Afah",
+            ..Default::default()
         },
         379 => LocalisedText {
             en: "Mor Dhona",
             ja: "モードゥナ",
             de: "Mor Dhona",
             fr: "Mor Dhona",
+            ..Default::default()
         },
         380 => LocalisedText {
             en: "Dalamud's Shadow",
             ja: "メテオの陰地",
             de: "Meteoritenkrater",
             fr: "Ombrage du Météore",
+            ..Default::default()
         },
         381 => LocalisedText {
             en: "The Outer Coil",
             ja: "バインディングコイル",
             de: "Äußere Schächte",
             fr: "L'Écheveau",
+            ..Default::default()
         },
         382 => LocalisedText {
             en: "Central Decks",
             ja: "ラグナロク級三番艦：艦体中央部",
             de: "Zentrale Decks",
             fr: "Secteur central RCI-03",
+            ..Default::default()
         },
         383 => LocalisedText {
             en: "The Holocharts",
             ja: "ラグナロク級三番艦：作戦室",
             de: "Holosphäre",
             fr: "Salle de contrôle RCI-03",
+            ..Default::default()
         },
         384 => LocalisedText {
             en: "Private Chambers - Mist",
             ja: "ミスト・ヴィレッジ：プライベートルーム",
             de: "Nebeldorf - Zimmer",
             fr: "Chambre individuelle - Brumée",
+            ..Default::default()
         },
         385 => LocalisedText {
             en: "Private Chambers - The Lavender Beds",
             ja: "ラベンダーベッド：プライベートルーム",
             de: "Lavendelbeete - Zimmer",
             fr: "Chambre individuelle - Lavandière",
+            ..Default::default()
         },
         386 => LocalisedText {
             en: "Private Chambers - The Goblet",
             ja: "ゴブレットビュート：プライベートルーム",
             de: "Kelchkuppe - Zimmer",
             fr: "Chambre individuelle - La Coupe",
+            ..Default::default()
         },
         387 => LocalisedText {
             en: "Sastasha",
             ja: "サスタシャ浸食洞",
             de: "Sastasha-Höhle",
             fr: "Sastasha",
+            ..Default::default()
         },
         388 => LocalisedText {
             en: "Chocobo Square",
             ja: "チョコボスクウェア",
             de: "Chocobo-Platz",
             fr: "Square des chocobos",
+            ..Default::default()
         },
         389 => LocalisedText {
             en: "Chocobo Square",
             ja: "チョコボスクウェア",
             de: "Chocobo-Platz",
             fr: "Square des chocobos",
+            ..Default::default()
         },
         390 => LocalisedText {
             en: "Chocobo Square",
             ja: "チョコボスクウェア",
             de: "Chocobo-Platz",
             fr: "Square des chocobos",
+            ..Default::default()
         },
         391 => LocalisedText {
             en: "Chocobo Square",
             ja: "チョコボスクウェア",
             de: "Chocobo-Platz",
             fr: "Square des chocobos",
+            ..Default::default()
         },
         392 => LocalisedText {
             en: "Sanctum of the Twelve",
             ja: "十二神大聖堂",
             de: "Dom der Einkehr",
             fr: "Sanctuaire des Douze",
+            ..Default::default()
         },
         393 => LocalisedText {
             en: "Sanctum of the Twelve",
             ja: "十二神大聖堂",
             de: "Dom der Einkehr",
             fr: "Sanctuaire des Douze",
+            ..Default::default()
         },
         394 => LocalisedText {
             en: "South Shroud",
             ja: "黒衣森：南部森林",
             de: "Südwald",
             fr: "Forêt du sud",
+            ..Default::default()
         },
         395 => LocalisedText {
             en: "Intercessory",
             ja: "応接室",
             de: "Empfangszimmer",
             fr: "Salle de réception",
+            ..Default::default()
         },
         396 => LocalisedText {
             en: "Amdapor Keep",
             ja: "古城アムダプール",
             de: "Ruinen von Amdapor",
             fr: "Château d'Amdapor",
+            ..Default::default()
         },
         397 => LocalisedText {
             en: "Coerthas Western Highlands",
             ja: "クルザス西部高地",
             de: "Westliches Hochland von Coerthas",
             fr: "Hautes terres du Coerthas occidental",
+            ..Default::default()
         },
         398 => LocalisedText {
             en: "The Dravanian Forelands",
             ja: "高地ドラヴァニア",
             de: "Dravanisches Vorland",
             fr: "Avant-pays dravanien",
+            ..Default::default()
         },
         399 => LocalisedText {
             en: "The Dravanian Hinterlands",
             ja: "低地ドラヴァニア",
             de: "Dravanisches Hinterland",
             fr: "Arrière-pays dravanien",
+            ..Default::default()
         },
         400 => LocalisedText {
             en: "The Churning Mists",
             ja: "ドラヴァニア雲海",
             de: "Wallende Nebel",
             fr: "L'Écume des cieux de Dravania",
+            ..Default::default()
         },
         401 => LocalisedText {
             en: "The Sea of Clouds",
             ja: "アバラシア雲海",
             de: "Abalathisches Wolkenmeer",
             fr: "L'Écume des cieux d'Abalathia",
+            ..Default::default()
         },
         402 => LocalisedText {
             en: "Azys Lla",
             ja: "アジス・ラー",
             de: "Azys Lla",
             fr: "Azys Lla",
+            ..Default::default()
         },
         403 => LocalisedText {
             en: "Ala Mhigo",
             ja: "アラミゴ",
             de: "Ala Mhigo",
             fr: "Ala Mhigo",
+            ..Default::default()
         },
         404 => LocalisedText {
             en: "Limsa Lominsa Lower Decks",
             ja: "リムサ・ロミンサ：下甲板層",
             de: "Untere Decks",
             fr: "Limsa Lominsa - L'Entrepont",
+            ..Default::default()
         },
         405 => LocalisedText {
             en: "Western La Noscea",
             ja: "西ラノシア",
             de: "Westliches La Noscea",
             fr: "Noscea occidentale",
+            ..Default::default()
         },
         406 => LocalisedText {
             en: "Western La Noscea",
             ja: "西ラノシア",
             de: "Westliches La Noscea",
             fr: "Noscea occidentale",
+            ..Default::default()
         },
         407 => LocalisedText {
             en: "Rhotano Sea",
             ja: "ロータノ海",
             de: "Rhotano-See",
             fr: "Mer de Rhotano",
+            ..Default::default()
         },
         408 => LocalisedText {
             en: "Eastern La Noscea",
             ja: "東ラノシア",
             de: "Östliches La Noscea",
             fr: "Noscea orientale",
+            ..Default::default()
         },
         409 => LocalisedText {
             en: "Limsa Lominsa Upper Decks",
             ja: "リムサ・ロミンサ：上甲板層",
             de: "Obere Decks",
             fr: "Limsa Lominsa - Le Tillac",
+            ..Default::default()
         },
         410 => LocalisedText {
             en: "Northern Thanalan",
             ja: "北ザナラーン",
             de: "Nördliches Thanalan",
             fr: "Thanalan septentrional",
+            ..Default::default()
         },
         411 => LocalisedText {
             en: "Eastern La Noscea",
             ja: "東ラノシア",
             de: "Östliches La Noscea",
             fr: "Noscea orientale",
+            ..Default::default()
         },
         412 => LocalisedText {
             en: "Upper La Noscea",
             ja: "高地ラノシア",
             de: "Oberes La Noscea",
             fr: "Haute-Noscea",
+            ..Default::default()
         },
         413 => LocalisedText {
             en: "Western La Noscea",
             ja: "西ラノシア",
             de: "Westliches La Noscea",
             fr: "Noscea occidentale",
+            ..Default::default()
         },
         414 => LocalisedText {
             en: "Eastern La Noscea",
             ja: "東ラノシア",
             de: "Östliches La Noscea",
             fr: "Noscea orientale",
+            ..Default::default()
         },
         415 => LocalisedText {
             en: "Lower La Noscea",
             ja: "低地ラノシア",
             de: "Unteres La Noscea",
             fr: "Basse-Noscea",
+            ..Default::default()
         },
         417 => LocalisedText {
             en: "Chocobo Square",
             ja: "チョコボスクウェア",
             de: "Chocobo-Platz",
             fr: "Square des chocobos",
+            ..Default::default()
         },
         418 => LocalisedText {
             en: "Foundation",
             ja: "イシュガルド：下層",
             de: "Fundamente",
             fr: "Ishgard - L'Assise",
+            ..Default::default()
         },
         419 => LocalisedText {
             en: "The Pillars",
             ja: "イシュガルド：上層",
             de: "Strebewerk",
             fr: "Ishgard - Les Contreforts",
+            ..Default::default()
         },
         420 => LocalisedText {
             en: "Neverreap",
             ja: "ネバーリープ",
             de: "Nimmerreich",
             fr: "Nalloncques",
+            ..Default::default()
         },
         423 => LocalisedText {
             en: "Company Workshop - Mist",
             ja: "ミスト・ヴィレッジ：地下工房",
             de: "Gesellschaftswerkstätte: Dorf des Nebels",
             fr: "Atelier de compagnie - Brumée",
+            ..Default::default()
         },
         424 => LocalisedText {
             en: "Company Workshop - The Goblet",
             ja: "ゴブレットビュート：地下工房",
             de: "Gesellschaftswerkstätte: Kelchkuppe",
             fr: "Atelier de compagnie - La Coupe",
+            ..Default::default()
         },
         425 => LocalisedText {
             en: "Company Workshop - The Lavender Beds",
             ja: "ラベンダーベッド：地下工房",
             de: "Gesellschaftswerkstätte: Lavendelbeete",
             fr: "Atelier de compagnie - Lavandière",
+            ..Default::default()
         },
         426 => LocalisedText {
             en: "The Chrysalis",
             ja: "戒律の殻",
             de: "Chrysalis",
             fr: "La Chrysalide",
+            ..Default::default()
         },
         427 => LocalisedText {
             en: "Saint Endalim's Scholasticate",
             ja: "聖アンダリム神学院",
             de: "Sankt Endalim-Theologikum",
             fr: "Scolasticat Saint-Endalim",
+            ..Default::default()
         },
         428 => LocalisedText {
             en: "Seat of the Lord Commander",
             ja: "神殿騎士団総長室",
             de: "Kommandantur des Großmeisters",
             fr: "Siège du capitaine général",
+            ..Default::default()
         },
         429 => LocalisedText {
             en: "Cloud Nine",
             ja: "旅籠「九つの雲」",
             de: "Zum Wolkenschäfchen",
             fr: "Les Neuf-Nuages",
+            ..Default::default()
         },
         430 => LocalisedText {
             en: "The Fractal Continuum",
             ja: "フラクタル・コンティニアム",
             de: "Die Fraktal-Kontinuum",
             fr: "Le Continuum fractal",
+            ..Default::default()
         },
         431 => LocalisedText {
             en: "Seal Rock",
             ja: "シールロック",
             de: "Robbenholm",
             fr: "Rocher des tréfonds",
+            ..Default::default()
         },
         432 => LocalisedText {
             en: "Thok ast Thok",
             ja: "武神の闘技場",
             de: "Thok ast Thok",
             fr: "Thok ast Thok",
+            ..Default::default()
         },
         433 => LocalisedText {
             en: "Fortemps Manor",
             ja: "フォルタン伯爵邸",
             de: "Anwesen der Fortemps",
             fr: "Manoir des Fortemps",
+            ..Default::default()
         },
         434 => LocalisedText {
             en: "Dusk Vigil",
             ja: "ダスクヴィジル",
             de: "Abendrot-Wacht",
             fr: "Vigile du Crépuscule",
+            ..Default::default()
         },
         436 => LocalisedText {
             en: "The Limitless Blue",
             ja: "群青空域",
             de: "Grenzenloses Blau",
             fr: "L'Immensité bleue",
+            ..Default::default()
         },
         437 => LocalisedText {
             en: "Singularity Reactor",
             ja: "シンギュラリティ・リアクター",
             de: "Singularitäts-Reaktor",
             fr: "Réacteur de singularité",
+            ..Default::default()
         },
         439 => LocalisedText {
             en: "The Lightfeather Proving Grounds",
             ja: "ライトフェザー闘技場",
             de: "Leichtfeder-Arena",
             fr: "Carrière de Lesteplume",
+            ..Default::default()
         },
         440 => LocalisedText {
             en: "Ruling Chamber",
             ja: "決闘裁判場",
             de: "Gerichtssaal",
             fr: "Salle des sentences",
+            ..Default::default()
         },
         442 => LocalisedText {
             en: "The Fist of the Father",
             ja: "フィスト・オブ・ゴルディオス",
             de: "Faust des Vaters",
             fr: "Le Poing du Père",
+            ..Default::default()
         },
         443 => LocalisedText {
             en: "The Cuff of the Father",
             ja: "カフ・オブ・ゴルディオス",
             de: "Elle des Vaters",
             fr: "Le Poignet du Père",
+            ..Default::default()
         },
         444 => LocalisedText {
             en: "The Arm of the Father",
             ja: "アーム・オブ・ゴルディオス",
             de: "Arm des Vaters",
             fr: "Le Bras du Père",
+            ..Default::default()
         },
         445 => LocalisedText {
             en: "The Burden of the Father",
             ja: "バーデン・オブ・ゴルディオス",
             de: "Last des Vaters",
             fr: "Le Fardeau du Père",
+            ..Default::default()
         },
         446 => LocalisedText {
             en: "Thok ast Thok",
             ja: "武神の闘技場",
             de: "Thok ast Thok",
             fr: "Thok ast Thok",
+            ..Default::default()
         },
         447 => LocalisedText {
             en: "The Limitless Blue",
             ja: "群青空域",
             de: "Grenzenloses Blau",
             fr: "L'Immensité bleue",
+            ..Default::default()
         },
         448 => LocalisedText {
             en: "Singularity Reactor",
             ja: "シンギュラリティ・リアクター",
             de: "Singularitäts-Reaktor",
             fr: "Réacteur de singularité",
+            ..Default::default()
         },
         449 => LocalisedText {
             en: "The Fist of the Father",
             ja: "フィスト・オブ・ゴルディオス",
             de: "Faust des Vaters",
             fr: "Le Poing du Père",
+            ..Default::default()
         },
         450 => LocalisedText {
             en: "The Cuff of the Father",
             ja: "カフ・オブ・ゴルディオス",
             de: "Elle des Vaters",
             fr: "Le Poignet du Père",
+            ..Default::default()
         },
         451 => LocalisedText {
             en: "The Arm of the Father",
             ja: "アーム・オブ・ゴルディオス",
             de: "Arm des Vaters",
             fr: "Le Bras du Père",
+            ..Default::default()
         },
         452 => LocalisedText {
             en: "The Burden of the Father",
             ja: "バーデン・オブ・ゴルディオス",
             de: "Last des Vaters",
             fr: "Le Fardeau du Père",
+            ..Default::default()
         },
         453 => LocalisedText {
             en: "Western La Noscea",
             ja: "西ラノシア",
             de: "Westliches La Noscea",
             fr: "Noscea occidentale",
+            ..Default::default()
         },
         454 => LocalisedText {
             en: "Upper La Noscea",
             ja: "高地ラノシア",
             de: "Oberes La Noscea",
             fr: "Haute-Noscea",
+            ..Default::default()
         },
         455 => LocalisedText {
             en: "The Sea of Clouds",
             ja: "アバラシア雲海",
             de: "Abalathisches Wolkenmeer",
             fr: "L'Écume des cieux d'Abalathia",
+            ..Default::default()
         },
         456 => LocalisedText {
             en: "Ruling Chamber",
             ja: "決闘裁判場",
             de: "Gerichtssaal",
             fr: "Salle des sentences",
+            ..Default::default()
         },
         457 => LocalisedText {
             en: "Akh Afah Amphitheatre",
             ja: "アク・アファー円形劇場",
             de: "Akh Afah",
             fr: "Amphithéâtre d'Akh Afah",
+            ..Default::default()
         },
         458 => LocalisedText {
             en: "Foundation",
             ja: "イシュガルド：下層",
             de: "Fundamente",
             fr: "Ishgard - L'Assise",
+            ..Default::default()
         },
         459 => LocalisedText {
             en: "Azys Lla",
             ja: "アジス・ラー",
             de: "Azys Lla",
             fr: "Azys Lla",
+            ..Default::default()
         },
         460 => LocalisedText {
             en: "Halatali",
             ja: "ハラタリ修練所",
             de: "Halatali",
             fr: "Halatali",
+            ..Default::default()
         },
         461 => LocalisedText {
             en: "The Sea of Clouds",
             ja: "アバラシア雲海",
             de: "Abalathisches Wolkenmeer",
             fr: "L'Écume des cieux d'Abalathia",
+            ..Default::default()
         },
         462 => LocalisedText {
             en: "Sacrificial Chamber",
             ja: "供物穴",
             de: "Opferkammer",
             fr: "Salle des offrandes",
+            ..Default::default()
         },
         463 => LocalisedText {
             en: "Matoya's Cave",
             ja: "マトーヤの洞窟",
             de: "Matoyas Höhle",
             fr: "Caverne de Matoya",
+            ..Default::default()
         },
         464 => LocalisedText {
             en: "The Dravanian Forelands",
             ja: "高地ドラヴァニア",
             de: "Dravanisches Vorland",
             fr: "Avant-pays dravanien",
+            ..Default::default()
         },
         465 => LocalisedText {
             en: "Eastern Thanalan",
             ja: "東ザナラーン",
             de: "Östliches Thanalan",
             fr: "Thanalan oriental",
+            ..Default::default()
         },
         466 => LocalisedText {
             en: "Upper La Noscea",
             ja: "高地ラノシア",
             de: "Oberes La Noscea",
             fr: "Haute-Noscea",
+            ..Default::default()
         },
         467 => LocalisedText {
             en: "Coerthas Western Highlands",
             ja: "クルザス西部高地",
             de: "Westliches Hochland von Coerthas",
             fr: "Hautes terres du Coerthas occidental",
+            ..Default::default()
         },
         468 => LocalisedText {
             en: "Coerthas Central Highlands",
             ja: "クルザス中央高地",
             de: "Zentrales Hochland von Coerthas",
             fr: "Hautes terres du Coerthas central",
+            ..Default::default()
         },
         469 => LocalisedText {
             en: "Coerthas Central Highlands",
             ja: "クルザス中央高地",
             de: "Zentrales Hochland von Coerthas",
             fr: "Hautes terres du Coerthas central",
+            ..Default::default()
         },
         470 => LocalisedText {
             en: "Coerthas Western Highlands",
             ja: "クルザス西部高地",
             de: "Westliches Hochland von Coerthas",
             fr: "Hautes terres du Coerthas occidental",
+            ..Default::default()
         },
         471 => LocalisedText {
             en: "Eastern La Noscea",
             ja: "東ラノシア",
             de: "Östliches La Noscea",
             fr: "Noscea orientale",
+            ..Default::default()
         },
         472 => LocalisedText {
             en: "Coerthas Western Highlands",
             ja: "クルザス西部高地",
             de: "Westliches Hochland von Coerthas",
             fr: "Hautes terres du Coerthas occidental",
+            ..Default::default()
         },
         473 => LocalisedText {
             en: "South Shroud",
             ja: "黒衣森：南部森林",
             de: "Südwald",
             fr: "Forêt du sud",
+            ..Default::default()
         },
         474 => LocalisedText {
             en: "Limsa Lominsa Upper Decks",
             ja: "リムサ・ロミンサ：上甲板層",
             de: "Obere Decks",
             fr: "Limsa Lominsa - Le Tillac",
+            ..Default::default()
         },
         475 => LocalisedText {
             en: "Coerthas Central Highlands",
             ja: "クルザス中央高地",
             de: "Zentrales Hochland von Coerthas",
             fr: "Hautes terres du Coerthas central",
+            ..Default::default()
         },
         476 => LocalisedText {
             en: "The Dravanian Hinterlands",
             ja: "低地ドラヴァニア",
             de: "Dravanisches Hinterland",
             fr: "Arrière-pays dravanien",
+            ..Default::default()
         },
         477 => LocalisedText {
             en: "Coerthas Western Highlands",
             ja: "クルザス西部高地",
             de: "Westliches Hochland von Coerthas",
             fr: "Hautes terres du Coerthas occidental",
+            ..Default::default()
         },
         478 => LocalisedText {
             en: "Idyllshire",
             ja: "イディルシャイア",
             de: "Frohehalde",
             fr: "Idyllée",
+            ..Default::default()
         },
         479 => LocalisedText {
             en: "Coerthas Western Highlands",
             ja: "クルザス西部高地",
             de: "Westliches Hochland von Coerthas",
             fr: "Hautes terres du Coerthas occidental",
+            ..Default::default()
         },
         480 => LocalisedText {
             en: "Mor Dhona",
             ja: "モードゥナ",
             de: "Mor Dhona",
             fr: "Mor Dhona",
+            ..Default::default()
         },
         481 => LocalisedText {
             en: "The Dravanian Forelands",
             ja: "高地ドラヴァニア",
             de: "Dravanisches Vorland",
             fr: "Avant-pays dravanien",
+            ..Default::default()
         },
         482 => LocalisedText {
             en: "The Dravanian Forelands",
             ja: "高地ドラヴァニア",
             de: "Dravanisches Vorland",
             fr: "Avant-pays dravanien",
+            ..Default::default()
         },
         483 => LocalisedText {
             en: "Northern Thanalan",
             ja: "北ザナラーン",
             de: "Nördliches Thanalan",
             fr: "Thanalan septentrional",
+            ..Default::default()
         },
         484 => LocalisedText {
             en: "Lower La Noscea",
             ja: "低地ラノシア",
             de: "Unteres La Noscea",
             fr: "Basse-Noscea",
+            ..Default::default()
         },
         485 => LocalisedText {
             en: "The Dravanian Hinterlands",
             ja: "低地ドラヴァニア",
             de: "Dravanisches Hinterland",
             fr: "Arrière-pays dravanien",
+            ..Default::default()
         },
         486 => LocalisedText {
             en: "Outer La Noscea",
             ja: "外地ラノシア",
             de: "Äußeres La Noscea",
             fr: "Noscea extérieure",
+            ..Default::default()
         },
         487 => LocalisedText {
             en: "Coerthas Central Highlands",
             ja: "クルザス中央高地",
             de: "Zentrales Hochland von Coerthas",
             fr: "Hautes terres du Coerthas central",
+            ..Default::default()
         },
         488 => LocalisedText {
             en: "Coerthas Central Highlands",
             ja: "クルザス中央高地",
             de: "Zentrales Hochland von Coerthas",
             fr: "Hautes terres du Coerthas central",
+            ..Default::default()
         },
         489 => LocalisedText {
             en: "Coerthas Western Highlands",
             ja: "クルザス西部高地",
             de: "Westliches Hochland von Coerthas",
             fr: "Hautes terres du Coerthas occidental",
+            ..Default::default()
         },
         490 => LocalisedText {
             en: "Hullbreaker Isle",
             ja: "ハルブレーカー・アイル",
             de: "Schiffbrecher-Insel",
             fr: "Île de Crèvecarène",
+            ..Default::default()
         },
         491 => LocalisedText {
             en: "Southern Thanalan",
             ja: "南ザナラーン",
             de: "Südliches Thanalan",
             fr: "Thanalan méridional",
+            ..Default::default()
         },
         492 => LocalisedText {
             en: "The Sea of Clouds",
             ja: "アバラシア雲海",
             de: "Abalathisches Wolkenmeer",
             fr: "L'Écume des cieux d'Abalathia",
+            ..Default::default()
         },
         493 => LocalisedText {
             en: "Coerthas Western Highlands",
             ja: "クルザス西部高地",
             de: "Westliches Hochland von Coerthas",
             fr: "Hautes terres du Coerthas occidental",
+            ..Default::default()
         },
         494 => LocalisedText {
             en: "Eastern Thanalan",
             ja: "東ザナラーン",
             de: "Östliches Thanalan",
             fr: "Thanalan oriental",
+            ..Default::default()
         },
         495 => LocalisedText {
             en: "Lower La Noscea",
             ja: "低地ラノシア",
             de: "Unteres La Noscea",
             fr: "Basse-Noscea",
+            ..Default::default()
         },
         496 => LocalisedText {
             en: "Coerthas Central Highlands",
             ja: "クルザス中央高地",
             de: "Zentrales Hochland von Coerthas",
             fr: "Hautes terres du Coerthas central",
+            ..Default::default()
         },
         497 => LocalisedText {
             en: "Coerthas Western Highlands",
             ja: "クルザス西部高地",
             de: "Westliches Hochland von Coerthas",
             fr: "Hautes terres du Coerthas occidental",
+            ..Default::default()
         },
         498 => LocalisedText {
             en: "Coerthas Western Highlands",
             ja: "クルザス西部高地",
             de: "Westliches Hochland von Coerthas",
             fr: "Hautes terres du Coerthas occidental",
+            ..Default::default()
         },
         499 => LocalisedText {
             en: "The Pillars",
             ja: "イシュガルド：上層",
             de: "Strebewerk",
             fr: "Ishgard - Les Contreforts",
+            ..Default::default()
         },
         500 => LocalisedText {
             en: "Coerthas Central Highlands",
             ja: "クルザス中央高地",
             de: "Zentrales Hochland von Coerthas",
             fr: "Hautes terres du Coerthas central",
+            ..Default::default()
         },
         501 => LocalisedText {
             en: "The Churning Mists",
             ja: "ドラヴァニア雲海",
             de: "Wallende Nebel",
             fr: "L'Écume des cieux de Dravania",
+            ..Default::default()
         },
         502 => LocalisedText {
             en: "Carteneau Flats: Borderland Ruins",
             ja: "カルテノー平原：外縁遺跡群",
             de: "Äußere Ruinen",
             fr: "Ruines frontalières",
+            ..Default::default()
         },
         503 => LocalisedText {
             en: "The Dravanian Hinterlands",
             ja: "低地ドラヴァニア",
             de: "Dravanisches Hinterland",
             fr: "Arrière-pays dravanien",
+            ..Default::default()
         },
         504 => LocalisedText {
             en: "The Eighteenth Floor",
             ja: "かいはつしつ",
             de: "Achtzehnter Stock",
             fr: "Salle des développeurs",
+            ..Default::default()
         },
         505 => LocalisedText {
             en: "Alexander",
             ja: "機工城アレキサンダー",
             de: "Alexander",
             fr: "Alexander",
+            ..Default::default()
         },
         506 => LocalisedText {
             en: "Chocobo Square",
             ja: "チョコボスクウェア",
             de: "Chocobo-Platz",
             fr: "Square des chocobos",
+            ..Default::default()
         },
         507 => LocalisedText {
             en: "Central Azys Lla",
             ja: "魔大陸中枢",
             de: "Zentral-Azys Lla",
             fr: "Cœur d'Azys Lla",
+            ..Default::default()
         },
         508 => LocalisedText {
             en: "Void Ark",
             ja: "魔航船ヴォイドアーク",
             de: "Nichts-Arche",
             fr: "Arche du néant",
+            ..Default::default()
         },
         509 => LocalisedText {
             en: "The Gilded Araya",
             ja: "アラヤの宝塔",
             de: "Arayas Palast",
             fr: "Temple d'Araya",
+            ..Default::default()
         },
         510 => LocalisedText {
             en: "Pharos Sirius",
             ja: "シリウス大灯台",
             de: "Pharos Sirius",
             fr: "Phare de Sirius",
+            ..Default::default()
         },
         511 => LocalisedText {
             en: "Saint Mocianne's Arboretum",
             ja: "聖モシャーヌ植物園",
             de: "Sankt Mocianne-Arboretum",
             fr: "Arboretum Sainte-Mocianne",
+            ..Default::default()
         },
         512 => LocalisedText {
             en: "The Diadem",
             ja: "ディアデム諸島",
             de: "Das Diadem",
             fr: "Le Diadème",
+            ..Default::default()
         },
         513 => LocalisedText {
             en: "The Vault",
             ja: "イシュガルド教皇庁",
             de: "Erzbasilika",
             fr: "La Voûte",
+            ..Default::default()
         },
         514 => LocalisedText {
             en: "The Diadem",
             ja: "ディアデム諸島",
             de: "Das Diadem",
             fr: "Le Diadème",
+            ..Default::default()
         },
         515 => LocalisedText {
             en: "The Diadem",
             ja: "ディアデム諸島",
             de: "Das Diadem",
             fr: "Le Diadème",
+            ..Default::default()
         },
         517 => LocalisedText {
             en: "Containment Bay S1T7",
             ja: "コンテイメントベイS1T7",
             de: "Arretur S1T7",
             fr: "Unité de contention S1P7",
+            ..Default::default()
         },
         519 => LocalisedText {
             en: "The Lost City of Amdapor",
             ja: "古アムダプール市街",
             de: "Historisches Amdapor",
             fr: "Vestiges de la cité d'Amdapor",
+            ..Default::default()
         },
         520 => LocalisedText {
             en: "The Fist of the Son",
             ja: "フィスト・オブ・ミダース",
             de: "Faust des Sohnes",
             fr: "Le Poing du Fils",
+            ..Default::default()
         },
         521 => LocalisedText {
             en: "The Cuff of the Son",
             ja: "カフ・オブ・ミダース",
             de: "Elle des Sohnes",
             fr: "Le Poignet du Fils",
+            ..Default::default()
         },
         522 => LocalisedText {
             en: "The Arm of the Son",
             ja: "アーム・オブ・ミダース",
             de: "Arm des Sohnes",
             fr: "Le Bras du Fils",
+            ..Default::default()
         },
         523 => LocalisedText {
             en: "The Burden of the Son",
             ja: "バーデン・オブ・ミダース",
             de: "Last des Sohnes",
             fr: "Le Fardeau du Fils",
+            ..Default::default()
         },
         524 => LocalisedText {
             en: "Containment Bay S1T7",
             ja: "コンテイメントベイS1T7",
             de: "Arretur S1T7",
             fr: "Unité de contention S1P7",
+            ..Default::default()
         },
         529 => LocalisedText {
             en: "The Fist of the Son",
             ja: "フィスト・オブ・ミダース",
             de: "Faust des Sohnes",
             fr: "Le Poing du Fils",
+            ..Default::default()
         },
         530 => LocalisedText {
             en: "The Cuff of the Son",
             ja: "カフ・オブ・ミダース",
             de: "Elle des Sohnes",
             fr: "Le Poignet du Fils",
+            ..Default::default()
         },
         531 => LocalisedText {
             en: "The Arm of the Son",
             ja: "アーム・オブ・ミダース",
             de: "Arm des Sohnes",
             fr: "Le Bras du Fils",
+            ..Default::default()
         },
         532 => LocalisedText {
             en: "The Burden of the Son",
             ja: "バーデン・オブ・ミダース",
             de: "Last des Sohnes",
             fr: "Le Fardeau du Fils",
+            ..Default::default()
         },
         533 => LocalisedText {
             en: "Coerthas Central Highlands",
             ja: "クルザス中央高地",
             de: "Zentrales Hochland von Coerthas",
             fr: "Hautes terres du Coerthas central",
+            ..Default::default()
         },
         534 => LocalisedText {
             en: "Twin Adder Barracks",
             ja: "双蛇党兵舎",
             de: "Wachstube der Bruderschaft",
             fr: "Caserne de l'ordre des Deux Vipères",
+            ..Default::default()
         },
         535 => LocalisedText {
             en: "Flame Barracks",
             ja: "不滅隊兵舎",
             de: "Wachstube der Legion",
             fr: "Caserne des Immortels",
+            ..Default::default()
         },
         536 => LocalisedText {
             en: "Maelstrom Barracks",
             ja: "黒渦団兵舎",
             de: "Wachstube des Mahlstroms",
             fr: "Caserne du Maelstrom",
+            ..Default::default()
         },
         537 => LocalisedText {
             en: "The Fold",
             ja: "ザ・フォールド",
             de: "Wolfspferch (The Fold)",
             fr: "La Louverie (The Fold)",
+            ..Default::default()
         },
         538 => LocalisedText {
             en: "The Fold",
             ja: "ザ・フォールド",
             de: "Wolfspferch (The Fold)",
             fr: "La Louverie (The Fold)",
+            ..Default::default()
         },
         539 => LocalisedText {
             en: "The Fold",
             ja: "ザ・フォールド",
             de: "Wolfspferch (The Fold)",
             fr: "La Louverie (The Fold)",
+            ..Default::default()
         },
         540 => LocalisedText {
             en: "The Fold",
             ja: "ザ・フォールド",
             de: "Wolfspferch (The Fold)",
             fr: "La Louverie (The Fold)",
+            ..Default::default()
         },
         541 => LocalisedText {
             en: "The Fold",
             ja: "ザ・フォールド",
             de: "Wolfspferch (The Fold)",
             fr: "La Louverie (The Fold)",
+            ..Default::default()
         },
         542 => LocalisedText {
             en: "The Fold",
             ja: "ザ・フォールド",
             de: "Wolfspferch (The Fold)",
             fr: "La Louverie (The Fold)",
+            ..Default::default()
         },
         543 => LocalisedText {
             en: "The Fold",
             ja: "ザ・フォールド",
             de: "Wolfspferch (The Fold)",
             fr: "La Louverie (The Fold)",
+            ..Default::default()
         },
         544 => LocalisedText {
             en: "The Fold",
             ja: "ザ・フォールド",
             de: "Wolfspferch (The Fold)",
             fr: "La Louverie (The Fold)",
+            ..Default::default()
         },
         545 => LocalisedText {
             en: "The Fold",
             ja: "ザ・フォールド",
             de: "Wolfspferch (The Fold)",
             fr: "La Louverie (The Fold)",
+            ..Default::default()
         },
         546 => LocalisedText {
             en: "The Fold",
             ja: "ザ・フォールド",
             de: "Wolfspferch (The Fold)",
             fr: "La Louverie (The Fold)",
+            ..Default::default()
         },
         547 => LocalisedText {
             en: "The Fold",
             ja: "ザ・フォールド",
             de: "Wolfspferch (The Fold)",
             fr: "La Louverie (The Fold)",
+            ..Default::default()
         },
         548 => LocalisedText {
             en: "The Fold",
             ja: "ザ・フォールド",
             de: "Wolfspferch (The Fold)",
             fr: "La Louverie (The Fold)",
+            ..Default::default()
         },
         549 => LocalisedText {
             en: "The Fold",
             ja: "ザ・フォールド",
             de: "Wolfspferch (The Fold)",
             fr: "La Louverie (The Fold)",
+            ..Default::default()
         },
         550 => LocalisedText {
             en: "The Fold",
             ja: "ザ・フォールド",
             de: "Wolfspferch (The Fold)",
             fr: "La Louverie (The Fold)",
+            ..Default::default()
         },
         551 => LocalisedText {
             en: "The Fold",
             ja: "ザ・フォールド",
             de: "Wolfspferch (The Fold)",
             fr: "La Louverie (The Fold)",
+            ..Default::default()
         },
         552 => LocalisedText {
             en: "Western La Noscea",
             ja: "西ラノシア",
             de: "Westliches La Noscea",
             fr: "Noscea occidentale",
+            ..Default::default()
         },
         553 => LocalisedText {
             en: "Alexander",
             ja: "機工城アレキサンダー",
             de: "Alexander",
             fr: "Alexander",
+            ..Default::default()
         },
         554 => LocalisedText {
             en: "The Fields of Glory",
             ja: "フィールド・オブ・グローリー",
             de: "Feld der Ehre",
             fr: "Champs de la Gloire",
+            ..Default::default()
         },
         556 => LocalisedText {
             en: "The Weeping City of Mhach",
             ja: "禁忌都市マハ",
             de: "Die Stadt der Tränen",
             fr: "Cité défendue de Mhach",
+            ..Default::default()
         },
         557 => LocalisedText {
             en: "Hullbreaker Isle",
             ja: "ハルブレーカー・アイル",
             de: "Schiffbrecher-Insel",
             fr: "Île de Crèvecarène",
+            ..Default::default()
         },
         558 => LocalisedText {
             en: "The Aquapolis",
             ja: "アクアポリス",
             de: "Aquapolis",
             fr: "Aquapole",
+            ..Default::default()
         },
         559 => LocalisedText {
             en: "Steps of Faith",
             ja: "雲廊",
             de: "Schicksalsweg",
             fr: "Avancée de la Foi",
+            ..Default::default()
         },
         560 => LocalisedText {
             en: "Aetherochemical Research Facility",
             ja: "魔科学研究所",
             de: "Ätherochemisches Forschungslabor",
             fr: "Laboratoire de magismologie",
+            ..Default::default()
         },
         561 => LocalisedText {
             en: "The Palace of the Dead",
             ja: "死者の宮殿",
             de: "Palast der Toten",
             fr: "Palais des morts",
+            ..Default::default()
         },
         562 => LocalisedText {
             en: "The Palace of the Dead",
             ja: "死者の宮殿",
             de: "Palast der Toten",
             fr: "Palais des morts",
+            ..Default::default()
         },
         563 => LocalisedText {
             en: "The Palace of the Dead",
             ja: "死者の宮殿",
             de: "Palast der Toten",
             fr: "Palais des morts",
+            ..Default::default()
         },
         564 => LocalisedText {
             en: "The Palace of the Dead",
             ja: "死者の宮殿",
             de: "Palast der Toten",
             fr: "Palais des morts",
+            ..Default::default()
         },
         565 => LocalisedText {
             en: "The Palace of the Dead",
             ja: "死者の宮殿",
             de: "Palast der Toten",
             fr: "Palais des morts",
+            ..Default::default()
         },
         566 => LocalisedText {
             en: "Steps of Faith",
             ja: "雲廊",
             de: "Schicksalsweg",
             fr: "Avancée de la Foi",
+            ..Default::default()
         },
         567 => LocalisedText {
             en: "The Parrock",
             ja: "パロック繋留基地",
             de: "Krähennest",
             fr: "Le Paddock",
+            ..Default::default()
         },
         568 => LocalisedText {
             en: "Leofard's Chambers",
             ja: "レオファードの私室",
             de: "Leofards Zimmer",
             fr: "Cabine de Leofard",
+            ..Default::default()
         },
         569 => LocalisedText {
             en: "Steps of Faith",
             ja: "雲廊",
             de: "Schicksalsweg",
             fr: "Avancée de la Foi",
+            ..Default::default()
         },
         570 => LocalisedText {
             en: "The Palace of the Dead",
             ja: "死者の宮殿",
             de: "Palast der Toten",
             fr: "Palais des morts",
+            ..Default::default()
         },
         571 => LocalisedText {
             en: "Haunted Manor",
             ja: "亡霊屋敷 ホーンテッドマナー",
             de: "Geisterschloss",
             fr: "Manoir hanté",
+            ..Default::default()
         },
         573 => LocalisedText {
             en: "Topmast Apartment Lobby",
             ja: "トップマスト：ロビー",
             de: "Fockmast (Eingangshalle)",
             fr: "Le Mât de hune (hall)",
+            ..Default::default()
         },
         574 => LocalisedText {
             en: "Lily Hills Apartment Lobby",
             ja: "リリーヒルズ：ロビー",
             de: "Lilienhügel (Eingangshalle)",
             fr: "Plaine-aux-Lys (hall)",
+            ..Default::default()
         },
         575 => LocalisedText {
             en: "Sultana's Breath Apartment Lobby",
             ja: "ナナモ大風車：ロビー",
             de: "Nanamo-Windrad (Eingangshalle)",
             fr: "Le Souffle de la Sultane (hall)",
+            ..Default::default()
         },
         576 => LocalisedText {
             en: "Containment Bay P1T6",
             ja: "コンテイメントベイP1T6",
             de: "Arretur P1T6",
             fr: "Unité de contention P1P6",
+            ..Default::default()
         },
         577 => LocalisedText {
             en: "Containment Bay P1T6",
             ja: "コンテイメントベイP1T6",
             de: "Arretur P1T6",
             fr: "Unité de contention P1P6",
+            ..Default::default()
         },
         578 => LocalisedText {
             en: "The Great Gubal Library",
             ja: "グブラ幻想図書館",
             de: "Große Gubal-Bibliothek",
             fr: "Grande bibliothèque de Gubal",
+            ..Default::default()
         },
         579 => LocalisedText {
             en: "The Battlehall",
             ja: "カードバトルルーム",
             de: "Weltensalon",
             fr: "Arène Triple Triade",
+            ..Default::default()
         },
         580 => LocalisedText {
             en: "Eyes of the Creator",
             ja: "アイズ・オブ・アレキサンダー",
             de: "Augen des Schöpfers",
             fr: "Les Yeux du Créateur",
+            ..Default::default()
         },
         581 => LocalisedText {
             en: "Breath of the Creator",
             ja: "ブレス・オブ・アレキサンダー",
             de: "Atem des Schöpfers",
             fr: "Le Souffle du Créateur",
+            ..Default::default()
         },
         582 => LocalisedText {
             en: "Heart of the Creator",
             ja: "ハート・オブ・アレキサンダー",
             de: "Herz des Schöpfers",
             fr: "Le Cœur du Créateur",
+            ..Default::default()
         },
         583 => LocalisedText {
             en: "Soul of the Creator",
             ja: "ソウル・オブ・アレキサンダー",
             de: "Seele des Schöpfers",
             fr: "L'Âme du Créateur",
+            ..Default::default()
         },
         584 => LocalisedText {
             en: "Eyes of the Creator",
             ja: "アイズ・オブ・アレキサンダー",
             de: "Augen des Schöpfers",
             fr: "Les Yeux du Créateur",
+            ..Default::default()
         },
         585 => LocalisedText {
             en: "Breath of the Creator",
             ja: "ブレス・オブ・アレキサンダー",
             de: "Atem des Schöpfers",
             fr: "Le Souffle du Créateur",
+            ..Default::default()
         },
         586 => LocalisedText {
             en: "Heart of the Creator",
             ja: "ハート・オブ・アレキサンダー",
             de: "Herz des Schöpfers",
             fr: "Le Cœur du Créateur",
+            ..Default::default()
         },
         587 => LocalisedText {
             en: "Soul of the Creator",
             ja: "ソウル・オブ・アレキサンダー",
             de: "Seele des Schöpfers",
             fr: "L'Âme du Créateur",
+            ..Default::default()
         },
         588 => LocalisedText {
             en: "Heart of the Creator",
             ja: "ハート・オブ・アレキサンダー",
             de: "Herz des Schöpfers",
             fr: "Le Cœur du Créateur",
+            ..Default::default()
         },
         589 => LocalisedText {
             en: "Chocobo Square",
             ja: "チョコボスクウェア",
             de: "Chocobo-Platz",
             fr: "Square des chocobos",
+            ..Default::default()
         },
         590 => LocalisedText {
             en: "Chocobo Square",
             ja: "チョコボスクウェア",
             de: "Chocobo-Platz",
             fr: "Square des chocobos",
+            ..Default::default()
         },
         591 => LocalisedText {
             en: "Chocobo Square",
             ja: "チョコボスクウェア",
             de: "Chocobo-Platz",
             fr: "Square des chocobos",
+            ..Default::default()
         },
         592 => LocalisedText {
             en: "Bowl of Embers",
             ja: "炎帝祭跡",
             de: "Das Grab der Lohe",
             fr: "Cratère des tisons",
+            ..Default::default()
         },
         593 => LocalisedText {
             en: "The Palace of the Dead",
             ja: "死者の宮殿",
             de: "Palast der Toten",
             fr: "Palais des morts",
+            ..Default::default()
         },
         594 => LocalisedText {
             en: "The Palace of the Dead",
             ja: "死者の宮殿",
             de: "Palast der Toten",
             fr: "Palais des morts",
+            ..Default::default()
         },
         595 => LocalisedText {
             en: "The Palace of the Dead",
             ja: "死者の宮殿",
             de: "Palast der Toten",
             fr: "Palais des morts",
+            ..Default::default()
         },
         596 => LocalisedText {
             en: "The Palace of the Dead",
             ja: "死者の宮殿",
             de: "Palast der Toten",
             fr: "Palais des morts",
+            ..Default::default()
         },
         597 => LocalisedText {
             en: "The Palace of the Dead",
             ja: "死者の宮殿",
             de: "Palast der Toten",
             fr: "Palais des morts",
+            ..Default::default()
         },
         598 => LocalisedText {
             en: "The Palace of the Dead",
             ja: "死者の宮殿",
             de: "Palast der Toten",
             fr: "Palais des morts",
+            ..Default::default()
         },
         599 => LocalisedText {
             en: "The Palace of the Dead",
             ja: "死者の宮殿",
             de: "Palast der Toten",
             fr: "Palais des morts",
+            ..Default::default()
         },
         600 => LocalisedText {
             en: "The Palace of the Dead",
             ja: "死者の宮殿",
             de: "Palast der Toten",
             fr: "Palais des morts",
+            ..Default::default()
         },
         601 => LocalisedText {
             en: "The Palace of the Dead",
             ja: "死者の宮殿",
             de: "Palast der Toten",
             fr: "Palais des morts",
+            ..Default::default()
         },
         602 => LocalisedText {
             en: "The Palace of the Dead",
             ja: "死者の宮殿",
             de: "Palast der Toten",
             fr: "Palais des morts",
+            ..Default::default()
         },
         603 => LocalisedText {
             en: "The Palace of the Dead",
             ja: "死者の宮殿",
             de: "Palast der Toten",
             fr: "Palais des morts",
+            ..Default::default()
         },
         604 => LocalisedText {
             en: "The Palace of the Dead",
             ja: "死者の宮殿",
             de: "Palast der Toten",
             fr: "Palais des morts",
+            ..Default::default()
         },
         605 => LocalisedText {
             en: "The Palace of the Dead",
             ja: "死者の宮殿",
             de: "Palast der Toten",
             fr: "Palais des morts",
+            ..Default::default()
         },
         606 => LocalisedText {
             en: "The Palace of the Dead",
             ja: "死者の宮殿",
             de: "Palast der Toten",
             fr: "Palais des morts",
+            ..Default::default()
         },
         607 => LocalisedText {
             en: "The Palace of the Dead",
             ja: "死者の宮殿",
             de: "Palast der Toten",
             fr: "Palais des morts",
+            ..Default::default()
         },
         608 => LocalisedText {
             en: "Topmast Apartment",
             ja: "トップマスト：部屋",
             de: "Fockmast (Wohnung)",
             fr: "Le Mât de hune (appartement)",
+            ..Default::default()
         },
         609 => LocalisedText {
             en: "Lily Hills Apartment",
             ja: "リリーヒルズ：部屋",
             de: "Lilienhügel (Wohnung)",
             fr: "Plaine-aux-Lys (appartement)",
+            ..Default::default()
         },
         610 => LocalisedText {
             en: "Sultana's Breath Apartment",
             ja: "ナナモ大風車：部屋",
             de: "Nanamo-Windrad (Wohnung)",
             fr: "Le Souffle de la Sultane (appartement)",
+            ..Default::default()
         },
         611 => LocalisedText {
             en: "Frondale's Home for Friendless Foundlings",
             ja: "フロンデール薬学院小児病棟",
             de: "Frondales Physiatrische Akademie",
             fr: "Clinique pédiatrique de l'Académie médicale de Frondale",
+            ..Default::default()
         },
         612 => LocalisedText {
             en: "The Fringes",
             ja: "ギラバニア辺境地帯",
             de: "Abanisches Grenzland",
             fr: "Les Marges",
+            ..Default::default()
         },
         613 => LocalisedText {
             en: "The Ruby Sea",
             ja: "紅玉海",
             de: "Rubinsee",
             fr: "Mer de Rubis",
+            ..Default::default()
         },
         614 => LocalisedText {
             en: "Yanxia",
             ja: "ヤンサ",
             de: "Yanxia",
             fr: "Yanxia",
+            ..Default::default()
         },
         616 => LocalisedText {
             en: "Shisui of the Violet Tides",
             ja: "紫水宮",
             de: "Shisui-Palast",
             fr: "Palais aux Marées violettes",
+            ..Default::default()
         },
         617 => LocalisedText {
             en: "Sohm Al",
             ja: "ソーム・アル",
             de: "Sohm Al",
             fr: "Sohm Al",
+            ..Default::default()
         },
         620 => LocalisedText {
             en: "The Peaks",
             ja: "ギラバニア山岳地帯",
             de: "Die Zinnen",
             fr: "Les Pics",
+            ..Default::default()
         },
         621 => LocalisedText {
             en: "The Lochs",
             ja: "ギラバニア湖畔地帯",
             de: "Das Fenn",
             fr: "Les Lacs",
+            ..Default::default()
         },
         622 => LocalisedText {
             en: "The Azim Steppe",
             ja: "アジムステップ",
             de: "Azim-Steppe",
             fr: "Steppe d'Azim",
+            ..Default::default()
         },
         624 => LocalisedText {
             en: "The Diadem",
             ja: "ディアデム諸島",
             de: "Das Diadem",
             fr: "Le Diadème",
+            ..Default::default()
         },
         625 => LocalisedText {
             en: "The Diadem",
             ja: "ディアデム諸島",
             de: "Das Diadem",
             fr: "Le Diadème",
+            ..Default::default()
         },
         627 => LocalisedText {
             en: "Dun Scaith",
             ja: "影の国ダン・スカー",
             de: "Dun Scaith",
             fr: "Dun Scaith",
+            ..Default::default()
         },
         628 => LocalisedText {
             en: "Kugane",
             ja: "クガネ",
             de: "Kugane",
             fr: "Kugane",
+            ..Default::default()
         },
         629 => LocalisedText {
             en: "Bokairo Inn",
             ja: "望海楼",
             de: "Badehaus Bokairo",
             fr: "Auberge du Bokairo",
+            ..Default::default()
         },
         633 => LocalisedText {
             en: "Carteneau Flats: Borderland Ruins",
             ja: "カルテノー平原：外縁遺跡群",
             de: "Äußere Ruinen",
             fr: "Ruines frontalières",
+            ..Default::default()
         },
         634 => LocalisedText {
             en: "Yanxia",
             ja: "ヤンサ",
             de: "Yanxia",
             fr: "Yanxia",
+            ..Default::default()
         },
         635 => LocalisedText {
             en: "Rhalgr's Reach",
             ja: "ラールガーズリーチ",
             de: "Rhalgrs Wacht",
             fr: "L'Étendue de Rhalgr",
+            ..Default::default()
         },
         636 => LocalisedText {
             en: "Omega Control",
             ja: "オメガ管制室",
             de: "Omega-Kontrollraum",
             fr: "Salle de contrôle d'Oméga",
+            ..Default::default()
         },
         637 => LocalisedText {
             en: "Containment Bay Z1T9",
             ja: "コンテイメントベイZ1T9",
             de: "Arretur Z1T9",
             fr: "Unité de contention Z1P9",
+            ..Default::default()
         },
         638 => LocalisedText {
             en: "Containment Bay Z1T9",
             ja: "コンテイメントベイZ1T9",
             de: "Arretur Z1T9",
             fr: "Unité de contention Z1P9",
+            ..Default::default()
         },
         639 => LocalisedText {
             en: "Ruby Bazaar Offices",
             ja: "ウルダハ商館応接室",
             de: "Handelshaus-Sitzungsraum",
             fr: "Salle d'accueil du Bazar de Rubis",
+            ..Default::default()
         },
         640 => LocalisedText {
             en: "The Fringes",
             ja: "ギラバニア辺境地帯",
             de: "Abanisches Grenzland",
             fr: "Les Marges",
+            ..Default::default()
         },
         641 => LocalisedText {
             en: "Shirogane",
             ja: "シロガネ",
             de: "Shirogane",
             fr: "Shirogane",
+            ..Default::default()
         },
         647 => LocalisedText {
             en: "The Fringes",
             ja: "ギラバニア辺境地帯",
             de: "Abanisches Grenzland",
             fr: "Les Marges",
+            ..Default::default()
         },
         648 => LocalisedText {
             en: "The Fringes",
             ja: "ギラバニア辺境地帯",
             de: "Abanisches Grenzland",
             fr: "Les Marges",
+            ..Default::default()
         },
         649 => LocalisedText {
             en: "Private Cottage - Shirogane",
             ja: "シロガネ：コテージ",
             de: "Privathütte (Shirogane)",
             fr: "Maisonnette - Shirogane",
+            ..Default::default()
         },
         650 => LocalisedText {
             en: "Private House - Shirogane",
             ja: "シロガネ：ハウス",
             de: "Privathaus (Shirogane)",
             fr: "Pavillon - Shirogane",
+            ..Default::default()
         },
         651 => LocalisedText {
             en: "Private Mansion - Shirogane",
             ja: "シロガネ：レジデンス",
             de: "Privatresidenz (Shirogane)",
             fr: "Villa - Shirogane",
+            ..Default::default()
         },
         652 => LocalisedText {
             en: "Private Chambers - Shirogane",
             ja: "シロガネ：プライベートルーム",
             de: "Shirogane - Zimmer",
             fr: "Chambre individuelle - Shirogane",
+            ..Default::default()
         },
         653 => LocalisedText {
             en: "Company Workshop - Shirogane",
             ja: "シロガネ：地下工房",
             de: "Gesellschaftswerkstätte: Shirogane",
             fr: "Atelier de compagnie - Shirogane",
+            ..Default::default()
         },
         654 => LocalisedText {
             en: "Kobai Goten Apartment Lobby",
             ja: "紅梅御殿：ロビー",
             de: "Kobai Goten (Eingangshalle)",
             fr: "Kobai Goten (hall)",
+            ..Default::default()
         },
         655 => LocalisedText {
             en: "Kobai Goten Apartment",
             ja: "紅梅御殿：部屋",
             de: "Kobai Goten (Wohnung)",
             fr: "Kobai Goten (appartement)",
+            ..Default::default()
         },
         656 => LocalisedText {
             en: "The Diadem",
             ja: "ディアデム諸島",
             de: "Das Diadem",
             fr: "Le Diadème",
+            ..Default::default()
         },
         657 => LocalisedText {
             en: "The Ruby Sea",
             ja: "紅玉海",
             de: "Rubinsee",
             fr: "Mer de Rubis",
+            ..Default::default()
         },
         658 => LocalisedText {
             en: "The Interdimensional Rift",
             ja: "次元の狭間",
             de: "Interdimensionaler Riss",
             fr: "Fissure interdimensionnelle",
+            ..Default::default()
         },
         659 => LocalisedText {
             en: "Rhalgr's Reach",
             ja: "ラールガーズリーチ",
             de: "Rhalgrs Wacht",
             fr: "L'Étendue de Rhalgr",
+            ..Default::default()
         },
         662 => LocalisedText {
             en: "Kugane Castle",
             ja: "クガネ城",
             de: "Schloss Kugane",
             fr: "Château de Kugane",
+            ..Default::default()
         },
         663 => LocalisedText {
             en: "The Temple of the Fist",
             ja: "星導山寺院",
             de: "Tempel der Faust",
             fr: "Temple du Poing",
+            ..Default::default()
         },
         664 => LocalisedText {
             en: "Kugane",
             ja: "クガネ",
             de: "Kugane",
             fr: "Kugane",
+            ..Default::default()
         },
         665 => LocalisedText {
             en: "Kugane",
             ja: "クガネ",
             de: "Kugane",
             fr: "Kugane",
+            ..Default::default()
         },
         666 => LocalisedText {
             en: "Ul'dah - Steps of Thal",
             ja: "ウルダハ：ザル回廊",
             de: "Thal-Kreuzgang",
             fr: "Ul'dah - Faubourg de Thal",
+            ..Default::default()
         },
         667 => LocalisedText {
             en: "Kugane",
             ja: "クガネ",
             de: "Kugane",
             fr: "Kugane",
+            ..Default::default()
         },
         668 => LocalisedText {
             en: "Eastern Thanalan",
             ja: "東ザナラーン",
             de: "Östliches Thanalan",
             fr: "Thanalan oriental",
+            ..Default::default()
         },
         669 => LocalisedText {
             en: "Southern Thanalan",
             ja: "南ザナラーン",
             de: "Südliches Thanalan",
             fr: "Thanalan méridional",
+            ..Default::default()
         },
         670 => LocalisedText {
             en: "The Fringes",
             ja: "ギラバニア辺境地帯",
             de: "Abanisches Grenzland",
             fr: "Les Marges",
+            ..Default::default()
         },
         671 => LocalisedText {
             en: "The Fringes",
             ja: "ギラバニア辺境地帯",
             de: "Abanisches Grenzland",
             fr: "Les Marges",
+            ..Default::default()
         },
         672 => LocalisedText {
             en: "Mor Dhona",
             ja: "モードゥナ",
             de: "Mor Dhona",
             fr: "Mor Dhona",
+            ..Default::default()
         },
         673 => LocalisedText {
             en: "Sohm Al",
             ja: "ソーム・アル",
             de: "Sohm Al",
             fr: "Sohm Al",
+            ..Default::default()
         },
         674 => LocalisedText {
             en: "The Blessed Treasury",
             ja: "宝物殿",
             de: "Schatzkammer",
             fr: "Crique aux tributs",
+            ..Default::default()
         },
         675 => LocalisedText {
             en: "Western La Noscea",
             ja: "西ラノシア",
             de: "Westliches La Noscea",
             fr: "Noscea occidentale",
+            ..Default::default()
         },
         676 => LocalisedText {
             en: "The Great Gubal Library",
             ja: "グブラ幻想図書館",
             de: "Große Gubal-Bibliothek",
             fr: "Grande bibliothèque de Gubal",
+            ..Default::default()
         },
         677 => LocalisedText {
             en: "The Blessed Treasury",
             ja: "宝物殿",
             de: "Schatzkammer",
             fr: "Crique aux tributs",
+            ..Default::default()
         },
         678 => LocalisedText {
             en: "The Fringes",
             ja: "ギラバニア辺境地帯",
             de: "Abanisches Grenzland",
             fr: "Les Marges",
+            ..Default::default()
         },
         679 => LocalisedText {
             en: "The Royal Airship Landing",
             ja: "アラミゴ王立飛空艇発着場",
             de: "Königlicher Luftschiff-Landeplatz",
             fr: "Aérodrome royal",
+            ..Default::default()
         },
         680 => LocalisedText {
             en: "The Misery",
             ja: "ミゼリー号",
             de: "Die Misery",
             fr: "Le Misère",
+            ..Default::default()
         },
         681 => LocalisedText {
             en: "The House of the Fierce",
             ja: "烈士庵",
             de: "Klause der Grimmigen",
             fr: "Cercle des Intrépides",
+            ..Default::default()
         },
         682 => LocalisedText {
             en: "The Doman Enclave",
             ja: "ドマ町人地",
             de: "Domanische Enklave",
             fr: "Quartier enclavé de Doma",
+            ..Default::default()
         },
         683 => LocalisedText {
             en: "The First Altar of Djanan Qhat",
             ja: "ジャナン・カット地下祭壇",
             de: "Altar von Djanan Qhat",
             fr: "Grand autel de Djanan Qhat",
+            ..Default::default()
         },
         684 => LocalisedText {
             en: "The Lochs",
             ja: "ギラバニア湖畔地帯",
             de: "Das Fenn",
             fr: "Les Lacs",
+            ..Default::default()
         },
         685 => LocalisedText {
             en: "Yanxia",
             ja: "ヤンサ",
             de: "Yanxia",
             fr: "Yanxia",
+            ..Default::default()
         },
         686 => LocalisedText {
             en: "The Lochs",
             ja: "ギラバニア湖畔地帯",
             de: "Das Fenn",
             fr: "Les Lacs",
+            ..Default::default()
         },
         687 => LocalisedText {
             en: "The Lochs",
             ja: "ギラバニア湖畔地帯",
             de: "Das Fenn",
             fr: "Les Lacs",
+            ..Default::default()
         },
         688 => LocalisedText {
             en: "The Azim Steppe",
             ja: "アジムステップ",
             de: "Azim-Steppe",
             fr: "Steppe d'Azim",
+            ..Default::default()
         },
         690 => LocalisedText {
             en: "The Interdimensional Rift",
             ja: "次元の狭間",
             de: "Interdimensionaler Riss",
             fr: "Fissure interdimensionnelle",
+            ..Default::default()
         },
         691 => LocalisedText {
             en: "Deltascape V1.0",
             ja: "被検世界「デルタ」V1.0",
             de: "Deltametrie 1.0",
             fr: "Deltastice v1.0",
+            ..Default::default()
         },
         692 => LocalisedText {
             en: "Deltascape V2.0",
             ja: "被検世界「デルタ」V2.0",
             de: "Deltametrie 2.0",
             fr: "Deltastice v2.0",
+            ..Default::default()
         },
         693 => LocalisedText {
             en: "Deltascape V3.0",
             ja: "被検世界「デルタ」V3.0",
             de: "Deltametrie 3.0",
             fr: "Deltastice v3.0",
+            ..Default::default()
         },
         694 => LocalisedText {
             en: "Deltascape V4.0",
             ja: "被検世界「デルタ」V4.0",
             de: "Deltametrie 4.0",
             fr: "Deltastice v4.0",
+            ..Default::default()
         },
         695 => LocalisedText {
             en: "Deltascape V1.0",
             ja: "被検世界「デルタ」V1.0",
             de: "Deltametrie 1.0",
             fr: "Deltastice v1.0",
+            ..Default::default()
         },
         696 => LocalisedText {
             en: "Deltascape V2.0",
             ja: "被検世界「デルタ」V2.0",
             de: "Deltametrie 2.0",
             fr: "Deltastice v2.0",
+            ..Default::default()
         },
         697 => LocalisedText {
             en: "Deltascape V3.0",
             ja: "被検世界「デルタ」V3.0",
             de: "Deltametrie 3.0",
             fr: "Deltastice v3.0",
+            ..Default::default()
         },
         698 => LocalisedText {
             en: "Deltascape V4.0",
             ja: "被検世界「デルタ」V4.0",
             de: "Deltametrie 4.0",
             fr: "Deltastice v4.0",
+            ..Default::default()
         },
         699 => LocalisedText {
             en: "Coerthas Central Highlands",
             ja: "クルザス中央高地",
             de: "Zentrales Hochland von Coerthas",
             fr: "Hautes terres du Coerthas central",
+            ..Default::default()
         },
         700 => LocalisedText {
             en: "Foundation",
             ja: "イシュガルド：下層",
             de: "Fundamente",
             fr: "Ishgard - L'Assise",
+            ..Default::default()
         },
         701 => LocalisedText {
             en: "Seal Rock",
             ja: "シールロック",
             de: "Robbenholm",
             fr: "Rocher des tréfonds",
+            ..Default::default()
         },
         702 => LocalisedText {
             en: "Aetherochemical Research Facility",
             ja: "魔科学研究所",
             de: "Ätherochemisches Forschungslabor",
             fr: "Laboratoire de magismologie",
+            ..Default::default()
         },
         703 => LocalisedText {
             en: "The Fringes",
             ja: "ギラバニア辺境地帯",
             de: "Abanisches Grenzland",
             fr: "Les Marges",
+            ..Default::default()
         },
         704 => LocalisedText {
             en: "Dalamud's Shadow",
             ja: "メテオの陰地",
             de: "Meteoritenkrater",
             fr: "Ombrage du Météore",
+            ..Default::default()
         },
         705 => LocalisedText {
             en: "Ul'dah - Steps of Thal",
             ja: "ウルダハ：ザル回廊",
             de: "Thal-Kreuzgang",
             fr: "Ul'dah - Faubourg de Thal",
+            ..Default::default()
         },
         706 => LocalisedText {
             en: "Ul'dah - Steps of Thal",
             ja: "ウルダハ：ザル回廊",
             de: "Thal-Kreuzgang",
             fr: "Ul'dah - Faubourg de Thal",
+            ..Default::default()
         },
         707 => LocalisedText {
             en: "The Weeping City of Mhach",
             ja: "禁忌都市マハ",
             de: "Die Stadt der Tränen",
             fr: "Cité défendue de Mhach",
+            ..Default::default()
         },
         708 => LocalisedText {
             en: "Rhotano Sea",
             ja: "ロータノ海",
             de: "Rhotano-See",
             fr: "Mer de Rhotano",
+            ..Default::default()
         },
         709 => LocalisedText {
             en: "Coerthas Western Highlands",
             ja: "クルザス西部高地",
             de: "Westliches Hochland von Coerthas",
             fr: "Hautes terres du Coerthas occidental",
+            ..Default::default()
         },
         710 => LocalisedText {
             en: "Kugane",
             ja: "クガネ",
             de: "Kugane",
             fr: "Kugane",
+            ..Default::default()
         },
         711 => LocalisedText {
             en: "The Ruby Sea",
             ja: "紅玉海",
             de: "Rubinsee",
             fr: "Mer de Rubis",
+            ..Default::default()
         },
         712 => LocalisedText {
             en: "The Lost Canals of Uznair",
             ja: "ウズネアカナル",
             de: "Kanäle von Uznair",
             fr: "Canaux perdus d'Uznair",
+            ..Default::default()
         },
         713 => LocalisedText {
             en: "The Azim Steppe",
             ja: "アジムステップ",
             de: "Azim-Steppe",
             fr: "Steppe d'Azim",
+            ..Default::default()
         },
         714 => LocalisedText {
             en: "Bardam's Mettle",
             ja: "バルダム覇道",
             de: "Bardams Probe",
             fr: "Force de Bardam",
+            ..Default::default()
         },
         715 => LocalisedText {
             en: "The Churning Mists",
             ja: "ドラヴァニア雲海",
             de: "Wallende Nebel",
             fr: "L'Écume des cieux de Dravania",
+            ..Default::default()
         },
         716 => LocalisedText {
             en: "The Peaks",
             ja: "ギラバニア山岳地帯",
             de: "Die Zinnen",
             fr: "Les Pics",
+            ..Default::default()
         },
         717 => LocalisedText {
             en: "Wolves' Den Pier",
             ja: "ウルヴズジェイル係船場",
             de: "Wolfshöhlen-Pier",
             fr: "Jetée de l'Antre des loups",
+            ..Default::default()
         },
         718 => LocalisedText {
             en: "The Azim Steppe",
             ja: "アジムステップ",
             de: "Azim-Steppe",
             fr: "Steppe d'Azim",
+            ..Default::default()
         },
         719 => LocalisedText {
             en: "Emanation",
             ja: "美神の地下神殿",
             de: "Heiligtum der Qalyana",
             fr: "Émanation",
+            ..Default::default()
         },
         720 => LocalisedText {
             en: "Emanation",
             ja: "美神の地下神殿",
             de: "Heiligtum der Qalyana",
             fr: "Émanation",
+            ..Default::default()
         },
         721 => LocalisedText {
             en: "Amdapor Keep",
             ja: "古城アムダプール",
             de: "Ruinen von Amdapor",
             fr: "Château d'Amdapor",
+            ..Default::default()
         },
         722 => LocalisedText {
             en: "The Lost City of Amdapor",
             ja: "古アムダプール市街",
             de: "Historisches Amdapor",
             fr: "Vestiges de la cité d'Amdapor",
+            ..Default::default()
         },
         723 => LocalisedText {
             en: "The Azim Steppe",
             ja: "アジムステップ",
             de: "Azim-Steppe",
             fr: "Steppe d'Azim",
+            ..Default::default()
         },
         724 => LocalisedText {
             en: "The Interdimensional Rift",
             ja: "次元の狭間",
             de: "Interdimensionaler Riss",
             fr: "Fissure interdimensionnelle",
+            ..Default::default()
         },
         725 => LocalisedText {
             en: "The Lost Canals of Uznair",
             ja: "ウズネアカナル",
             de: "Kanäle von Uznair",
             fr: "Canaux perdus d'Uznair",
+            ..Default::default()
         },
         726 => LocalisedText {
             en: "The Ruby Sea",
             ja: "紅玉海",
             de: "Rubinsee",
             fr: "Mer de Rubis",
+            ..Default::default()
         },
         727 => LocalisedText {
             en: "The Royal Menagerie",
             ja: "アラミゴ王宮屋上庭園",
             de: "Königliche Menagerie",
             fr: "Ménagerie royale",
+            ..Default::default()
         },
         728 => LocalisedText {
             en: "Mordion Gaol",
             ja: "モルディオン監獄",
             de: "Mordion-Kerker",
             fr: "Geôle de Mordion",
+            ..Default::default()
         },
         729 => LocalisedText {
             en: "Astragalos",
             ja: "アストラガロス",
             de: "Astragalos",
             fr: "Astragalos",
+            ..Default::default()
         },
         730 => LocalisedText {
             en: "Transparency",
             ja: "結晶化空間",
             de: "Raum der Transparenz",
             fr: "Transparence",
+            ..Default::default()
         },
         732 => LocalisedText {
             en: "Eureka Anemos",
             ja: "エウレカ：アネモス帯",
             de: "Eureka Anemos",
             fr: "Eurêka Anemos",
+            ..Default::default()
         },
         733 => LocalisedText {
             en: "The Binding Coil of Bahamut",
             ja: "大迷宮バハムート",
             de: "Die Verschlungenen Schatten von Bahamut",
             fr: "Abîme infini de Bahamut",
+            ..Default::default()
         },
         734 => LocalisedText {
             en: "The Royal City of Rabanastre",
             ja: "失われた都 ラバナスタ",
             de: "Die königliche Stadt Rabanastre",
             fr: "Cité royale de Rabanastre",
+            ..Default::default()
         },
         735 => LocalisedText {
             en: "The Prima Vista Tiring Room",
             ja: "劇場艇プリマビスタ・大道具部屋",
             de: "Theaterwerkstatt",
             fr: "Salle de répétition du Prima Vista",
+            ..Default::default()
         },
         736 => LocalisedText {
             en: "The Prima Vista Bridge",
             ja: "劇場艇プリマビスタ・ブリッジ",
             de: "Brücke",
             fr: "Pont du Prima Vista",
+            ..Default::default()
         },
         737 => LocalisedText {
             en: "Royal Palace",
             ja: "アラミゴ王宮",
             de: "Königlicher Palast",
             fr: "Palais royal d'Ala Mhigo",
+            ..Default::default()
         },
         738 => LocalisedText {
             en: "The Resonatorium",
             ja: "超越技術研究所",
             de: "Transzendenz-Laboratorium",
             fr: "Laboratoire impérial d'étude sur les Résonnants",
+            ..Default::default()
         },
         739 => LocalisedText {
             en: "The Doman Enclave",
             ja: "ドマ町人地",
             de: "Domanische Enklave",
             fr: "Quartier enclavé de Doma",
+            ..Default::default()
         },
         740 => LocalisedText {
             en: "The Royal Menagerie",
             ja: "アラミゴ王宮屋上庭園",
             de: "Königliche Menagerie",
             fr: "Ménagerie royale",
+            ..Default::default()
         },
         741 => LocalisedText {
             en: "Sanctum of the Twelve",
             ja: "十二神大聖堂",
             de: "Dom der Einkehr",
             fr: "Sanctuaire des Douze",
+            ..Default::default()
         },
         742 => LocalisedText {
             en: "Hells' Lid",
             ja: "獄之蓋",
             de: "Höllenspund",
             fr: "Couvercle des enfers",
+            ..Default::default()
         },
         743 => LocalisedText {
             en: "The Fractal Continuum",
             ja: "フラクタル・コンティニアム",
             de: "Die Fraktal-Kontinuum",
             fr: "Le Continuum fractal",
+            ..Default::default()
         },
         744 => LocalisedText {
             en: "Kienkan",
             ja: "帰燕館",
             de: "Kienkan",
             fr: "Kienkan",
+            ..Default::default()
         },
         746 => LocalisedText {
             en: "The Jade Stoa",
             ja: "白帝の竹林",
             de: "Jadelichtung",
             fr: "Clairière de Jade",
+            ..Default::default()
         },
         748 => LocalisedText {
             en: "Sigmascape V1.0",
             ja: "被検世界「シグマ」V1.0",
             de: "Sigmametrie 1.0",
             fr: "Sigmastice v1.0",
+            ..Default::default()
         },
         749 => LocalisedText {
             en: "Sigmascape V2.0",
             ja: "被検世界「シグマ」V2.0",
             de: "Sigmametrie 2.0",
             fr: "Sigmastice v2.0",
+            ..Default::default()
         },
         750 => LocalisedText {
             en: "Sigmascape V3.0",
             ja: "被検世界「シグマ」V3.0",
             de: "Sigmametrie 3.0",
             fr: "Sigmastice v3.0",
+            ..Default::default()
         },
         751 => LocalisedText {
             en: "Sigmascape V4.0",
             ja: "被検世界「シグマ」V4.0",
             de: "Sigmametrie 4.0",
             fr: "Sigmastice v4.0",
+            ..Default::default()
         },
         752 => LocalisedText {
             en: "Sigmascape V1.0",
             ja: "被検世界「シグマ」V1.0",
             de: "Sigmametrie 1.0",
             fr: "Sigmastice v1.0",
+            ..Default::default()
         },
         753 => LocalisedText {
             en: "Sigmascape V2.0",
             ja: "被検世界「シグマ」V2.0",
             de: "Sigmametrie 2.0",
             fr: "Sigmastice v2.0",
+            ..Default::default()
         },
         754 => LocalisedText {
             en: "Sigmascape V3.0",
             ja: "被検世界「シグマ」V3.0",
             de: "Sigmametrie 3.0",
             fr: "Sigmastice v3.0",
+            ..Default::default()
         },
         755 => LocalisedText {
             en: "Sigmascape V4.0",
             ja: "被検世界「シグマ」V4.0",
             de: "Sigmametrie 4.0",
             fr: "Sigmastice v4.0",
+            ..Default::default()
         },
         756 => LocalisedText {
             en: "The Interdimensional Rift",
             ja: "次元の狭間",
             de: "Interdimensionaler Riss",
             fr: "Fissure interdimensionnelle",
+            ..Default::default()
         },
         757 => LocalisedText {
             en: "The Ruby Sea",
             ja: "紅玉海",
             de: "Rubinsee",
             fr: "Mer de Rubis",
+            ..Default::default()
         },
         758 => LocalisedText {
             en: "The Jade Stoa",
             ja: "白帝の竹林",
             de: "Jadelichtung",
             fr: "Clairière de Jade",
+            ..Default::default()
         },
         759 => LocalisedText {
             en: "The Doman Enclave",
             ja: "ドマ町人地",
             de: "Domanische Enklave",
             fr: "Quartier enclavé de Doma",
+            ..Default::default()
         },
         760 => LocalisedText {
             en: "The Fringes",
             ja: "ギラバニア辺境地帯",
             de: "Abanisches Grenzland",
             fr: "Les Marges",
+            ..Default::default()
         },
         761 => LocalisedText {
             en: "The Great Hunt",
             ja: "大草原の狩場",
             de: "Revier des Rathalos",
             fr: "Domaine du roi des cieux",
+            ..Default::default()
         },
         762 => LocalisedText {
             en: "The Great Hunt",
             ja: "大草原の狩場",
             de: "Revier des Rathalos",
             fr: "Domaine du roi des cieux",
+            ..Default::default()
         },
         763 => LocalisedText {
             en: "Eureka Pagos",
             ja: "エウレカ：パゴス帯",
             de: "Eureka Pagos",
             fr: "Eurêka Pagos",
+            ..Default::default()
         },
         764 => LocalisedText {
             en: "Reisen Temple",
             ja: "醴泉神社境内",
             de: "Reisen-Schrein",
             fr: "Sanctuaire Reisen",
+            ..Default::default()
         },
         768 => LocalisedText {
             en: "The Swallow's Compass",
             ja: "ガンエン廟",
             de: "Kompass der Schwalbe",
             fr: "Compas de l'Hirondelle",
+            ..Default::default()
         },
         769 => LocalisedText {
             en: "The Burn",
             ja: "ザ・バーン",
             de: "Kargland",
             fr: "L'Escarre",
+            ..Default::default()
         },
         770 => LocalisedText {
             en: "Heaven-on-High",
             ja: "アメノミハシラ",
             de: "Himmelssäule",
             fr: "Pilier des Cieux",
+            ..Default::default()
         },
         771 => LocalisedText {
             en: "Heaven-on-High",
             ja: "アメノミハシラ",
             de: "Himmelssäule",
             fr: "Pilier des Cieux",
+            ..Default::default()
         },
         772 => LocalisedText {
             en: "Heaven-on-High",
             ja: "アメノミハシラ",
             de: "Himmelssäule",
             fr: "Pilier des Cieux",
+            ..Default::default()
         },
         773 => LocalisedText {
             en: "Heaven-on-High",
             ja: "アメノミハシラ",
             de: "Himmelssäule",
             fr: "Pilier des Cieux",
+            ..Default::default()
         },
         774 => LocalisedText {
             en: "Heaven-on-High",
             ja: "アメノミハシラ",
             de: "Himmelssäule",
             fr: "Pilier des Cieux",
+            ..Default::default()
         },
         775 => LocalisedText {
             en: "Heaven-on-High",
             ja: "アメノミハシラ",
             de: "Himmelssäule",
             fr: "Pilier des Cieux",
+            ..Default::default()
         },
         776 => LocalisedText {
             en: "The Ridorana Lighthouse",
             ja: "封じられた聖塔 リドルアナ",
             de: "Richtfeuer von Ridorana",
             fr: "Le Phare de Ridorana",
+            ..Default::default()
         },
         777 => LocalisedText {
             en: "Ultimacy",
             ja: "禁絶幻想",
             de: "Endgültigkeit",
             fr: "Fantasmagorie d'Ultima",
+            ..Default::default()
         },
         778 => LocalisedText {
             en: "Castrum Fluminis",
             ja: "カストルム・フルーミニス",
             de: "Castrum Fluminis",
             fr: "Castrum Fluminis",
+            ..Default::default()
         },
         779 => LocalisedText {
             en: "Castrum Fluminis",
             ja: "カストルム・フルーミニス",
             de: "Castrum Fluminis",
             fr: "Castrum Fluminis",
+            ..Default::default()
         },
         780 => LocalisedText {
             en: "Heaven-on-High",
             ja: "アメノミハシラ",
             de: "Himmelssäule",
             fr: "Pilier des Cieux",
+            ..Default::default()
         },
         781 => LocalisedText {
             en: "Reisen Temple Road",
             ja: "醴泉神社参道",
             de: "Reisen-Schreinweg",
             fr: "Chemin du sanctuaire Reisen",
+            ..Default::default()
         },
         782 => LocalisedText {
             en: "Heaven-on-High",
             ja: "アメノミハシラ",
             de: "Himmelssäule",
             fr: "Pilier des Cieux",
+            ..Default::default()
         },
         783 => LocalisedText {
             en: "Heaven-on-High",
             ja: "アメノミハシラ",
             de: "Himmelssäule",
             fr: "Pilier des Cieux",
+            ..Default::default()
         },
         784 => LocalisedText {
             en: "Heaven-on-High",
             ja: "アメノミハシラ",
             de: "Himmelssäule",
             fr: "Pilier des Cieux",
+            ..Default::default()
         },
         785 => LocalisedText {
             en: "Heaven-on-High",
             ja: "アメノミハシラ",
             de: "Himmelssäule",
             fr: "Pilier des Cieux",
+            ..Default::default()
         },
         786 => LocalisedText {
             en: "Castrum Fluminis",
             ja: "カストルム・フルーミニス",
             de: "Castrum Fluminis",
             fr: "Castrum Fluminis",
+            ..Default::default()
         },
         787 => LocalisedText {
             en: "The Ridorana Cataract",
             ja: "リドルアナ大瀑布",
             de: "Ridorana-Katarakt",
             fr: "Cataractes de Ridorana",
+            ..Default::default()
         },
         788 => LocalisedText {
             en: "Saint Mocianne's Arboretum",
             ja: "聖モシャーヌ植物園",
             de: "Sankt Mocianne-Arboretum",
             fr: "Arboretum Sainte-Mocianne",
+            ..Default::default()
         },
         790 => LocalisedText {
             en: "Ul'dah - Steps of Nald",
             ja: "ウルダハ：ナル回廊",
             de: "Nald-Kreuzgang",
             fr: "Ul'dah - Faubourg de Nald",
+            ..Default::default()
         },
         791 => LocalisedText {
             en: "Hidden Gorge",
             ja: "ヒドゥンゴージ",
             de: "Verborgene Schlucht",
             fr: "Gorge dérobée",
+            ..Default::default()
         },
         792 => LocalisedText {
             en: "The Fall of Belah'dia",
             ja: "ベラフディア遺跡風特設アスレチック",
             de: "Belah'dias Fall",
             fr: "Chutes de Belah'dia",
+            ..Default::default()
         },
         794 => LocalisedText {
             en: "The Shifting Altars of Uznair",
             ja: "ウズネアカナル祭殿",
             de: "Halle der Glücksaltäre",
             fr: "Temple sacré d'Uznair",
+            ..Default::default()
         },
         795 => LocalisedText {
             en: "Eureka Pyros",
             ja: "エウレカ：ピューロス帯",
             de: "Eureka Pyros",
             fr: "Eurêka Pyros",
+            ..Default::default()
         },
         796 => LocalisedText {
             en: "Blue Sky",
             ja: "ブルースカイ",
             de: "Himmlische Arena",
             fr: "Ciel azur",
+            ..Default::default()
         },
         797 => LocalisedText {
             en: "The Azim Steppe",
             ja: "アジムステップ",
             de: "Azim-Steppe",
             fr: "Steppe d'Azim",
+            ..Default::default()
         },
         798 => LocalisedText {
             en: "Psiscape V1.0",
             ja: "被検世界「プサイ」V1.0",
             de: "Psimetrie 1.0",
             fr: "Psistice v1.0",
+            ..Default::default()
         },
         799 => LocalisedText {
             en: "Psiscape V2.0",
             ja: "被検世界「プサイ」V2.0",
             de: "Psimetrie 2.0",
             fr: "Psistice v2.0",
+            ..Default::default()
         },
         800 => LocalisedText {
             en: "The Interdimensional Rift",
             ja: "次元の狭間",
             de: "Interdimensionaler Riss",
             fr: "Fissure interdimensionnelle",
+            ..Default::default()
         },
         801 => LocalisedText {
             en: "The Interdimensional Rift",
             ja: "次元の狭間",
             de: "Interdimensionaler Riss",
             fr: "Fissure interdimensionnelle",
+            ..Default::default()
         },
         802 => LocalisedText {
             en: "Psiscape V1.0",
             ja: "被検世界「プサイ」V1.0",
             de: "Psimetrie 1.0",
             fr: "Psistice v1.0",
+            ..Default::default()
         },
         803 => LocalisedText {
             en: "Psiscape V2.0",
             ja: "被検世界「プサイ」V2.0",
             de: "Psimetrie 2.0",
             fr: "Psistice v2.0",
+            ..Default::default()
         },
         804 => LocalisedText {
             en: "The Interdimensional Rift",
             ja: "次元の狭間",
             de: "Interdimensionaler Riss",
             fr: "Fissure interdimensionnelle",
+            ..Default::default()
         },
         805 => LocalisedText {
             en: "The Interdimensional Rift",
             ja: "次元の狭間",
             de: "Interdimensionaler Riss",
             fr: "Fissure interdimensionnelle",
+            ..Default::default()
         },
         806 => LocalisedText {
             en: "Kugane Ohashi",
             ja: "クガネ大橋",
             de: "Kugane-Brücke",
             fr: "Pont Ohashi",
+            ..Default::default()
         },
         807 => LocalisedText {
             en: "The Interdimensional Rift",
             ja: "次元の狭間",
             de: "Interdimensionaler Riss",
             fr: "Fissure interdimensionnelle",
+            ..Default::default()
         },
         808 => LocalisedText {
             en: "The Interdimensional Rift",
             ja: "次元の狭間",
             de: "Interdimensionaler Riss",
             fr: "Fissure interdimensionnelle",
+            ..Default::default()
         },
         809 => LocalisedText {
             en: "Haunted Manor",
             ja: "亡霊屋敷 ホーンテッドマナー",
             de: "Geisterschloss",
             fr: "Manoir hanté",
+            ..Default::default()
         },
         810 => LocalisedText {
             en: "Hells' Kier",
             ja: "獄之底",
             de: "Höllengrund",
             fr: "Fond des enfers",
+            ..Default::default()
         },
         811 => LocalisedText {
             en: "Hells' Kier",
             ja: "獄之底",
             de: "Höllengrund",
             fr: "Fond des enfers",
+            ..Default::default()
         },
         812 => LocalisedText {
             en: "The Interdimensional Rift",
             ja: "次元の狭間",
             de: "Interdimensionaler Riss",
             fr: "Fissure interdimensionnelle",
+            ..Default::default()
         },
         813 => LocalisedText {
             en: "Lakeland",
             ja: "レイクランド",
             de: "Seenland",
             fr: "Grand-Lac",
+            ..Default::default()
         },
         814 => LocalisedText {
             en: "Kholusia",
             ja: "コルシア島",
             de: "Kholusia",
             fr: "Kholusia",
+            ..Default::default()
         },
         815 => LocalisedText {
             en: "Amh Araeng",
             ja: "アム・アレーン",
             de: "Amh Araeng",
             fr: "Amh Araeng",
+            ..Default::default()
         },
         816 => LocalisedText {
             en: "Il Mheg",
             ja: "イル・メグ",
             de: "Il Mheg",
             fr: "Il Mheg",
+            ..Default::default()
         },
         817 => LocalisedText {
             en: "The Rak'tika Greatwood",
             ja: "ラケティカ大森林",
             de: "Der Große Wald Rak'tika",
             fr: "Rak'tika",
+            ..Default::default()
         },
         818 => LocalisedText {
             en: "The Tempest",
             ja: "テンペスト",
             de: "Tempest",
             fr: "La Tempête",
+            ..Default::default()
         },
         819 => LocalisedText {
             en: "The Crystarium",
             ja: "クリスタリウム",
             de: "Crystarium",
             fr: "Cristarium",
+            ..Default::default()
         },
         820 => LocalisedText {
             en: "Eulmore",
             ja: "ユールモア",
             de: "Eulmore",
             fr: "Eulmore",
+            ..Default::default()
         },
         821 => LocalisedText {
             en: "Dohn Mheg",
             ja: "ドォーヌ・メグ",
             de: "Dohn Mheg",
             fr: "Dohn Mheg",
+            ..Default::default()
         },
         822 => LocalisedText {
             en: "Mt. Gulg",
             ja: "グルグ火山",
             de: "Gulg",
             fr: "Mont Gulg",
+            ..Default::default()
         },
         823 => LocalisedText {
             en: "The Qitana Ravel",
             ja: "キタンナ神影洞",
             de: "Irrungen der Qitari",
             fr: "Enchevêtrement des Qitari",
+            ..Default::default()
         },
         824 => LocalisedText {
             en: "The Wreath of Snakes",
             ja: "蛇神大社",
             de: "Heiligtum der Schlange",
             fr: "Sanctuaire du Grand Serpent",
+            ..Default::default()
         },
         825 => LocalisedText {
             en: "The Wreath of Snakes",
             ja: "蛇神大社",
             de: "Heiligtum der Schlange",
             fr: "Sanctuaire du Grand Serpent",
+            ..Default::default()
         },
         826 => LocalisedText {
             en: "The Orbonne Monastery",
             ja: "楽欲の僧院 オーボンヌ",
             de: "Kloster von Orbonne",
             fr: "Monastère d'Orbonne",
+            ..Default::default()
         },
         827 => LocalisedText {
             en: "Eureka Hydatos",
             ja: "エウレカ：ヒュダトス帯",
             de: "Eureka Hydatos",
             fr: "Eurêka Hydatos",
+            ..Default::default()
         },
         828 => LocalisedText {
             en: "The Prima Vista Tiring Room",
             ja: "劇場艇プリマビスタ・大道具部屋",
             de: "Theaterwerkstatt",
             fr: "Salle de répétition du Prima Vista",
+            ..Default::default()
         },
         829 => LocalisedText {
             en: "Eorzean Alliance Headquarters",
             ja: "エオルゼア同盟軍本陣",
             de: "Hauptquartier der Allianz",
             fr: "Quartier général de l'Alliance",
+            ..Default::default()
         },
         830 => LocalisedText {
             en: "The Ghimlyt Dark",
             ja: "ギムリトダーク",
             de: "Ghimlyt-Finsternis",
             fr: "Les Ténèbres de Ghimlyt",
+            ..Default::default()
         },
         831 => LocalisedText {
             en: "The Manderville Tables",
             ja: "魔導雀荘「マンダヴィル」",
             de: "Manderville-Tische",
             fr: "Tables des Manderville",
+            ..Default::default()
         },
         832 => LocalisedText {
             en: "The Gold Saucer",
             ja: "ゴールドソーサー",
             de: "Gold Saucer",
             fr: "Gold Saucer",
+            ..Default::default()
         },
         833 => LocalisedText {
             en: "The Howling Eye",
             ja: "ハウリングアイ外縁",
             de: "Das Tosende Auge",
             fr: "Hurlœil (abord)",
+            ..Default::default()
         },
         834 => LocalisedText {
             en: "The Howling Eye",
             ja: "ハウリングアイ石塔群",
             de: "Das Tosende Auge",
             fr: "Hurlœil",
+            ..Default::default()
         },
         836 => LocalisedText {
             en: "Malikah's Well",
             ja: "マリカの大井戸",
             de: "Malikahs Brunnen",
             fr: "Puits de Malikah",
+            ..Default::default()
         },
         837 => LocalisedText {
             en: "Holminster Switch",
             ja: "ホルミンスター",
             de: "Holminster",
             fr: "Holminster",
+            ..Default::default()
         },
         838 => LocalisedText {
             en: "Amaurot",
             ja: "アーモロート",
             de: "Amaurot",
             fr: "Amaurote",
+            ..Default::default()
         },
         839 => LocalisedText {
             en: "East Shroud",
             ja: "黒衣森：東部森林",
             de: "Ostwald",
             fr: "Forêt de l'est",
+            ..Default::default()
         },
         840 => LocalisedText {
             en: "The Twinning",
             ja: "シルクス・ツイニング",
             de: "Kristallzwilling",
             fr: "Macle de Syrcus",
+            ..Default::default()
         },
         841 => LocalisedText {
             en: "Akadaemia Anyder",
             ja: "アナイダアカデミア",
             de: "Akadaemia Anyder",
             fr: "Akadaemia Anydre",
+            ..Default::default()
         },
         842 => LocalisedText {
             en: "The Syrcus Trench",
             ja: "シルクスの峡間",
             de: "Syrcus-Schlucht",
             fr: "Gorge de Syrcus",
+            ..Default::default()
         },
         843 => LocalisedText {
             en: "The Pendants Personal Suite",
             ja: "ペンダント居住館：居室",
             de: "Isomorph-Quartiere",
             fr: "Les Pendants",
+            ..Default::default()
         },
         844 => LocalisedText {
             en: "The Ocular",
             ja: "星見の間 ",
             de: "Okular",
             fr: "L'Observatoire",
+            ..Default::default()
         },
         845 => LocalisedText {
             en: "The Dancing Plague",
             ja: "妖精王の舞踏場",
             de: "Königlicher Tanzgarten",
             fr: "Salle de bal de Titania",
+            ..Default::default()
         },
         846 => LocalisedText {
             en: "The Crown of the Immaculate",
             ja: "イマキュレートクラウン",
             de: "Strahlenkranz der Makellosigkeit",
             fr: "Couronne de l'Immaculé",
+            ..Default::default()
         },
         847 => LocalisedText {
             en: "The Dying Gasp",
             ja: "終末のアーモロート",
             de: "Die Letzten Tage von Amaurot",
             fr: "Râle de l'Agonie",
+            ..Default::default()
         },
         848 => LocalisedText {
             en: "The Crown of the Immaculate",
             ja: "イマキュレートクラウン",
             de: "Strahlenkranz der Makellosigkeit",
             fr: "Couronne de l'Immaculé",
+            ..Default::default()
         },
         849 => LocalisedText {
             en: "The Core",
             ja: "エデンコア",
             de: "Das Herz",
             fr: "Cœur d'Éden",
+            ..Default::default()
         },
         850 => LocalisedText {
             en: "The Halo",
             ja: "エデン甲板",
             de: "Das Deck",
             fr: "Halo pinaculaire",
+            ..Default::default()
         },
         851 => LocalisedText {
             en: "The Nereus Trench",
             ja: "ネレウス海淵",
             de: "Nereus-Graben",
             fr: "Fosse de Nérée",
+            ..Default::default()
         },
         852 => LocalisedText {
             en: "Atlas Peak",
             ja: "アトラス山頂",
             de: "Atlas-Gipfel",
             fr: "Mont Atlas",
+            ..Default::default()
         },
         853 => LocalisedText {
             en: "The Core",
             ja: "エデンコア",
             de: "Das Herz",
             fr: "Cœur d'Éden",
+            ..Default::default()
         },
         854 => LocalisedText {
             en: "The Halo",
             ja: "エデン甲板",
             de: "Das Deck",
             fr: "Halo pinaculaire",
+            ..Default::default()
         },
         855 => LocalisedText {
             en: "The Nereus Trench",
             ja: "ネレウス海淵",
             de: "Nereus-Graben",
             fr: "Fosse de Nérée",
+            ..Default::default()
         },
         856 => LocalisedText {
             en: "Atlas Peak",
             ja: "アトラス山頂",
             de: "Atlas-Gipfel",
             fr: "Mont Atlas",
+            ..Default::default()
         },
         857 => LocalisedText {
             en: "The Core",
             ja: "エデンコア",
             de: "Das Herz",
             fr: "Cœur d'Éden",
+            ..Default::default()
         },
         858 => LocalisedText {
             en: "The Dancing Plague",
             ja: "妖精王の舞踏場",
             de: "Königlicher Tanzgarten",
             fr: "Salle de bal de Titania",
+            ..Default::default()
         },
         859 => LocalisedText {
             en: "The Confessional of Toupasa the Elder",
             ja: "大トゥパサの崇神所",
             de: "Beichtstuhl von Toupasa dem Älteren",
             fr: "Confessionnal de Toupasa l'ancien",
+            ..Default::default()
         },
         860 => LocalisedText {
             en: "Amh Araeng",
             ja: "アム・アレーン",
             de: "Amh Araeng",
             fr: "Amh Araeng",
+            ..Default::default()
         },
         861 => LocalisedText {
             en: "Lakeland",
             ja: "レイクランド",
             de: "Seenland",
             fr: "Grand-Lac",
+            ..Default::default()
         },
         862 => LocalisedText {
             en: "Lakeland",
             ja: "レイクランド",
             de: "Seenland",
             fr: "Grand-Lac",
+            ..Default::default()
         },
         863 => LocalisedText {
             en: "Eulmore",
             ja: "ユールモア",
             de: "Eulmore",
             fr: "Eulmore",
+            ..Default::default()
         },
         864 => LocalisedText {
             en: "Kholusia",
             ja: "コルシア島",
             de: "Kholusia",
             fr: "Kholusia",
+            ..Default::default()
         },
         865 => LocalisedText {
             en: "Old Gridania",
             ja: "グリダニア：旧市街",
             de: "Alt-Gridania",
             fr: "Vieille Gridania",
+            ..Default::default()
         },
         866 => LocalisedText {
             en: "Coerthas Western Highlands",
             ja: "クルザス西部高地",
             de: "Westliches Hochland von Coerthas",
             fr: "Hautes terres du Coerthas occidental",
+            ..Default::default()
         },
         867 => LocalisedText {
             en: "Eastern La Noscea",
             ja: "東ラノシア",
             de: "Östliches La Noscea",
             fr: "Noscea orientale",
+            ..Default::default()
         },
         868 => LocalisedText {
             en: "The Peaks",
             ja: "ギラバニア山岳地帯",
             de: "Die Zinnen",
             fr: "Les Pics",
+            ..Default::default()
         },
         869 => LocalisedText {
             en: "Il Mheg",
             ja: "イル・メグ",
             de: "Il Mheg",
             fr: "Il Mheg",
+            ..Default::default()
         },
         870 => LocalisedText {
             en: "Kholusia",
             ja: "コルシア島",
             de: "Kholusia",
             fr: "Kholusia",
+            ..Default::default()
         },
         871 => LocalisedText {
             en: "The Rak'tika Greatwood",
             ja: "ラケティカ大森林",
             de: "Der Große Wald Rak'tika",
             fr: "Rak'tika",
+            ..Default::default()
         },
         872 => LocalisedText {
             en: "Amh Araeng",
             ja: "アム・アレーン",
             de: "Amh Araeng",
             fr: "Amh Araeng",
+            ..Default::default()
         },
         873 => LocalisedText {
             en: "The Dancing Plague",
             ja: "妖精王の舞踏場",
             de: "Königlicher Tanzgarten",
             fr: "Salle de bal de Titania",
+            ..Default::default()
         },
         874 => LocalisedText {
             en: "The Rak'tika Greatwood",
             ja: "ラケティカ大森林",
             de: "Der Große Wald Rak'tika",
             fr: "Rak'tika",
+            ..Default::default()
         },
         875 => LocalisedText {
             en: "The Rak'tika Greatwood",
             ja: "ラケティカ大森林",
             de: "Der Große Wald Rak'tika",
             fr: "Rak'tika",
+            ..Default::default()
         },
         876 => LocalisedText {
             en: "The Nabaath Mines",
             ja: "ナバース家の鉱山",
             de: "Nabaath-Mine",
             fr: "Mines des Nabaath",
+            ..Default::default()
         },
         877 => LocalisedText {
             en: "Lakeland",
             ja: "レイクランド",
             de: "Seenland",
             fr: "Grand-Lac",
+            ..Default::default()
         },
         878 => LocalisedText {
             en: "The Empty",
             ja: "無の大地",
             de: "Die Große Leere",
             fr: "Le Grand Vide",
+            ..Default::default()
         },
         879 => LocalisedText {
             en: "The Dungeons of Lyhe Ghiah",
             ja: "リェー・ギア・ダンジョン",
             de: "Verliese von Lyhe Ghiah",
             fr: "Donjon hypogéen du Lyhe Ghiah",
+            ..Default::default()
         },
         880 => LocalisedText {
             en: "The Crown of the Immaculate",
             ja: "イマキュレートクラウン",
             de: "Strahlenkranz der Makellosigkeit",
             fr: "Couronne de l'Immaculé",
+            ..Default::default()
         },
         881 => LocalisedText {
             en: "The Dying Gasp",
             ja: "終末のアーモロート",
             de: "Die Letzten Tage von Amaurot",
             fr: "Râle de l'Agonie",
+            ..Default::default()
         },
         882 => LocalisedText {
             en: "The Copied Factory",
             ja: "複製サレタ工場廃墟",
             de: "Die kopierte Fabrik",
             fr: "Réplique de l'usine désaffectée",
+            ..Default::default()
         },
         884 => LocalisedText {
             en: "The Grand Cosmos",
             ja: "グラン・コスモス",
             de: "Chateau Cosmea",
             fr: "Cosmos coruscant",
+            ..Default::default()
         },
         885 => LocalisedText {
             en: "The Dying Gasp",
             ja: "終末のアーモロート",
             de: "Die Letzten Tage von Amaurot",
             fr: "Râle de l'Agonie",
+            ..Default::default()
         },
         886 => LocalisedText {
             en: "The Firmament",
             ja: "蒼天街",
             de: "Himmelsstadt",
             fr: "Azurée",
+            ..Default::default()
         },
         887 => LocalisedText {
             en: "Liminal Space",
             ja: "階差閉宇宙",
             de: "Geschlossene Raumzeit",
             fr: "Univers différentiel",
+            ..Default::default()
         },
         888 => LocalisedText {
             en: "Onsal Hakair",
             ja: "オンサル・ハカイル",
             de: "Onsal Hakair",
             fr: "Onsal Hakair",
+            ..Default::default()
         },
         889 => LocalisedText {
             en: "Lyhe Mheg",
             ja: "リェー・メグ",
             de: "Lyhe Mheg",
             fr: "Lyhe Mheg",
+            ..Default::default()
         },
         890 => LocalisedText {
             en: "Lyhe Mheg",
             ja: "リェー・メグ",
             de: "Lyhe Mheg",
             fr: "Lyhe Mheg",
+            ..Default::default()
         },
         891 => LocalisedText {
             en: "Lyhe Mheg",
             ja: "リェー・メグ",
             de: "Lyhe Mheg",
             fr: "Lyhe Mheg",
+            ..Default::default()
         },
         892 => LocalisedText {
             en: "Lyhe Mheg",
             ja: "リェー・メグ",
             de: "Lyhe Mheg",
             fr: "Lyhe Mheg",
+            ..Default::default()
         },
         893 => LocalisedText {
             en: "The Imperial Palace",
             ja: "帝都魔導城",
             de: "Kaiserpalast",
             fr: "Palais impérial",
+            ..Default::default()
         },
         894 => LocalisedText {
             en: "Lyhe Mheg",
             ja: "リェー・メグ",
             de: "Lyhe Mheg",
             fr: "Lyhe Mheg",
+            ..Default::default()
         },
         895 => LocalisedText {
             en: "Excavation Tunnels",
             ja: "機械遺跡の坑道",
             de: "Alter Stollen",
             fr: "Entrée des ruines mécaniques",
+            ..Default::default()
         },
         896 => LocalisedText {
             en: "The Copied Factory",
             ja: "複製サレタ工場廃墟",
             de: "Die kopierte Fabrik",
             fr: "Réplique de l'usine désaffectée",
+            ..Default::default()
         },
         897 => LocalisedText {
             en: "Cinder Drift",
             ja: "ギムリト防衛ライン",
             de: "Verteidigungslinie Ghimlyt",
             fr: "Les Nuées de Brandons",
+            ..Default::default()
         },
         898 => LocalisedText {
             en: "Anamnesis Anyder",
             ja: "アニドラス・アナムネーシス",
             de: "Anamnesis Anyder",
             fr: "Anamnesis Anydre",
+            ..Default::default()
         },
         899 => LocalisedText {
             en: "The Falling City of Nym",
             ja: "ニーム浮遊遺跡風特設アスレチック",
             de: "Schwebende Stadt Nym",
             fr: "Cité croulante de Nym",
+            ..Default::default()
         },
         900 => LocalisedText {
             en: "The Endeavor",
             ja: "エンデバー号",
             de: "Die Endeavor",
             fr: "L'Ambition",
+            ..Default::default()
         },
         901 => LocalisedText {
             en: "The Diadem",
             ja: "ディアデム諸島",
             de: "Das Diadem",
             fr: "Le Diadème",
+            ..Default::default()
         },
         902 => LocalisedText {
             en: "The Gandof Thunder Plains",
             ja: "ガンドフ雷平原",
             de: "Gandoph-Donnersteppe",
             fr: "Plaine foudroyée de Gandof",
+            ..Default::default()
         },
         903 => LocalisedText {
             en: "Ashfall",
             ja: "灰の旅程",
             de: "Aschenfall",
             fr: "Bassin cendreux",
+            ..Default::default()
         },
         904 => LocalisedText {
             en: "The Halo",
             ja: "エデン甲板",
             de: "Das Deck",
             fr: "Pont supérieur d'Éden",
+            ..Default::default()
         },
         905 => LocalisedText {
             en: "Great Glacier",
             ja: "大氷河",
             de: "Gletscher",
             fr: "Grand Glacier",
+            ..Default::default()
         },
         906 => LocalisedText {
             en: "The Gandof Thunder Plains",
             ja: "ガンドフ雷平原",
             de: "Gandoph-Donnersteppe",
             fr: "Plaine foudroyée de Gandof",
+            ..Default::default()
         },
         907 => LocalisedText {
             en: "Ashfall",
             ja: "灰の旅程",
             de: "Aschenfall",
             fr: "Bassin cendreux",
+            ..Default::default()
         },
         908 => LocalisedText {
             en: "The Halo",
             ja: "エデン甲板",
             de: "Das Deck",
             fr: "Pont supérieur d'Éden",
+            ..Default::default()
         },
         909 => LocalisedText {
             en: "Great Glacier",
             ja: "大氷河",
             de: "Gletscher",
             fr: "Grand Glacier",
+            ..Default::default()
         },
         911 => LocalisedText {
             en: "Cid's Memory",
             ja: "シドの記憶空間",
             de: "Cids Gedächtnis",
             fr: "Mémoire de Cid",
+            ..Default::default()
         },
         912 => LocalisedText {
             en: "Cinder Drift",
             ja: "ギムリト防衛ライン",
             de: "Verteidigungslinie Ghimlyt",
             fr: "Les Nuées de Brandons",
+            ..Default::default()
         },
         913 => LocalisedText {
             en: "Transmission Control",
             ja: "交信雷波塔管制室",
             de: "Signalkontrollstation",
             fr: "Salle de contrôle",
+            ..Default::default()
         },
         914 => LocalisedText {
             en: "Trial's Threshold",
             ja: "徳測りし前室",
             de: "Halle der Prüfung",
             fr: "Antichambre de l'épreuve",
+            ..Default::default()
         },
         915 => LocalisedText {
             en: "Gangos",
             ja: "ガンゴッシュ",
             de: "Gangos",
             fr: "Gangos",
+            ..Default::default()
         },
         916 => LocalisedText {
             en: "The Heroes' Gauntlet",
             ja: "漆黒決戦 ノルヴラント",
             de: "Schlacht um Norvrandt",
             fr: "Traversée de Norvrandt",
+            ..Default::default()
         },
         917 => LocalisedText {
             en: "The Puppets' Bunker",
             ja: "人形タチノ軍事基地",
             de: "Die Puppenfestung",
             fr: "Base militaire des Pantins",
+            ..Default::default()
         },
         918 => LocalisedText {
             en: "Anamnesis Anyder",
             ja: "アニドラス・アナムネーシス",
             de: "Anamnesis Anyder",
             fr: "Anamnesis Anydre",
+            ..Default::default()
         },
         919 => LocalisedText {
             en: "Terncliff",
             ja: "ターンクリフ",
             de: "Wendeklipp",
             fr: "Rocasternes",
+            ..Default::default()
         },
         920 => LocalisedText {
             en: "Bozjan Southern Front",
             ja: "南方ボズヤ戦線",
             de: "Bozja-Südfront",
             fr: "Front sud de Bozja",
+            ..Default::default()
         },
         921 => LocalisedText {
             en: "Frondale's Home for Friendless Foundlings",
             ja: "フロンデール薬学院小児病棟",
             de: "Frondales Physiatrische Akademie",
             fr: "Clinique pédiatrique de l'Académie médicale de Frondale",
+            ..Default::default()
         },
         922 => LocalisedText {
             en: "The Seat of Sacrifice",
             ja: "始皇帝の玉座",
             de: "Der Kaiserliche Thron",
             fr: "Le Trône du Sacrifice",
+            ..Default::default()
         },
         923 => LocalisedText {
             en: "The Seat of Sacrifice",
             ja: "始皇帝の玉座",
             de: "Der Kaiserliche Thron",
             fr: "Le Trône du Sacrifice",
+            ..Default::default()
         },
         924 => LocalisedText {
             en: "The Shifting Oubliettes of Lyhe Ghiah",
             ja: "リェー・ギア・ダンジョン祭殿",
             de: "Das Karussell von Lyhe Ghiah",
             fr: "Jardin secret du Lyhe Ghiah",
+            ..Default::default()
         },
         925 => LocalisedText {
             en: "Terncliff Bay",
             ja: "ターンクリフ沖",
             de: "Wendeklipp-Küste",
             fr: "Large de Rocasternes",
+            ..Default::default()
         },
         926 => LocalisedText {
             en: "Terncliff Bay",
             ja: "ターンクリフ沖",
             de: "Wendeklipp-Küste",
             fr: "Large de Rocasternes",
+            ..Default::default()
         },
         928 => LocalisedText {
             en: "The Puppets' Bunker",
             ja: "人形タチノ軍事基地",
             de: "Die Puppenfestung",
             fr: "Base militaire des Pantins",
+            ..Default::default()
         },
         929 => LocalisedText {
             en: "The Diadem",
             ja: "ディアデム諸島",
             de: "Das Diadem",
             fr: "Le Diadème",
+            ..Default::default()
         },
         931 => LocalisedText {
             en: "The Seat of Sacrifice",
             ja: "始皇帝の玉座",
             de: "Der Kaiserliche Thron",
             fr: "Le Trône du Sacrifice",
+            ..Default::default()
         },
         932 => LocalisedText {
             en: "The Tempest",
             ja: "テンペスト",
             de: "Tempest",
             fr: "La Tempête",
+            ..Default::default()
         },
         933 => LocalisedText {
             en: "Matoya's Relict",
             ja: "マトーヤのアトリエ",
             de: "Matoyas Atelier",
             fr: "Atelier abandonné de Matoya",
+            ..Default::default()
         },
         934 => LocalisedText {
             en: "Castrum Marinum Drydocks",
             ja: "カステッルム・マリヌム・ドライドック",
             de: "Trockendock von Castrum Marinum",
             fr: "Cale sèche de Castrum Marinum",
+            ..Default::default()
         },
         935 => LocalisedText {
             en: "Castrum Marinum Drydocks",
             ja: "カステッルム・マリヌム・ドライドック",
             de: "Trockendock von Castrum Marinum",
             fr: "Cale sèche de Castrum Marinum",
+            ..Default::default()
         },
         936 => LocalisedText {
             en: "Delubrum Reginae",
             ja: "グンヒルド・ディルーブラム",
             de: "Delubrum Reginae",
             fr: "Delubrum Reginae",
+            ..Default::default()
         },
         937 => LocalisedText {
             en: "Delubrum Reginae",
             ja: "グンヒルド・ディルーブラム",
             de: "Delubrum Reginae",
             fr: "Delubrum Reginae",
+            ..Default::default()
         },
         938 => LocalisedText {
             en: "Paglth'an",
             ja: "パガルザン",
             de: "Paglth'an",
             fr: "Paglth'an",
+            ..Default::default()
         },
         939 => LocalisedText {
             en: "The Diadem",
             ja: "ディアデム諸島",
             de: "Das Diadem",
             fr: "Le Diadème",
+            ..Default::default()
         },
         940 => LocalisedText {
             en: "The Battlehall",
             ja: "カードバトルルーム",
             de: "Weltensalon",
             fr: "Arène Triple Triade",
+            ..Default::default()
         },
         941 => LocalisedText {
             en: "The Battlehall",
             ja: "カードバトルルーム",
             de: "Weltensalon",
             fr: "Arène Triple Triade",
+            ..Default::default()
         },
         942 => LocalisedText {
             en: "Sphere of Naught",
             ja: "暗闇の領域",
             de: "Nichtssphäre",
             fr: "Sphère de l'absence",
+            ..Default::default()
         },
         943 => LocalisedText {
             en: "Laxan Loft",
             ja: "ラクサン城",
             de: "Burg Selard",
             fr: "Château de Ganlag",
+            ..Default::default()
         },
         944 => LocalisedText {
             en: "Bygone Gaol",
             ja: "ユールモア監獄棟",
             de: "Verlies von Eulmore",
             fr: "Prison d'Eulmore",
+            ..Default::default()
         },
         945 => LocalisedText {
             en: "The Garden of Nowhere",
             ja: "絶望の園",
             de: "Nimmerwo-Garten",
             fr: "Jardin du désespoir",
+            ..Default::default()
         },
         946 => LocalisedText {
             en: "Sphere of Naught",
             ja: "暗闇の領域",
             de: "Nichtssphäre",
             fr: "Sphère de l'absence",
+            ..Default::default()
         },
         947 => LocalisedText {
             en: "Laxan Loft",
             ja: "ラクサン城",
             de: "Burg Selard",
             fr: "Château de Ganlag",
+            ..Default::default()
         },
         948 => LocalisedText {
             en: "Bygone Gaol",
             ja: "ユールモア監獄棟",
             de: "Verlies von Eulmore",
             fr: "Prison d'Eulmore",
+            ..Default::default()
         },
         949 => LocalisedText {
             en: "The Garden of Nowhere",
             ja: "絶望の園",
             de: "Nimmerwo-Garten",
             fr: "Jardin du désespoir",
+            ..Default::default()
         },
         950 => LocalisedText {
             en: "G-Savior Deck",
             ja: "Gセイヴァー戦闘甲板",
             de: "Gefechtsplattform des G-Retters",
             fr: "Ponton du Gardien-G",
+            ..Default::default()
         },
         951 => LocalisedText {
             en: "G-Savior Deck",
             ja: "Gセイヴァー戦闘甲板",
             de: "Gefechtsplattform des G-Retters",
             fr: "Ponton du Gardien-G",
+            ..Default::default()
         },
         952 => LocalisedText {
             en: "The Tower of Zot",
             ja: "ゾットの塔",
             de: "Turm von Zot",
             fr: "Tour de Zott",
+            ..Default::default()
         },
         954 => LocalisedText {
             en: "The Navel",
             ja: "オ・ゴモロ火口神殿",
             de: "Der Nabel",
             fr: "Le Nombril",
+            ..Default::default()
         },
         955 => LocalisedText {
             en: "The Last Trace",
             ja: "失われた遺跡",
             de: "Verlorene Ruinen",
             fr: "Vestiges oubliés",
+            ..Default::default()
         },
         956 => LocalisedText {
             en: "Labyrinthos",
             ja: "ラヴィリンソス",
             de: "Labyrinthos",
             fr: "Le Labyrinthos",
+            ..Default::default()
         },
         957 => LocalisedText {
             en: "Thavnair",
             ja: "サベネア島",
             de: "Thavnair",
             fr: "Thavnair",
+            ..Default::default()
         },
         958 => LocalisedText {
             en: "Garlemald",
             ja: "ガレマルド",
             de: "Garlemald",
             fr: "Garlemald",
+            ..Default::default()
         },
         959 => LocalisedText {
             en: "Mare Lamentorum",
             ja: "嘆きの海",
             de: "Mare Lamentorum",
             fr: "Mare Lamentorum",
+            ..Default::default()
         },
         960 => LocalisedText {
             en: "Ultima Thule",
             ja: "ウルティマ・トゥーレ",
             de: "Ultima Thule",
             fr: "Ultima Thulé",
+            ..Default::default()
         },
         961 => LocalisedText {
             en: "Elpis",
             ja: "エルピス",
             de: "Elpis",
             fr: "Elpis",
+            ..Default::default()
         },
         962 => LocalisedText {
             en: "Old Sharlayan",
             ja: "オールド・シャーレアン",
             de: "Alt-Sharlayan",
             fr: "Vieille Sharlayan",
+            ..Default::default()
         },
         963 => LocalisedText {
             en: "Radz-at-Han",
             ja: "ラザハン",
             de: "Radz-at-Han",
             fr: "Radz-at-Han",
+            ..Default::default()
         },
         964 => LocalisedText {
             en: "The Last Trace",
             ja: "失われた遺跡",
             de: "Verlorene Ruinen",
             fr: "Vestiges oubliés",
+            ..Default::default()
         },
         965 => LocalisedText {
             en: "The Empty",
             ja: "無の大地",
             de: "Die Große Leere",
             fr: "Le Grand Vide",
+            ..Default::default()
         },
         966 => LocalisedText {
             en: "The Tower at Paradigm's Breach",
             ja: "希望ノ砲台：「塔」",
             de: "Der Turm, Paradigmenbrecher",
             fr: "Tour de la Contingence",
+            ..Default::default()
         },
         967 => LocalisedText {
             en: "Castrum Marinum Drydocks",
             ja: "カステッルム・マリヌム・ドライドック",
             de: "Trockendock von Castrum Marinum",
             fr: "Cale sèche de Castrum Marinum",
+            ..Default::default()
         },
         968 => LocalisedText {
             en: "Medias Res",
             ja: "詩想空間",
             de: "Medias Res",
             fr: "Interstice du chant onirique",
+            ..Default::default()
         },
         969 => LocalisedText {
             en: "The Tower of Babil",
             ja: "バブイルの塔",
             de: "Turm von Babil",
             fr: "Tour de Babil",
+            ..Default::default()
         },
         970 => LocalisedText {
             en: "Vanaspati",
             ja: "ヴァナスパティ樹海",
             de: "Vanaspati",
             fr: "Vanaspati",
+            ..Default::default()
         },
         971 => LocalisedText {
             en: "Lemures Headquarters",
             ja: "レムレースのオフィス",
             de: "Hauptquartier der Lemuren",
             fr: "Repaire des Lémures",
+            ..Default::default()
         },
         973 => LocalisedText {
             en: "The Dead Ends",
             ja: "レムナント",
             de: "Das Sternengrab",
             fr: "L'Issue aux Impasses",
+            ..Default::default()
         },
         974 => LocalisedText {
             en: "Ktisis Hyperboreia",
             ja: "ヒュペルボレア造物院",
             de: "Ktisis Hyperboreia",
             fr: "Hyperborée",
+            ..Default::default()
         },
         975 => LocalisedText {
             en: "Zadnor",
             ja: "ザトゥノル高原",
             de: "Zadnor-Hochebene",
             fr: "Hauts plateaux de Zadnor",
+            ..Default::default()
         },
         976 => LocalisedText {
             en: "Smileton",
             ja: "スマイルトン",
             de: "Smileton",
             fr: "Risette-sur-lune",
+            ..Default::default()
         },
         977 => LocalisedText {
             en: "Carteneau Flats: Borderland Ruins",
             ja: "カルテノー平原：外縁遺跡群",
             de: "Äußere Ruinen",
             fr: "Ruines frontalières",
+            ..Default::default()
         },
         978 => LocalisedText {
             en: "The Aitiascope",
             ja: "アイティオン星晶鏡",
             de: "Aitiaskop",
             fr: "Prisme de l'Aitia",
+            ..Default::default()
         },
         979 => LocalisedText {
             en: "Empyreum",
             ja: "エンピレアム",
             de: "Empyreum",
             fr: "Empyrée",
+            ..Default::default()
         },
         980 => LocalisedText {
             en: "Private Cottage - Empyreum",
             ja: "エンピレアム：コテージ",
             de: "Privathütte (Empyreum)",
             fr: "Maisonnette - Empyrée",
+            ..Default::default()
         },
         981 => LocalisedText {
             en: "Private House - Empyreum",
             ja: "エンピレアム：ハウス",
             de: "Privathaus (Empyreum)",
             fr: "Pavillon - Empyrée",
+            ..Default::default()
         },
         982 => LocalisedText {
             en: "Private Mansion - Empyreum",
             ja: "エンピレアム：レジデンス",
             de: "Privatresidenz (Empyreum)",
             fr: "Villa - Empyrée",
+            ..Default::default()
         },
         983 => LocalisedText {
             en: "Private Chambers - Empyreum",
             ja: "エンピレアム：プライベートルーム",
             de: "Empyreum - Zimmer",
             fr: "Chambre individuelle - Empyrée",
+            ..Default::default()
         },
         984 => LocalisedText {
             en: "Company Workshop - Empyreum",
             ja: "エンピレアム：地下工房",
             de: "Gesellschaftswerkstätte: Empyreum",
             fr: "Atelier de compagnie - Empyrée",
+            ..Default::default()
         },
         985 => LocalisedText {
             en: "Ingleside Apartment Lobby",
             ja: "イングルサイド：ロビー",
             de: "Adlerblick (Eingangshalle)",
             fr: "Le Coin de l'Âtre (hall)",
+            ..Default::default()
         },
         986 => LocalisedText {
             en: "The Stigma Dreamscape",
             ja: "スティグマ・フォー",
             de: "Stigma-4",
             fr: "Rêve électrique de Stigma-4",
+            ..Default::default()
         },
         987 => LocalisedText {
             en: "Main Hall",
             ja: "メインホール",
             de: "Hauptsaal",
             fr: "Salon principal",
+            ..Default::default()
         },
         990 => LocalisedText {
             en: "Andron",
             ja: "ナップルーム",
             de: "Ruheräume",
             fr: "Chambre à coucher",
+            ..Default::default()
         },
         991 => LocalisedText {
             en: "G-Savior Deck",
             ja: "Gセイヴァー戦闘甲板",
             de: "Gefechtsplattform des G-Retters",
             fr: "Ponton du Gardien-G",
+            ..Default::default()
         },
         992 => LocalisedText {
             en: "The Dark Inside",
             ja: "月の底",
             de: "Grund des Mondes",
             fr: "Prison lunaire",
+            ..Default::default()
         },
         993 => LocalisedText {
             en: "The Dark Inside",
             ja: "月の底",
             de: "Grund des Mondes",
             fr: "Prison lunaire",
+            ..Default::default()
         },
         994 => LocalisedText {
             en: "The Phantoms' Feast",
             ja: "ホーンテッドフィースト",
             de: "Lustiges Bankett",
             fr: "Banquet cauchemardesque",
+            ..Default::default()
         },
         995 => LocalisedText {
             en: "The Mothercrystal",
             ja: "マザークリスタル",
             de: "Urkristall",
             fr: "Cristal-mère",
+            ..Default::default()
         },
         996 => LocalisedText {
             en: "The Mothercrystal",
             ja: "マザークリスタル",
             de: "Urkristall",
             fr: "Cristal-mère",
+            ..Default::default()
         },
         997 => LocalisedText {
             en: "The Final Day",
             ja: "果ての終焉",
             de: "Am Rande des Endes",
             fr: "Orée de la fin",
+            ..Default::default()
         },
         998 => LocalisedText {
             en: "The Final Day",
             ja: "果ての終焉",
             de: "Am Rande des Endes",
             fr: "Orée de la fin",
+            ..Default::default()
         },
         999 => LocalisedText {
             en: "Ingleside Apartment",
             ja: "イングルサイド：部屋",
             de: "Adlerblick (Wohnung)",
             fr: "Le Coin de l'Âtre (appartement)",
+            ..Default::default()
         },
         1000 => LocalisedText {
             en: "The Excitatron 6000",
             ja: "エキサイトロン",
             de: "Euphoratron",
             fr: "Ludodrome",
+            ..Default::default()
         },
         1001 => LocalisedText {
             en: "Strategy Room",
             ja: "作戦立案室",
             de: "Strategieraum",
             fr: "Salle des opérations",
+            ..Default::default()
         },
         1002 => LocalisedText {
             en: "The Gates of Pandæmonium",
             ja: "パンデモニウム正門",
             de: "Pforten des Pandæmoniums",
             fr: "Limbes du Pandæmonium - Parvis",
+            ..Default::default()
         },
         1003 => LocalisedText {
             en: "The Gates of Pandæmonium",
             ja: "パンデモニウム正門",
             de: "Pforten des Pandæmoniums",
             fr: "Limbes du Pandæmonium - Parvis",
+            ..Default::default()
         },
         1004 => LocalisedText {
             en: "The Stagnant Limbo",
             ja: "パンデモニウム辺獄水道",
             de: "Trister Limbus",
             fr: "Limbes du Pandæmonium - Cloaque",
+            ..Default::default()
         },
         1005 => LocalisedText {
             en: "The Stagnant Limbo",
             ja: "パンデモニウム辺獄水道",
             de: "Trister Limbus",
             fr: "Limbes du Pandæmonium - Cloaque",
+            ..Default::default()
         },
         1006 => LocalisedText {
             en: "The Fervid Limbo",
             ja: "パンデモニウム辺獄下層",
             de: "Glühender Limbus",
             fr: "Limbes du Pandæmonium - Fournaise",
+            ..Default::default()
         },
         1007 => LocalisedText {
             en: "The Fervid Limbo",
             ja: "パンデモニウム辺獄下層",
             de: "Glühender Limbus",
             fr: "Limbes du Pandæmonium - Fournaise",
+            ..Default::default()
         },
         1008 => LocalisedText {
             en: "The Sanguine Limbo",
             ja: "パンデモニウム辺獄深層",
             de: "Blutender Limbus",
             fr: "Limbes du Pandæmonium - Abîme",
+            ..Default::default()
         },
         1009 => LocalisedText {
             en: "The Sanguine Limbo",
             ja: "パンデモニウム辺獄深層",
             de: "Blutender Limbus",
             fr: "Limbes du Pandæmonium - Abîme",
+            ..Default::default()
         },
         1010 => LocalisedText {
             en: "Magna Glacies",
             ja: "マグナ・グラキエス",
             de: "Magna Glacies",
             fr: "Magna Glacies",
+            ..Default::default()
         },
         1011 => LocalisedText {
             en: "Garlemald",
             ja: "ガレマルド",
             de: "Garlemald",
             fr: "Garlemald",
+            ..Default::default()
         },
         1012 => LocalisedText {
             en: "Magna Glacies",
             ja: "マグナ・グラキエス",
             de: "Magna Glacies",
             fr: "Magna Glacies",
+            ..Default::default()
         },
         1013 => LocalisedText {
             en: "Beyond the Stars",
             ja: "天の最果て",
             de: "Äußerster Rand des Universums",
             fr: "Confins de l'univers",
+            ..Default::default()
         },
         1014 => LocalisedText {
             en: "Elpis",
             ja: "エルピス",
             de: "Elpis",
             fr: "Elpis",
+            ..Default::default()
         },
         1015 => LocalisedText {
             en: "Central Shroud",
             ja: "黒衣森：中央森林",
             de: "Tiefer Wald",
             fr: "Forêt centrale",
+            ..Default::default()
         },
         1016 => LocalisedText {
             en: "Sastasha",
             ja: "サスタシャ浸食洞",
             de: "Sastasha-Höhle",
             fr: "Sastasha",
+            ..Default::default()
         },
         1017 => LocalisedText {
             en: "The Swallow's Compass",
             ja: "ガンエン廟",
             de: "Kompass der Schwalbe",
             fr: "Compas de l'Hirondelle",
+            ..Default::default()
         },
         1018 => LocalisedText {
             en: "The Vault",
             ja: "イシュガルド教皇庁",
             de: "Erzbasilika",
             fr: "La Voûte",
+            ..Default::default()
         },
         1019 => LocalisedText {
             en: "The Peaks",
             ja: "ギラバニア山岳地帯",
             de: "Die Zinnen",
             fr: "Les Pics",
+            ..Default::default()
         },
         1020 => LocalisedText {
             en: "Cutter's Cry",
             ja: "カッターズクライ",
             de: "Sägerschrei",
             fr: "Gouffre hurlant",
+            ..Default::default()
         },
         1021 => LocalisedText {
             en: "Dusk Vigil",
             ja: "ダスクヴィジル",
             de: "Abendrot-Wacht",
             fr: "Vigile du Crépuscule",
+            ..Default::default()
         },
         1022 => LocalisedText {
             en: "Saint Mocianne's Arboretum",
             ja: "聖モシャーヌ植物園",
             de: "Sankt Mocianne-Arboretum",
             fr: "Arboretum Sainte-Mocianne",
+            ..Default::default()
         },
         1023 => LocalisedText {
             en: "The Dravanian Forelands",
             ja: "高地ドラヴァニア",
             de: "Dravanisches Vorland",
             fr: "Avant-pays dravanien",
+            ..Default::default()
         },
         1024 => LocalisedText {
             en: "The Nethergate",
             ja: "神門の間",
             de: "Gottesportal",
             fr: "Porte des Dieux",
+            ..Default::default()
         },
         1025 => LocalisedText {
             en: "The Gates of Pandæmonium",
             ja: "パンデモニウム正門",
             de: "Pforten des Pandæmoniums",
             fr: "Limbes du Pandæmonium - Parvis",
+            ..Default::default()
         },
         1026 => LocalisedText {
             en: "Beyond the Stars",
             ja: "天の最果て",
             de: "Äußerster Rand des Universums",
             fr: "Confins de l'univers",
+            ..Default::default()
         },
         1027 => LocalisedText {
             en: "Ultima Thule",
             ja: "ウルティマ・トゥーレ",
             de: "Ultima Thule",
             fr: "Ultima Thulé",
+            ..Default::default()
         },
         1028 => LocalisedText {
             en: "The Dark Inside",
             ja: "月の底",
             de: "Grund des Mondes",
             fr: "Prison lunaire",
+            ..Default::default()
         },
         1029 => LocalisedText {
             en: "The Final Day",
             ja: "果ての終焉",
             de: "Am Rande des Endes",
             fr: "Orée de la fin",
+            ..Default::default()
         },
         1030 => LocalisedText {
             en: "The Mothercrystal",
             ja: "マザークリスタル",
             de: "Urkristall",
             fr: "Cristal-mère",
+            ..Default::default()
         },
         1031 => LocalisedText {
             en: "Propylaion",
             ja: "プロピュライオン",
             de: "Propylaion",
             fr: "Le Propylée",
+            ..Default::default()
         },
         1032 => LocalisedText {
             en: "The Palaistra",
             ja: "パライストラ",
             de: "Die Palästra",
             fr: "Le Palestre",
+            ..Default::default()
         },
         1033 => LocalisedText {
             en: "The Volcanic Heart",
             ja: "ヴォルカニック・ハート",
             de: "Das Herz des Vulkans",
             fr: "Le Cœur volcanique",
+            ..Default::default()
         },
         1034 => LocalisedText {
             en: "Cloud Nine",
             ja: "クラウドナイン",
             de: "Wolke Sieben",
             fr: "Le Petit Nuage",
+            ..Default::default()
         },
         1036 => LocalisedText {
             en: "Sastasha",
             ja: "サスタシャ浸食洞",
             de: "Sastasha-Höhle",
             fr: "Sastasha",
+            ..Default::default()
         },
         1037 => LocalisedText {
             en: "The Tam-Tara Deepcroft",
             ja: "タムタラの墓所",
             de: "Totenacker Tam-Tara",
             fr: "Hypogée de Tam-Tara",
+            ..Default::default()
         },
         1038 => LocalisedText {
             en: "Copperbell Mines",
             ja: "カッパーベル銅山",
             de: "Kupferglocken-Mine",
             fr: "Mines de Clochecuivre",
+            ..Default::default()
         },
         1039 => LocalisedText {
             en: "The Thousand Maws of Toto-Rak",
             ja: "トトラクの千獄",
             de: "Tausend Löcher von Toto-Rak",
             fr: "Mille Gueules de Toto-Rak",
+            ..Default::default()
         },
         1040 => LocalisedText {
             en: "Haukke Manor",
             ja: "ハウケタ御用邸",
             de: "Haukke-Herrenhaus",
             fr: "Manoir des Haukke",
+            ..Default::default()
         },
         1041 => LocalisedText {
             en: "Brayflox's Longstop",
             ja: "ブレイフロクスの野営地",
             de: "Brüllvolx' Langrast",
             fr: "Bivouac de Brayflox",
+            ..Default::default()
         },
         1042 => LocalisedText {
             en: "Stone Vigil",
             ja: "ストーンヴィジル",
             de: "Steinerne Wacht",
             fr: "Vigile de Pierre",
+            ..Default::default()
         },
         1043 => LocalisedText {
             en: "Castrum Meridianum",
             ja: "カストルム・メリディアヌム",
             de: "Castrum Meridianum",
             fr: "Castrum Meridianum",
+            ..Default::default()
         },
         1044 => LocalisedText {
             en: "The Praetorium",
             ja: "魔導城プラエトリウム",
             de: "Praetorium",
             fr: "Praetorium",
+            ..Default::default()
         },
         1045 => LocalisedText {
             en: "Bowl of Embers",
             ja: "炎帝祭跡",
             de: "Das Grab der Lohe",
             fr: "Cratère des tisons",
+            ..Default::default()
         },
         1046 => LocalisedText {
             en: "The Navel",
             ja: "オ・ゴモロ火口神殿",
             de: "Der Nabel",
             fr: "Le Nombril",
+            ..Default::default()
         },
         1047 => LocalisedText {
             en: "The Howling Eye",
             ja: "ハウリングアイ石塔群",
             de: "Das Tosende Auge",
             fr: "Hurlœil",
+            ..Default::default()
         },
         1048 => LocalisedText {
             en: "Porta Decumana",
             ja: "ポルタ・デクマーナ",
             de: "Porta Decumana",
             fr: "Porta Decumana",
+            ..Default::default()
         },
         1049 => LocalisedText {
             en: "Western Thanalan",
             ja: "西ザナラーン",
             de: "Westliches Thanalan",
             fr: "Thanalan occidental",
+            ..Default::default()
         },
         1050 => LocalisedText {
             en: "Alzadaal's Legacy",
             ja: "アルザダール海底遺跡群",
             de: "Alzadaals Vermächtnis",
             fr: "Legs d'Alzadaal",
+            ..Default::default()
         },
         1051 => LocalisedText {
             en: "The Tower of Babil",
             ja: "バブイルの塔",
             de: "Turm von Babil",
             fr: "Tour de Babil",
+            ..Default::default()
         },
         1052 => LocalisedText {
             en: "The Porta Decumana",
             ja: "ポルタ・デクマーナ",
             de: "Porta Decumana",
             fr: "Porta Decumana",
+            ..Default::default()
         },
         1053 => LocalisedText {
             en: "The Porta Decumana",
             ja: "ポルタ・デクマーナ",
             de: "Porta Decumana",
             fr: "Porta Decumana",
+            ..Default::default()
         },
         1054 => LocalisedText {
             en: "Aglaia",
             ja: "輝ける神域 アグライア",
             de: "Aglaia",
             fr: "Domaine divin - Aglaé",
+            ..Default::default()
         },
         1055 => LocalisedText {
             en: "Unnamed Island",
             ja: "名もなき島",
             de: "Einsame Insel",
             fr: "Île sans nom",
+            ..Default::default()
         },
         1056 => LocalisedText {
             en: "Alzadaal's Legacy",
             ja: "アルザダール海底遺跡群",
             de: "Alzadaals Vermächtnis",
             fr: "Legs d'Alzadaal",
+            ..Default::default()
         },
         1057 => LocalisedText {
             en: "Restricted Archives",
             ja: "ヌーメノン大書院：禁書庫",
             de: "Verbotener Flügel",
             fr: "Archives interdites du Noumène",
+            ..Default::default()
         },
         1058 => LocalisedText {
             en: "The Palaistra",
             ja: "パライストラ",
             de: "Die Palästra",
             fr: "Le Palestre",
+            ..Default::default()
         },
         1059 => LocalisedText {
             en: "The Volcanic Heart",
             ja: "ヴォルカニック・ハート",
             de: "Das Herz des Vulkans",
             fr: "Le Cœur volcanique",
+            ..Default::default()
         },
         1060 => LocalisedText {
             en: "Cloud Nine",
             ja: "クラウドナイン",
             de: "Wolke Sieben",
             fr: "Le Petit Nuage",
+            ..Default::default()
         },
         1061 => LocalisedText {
             en: "The Omphalos",
             ja: "オムファロス",
             de: "Omphalos",
             fr: "Omphalos",
+            ..Default::default()
         },
         1062 => LocalisedText {
             en: "Snowcloak",
             ja: "スノークローク大氷壁",
             de: "Schneekleid",
             fr: "Manteneige",
+            ..Default::default()
         },
         1063 => LocalisedText {
             en: "The Keeper of the Lake",
             ja: "黙約の塔",
             de: "Der Hüter des Sees",
             fr: "Gardien du lac",
+            ..Default::default()
         },
         1064 => LocalisedText {
             en: "Sohm Al",
             ja: "ソーム・アル",
             de: "Sohm Al",
             fr: "Sohm Al",
+            ..Default::default()
         },
         1065 => LocalisedText {
             en: "The Aery",
             ja: "ドラゴンズエアリー",
             de: "Nest des Drachen",
             fr: "L'Aire",
+            ..Default::default()
         },
         1066 => LocalisedText {
             en: "The Vault",
             ja: "イシュガルド教皇庁",
             de: "Erzbasilika",
             fr: "La Voûte",
+            ..Default::default()
         },
         1067 => LocalisedText {
             en: "Thornmarch",
             ja: "茨の園",
             de: "Dornmarsch",
             fr: "Lisière de ronces",
+            ..Default::default()
         },
         1068 => LocalisedText {
             en: "Steps of Faith",
             ja: "雲廊",
             de: "Schicksalsweg",
             fr: "Avancée de la Foi",
+            ..Default::default()
         },
         1069 => LocalisedText {
             en: "The Sil'dihn Subterrane",
             ja: "シラディハ水道",
             de: "Unterstadt von Sil'dih",
             fr: "Canalisations sildiennes",
+            ..Default::default()
         },
         1070 => LocalisedText {
             en: "The Fell Court of Troia",
             ja: "トロイアコート",
             de: "Schwarzer Hof von Troia",
             fr: "Château de Troïa",
+            ..Default::default()
         },
         1071 => LocalisedText {
             en: "Storm's Crown",
             ja: "呪髪の風塔",
             de: "Sturmes Krone",
             fr: "Toison des tempêtes",
+            ..Default::default()
         },
         1072 => LocalisedText {
             en: "Storm's Crown",
             ja: "呪髪の風塔",
             de: "Sturmes Krone",
             fr: "Toison des tempêtes",
+            ..Default::default()
         },
         1073 => LocalisedText {
             en: "Elysion",
             ja: "エリュシオン",
             de: "Elysium",
             fr: "Élysée",
+            ..Default::default()
         },
         1075 => LocalisedText {
             en: "Another Sil'dihn Subterrane",
             ja: "異聞シラディハ水道",
             de: "Kuriose Unterstadt von Sil'dih",
             fr: "Canalisations sildiennes annexes",
+            ..Default::default()
         },
         1076 => LocalisedText {
             en: "Another Sil'dihn Subterrane",
             ja: "異聞シラディハ水道",
             de: "Kuriose Unterstadt von Sil'dih",
             fr: "Canalisations sildiennes annexes",
+            ..Default::default()
         },
         1077 => LocalisedText {
             en: "Zero's Domain",
             ja: "ゼロの領域",
             de: "Zeros Unterschlupf",
             fr: "Territoire de Zero",
+            ..Default::default()
         },
         1078 => LocalisedText {
             en: "Meghaduta Guest Chambers",
             ja: "メーガドゥータ宮の客間",
             de: "Gästezimmer des Meghaduta-Tempels",
             fr: "Appartements du palais du Meghaduta",
+            ..Default::default()
         },
         1079 => LocalisedText {
             en: "The Aitiascope",
             ja: "アイティオン星晶鏡",
             de: "Aitiaskop",
             fr: "Prisme de l'Aitia",
+            ..Default::default()
         },
         1081 => LocalisedText {
             en: "The Caustic Purgatory",
             ja: "猛毒生物隔離棟",
             de: "Sicherheitstrakt für toxische Lebenformen",
             fr: "Bloc des créatures extrêmement nocives",
+            ..Default::default()
         },
         1082 => LocalisedText {
             en: "The Caustic Purgatory",
             ja: "猛毒生物隔離棟",
             de: "Sicherheitstrakt für toxische Lebenformen",
             fr: "Bloc des créatures extrêmement nocives",
+            ..Default::default()
         },
         1083 => LocalisedText {
             en: "The Pestilent Purgatory",
             ja: "寄生生物隔離棟",
             de: "Isolationstrakt für parasitäre Lebensformen",
             fr: "Bloc des organismes parasites",
+            ..Default::default()
         },
         1084 => LocalisedText {
             en: "The Pestilent Purgatory",
             ja: "寄生生物隔離棟",
             de: "Isolationstrakt für parasitäre Lebensformen",
             fr: "Bloc des organismes parasites",
+            ..Default::default()
         },
         1085 => LocalisedText {
             en: "The Hollow Purgatory",
             ja: "創生樹隔離棟",
             de: "Separationstrakt für pflanzliche Lebensformen",
             fr: "Bloc des créations arborescentes",
+            ..Default::default()
         },
         1086 => LocalisedText {
             en: "The Hollow Purgatory",
             ja: "創生樹隔離棟",
             de: "Separationstrakt für pflanzliche Lebensformen",
             fr: "Bloc des créations arborescentes",
+            ..Default::default()
         },
         1087 => LocalisedText {
             en: "Stygian Insenescence Cells",
             ja: "生命神秘研究棟",
             de: "Forschungstrakt für die Geheimnisse des Lebens",
             fr: "Laboratoire d'études des secrets du vivant",
+            ..Default::default()
         },
         1088 => LocalisedText {
             en: "Stygian Insenescence Cells",
             ja: "生命神秘研究棟",
             de: "Forschungstrakt für die Geheimnisse des Lebens",
             fr: "Laboratoire d'études des secrets du vivant",
+            ..Default::default()
         },
         1089 => LocalisedText {
             en: "The Fell Court of Troia",
             ja: "トロイアコート",
             de: "Schwarzer Hof von Troia",
             fr: "Château de Troïa",
+            ..Default::default()
         },
         1091 => LocalisedText {
             en: "The Fell Court of Troia",
             ja: "トロイアコート",
             de: "Schwarzer Hof von Troia",
             fr: "Château de Troïa",
+            ..Default::default()
         },
         1092 => LocalisedText {
             en: "Storm's Crown",
             ja: "呪髪の風塔",
             de: "Sturmes Krone",
             fr: "Toison des tempêtes",
+            ..Default::default()
         },
         1093 => LocalisedText {
             en: "Stygian Insenescence Cells",
             ja: "生命神秘研究棟",
             de: "Forschungstrakt für die Geheimnisse des Lebens",
             fr: "Laboratoire d'études des secrets du vivant",
+            ..Default::default()
         },
         1094 => LocalisedText {
             en: "Sneaky Hollow",
             ja: "魔人の隠れ家",
             de: "Versteck der Kürbishexe",
             fr: "Demeure de la femme à tête de citrouille",
+            ..Default::default()
         },
         1095 => LocalisedText {
             en: "Mount Ordeals",
             ja: "試練の山",
             de: "Berg der Tortur",
             fr: "Mont du Supplice",
+            ..Default::default()
         },
         1096 => LocalisedText {
             en: "Mount Ordeals",
             ja: "試練の山",
             de: "Berg der Tortur",
             fr: "Mont du Supplice",
+            ..Default::default()
         },
         1097 => LocalisedText {
             en: "Lapis Manalis",
             ja: "ラピス・マナリス",
             de: "Lapis Manalis",
             fr: "Lapis Manalis",
+            ..Default::default()
         },
         1098 => LocalisedText {
             en: "Sylphstep",
             ja: "シルフ領風特設アスレチック",
             de: "Sylphenland",
             fr: "Sylve sylphe sibylline",
+            ..Default::default()
         },
         1099 => LocalisedText {
             en: "Eureka Orthos",
             ja: "オルト・エウレカ",
             de: "Eureka Orthos",
             fr: "Eurêka Orthos",
+            ..Default::default()
         },
         1100 => LocalisedText {
             en: "Eureka Orthos",
             ja: "オルト・エウレカ",
             de: "Eureka Orthos",
             fr: "Eurêka Orthos",
+            ..Default::default()
         },
         1101 => LocalisedText {
             en: "Eureka Orthos",
             ja: "オルト・エウレカ",
             de: "Eureka Orthos",
             fr: "Eurêka Orthos",
+            ..Default::default()
         },
         1102 => LocalisedText {
             en: "Eureka Orthos",
             ja: "オルト・エウレカ",
             de: "Eureka Orthos",
             fr: "Eurêka Orthos",
+            ..Default::default()
         },
         1103 => LocalisedText {
             en: "Eureka Orthos",
             ja: "オルト・エウレカ",
             de: "Eureka Orthos",
             fr: "Eurêka Orthos",
+            ..Default::default()
         },
         1104 => LocalisedText {
             en: "Eureka Orthos",
             ja: "オルト・エウレカ",
             de: "Eureka Orthos",
             fr: "Eurêka Orthos",
+            ..Default::default()
         },
         1105 => LocalisedText {
             en: "Eureka Orthos",
             ja: "オルト・エウレカ",
             de: "Eureka Orthos",
             fr: "Eurêka Orthos",
+            ..Default::default()
         },
         1106 => LocalisedText {
             en: "Eureka Orthos",
             ja: "オルト・エウレカ",
             de: "Eureka Orthos",
             fr: "Eurêka Orthos",
+            ..Default::default()
         },
         1107 => LocalisedText {
             en: "Eureka Orthos",
             ja: "オルト・エウレカ",
             de: "Eureka Orthos",
             fr: "Eurêka Orthos",
+            ..Default::default()
         },
         1108 => LocalisedText {
             en: "Eureka Orthos",
             ja: "オルト・エウレカ",
             de: "Eureka Orthos",
             fr: "Eurêka Orthos",
+            ..Default::default()
         },
         1109 => LocalisedText {
             en: "The Great Gubal Library",
             ja: "グブラ幻想図書館",
             de: "Große Gubal-Bibliothek",
             fr: "Grande bibliothèque de Gubal",
+            ..Default::default()
         },
         1110 => LocalisedText {
             en: "Aetherochemical Research Facility",
             ja: "魔科学研究所",
             de: "Ätherochemisches Forschungslabor",
             fr: "Laboratoire de magismologie",
+            ..Default::default()
         },
         1111 => LocalisedText {
             en: "The Antitower",
             ja: "逆さの塔",
             de: "Antiturm",
             fr: "L'Antitour",
+            ..Default::default()
         },
         1112 => LocalisedText {
             en: "Sohr Khai",
             ja: "ソール・カイ",
             de: "Sohr Khai",
             fr: "Sohr Khai",
+            ..Default::default()
         },
         1113 => LocalisedText {
             en: "Xelphatol",
             ja: "ゼルファトル",
             de: "Xelphatol",
             fr: "Xelphatol",
+            ..Default::default()
         },
         1114 => LocalisedText {
             en: "Baelsar's Wall",
             ja: "バエサルの長城",
             de: "Baelsar-Wall",
             fr: "Muraille de Baelsar",
+            ..Default::default()
         },
         1115 => LocalisedText {
             en: "The Tower of Babil",
             ja: "バブイルの塔",
             de: "Turm von Babil",
             fr: "Tour de Babil",
+            ..Default::default()
         },
         1116 => LocalisedText {
             en: "The Clockwork Castletown",
             ja: "東方絡繰御殿",
             de: "Die Mechanische Menagerie",
             fr: "Le Traquenard oriental",
+            ..Default::default()
         },
         1117 => LocalisedText {
             en: "The Clockwork Castletown",
             ja: "東方絡繰御殿",
             de: "Die Mechanische Menagerie",
             fr: "Le Traquenard oriental",
+            ..Default::default()
         },
         1118 => LocalisedText {
             en: "Euphrosyne",
             ja: "喜びの神域 エウプロシュネ",
             de: "Euphrosyne",
             fr: "Domaine divin - Euphrosyne",
+            ..Default::default()
         },
         1119 => LocalisedText {
             en: "Lapis Manalis",
             ja: "ラピス・マナリス",
             de: "Lapis Manalis",
             fr: "Lapis Manalis",
+            ..Default::default()
         },
         1120 => LocalisedText {
             en: "Garlemald",
             ja: "ガレマルド",
             de: "Garlemald",
             fr: "Garlemald",
+            ..Default::default()
         },
         1122 => LocalisedText {
             en: "The Interdimensional Rift",
             ja: "次元の狭間",
             de: "Interdimensionaler Riss",
             fr: "Fissure interdimensionnelle",
+            ..Default::default()
         },
         1123 => LocalisedText {
             en: "The Shifting Gymnasion Agonon",
             ja: "エルピス・ギュムナシオン祭殿",
             de: "Gymnasion Agonon",
             fr: "Elpis Gymnasion",
+            ..Default::default()
         },
         1124 => LocalisedText {
             en: "Eureka Orthos",
             ja: "オルト・エウレカ",
             de: "Eureka Orthos",
             fr: "Eurêka Orthos",
+            ..Default::default()
         },
         1125 => LocalisedText {
             en: "Khadga",
             ja: "カドガの大岩",
             de: "Khadga-Felsen",
             fr: "Khadga",
+            ..Default::default()
         },
         1126 => LocalisedText {
             en: "The Aetherfont",
             ja: "ハーム島",
             de: "Ätherborn",
             fr: "Île de Haam",
+            ..Default::default()
         },
         1127 => LocalisedText {
             en: "The Fold",
             ja: "ザ・フォールド",
             de: "Wolfspferch (The Fold)",
             fr: "La Louverie (The Fold)",
+            ..Default::default()
         },
         1128 => LocalisedText {
             en: "The Fold",
             ja: "ザ・フォールド",
             de: "Wolfspferch (The Fold)",
             fr: "La Louverie (The Fold)",
+            ..Default::default()
         },
         1129 => LocalisedText {
             en: "The Fold",
             ja: "ザ・フォールド",
             de: "Wolfspferch (The Fold)",
             fr: "La Louverie (The Fold)",
+            ..Default::default()
         },
         1136 => LocalisedText {
             en: "The Gilded Araya",
             ja: "アラヤの宝塔",
             de: "Arayas Palast",
             fr: "Temple d'Araya",
+            ..Default::default()
         },
         1137 => LocalisedText {
             en: "Mount Rokkon",
             ja: "六根山",
             de: "Rokkon",
             fr: "Mont Rokkon",
+            ..Default::default()
         },
         1138 => LocalisedText {
             en: "The Red Sands",
             ja: "レッド・サンズ",
             de: "Die Roten Sande",
             fr: "Les Sables sanglants",
+            ..Default::default()
         },
         1139 => LocalisedText {
             en: "The Red Sands",
             ja: "レッド・サンズ",
             de: "Die Roten Sande",
             fr: "Les Sables sanglants",
+            ..Default::default()
         },
         1140 => LocalisedText {
             en: "The Voidcast Dais",
             ja: "立方魔法陣",
             de: "Krone der Schatten",
             fr: "Hypercube de Golbez",
+            ..Default::default()
         },
         1141 => LocalisedText {
             en: "The Voidcast Dais",
             ja: "立方魔法陣",
             de: "Krone der Schatten",
             fr: "Hypercube de Golbez",
+            ..Default::default()
         },
         1142 => LocalisedText {
             en: "The Sirensong Sea",
             ja: "セイレーン海",
             de: "Sirenen-See",
             fr: "Mer du Chant des sirènes",
+            ..Default::default()
         },
         1143 => LocalisedText {
             en: "Bardam's Mettle",
             ja: "バルダム覇道",
             de: "Bardams Probe",
             fr: "Force de Bardam",
+            ..Default::default()
         },
         1144 => LocalisedText {
             en: "Doma Castle",
             ja: "ドマ城",
             de: "Burg Doma",
             fr: "Château de Doma",
+            ..Default::default()
         },
         1145 => LocalisedText {
             en: "Castrum Abania",
             ja: "カストルム・アバニア",
             de: "Castrum Abania",
             fr: "Castrum Abania",
+            ..Default::default()
         },
         1146 => LocalisedText {
             en: "Ala Mhigo",
             ja: "アラミゴ",
             de: "Ala Mhigo",
             fr: "Ala Mhigo",
+            ..Default::default()
         },
         1147 => LocalisedText {
             en: "The Aetherial Slough",
             ja: "星海滞留域",
             de: "Sphäre des Pandæmoniums",
             fr: "Nappe stagnante",
+            ..Default::default()
         },
         1148 => LocalisedText {
             en: "The Aetherial Slough",
             ja: "星海滞留域",
             de: "Sphäre des Pandæmoniums",
             fr: "Nappe stagnante",
+            ..Default::default()
         },
         1149 => LocalisedText {
             en: "The Dæmons' Nest",
             ja: "万魔の産屋",
             de: "Dæmonisches Nest",
             fr: "Maternité démoniaque",
+            ..Default::default()
         },
         1150 => LocalisedText {
             en: "The Dæmons' Nest",
             ja: "万魔の産屋",
             de: "Dæmonisches Nest",
             fr: "Maternité démoniaque",
+            ..Default::default()
         },
         1151 => LocalisedText {
             en: "The Chamber of Fourteen",
             ja: "十四座の間",
             de: "Halle der Vierzehn Sitze",
             fr: "Prétoire des Quatorze",
+            ..Default::default()
         },
         1152 => LocalisedText {
             en: "The Chamber of Fourteen",
             ja: "十四座の間",
             de: "Halle der Vierzehn Sitze",
             fr: "Prétoire des Quatorze",
+            ..Default::default()
         },
         1153 => LocalisedText {
             en: "Ascension",
             ja: "神の座",
             de: "Göttlicher Thron",
             fr: "Trône divin",
+            ..Default::default()
         },
         1154 => LocalisedText {
             en: "Ascension",
             ja: "神の座",
             de: "Göttlicher Thron",
             fr: "Trône divin",
+            ..Default::default()
         },
         1155 => LocalisedText {
             en: "Another Mount Rokkon",
             ja: "異聞六根山",
             de: "Kurioser Rokkon",
             fr: "Mont Rokkon annexe",
+            ..Default::default()
         },
         1156 => LocalisedText {
             en: "Another Mount Rokkon",
             ja: "異聞六根山",
             de: "Kurioser Rokkon",
             fr: "Mont Rokkon annexe",
+            ..Default::default()
         },
         1158 => LocalisedText {
             en: "The Dæmons' Nest",
             ja: "万魔の産屋",
             de: "Dæmonisches Nest",
             fr: "Maternité démoniaque",
+            ..Default::default()
         },
         1159 => LocalisedText {
             en: "The Voidcast Dais",
             ja: "立方魔法陣",
             de: "Krone der Schatten",
             fr: "Hypercube de Golbez",
+            ..Default::default()
         },
         1160 => LocalisedText {
             en: "Senatus",
             ja: "セナトゥス駅",
             de: "Senatus",
             fr: "Gare de Senatus",
+            ..Default::default()
         },
         1161 => LocalisedText {
             en: "Estinien's Chambers",
             ja: "エスティニアンの客室",
             de: "Estiniens Räumlichkeiten",
             fr: "Chambre d'Estinien",
+            ..Default::default()
         },
         1162 => LocalisedText {
             en: "The Red Moon",
             ja: "赤き月",
             de: "Roter Mond",
             fr: "Lune rouge",
+            ..Default::default()
         },
         1163 => LocalisedText {
             en: "The Endeavor",
             ja: "エンデバー号",
             de: "Die Endeavor",
             fr: "L'Ambition",
+            ..Default::default()
         },
         1164 => LocalisedText {
             en: "The Lunar Subterrane",
             ja: "月の地下渓谷",
             de: "Monduntergrund",
             fr: "Souterrain lunaire",
+            ..Default::default()
         },
         1165 => LocalisedText {
             en: "Blunderville",
             ja: "ブランダーヴィル",
             de: "Stolperville",
             fr: "Blunderville",
+            ..Default::default()
         },
         1166 => LocalisedText {
             en: "The Memory of Embers",
             ja: "詩想空間",
             de: "Lied der Flammen",
             fr: "Interstice du chant onirique",
+            ..Default::default()
         },
         1167 => LocalisedText {
             en: "Ihuykatumu",
             ja: "イフイカ・トゥム",
             de: "Ihuykatumu",
             fr: "Ihuykatumu",
+            ..Default::default()
         },
         1168 => LocalisedText {
             en: "The Abyssal Fracture",
             ja: "赤き月の底",
             de: "Tiefen des roten Mondes",
             fr: "Cœur de la lune rouge",
+            ..Default::default()
         },
         1169 => LocalisedText {
             en: "The Abyssal Fracture",
             ja: "赤き月の底",
             de: "Tiefen des roten Mondes",
             fr: "Cœur de la lune rouge",
+            ..Default::default()
         },
         1170 => LocalisedText {
             en: "Sunperch",
             ja: "金凰の間",
             de: "Sonnensitz",
             fr: "Salle de l'Aurore",
+            ..Default::default()
         },
         1171 => LocalisedText {
             en: "Earthen Sky Hideout",
             ja: "空の坑道のアジト",
             de: "Unterschlupf im Irdenen Himmel",
             fr: "Refuge des Cavernes célestes",
+            ..Default::default()
         },
         1172 => LocalisedText {
             en: "The Drowned City of Skalla",
             ja: "スカラ",
             de: "Die versunkene Stadt Skalla",
             fr: "Cité engloutie de Skalla",
+            ..Default::default()
         },
         1173 => LocalisedText {
             en: "The Burn",
             ja: "ザ・バーン",
             de: "Kargland",
             fr: "L'Escarre",
+            ..Default::default()
         },
         1174 => LocalisedText {
             en: "The Ghimlyt Dark",
             ja: "ギムリトダーク",
             de: "Ghimlyt-Finsternis",
             fr: "Les Ténèbres de Ghimlyt",
+            ..Default::default()
         },
         1176 => LocalisedText {
             en: "Aloalo Island",
             ja: "アロアロ島",
             de: "Aloalo",
             fr: "Île d'Aloalo",
+            ..Default::default()
         },
         1177 => LocalisedText {
             en: "The Aetherfont",
             ja: "ハーム島",
             de: "Ätherborn",
             fr: "Île de Haam",
+            ..Default::default()
         },
         1178 => LocalisedText {
             en: "Thaleia",
             ja: "華めく神域 タレイア",
             de: "Thaleia",
             fr: "Domaine divin - Thalie",
+            ..Default::default()
         },
         1179 => LocalisedText {
             en: "Another Aloalo Island",
             ja: "異聞アロアロ島",
             de: "Kurioses Aloalo",
             fr: "Île d'Aloalo annexe",
+            ..Default::default()
         },
         1180 => LocalisedText {
             en: "Another Aloalo Island",
             ja: "異聞アロアロ島",
             de: "Kurioses Aloalo",
             fr: "Île d'Aloalo annexe",
+            ..Default::default()
         },
         1181 => LocalisedText {
             en: "The Abyssal Fracture",
             ja: "赤き月の底",
             de: "Tiefen des roten Mondes",
             fr: "Cœur de la lune rouge",
+            ..Default::default()
         },
         1182 => LocalisedText {
             en: "Thaleia",
             ja: "華めく神域 タレイア",
             de: "Thaleia",
             fr: "Domaine divin - Thalie",
+            ..Default::default()
         },
         1183 => LocalisedText {
             en: "The Gilded Araya",
             ja: "アラヤの宝塔",
             de: "Arayas Palast",
             fr: "Temple d'Araya",
+            ..Default::default()
         },
         1184 => LocalisedText {
             en: "The Lunar Subterrane",
             ja: "月の地下渓谷",
             de: "Monduntergrund",
             fr: "Souterrain lunaire",
+            ..Default::default()
         },
         1185 => LocalisedText {
             en: "Tuliyollal",
             ja: "トライヨラ",
             de: "Tuliyollal",
             fr: "Tuliyollal",
+            ..Default::default()
         },
         1186 => LocalisedText {
             en: "Solution Nine",
             ja: "ソリューション・ナイン",
             de: "Lösung Neun",
             fr: "Solution Neuf",
+            ..Default::default()
         },
         1187 => LocalisedText {
             en: "Urqopacha",
             ja: "オルコ・パチャ",
             de: "Urqopacha",
             fr: "Urqopacha",
+            ..Default::default()
         },
         1188 => LocalisedText {
             en: "Kozama'uka",
             ja: "コザマル・カ",
             de: "Kozama'uka",
             fr: "Kozama'uka",
+            ..Default::default()
         },
         1189 => LocalisedText {
             en: "Yak T'el",
             ja: "ヤクテル樹海",
             de: "Yak T'el",
             fr: "Yak T'el",
+            ..Default::default()
         },
         1190 => LocalisedText {
             en: "Shaaloani",
             ja: "シャーローニ荒野",
             de: "Shaaloani",
             fr: "Shaaloani",
+            ..Default::default()
         },
         1191 => LocalisedText {
             en: "Heritage Found",
             ja: "ヘリテージファウンド",
             de: "Ewiges Erbe",
             fr: "L'Hoirie recouvrée",
+            ..Default::default()
         },
         1192 => LocalisedText {
             en: "Living Memory",
             ja: "リビング・メモリー",
             de: "Lebende Erinnerung",
             fr: "La Mémoire vivante",
+            ..Default::default()
         },
         1193 => LocalisedText {
             en: "Worqor Zormor",
             ja: "ウォーコー・ゾーモー",
             de: "Worqor Zormor",
             fr: "Worqor Zormor",
+            ..Default::default()
         },
         1194 => LocalisedText {
             en: "The Skydeep Cenote",
             ja: "天深きセノーテ",
             de: "Himmelstiefer Cenote",
             fr: "Cénote des Cieux infinis",
+            ..Default::default()
         },
         1195 => LocalisedText {
             en: "Worqor Lar Dor",
             ja: "ウォーコー・ラァ・ドー",
             de: "Worqor Lar Dor",
             fr: "Worqor Lar Dor",
+            ..Default::default()
         },
         1196 => LocalisedText {
             en: "Worqor Lar Dor",
             ja: "ウォーコー・ラァ・ドー",
             de: "Worqor Lar Dor",
             fr: "Worqor Lar Dor",
+            ..Default::default()
         },
         1197 => LocalisedText {
             en: "Blunderville Square",
             ja: "ブランダーヴィルスクウェア",
             de: "Stolperville-Platz",
             fr: "Square du Blunderville",
+            ..Default::default()
         },
         1198 => LocalisedText {
             en: "Vanguard",
             ja: "ヴァンガード",
             de: "Außenposten",
             fr: "L'Avant-garde",
+            ..Default::default()
         },
         1199 => LocalisedText {
             en: "Alexandria",
             ja: "アレクサンドリア",
             de: "Alexandria",
             fr: "Alexandrie",
+            ..Default::default()
         },
         1200 => LocalisedText {
             en: "Summit of Everkeep",
             ja: "エバーキープ最上層",
             de: "Immerspitze",
             fr: "Sommet de la Gardienne de l'Éternité",
+            ..Default::default()
         },
         1201 => LocalisedText {
             en: "Summit of Everkeep",
             ja: "エバーキープ最上層",
             de: "Immerspitze",
             fr: "Sommet de la Gardienne de l'Éternité",
+            ..Default::default()
         },
         1202 => LocalisedText {
             en: "Interphos",
             ja: "次元黄道",
             de: "Interdimensionale Ekliptik",
             fr: "Écliptique dimensionnelle",
+            ..Default::default()
         },
         1203 => LocalisedText {
             en: "Tender Valley",
             ja: "サボテンダーバレー",
             de: "Kaktorsenke",
             fr: "Vallée des Pampas",
+            ..Default::default()
         },
         1204 => LocalisedText {
             en: "Strayborough",
             ja: "ストレイバロー",
             de: "Mahrlingen-Promenade",
             fr: "Parc-aux-Errants",
+            ..Default::default()
         },
         1205 => LocalisedText {
             en: "The For'ard Cabins",
             ja: "フォルアード・キャビンズ",
             de: "Kielkajüten",
             fr: "Cabines de Proue",
+            ..Default::default()
         },
         1206 => LocalisedText {
             en: "Main Deck",
             ja: "大洋の船上",
             de: "Auf hoher See",
             fr: "Pont du navire",
+            ..Default::default()
         },
         1207 => LocalisedText {
             en: "The Backroom",
             ja: "バックルーム",
             de: "Hinterzimmer",
             fr: "Le Repaire",
+            ..Default::default()
         },
         1208 => LocalisedText {
             en: "Origenics",
             ja: "オリジェニクス",
             de: "Origenik",
             fr: "L'Origenèse",
+            ..Default::default()
         },
         1209 => LocalisedText {
             en: "Cenote Ja Ja Gural",
             ja: "セノーテ・ジャジャグラル",
             de: "Cenote Ja Ja Gural",
             fr: "Cénote Ja Ja Gural",
+            ..Default::default()
         },
         1210 => LocalisedText {
             en: "Sunperch",
             ja: "金凰の間",
             de: "Sonnensitz",
             fr: "Salle de l'Aurore",
+            ..Default::default()
         },
         1211 => LocalisedText {
             en: "Yak T'el",
             ja: "ヤクテル樹海",
             de: "Yak T'el",
             fr: "Yak T'el",
+            ..Default::default()
         },
         1212 => LocalisedText {
             en: "Yak T'el",
             ja: "ヤクテル樹海",
             de: "Yak T'el",
             fr: "Yak T'el",
+            ..Default::default()
         },
         1213 => LocalisedText {
             en: "Solution Nine",
             ja: "ソリューション・ナイン",
             de: "Lösung Neun",
             fr: "Solution Neuf",
+            ..Default::default()
         },
         1214 => LocalisedText {
             en: "The Sea of Clouds",
             ja: "アバラシア雲海",
             de: "Abalathisches Wolkenmeer",
             fr: "L'Écume des cieux d'Abalathia",
+            ..Default::default()
         },
         1215 => LocalisedText {
             en: "Brayflox's Longstop",
             ja: "ブレイフロクスの野営地",
             de: "Brüllvolx' Langrast",
             fr: "Bivouac de Brayflox",
+            ..Default::default()
         },
         1216 => LocalisedText {
             en: "Bardam's Mettle",
             ja: "バルダム覇道",
             de: "Bardams Probe",
             fr: "Force de Bardam",
+            ..Default::default()
         },
         1217 => LocalisedText {
             en: "Ala Mhigo",
             ja: "アラミゴ",
             de: "Ala Mhigo",
             fr: "Ala Mhigo",
+            ..Default::default()
         },
         1218 => LocalisedText {
             en: "Khadga",
             ja: "カドガの大岩",
             de: "Khadga-Felsen",
             fr: "Khadga",
+            ..Default::default()
         },
         1219 => LocalisedText {
             en: "Vanguard",
             ja: "ヴァンガード",
             de: "Außenposten",
             fr: "L'Avant-garde",
+            ..Default::default()
         },
         1220 => LocalisedText {
             en: "Summit of Everkeep",
             ja: "エバーキープ最上層",
             de: "Immerspitze",
             fr: "Sommet de la Gardienne de l'Éternité",
+            ..Default::default()
         },
         1221 => LocalisedText {
             en: "Interphos",
             ja: "次元黄道",
             de: "Interdimensionale Ekliptik",
             fr: "Écliptique dimensionnelle",
+            ..Default::default()
         },
         1222 => LocalisedText {
             en: "Skydeep Cenote Inner Chamber",
             ja: "天深きセノーテ 最深部",
             de: "Tiefste Tiefen des Himmelstiefen Cenote",
             fr: "Profondeurs du cénote des Cieux infinis",
+            ..Default::default()
         },
         1223 => LocalisedText {
             en: "Tritails Training",
             ja: "ジム・トライテール",
             de: "Tatzentrio-Training",
             fr: "Trois Queues",
+            ..Default::default()
         },
         1224 => LocalisedText {
             en: "Greenroom",
             ja: "控室",
             de: "Warteraum",
             fr: "Locaux de la Société de l'Arcadion",
+            ..Default::default()
         },
         1225 => LocalisedText {
             en: "Scratching Ring",
             ja: "リング・オブ・キャット",
             de: "Katzenring",
             fr: "Ring des Griffes félines",
+            ..Default::default()
         },
         1226 => LocalisedText {
             en: "Scratching Ring",
             ja: "リング・オブ・キャット",
             de: "Katzenring",
             fr: "Ring des Griffes félines",
+            ..Default::default()
         },
         1227 => LocalisedText {
             en: "Lovely Lovering",
             ja: "リング・オブ・ラブ",
             de: "Liebesring",
             fr: "Ring de l'Amour mielleux",
+            ..Default::default()
         },
         1228 => LocalisedText {
             en: "Lovely Lovering",
             ja: "リング・オブ・ラブ",
             de: "Liebesring",
             fr: "Ring de l'Amour mielleux",
+            ..Default::default()
         },
         1229 => LocalisedText {
             en: "Blasting Ring",
             ja: "リング・オブ・ボンバー",
             de: "Bombenring",
             fr: "Ring du Bombardement brutal",
+            ..Default::default()
         },
         1230 => LocalisedText {
             en: "Blasting Ring",
             ja: "リング・オブ・ボンバー",
             de: "Bombenring",
             fr: "Ring du Bombardement brutal",
+            ..Default::default()
         },
         1231 => LocalisedText {
             en: "The Thundering",
             ja: "リング・オブ・サンダー",
             de: "Donnerring",
             fr: "Ring du Tonnerre terrifiant",
+            ..Default::default()
         },
         1232 => LocalisedText {
             en: "The Thundering",
             ja: "リング・オブ・サンダー",
             de: "Donnerring",
             fr: "Ring du Tonnerre terrifiant",
+            ..Default::default()
         },
         1233 => LocalisedText {
             en: "Manor Basement",
             ja: "廃屋の地下室",
             de: "Keller des verlassenen Gebäudes",
             fr: "Sous-sol du vieux manoir",
+            ..Default::default()
         },
         1234 => LocalisedText {
             en: "Dreamlike Palace",
             ja: "夢香る宮殿",
             de: "Palast der Träume",
             fr: "Palais au parfum onirique",
+            ..Default::default()
         },
         1235 => LocalisedText {
             en: "Central Thanalan",
             ja: "中央ザナラーン",
             de: "Zentrales Thanalan",
             fr: "Thanalan central",
+            ..Default::default()
         },
         1236 => LocalisedText {
             en: "Southern Thanalan",
             ja: "南ザナラーン",
             de: "Südliches Thanalan",
             fr: "Thanalan méridional",
+            ..Default::default()
         },
         1237 => LocalisedText {
             en: "Sinus Ardorum",
             ja: "焦がれの入江",
             de: "Sinus Ardorum",
             fr: "Sinus Ardorum",
+            ..Default::default()
         },
         1238 => LocalisedText {
             en: "A Future Rewritten",
             ja: "もうひとつの未来",
             de: "Eine zweite Zukunft",
             fr: "Avenirs réécrits",
+            ..Default::default()
         },
         1241 => LocalisedText {
             en: "Sphere of Naught",
             ja: "暗闇の領域",
             de: "Sphäre der Leere",
             fr: "Sphère de l'absence",
+            ..Default::default()
         },
         1242 => LocalisedText {
             en: "Yuweyawata",
             ja: "ユウェヤーワータ",
             de: "Yuweyawata",
             fr: "Yuweyawata",
+            ..Default::default()
         },
         1243 => LocalisedText {
             en: "Interphos",
             ja: "次元黄道",
             de: "Interdimensionale Ekliptik",
             fr: "Écliptique dimensionnelle",
+            ..Default::default()
         },
         1244 => LocalisedText {
             en: "Shaaloani",
             ja: "シャーローニ荒野",
             de: "Shaaloani",
             fr: "Shaaloani",
+            ..Default::default()
         },
         1245 => LocalisedText {
             en: "Halatali",
             ja: "ハラタリ修練所",
             de: "Halatali",
             fr: "Halatali",
+            ..Default::default()
         },
         1246 => LocalisedText {
             en: "Zorgor the Boundless",
             ja: "ゾーゴー永結橋",
             de: "Zorgor die Weite",
             fr: "Traverse de Zorgor",
+            ..Default::default()
         },
         1247 => LocalisedText {
             en: "Starlight Stalls",
             ja: "星芒マーケット",
             de: "Sternenlicht-Stände",
             fr: "Marché des étoiles",
+            ..Default::default()
         },
         1248 => LocalisedText {
             en: "Jeuno: The First Walk",
             ja: "ジュノ：ザ・ファーストウォーク",
             de: "Jeuno: Die erste Etappe",
             fr: "Jeuno - La première perambulation",
+            ..Default::default()
         },
         1252 => LocalisedText {
             en: "South Horn",
             ja: "三日月島南部",
             de: "Südliches Kreszentia",
             fr: "Île de Lunule méridionale",
+            ..Default::default()
         },
         1253 => LocalisedText {
             en: "Starlight Stalls",
             ja: "星芒マーケット",
             de: "Sternenlicht-Stände",
             fr: "Marché des étoiles",
+            ..Default::default()
         },
         1254 => LocalisedText {
             en: "Yuweyawata",
             ja: "ユウェヤーワータ",
             de: "Yuweyawata",
             fr: "Yuweyawata",
+            ..Default::default()
         },
         1255 => LocalisedText {
             en: "Hall of the Unbound",
             ja: "仮設オーディション会場",
             de: "Audienzsaal",
             fr: "Salle d'audition des Affranchis",
+            ..Default::default()
         },
         1256 => LocalisedText {
             en: "Groovy Ring",
             ja: "リング・オブ・ダンス",
             de: "Tanzring",
             fr: "Ring des Chorégraphies endiablées",
+            ..Default::default()
         },
         1257 => LocalisedText {
             en: "Groovy Ring",
             ja: "リング・オブ・ダンス",
             de: "Tanzring",
             fr: "Ring des Chorégraphies endiablées",
+            ..Default::default()
         },
         1258 => LocalisedText {
             en: "Rebel Ring",
             ja: "リング・オブ・アート",
             de: "Kunstring",
             fr: "Ring de l'Inspiration artistique",
+            ..Default::default()
         },
         1259 => LocalisedText {
             en: "Rebel Ring",
             ja: "リング・オブ・アート",
             de: "Kunstring",
             fr: "Ring de l'Inspiration artistique",
+            ..Default::default()
         },
         1260 => LocalisedText {
             en: "Demolition Site",
             ja: "第八層建設区域",
             de: "Neubaugebiet der achten Ebene",
             fr: "Site de construction du huitième étage",
+            ..Default::default()
         },
         1261 => LocalisedText {
             en: "Demolition Site",
             ja: "第八層建設区域",
             de: "Neubaugebiet der achten Ebene",
             fr: "Site de construction du huitième étage",
+            ..Default::default()
         },
         1262 => LocalisedText {
             en: "Hunter's Ring",
             ja: "リング・オブ・ウルフ",
             de: "Wolfsring",
             fr: "Ring du Champion lupin",
+            ..Default::default()
         },
         1263 => LocalisedText {
             en: "Hunter's Ring",
             ja: "リング・オブ・ウルフ",
             de: "Wolfsring",
             fr: "Ring du Champion lupin",
+            ..Default::default()
         },
         1264 => LocalisedText {
             en: "Lower Jeuno",
             ja: "ジュノ下層",
             de: "Unter-Jeuno",
             fr: "Bas Jeuno",
+            ..Default::default()
         },
         1265 => LocalisedText {
             en: "Lower Jeuno",
             ja: "ジュノ下層",
             de: "Unter-Jeuno",
             fr: "Bas Jeuno",
+            ..Default::default()
         },
         1266 => LocalisedText {
             en: "The Underkeep",
             ja: "アンダーキープ",
             de: "Unterfried",
             fr: "Gardienne de l'Immémorial",
+            ..Default::default()
         },
         1267 => LocalisedText {
             en: "The Sunken Temple of Qarn",
             ja: "カルン埋没寺院",
             de: "Versunkener Tempel von Qarn",
             fr: "Temple enseveli de Qarn",
+            ..Default::default()
         },
         1268 => LocalisedText {
             en: "Break Room",
             ja: "仮眠室",
             de: "Ruheraum",
             fr: "Salle de repos",
+            ..Default::default()
         },
         1269 => LocalisedText {
             en: "Phantom Village",
             ja: "蜃気楼の町",
             de: "Trugstadt",
             fr: "Ville fantôme",
+            ..Default::default()
         },
         1270 => LocalisedText {
             en: "Recollection",
             ja: "玉座の間",
             de: "Audienzhalle",
             fr: "Salle du trône séculaire",
+            ..Default::default()
         },
         1271 => LocalisedText {
             en: "Recollection",
             ja: "玉座の間",
             de: "Audienzhalle",
             fr: "Salle du trône séculaire",
+            ..Default::default()
         },
         1273 => LocalisedText {
             en: "Carteneau Flats: Borderland Ruins",
             ja: "カルテノー平原：外縁遺跡群",
             de: "Äußere Ruinen",
             fr: "Ruines frontalières",
+            ..Default::default()
         },
         1274 => LocalisedText {
             en: "Throne Room",
             ja: "玉座の間",
             de: "Audienzhalle",
             fr: "Salle du trône séculaire",
+            ..Default::default()
         },
         1275 => LocalisedText {
             en: "Recollection",
             ja: "玉座の間",
             de: "Audienzhalle",
             fr: "Salle du trône séculaire",
+            ..Default::default()
         },
         1276 => LocalisedText {
             en: "Demolition Site",
             ja: "第八層建設区域",
             de: "Neubaugebiet der achten Ebene",
             fr: "Site de construction du huitième étage",
+            ..Default::default()
         },
         1277 => LocalisedText {
             en: "Main Deck",
             ja: "大洋の船上",
             de: "Auf hoher See",
             fr: "Pont du navire",
+            ..Default::default()
         },
         1278 => LocalisedText {
             en: "Phantom Village",
             ja: "蜃気楼の町",
             de: "Trugstadt",
             fr: "Ville fantôme",
+            ..Default::default()
         },
         1279 => LocalisedText {
             en: "Vault Oneiron",
             ja: "ボルト・オネイロン",
             de: "Tresor Oneiron",
             fr: "Coffre d'Oneiron",
+            ..Default::default()
         },
         1280 => LocalisedText {
             en: "Pilgrim's Traverse",
             ja: "ピルグリム・トラバース",
             de: "Pilgers Pfad",
             fr: "Sanctuaire des pèlerins",
+            ..Default::default()
         },
         1281 => LocalisedText {
             en: "Pilgrim's Traverse",
             ja: "ピルグリム・トラバース",
             de: "Pilgers Pfad",
             fr: "Sanctuaire des pèlerins",
+            ..Default::default()
         },
         1282 => LocalisedText {
             en: "Pilgrim's Traverse",
             ja: "ピルグリム・トラバース",
             de: "Pilgers Pfad",
             fr: "Sanctuaire des pèlerins",
+            ..Default::default()
         },
         1283 => LocalisedText {
             en: "Pilgrim's Traverse",
             ja: "ピルグリム・トラバース",
             de: "Pilgers Pfad",
             fr: "Sanctuaire des pèlerins",
+            ..Default::default()
         },
         1284 => LocalisedText {
             en: "Pilgrim's Traverse",
             ja: "ピルグリム・トラバース",
             de: "Pilgers Pfad",
             fr: "Sanctuaire des pèlerins",
+            ..Default::default()
         },
         1285 => LocalisedText {
             en: "Pilgrim's Traverse",
             ja: "ピルグリム・トラバース",
             de: "Pilgers Pfad",
             fr: "Sanctuaire des pèlerins",
+            ..Default::default()
         },
         1286 => LocalisedText {
             en: "Pilgrim's Traverse",
             ja: "ピルグリム・トラバース",
             de: "Pilgers Pfad",
             fr: "Sanctuaire des pèlerins",
+            ..Default::default()
         },
         1287 => LocalisedText {
             en: "Pilgrim's Traverse",
             ja: "ピルグリム・トラバース",
             de: "Pilgers Pfad",
             fr: "Sanctuaire des pèlerins",
+            ..Default::default()
         },
         1288 => LocalisedText {
             en: "Pilgrim's Traverse",
             ja: "ピルグリム・トラバース",
             de: "Pilgers Pfad",
             fr: "Sanctuaire des pèlerins",
+            ..Default::default()
         },
         1289 => LocalisedText {
             en: "Pilgrim's Traverse",
             ja: "ピルグリム・トラバース",
             de: "Pilgers Pfad",
             fr: "Sanctuaire des pèlerins",
+            ..Default::default()
         },
         1290 => LocalisedText {
             en: "Pilgrim's Traverse",
             ja: "ピルグリム・トラバース",
             de: "Pilgers Pfad",
             fr: "Sanctuaire des pèlerins",
+            ..Default::default()
         },
         1291 => LocalisedText {
             en: "Phaenna",
             ja: "惑星パエンナ",
             de: "Phaenna",
             fr: "Phaenna",
+            ..Default::default()
         },
         1292 => LocalisedText {
             en: "The Meso Terminal",
             ja: "メインターミナル",
             de: "Meso-System",
             fr: "Mésoterminal",
+            ..Default::default()
         },
         1293 => LocalisedText {
             en: "The Bayside Battleground",
             ja: "ベイサイド・バトルグラウンド",
             de: "Der Küstenkampf",
             fr: "Le Rivage redoutable",
+            ..Default::default()
         },
         1294 => LocalisedText {
             en: "The Bayside Battleground",
             ja: "ベイサイド・バトルグラウンド",
             de: "Der Küstenkampf",
             fr: "Le Rivage redoutable",
+            ..Default::default()
         },
         1295 => LocalisedText {
             en: "The Ageless Necropolis",
             ja: "死の満ちる地",
             de: "Fülle des Todes",
             fr: "Nécropole intemporelle",
+            ..Default::default()
         },
         1296 => LocalisedText {
             en: "The Ageless Necropolis",
             ja: "死の満ちる地",
             de: "Fülle des Todes",
             fr: "Nécropole intemporelle",
+            ..Default::default()
         },
         1297 => LocalisedText {
             en: "Zirgorteh the Open-armed",
             ja: "ジィゴーテー拝壇",
             de: "Zirgorteh der Grüßende",
             fr: "Autel de Zirgorteh",
+            ..Default::default()
         },
         1298 => LocalisedText {
             en: "The Pâtisserie",
             ja: "ヴァレンティオンパティスリー",
             de: "Pâtisserie",
             fr: "Pâtisserie de la Valention",
+            ..Default::default()
         },
         1299 => LocalisedText {
             en: "Containment Complex 10-29",
             ja: "格納殻10-29",
             de: "Lagerhülle 10-29",
             fr: "Division d'entreposage 10-29",
+            ..Default::default()
         },
         1300 => LocalisedText {
             en: "The Windward Wilds",
             ja: "隔ての荒地",
             de: "Wildebene",
             fr: "Plaines venteuses",
+            ..Default::default()
         },
         1301 => LocalisedText {
             en: "The Windward Wilds",
             ja: "隔ての荒地",
             de: "Wildebene",
             fr: "Plaines venteuses",
+            ..Default::default()
         },
         1303 => LocalisedText {
             en: "Cutter's Cry",
             ja: "カッターズクライ",
             de: "Sägerschrei",
             fr: "Gouffre hurlant",
+            ..Default::default()
         },
         1304 => LocalisedText {
             en: "San d'Oria: The Second Walk",
             ja: "サンドリア：ザ・セカンドウォーク",
             de: "San d'Oria: Die zweite Etappe",
             fr: "San d'Oria - La deuxième perambulation",
+            ..Default::default()
         },
         1305 => LocalisedText {
             en: "Haunted Manor",
             ja: "亡霊屋敷 ホーンテッドマナー",
             de: "Geisterschloss",
             fr: "Manoir hanté",
+            ..Default::default()
         },
         1306 => LocalisedText {
             en: "The Windward Wilds",
             ja: "隔ての荒地",
             de: "Wildebene",
             fr: "Plaines venteuses",
+            ..Default::default()
         },
         1307 => LocalisedText {
             en: "Hell on Rails",
             ja: "鉄馬車線路",
             de: "Gütergleise",
             fr: "Voie ferroviaire",
+            ..Default::default()
         },
         1308 => LocalisedText {
             en: "Hell on Rails",
             ja: "鉄馬車線路",
             de: "Gütergleise",
             fr: "Voie ferroviaire",
+            ..Default::default()
         },
         1309 => LocalisedText {
             en: "Starlight Stalls",
             ja: "星芒マーケット",
             de: "Sternenlicht-Stände",
             fr: "Marché des étoiles",
+            ..Default::default()
         },
         1311 => LocalisedText {
             en: "Pilgrim's Traverse",
             ja: "ピルグリム・トラバース",
             de: "Pilgers Pfad",
             fr: "Sanctuaire des pèlerins",
+            ..Default::default()
         },
         1312 => LocalisedText {
             en: "The Ageless Necropolis",
             ja: "死の満ちる地",
             de: "Fülle des Todes",
             fr: "Nécropole intemporelle",
+            ..Default::default()
         },
         1313 => LocalisedText {
             en: "Worqor Chirteh",
             ja: "ウォーコー・チーテ",
             de: "Worqor Chirteh",
             fr: "Worqor Chirteh",
+            ..Default::default()
         },
         1314 => LocalisedText {
             en: "Mistwake",
             ja: "ミストウェイク",
             de: "Nebeltrift",
             fr: "Sillage brumeux",
+            ..Default::default()
         },
         1318 => LocalisedText {
             en: "Castrum Fluminis",
             ja: "カストルム・フルーミニス",
             de: "Castrum Fluminis",
             fr: "Castrum Fluminis",
+            ..Default::default()
         },
         1320 => LocalisedText {
             en: "Ring Noir",
             ja: "リング・オブ・ヴァンプ",
             de: "Vampirring",
             fr: "Ring fatal",
+            ..Default::default()
         },
         1321 => LocalisedText {
             en: "Ring Noir",
             ja: "リング・オブ・ヴァンプ",
             de: "Vampirring",
             fr: "Ring fatal",
+            ..Default::default()
         },
         1322 => LocalisedText {
             en: "The X-Ring",
             ja: "リング・オブ・エクストリーム",
             de: "Extremring",
             fr: "Ring extrême",
+            ..Default::default()
         },
         1323 => LocalisedText {
             en: "The X-Ring",
             ja: "リング・オブ・エクストリーム",
             de: "Extremring",
             fr: "Ring extrême",
+            ..Default::default()
         },
         1324 => LocalisedText {
             en: "The Crown",
             ja: "リング・オブ・タイラント",
             de: "Tyrannenring",
             fr: "Ring du champion",
+            ..Default::default()
         },
         1325 => LocalisedText {
             en: "The Crown",
             ja: "リング・オブ・タイラント",
             de: "Tyrannenring",
             fr: "Ring du champion",
+            ..Default::default()
         },
         1326 => LocalisedText {
             en: "Arcadia",
             ja: "リング・オブ・アルカディア",
             de: "Arkadionring",
             fr: "Ring arcadien",
+            ..Default::default()
         },
         1327 => LocalisedText {
             en: "Arcadia",
             ja: "リング・オブ・アルカディア",
             de: "Arkadionring",
             fr: "Ring arcadien",
+            ..Default::default()
         },
         1328 => LocalisedText {
             en: "Treno",
             ja: "トレノ",
             de: "Treno",
             fr: "Tréno",
+            ..Default::default()
         },
         1330 => LocalisedText {
             en: "Dzemael Darkhold",
             ja: "ゼーメル要塞",
             de: "Feste Dzemael",
             fr: "Forteresse de Dzemael",
+            ..Default::default()
         },
         1331 => LocalisedText {
             en: "Aurum Vale",
             ja: "オーラムヴェイル",
             de: "Goldklamm",
             fr: "Val d'Aurum",
+            ..Default::default()
         },
         1332 => LocalisedText {
             en: "Treno",
             ja: "トレノ",
             de: "Treno",
             fr: "Tréno",
+            ..Default::default()
         },
         1333 => LocalisedText {
             en: "Pilgrim's Traverse",
             ja: "ピルグリム・トラバース",
             de: "Pilgers Pfad",
             fr: "Sanctuaire des pèlerins",
+            ..Default::default()
         },
         1334 => LocalisedText {
             en: "Alexandria Crater",
             ja: "アレクサンドリア王国跡地",
             de: "Alexandrias Ruinenring",
             fr: "Vestiges d'Alexandrie",
+            ..Default::default()
         },
         1335 => LocalisedText {
             en: "The Gold Saucer",
             ja: "ゴールドソーサー",
             de: "Gold Saucer",
             fr: "Gold Saucer",
+            ..Default::default()
         },
         1336 => LocalisedText {
             en: "Lilyswim",
             ja: "睡蓮湖近郊",
             de: "睡蓮湖近郊",
             fr: "Alentours du lac aux Nénuphars",
+            ..Default::default()
         },
         1338 => LocalisedText {
             en: "Bentini Depot",
             ja: "ベンティーニ貨物駅",
             de: "Bahnhof Bentini",
             fr: "Gare de Bendini",
+            ..Default::default()
         },
         1344 => LocalisedText {
             en: "Tritails Training",
             ja: "ジム・トライテール",
             de: "Tatzentrio-Training",
             fr: "Trois Queues",
+            ..Default::default()
         },
     };
 }
+
+#[cfg(not(generated_tables))]
+lazy_static::lazy_static! {
+    pub static ref FATE_TERRITORIES: HashSet<u32> = maplit::hashset! {
+        134,
+        135,
+        137,
+        138,
+        139,
+        140,
+        141,
+        145,
+        146,
+        147,
+        148,
+        152,
+        153,
+        154,
+        155,
+        156,
+        180,
+        397,
+        398,
+        399,
+        400,
+        401,
+        402,
+        612,
+        613,
+        614,
+        620,
+        621,
+        622,
+        732,
+        763,
+        795,
+        813,
+        814,
+        815,
+        816,
+        817,
+        818,
+        827,
+        920,
+        956,
+        957,
+        958,
+        959,
+        960,
+        961,
+        975,
+        1187,
+        1188,
+        1189,
+        1190,
+        1191,
+        1192,
+        1252,
+    };
+}
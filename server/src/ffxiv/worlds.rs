@@ -1,92 +1,837 @@
 use std::collections::HashMap;
-use ffxiv_types::World;
+use super::{Region, WorldInfo};
 
+#[cfg(generated_tables)]
+include!(concat!(env!("OUT_DIR"), "/worlds.rs"));
+
+#[cfg(not(generated_tables))]
 lazy_static::lazy_static! {
-    pub static ref WORLDS: HashMap<u32, World> = maplit::hashmap! {
-        21 => World::Ravana,
-        22 => World::Bismarck,
-        23 => World::Asura,
-        24 => World::Belias,
-        28 => World::Pandaemonium,
-        29 => World::Shinryu,
-        30 => World::Unicorn,
-        31 => World::Yojimbo,
-        32 => World::Zeromus,
-        33 => World::Twintania,
-        34 => World::Brynhildr,
-        35 => World::Famfrit,
-        36 => World::Lich,
-        37 => World::Mateus,
-        39 => World::Omega,
-        40 => World::Jenova,
-        41 => World::Zalera,
-        42 => World::Zodiark,
-        43 => World::Alexander,
-        44 => World::Anima,
-        45 => World::Carbuncle,
-        46 => World::Fenrir,
-        47 => World::Hades,
-        48 => World::Ixion,
-        49 => World::Kujata,
-        50 => World::Typhon,
-        51 => World::Ultima,
-        52 => World::Valefor,
-        53 => World::Exodus,
-        54 => World::Faerie,
-        55 => World::Lamia,
-        56 => World::Phoenix,
-        57 => World::Siren,
-        58 => World::Garuda,
-        59 => World::Ifrit,
-        60 => World::Ramuh,
-        61 => World::Titan,
-        62 => World::Diabolos,
-        63 => World::Gilgamesh,
-        64 => World::Leviathan,
-        65 => World::Midgardsormr,
-        66 => World::Odin,
-        67 => World::Shiva,
-        68 => World::Atomos,
-        69 => World::Bahamut,
-        70 => World::Chocobo,
-        71 => World::Moogle,
-        72 => World::Tonberry,
-        73 => World::Adamantoise,
-        74 => World::Coeurl,
-        75 => World::Malboro,
-        76 => World::Tiamat,
-        77 => World::Ultros,
-        78 => World::Behemoth,
-        79 => World::Cactuar,
-        80 => World::Cerberus,
-        81 => World::Goblin,
-        82 => World::Mandragora,
-        83 => World::Louisoix,
-        85 => World::Spriggan,
-        86 => World::Sephirot,
-        87 => World::Sophia,
-        88 => World::Zurvan,
-        90 => World::Aegis,
-        91 => World::Balmung,
-        92 => World::Durandal,
-        93 => World::Excalibur,
-        94 => World::Gungnir,
-        95 => World::Hyperion,
-        96 => World::Masamune,
-        97 => World::Ragnarok,
-        98 => World::Ridill,
-        99 => World::Sargatanas,
-        400 => World::Sagittarius,
-        401 => World::Phantom,
-        402 => World::Alpha,
-        403 => World::Raiden,
-        404 => World::Marilith,
-        405 => World::Seraph,
-        406 => World::Halicarnassus,
-        407 => World::Maduin,
-        408 => World::Cuchulainn,
-        409 => World::Kraken,
-        410 => World::Rafflesia,
-        411 => World::Golem,
+    pub static ref WORLDS: HashMap<u32, WorldInfo> = maplit::hashmap! {
+        21 => WorldInfo {
+            id: 21,
+            name: "Ravana",
+            data_centre: "Materia",
+            region: Region::from_u32(4),
+            travel: true,
+        },
+        22 => WorldInfo {
+            id: 22,
+            name: "Bismarck",
+            data_centre: "Materia",
+            region: Region::from_u32(4),
+            travel: true,
+        },
+        23 => WorldInfo {
+            id: 23,
+            name: "Asura",
+            data_centre: "Mana",
+            region: Region::from_u32(1),
+            travel: true,
+        },
+        24 => WorldInfo {
+            id: 24,
+            name: "Belias",
+            data_centre: "Meteor",
+            region: Region::from_u32(1),
+            travel: true,
+        },
+        28 => WorldInfo {
+            id: 28,
+            name: "Pandaemonium",
+            data_centre: "Mana",
+            region: Region::from_u32(1),
+            travel: true,
+        },
+        29 => WorldInfo {
+            id: 29,
+            name: "Shinryu",
+            data_centre: "Meteor",
+            region: Region::from_u32(1),
+            travel: true,
+        },
+        30 => WorldInfo {
+            id: 30,
+            name: "Unicorn",
+            data_centre: "Meteor",
+            region: Region::from_u32(1),
+            travel: true,
+        },
+        31 => WorldInfo {
+            id: 31,
+            name: "Yojimbo",
+            data_centre: "Meteor",
+            region: Region::from_u32(1),
+            travel: true,
+        },
+        32 => WorldInfo {
+            id: 32,
+            name: "Zeromus",
+            data_centre: "Meteor",
+            region: Region::from_u32(1),
+            travel: true,
+        },
+        33 => WorldInfo {
+            id: 33,
+            name: "Twintania",
+            data_centre: "Light",
+            region: Region::from_u32(3),
+            travel: true,
+        },
+        34 => WorldInfo {
+            id: 34,
+            name: "Brynhildr",
+            data_centre: "Crystal",
+            region: Region::from_u32(2),
+            travel: true,
+        },
+        35 => WorldInfo {
+            id: 35,
+            name: "Famfrit",
+            data_centre: "Primal",
+            region: Region::from_u32(2),
+            travel: true,
+        },
+        36 => WorldInfo {
+            id: 36,
+            name: "Lich",
+            data_centre: "Light",
+            region: Region::from_u32(3),
+            travel: true,
+        },
+        37 => WorldInfo {
+            id: 37,
+            name: "Mateus",
+            data_centre: "Crystal",
+            region: Region::from_u32(2),
+            travel: true,
+        },
+        39 => WorldInfo {
+            id: 39,
+            name: "Omega",
+            data_centre: "Chaos",
+            region: Region::from_u32(3),
+            travel: true,
+        },
+        40 => WorldInfo {
+            id: 40,
+            name: "Jenova",
+            data_centre: "Aether",
+            region: Region::from_u32(2),
+            travel: true,
+        },
+        41 => WorldInfo {
+            id: 41,
+            name: "Zalera",
+            data_centre: "Crystal",
+            region: Region::from_u32(2),
+            travel: true,
+        },
+        42 => WorldInfo {
+            id: 42,
+            name: "Zodiark",
+            data_centre: "Light",
+            region: Region::from_u32(3),
+            travel: true,
+        },
+        43 => WorldInfo {
+            id: 43,
+            name: "Alexander",
+            data_centre: "Gaia",
+            region: Region::from_u32(1),
+            travel: true,
+        },
+        44 => WorldInfo {
+            id: 44,
+            name: "Anima",
+            data_centre: "Mana",
+            region: Region::from_u32(1),
+            travel: true,
+        },
+        45 => WorldInfo {
+            id: 45,
+            name: "Carbuncle",
+            data_centre: "Elemental",
+            region: Region::from_u32(1),
+            travel: true,
+        },
+        46 => WorldInfo {
+            id: 46,
+            name: "Fenrir",
+            data_centre: "Gaia",
+            region: Region::from_u32(1),
+            travel: true,
+        },
+        47 => WorldInfo {
+            id: 47,
+            name: "Hades",
+            data_centre: "Mana",
+            region: Region::from_u32(1),
+            travel: true,
+        },
+        48 => WorldInfo {
+            id: 48,
+            name: "Ixion",
+            data_centre: "Mana",
+            region: Region::from_u32(1),
+            travel: true,
+        },
+        49 => WorldInfo {
+            id: 49,
+            name: "Kujata",
+            data_centre: "Elemental",
+            region: Region::from_u32(1),
+            travel: true,
+        },
+        50 => WorldInfo {
+            id: 50,
+            name: "Typhon",
+            data_centre: "Elemental",
+            region: Region::from_u32(1),
+            travel: true,
+        },
+        51 => WorldInfo {
+            id: 51,
+            name: "Ultima",
+            data_centre: "Gaia",
+            region: Region::from_u32(1),
+            travel: true,
+        },
+        52 => WorldInfo {
+            id: 52,
+            name: "Valefor",
+            data_centre: "Meteor",
+            region: Region::from_u32(1),
+            travel: true,
+        },
+        53 => WorldInfo {
+            id: 53,
+            name: "Exodus",
+            data_centre: "Primal",
+            region: Region::from_u32(2),
+            travel: true,
+        },
+        54 => WorldInfo {
+            id: 54,
+            name: "Faerie",
+            data_centre: "Aether",
+            region: Region::from_u32(2),
+            travel: true,
+        },
+        55 => WorldInfo {
+            id: 55,
+            name: "Lamia",
+            data_centre: "Primal",
+            region: Region::from_u32(2),
+            travel: true,
+        },
+        56 => WorldInfo {
+            id: 56,
+            name: "Phoenix",
+            data_centre: "Light",
+            region: Region::from_u32(3),
+            travel: true,
+        },
+        57 => WorldInfo {
+            id: 57,
+            name: "Siren",
+            data_centre: "Aether",
+            region: Region::from_u32(2),
+            travel: true,
+        },
+        58 => WorldInfo {
+            id: 58,
+            name: "Garuda",
+            data_centre: "Elemental",
+            region: Region::from_u32(1),
+            travel: true,
+        },
+        59 => WorldInfo {
+            id: 59,
+            name: "Ifrit",
+            data_centre: "Gaia",
+            region: Region::from_u32(1),
+            travel: true,
+        },
+        60 => WorldInfo {
+            id: 60,
+            name: "Ramuh",
+            data_centre: "Meteor",
+            region: Region::from_u32(1),
+            travel: true,
+        },
+        61 => WorldInfo {
+            id: 61,
+            name: "Titan",
+            data_centre: "Mana",
+            region: Region::from_u32(1),
+            travel: true,
+        },
+        62 => WorldInfo {
+            id: 62,
+            name: "Diabolos",
+            data_centre: "Crystal",
+            region: Region::from_u32(2),
+            travel: true,
+        },
+        63 => WorldInfo {
+            id: 63,
+            name: "Gilgamesh",
+            data_centre: "Aether",
+            region: Region::from_u32(2),
+            travel: true,
+        },
+        64 => WorldInfo {
+            id: 64,
+            name: "Leviathan",
+            data_centre: "Primal",
+            region: Region::from_u32(2),
+            travel: true,
+        },
+        65 => WorldInfo {
+            id: 65,
+            name: "Midgardsormr",
+            data_centre: "Aether",
+            region: Region::from_u32(2),
+            travel: true,
+        },
+        66 => WorldInfo {
+            id: 66,
+            name: "Odin",
+            data_centre: "Light",
+            region: Region::from_u32(3),
+            travel: true,
+        },
+        67 => WorldInfo {
+            id: 67,
+            name: "Shiva",
+            data_centre: "Light",
+            region: Region::from_u32(3),
+            travel: true,
+        },
+        68 => WorldInfo {
+            id: 68,
+            name: "Atomos",
+            data_centre: "Elemental",
+            region: Region::from_u32(1),
+            travel: true,
+        },
+        69 => WorldInfo {
+            id: 69,
+            name: "Bahamut",
+            data_centre: "Gaia",
+            region: Region::from_u32(1),
+            travel: true,
+        },
+        70 => WorldInfo {
+            id: 70,
+            name: "Chocobo",
+            data_centre: "Mana",
+            region: Region::from_u32(1),
+            travel: true,
+        },
+        71 => WorldInfo {
+            id: 71,
+            name: "Moogle",
+            data_centre: "Chaos",
+            region: Region::from_u32(3),
+            travel: true,
+        },
+        72 => WorldInfo {
+            id: 72,
+            name: "Tonberry",
+            data_centre: "Elemental",
+            region: Region::from_u32(1),
+            travel: true,
+        },
+        73 => WorldInfo {
+            id: 73,
+            name: "Adamantoise",
+            data_centre: "Aether",
+            region: Region::from_u32(2),
+            travel: true,
+        },
+        74 => WorldInfo {
+            id: 74,
+            name: "Coeurl",
+            data_centre: "Crystal",
+            region: Region::from_u32(2),
+            travel: true,
+        },
+        75 => WorldInfo {
+            id: 75,
+            name: "Malboro",
+            data_centre: "Crystal",
+            region: Region::from_u32(2),
+            travel: true,
+        },
+        76 => WorldInfo {
+            id: 76,
+            name: "Tiamat",
+            data_centre: "Gaia",
+            region: Region::from_u32(1),
+            travel: true,
+        },
+        77 => WorldInfo {
+            id: 77,
+            name: "Ultros",
+            data_centre: "Primal",
+            region: Region::from_u32(2),
+            travel: true,
+        },
+        78 => WorldInfo {
+            id: 78,
+            name: "Behemoth",
+            data_centre: "Primal",
+            region: Region::from_u32(2),
+            travel: true,
+        },
+        79 => WorldInfo {
+            id: 79,
+            name: "Cactuar",
+            data_centre: "Aether",
+            region: Region::from_u32(2),
+            travel: true,
+        },
+        80 => WorldInfo {
+            id: 80,
+            name: "Cerberus",
+            data_centre: "Chaos",
+            region: Region::from_u32(3),
+            travel: true,
+        },
+        81 => WorldInfo {
+            id: 81,
+            name: "Goblin",
+            data_centre: "Crystal",
+            region: Region::from_u32(2),
+            travel: true,
+        },
+        82 => WorldInfo {
+            id: 82,
+            name: "Mandragora",
+            data_centre: "Meteor",
+            region: Region::from_u32(1),
+            travel: true,
+        },
+        83 => WorldInfo {
+            id: 83,
+            name: "Louisoix",
+            data_centre: "Chaos",
+            region: Region::from_u32(3),
+            travel: true,
+        },
+        85 => WorldInfo {
+            id: 85,
+            name: "Spriggan",
+            data_centre: "Chaos",
+            region: Region::from_u32(3),
+            travel: true,
+        },
+        86 => WorldInfo {
+            id: 86,
+            name: "Sephirot",
+            data_centre: "Materia",
+            region: Region::from_u32(4),
+            travel: true,
+        },
+        87 => WorldInfo {
+            id: 87,
+            name: "Sophia",
+            data_centre: "Materia",
+            region: Region::from_u32(4),
+            travel: true,
+        },
+        88 => WorldInfo {
+            id: 88,
+            name: "Zurvan",
+            data_centre: "Materia",
+            region: Region::from_u32(4),
+            travel: true,
+        },
+        90 => WorldInfo {
+            id: 90,
+            name: "Aegis",
+            data_centre: "Elemental",
+            region: Region::from_u32(1),
+            travel: true,
+        },
+        91 => WorldInfo {
+            id: 91,
+            name: "Balmung",
+            data_centre: "Crystal",
+            region: Region::from_u32(2),
+            travel: true,
+        },
+        92 => WorldInfo {
+            id: 92,
+            name: "Durandal",
+            data_centre: "Gaia",
+            region: Region::from_u32(1),
+            travel: true,
+        },
+        93 => WorldInfo {
+            id: 93,
+            name: "Excalibur",
+            data_centre: "Primal",
+            region: Region::from_u32(2),
+            travel: true,
+        },
+        94 => WorldInfo {
+            id: 94,
+            name: "Gungnir",
+            data_centre: "Elemental",
+            region: Region::from_u32(1),
+            travel: true,
+        },
+        95 => WorldInfo {
+            id: 95,
+            name: "Hyperion",
+            data_centre: "Primal",
+            region: Region::from_u32(2),
+            travel: true,
+        },
+        96 => WorldInfo {
+            id: 96,
+            name: "Masamune",
+            data_centre: "Mana",
+            region: Region::from_u32(1),
+            travel: true,
+        },
+        97 => WorldInfo {
+            id: 97,
+            name: "Ragnarok",
+            data_centre: "Chaos",
+            region: Region::from_u32(3),
+            travel: true,
+        },
+        98 => WorldInfo {
+            id: 98,
+            name: "Ridill",
+            data_centre: "Gaia",
+            region: Region::from_u32(1),
+            travel: true,
+        },
+        99 => WorldInfo {
+            id: 99,
+            name: "Sargatanas",
+            data_centre: "Aether",
+            region: Region::from_u32(2),
+            travel: true,
+        },
+        400 => WorldInfo {
+            id: 400,
+            name: "Sagittarius",
+            data_centre: "Chaos",
+            region: Region::from_u32(3),
+            travel: true,
+        },
+        401 => WorldInfo {
+            id: 401,
+            name: "Phantom",
+            data_centre: "Chaos",
+            region: Region::from_u32(3),
+            travel: true,
+        },
+        402 => WorldInfo {
+            id: 402,
+            name: "Alpha",
+            data_centre: "Light",
+            region: Region::from_u32(3),
+            travel: true,
+        },
+        403 => WorldInfo {
+            id: 403,
+            name: "Raiden",
+            data_centre: "Light",
+            region: Region::from_u32(3),
+            travel: true,
+        },
+        404 => WorldInfo {
+            id: 404,
+            name: "Marilith",
+            data_centre: "Dynamis",
+            region: Region::from_u32(2),
+            travel: true,
+        },
+        405 => WorldInfo {
+            id: 405,
+            name: "Seraph",
+            data_centre: "Dynamis",
+            region: Region::from_u32(2),
+            travel: true,
+        },
+        406 => WorldInfo {
+            id: 406,
+            name: "Halicarnassus",
+            data_centre: "Dynamis",
+            region: Region::from_u32(2),
+            travel: true,
+        },
+        407 => WorldInfo {
+            id: 407,
+            name: "Maduin",
+            data_centre: "Dynamis",
+            region: Region::from_u32(2),
+            travel: true,
+        },
+        408 => WorldInfo {
+            id: 408,
+            name: "Cuchulainn",
+            data_centre: "Dynamis",
+            region: Region::from_u32(2),
+            travel: true,
+        },
+        409 => WorldInfo {
+            id: 409,
+            name: "Kraken",
+            data_centre: "Dynamis",
+            region: Region::from_u32(2),
+            travel: true,
+        },
+        410 => WorldInfo {
+            id: 410,
+            name: "Rafflesia",
+            data_centre: "Dynamis",
+            region: Region::from_u32(2),
+            travel: true,
+        },
+        411 => WorldInfo {
+            id: 411,
+            name: "Golem",
+            data_centre: "Dynamis",
+            region: Region::from_u32(2),
+            travel: true,
+        },
+        1042 => WorldInfo {
+            id: 1042,
+            name: "拉诺西亚",
+            data_centre: "陆行鸟",
+            region: Region::from_u32(5),
+            travel: false,
+        },
+        1043 => WorldInfo {
+            id: 1043,
+            name: "紫水栈桥",
+            data_centre: "猫小胖",
+            region: Region::from_u32(5),
+            travel: false,
+        },
+        1044 => WorldInfo {
+            id: 1044,
+            name: "幻影群岛",
+            data_centre: "陆行鸟",
+            region: Region::from_u32(5),
+            travel: false,
+        },
+        1045 => WorldInfo {
+            id: 1045,
+            name: "摩杜纳",
+            data_centre: "猫小胖",
+            region: Region::from_u32(5),
+            travel: false,
+        },
+        1060 => WorldInfo {
+            id: 1060,
+            name: "萌芽池",
+            data_centre: "陆行鸟",
+            region: Region::from_u32(5),
+            travel: false,
+        },
+        1076 => WorldInfo {
+            id: 1076,
+            name: "白金幻象",
+            data_centre: "莫古力",
+            region: Region::from_u32(5),
+            travel: false,
+        },
+        1081 => WorldInfo {
+            id: 1081,
+            name: "神意之地",
+            data_centre: "陆行鸟",
+            region: Region::from_u32(5),
+            travel: false,
+        },
+        1106 => WorldInfo {
+            id: 1106,
+            name: "静语庄园",
+            data_centre: "猫小胖",
+            region: Region::from_u32(5),
+            travel: false,
+        },
+        1113 => WorldInfo {
+            id: 1113,
+            name: "旅人栈桥",
+            data_centre: "莫古力",
+            region: Region::from_u32(5),
+            travel: false,
+        },
+        1121 => WorldInfo {
+            id: 1121,
+            name: "拂晓之间",
+            data_centre: "莫古力",
+            region: Region::from_u32(5),
+            travel: false,
+        },
+        1166 => WorldInfo {
+            id: 1166,
+            name: "龙巢神殿",
+            data_centre: "莫古力",
+            region: Region::from_u32(5),
+            travel: false,
+        },
+        1167 => WorldInfo {
+            id: 1167,
+            name: "红玉海",
+            data_centre: "陆行鸟",
+            region: Region::from_u32(5),
+            travel: false,
+        },
+        1169 => WorldInfo {
+            id: 1169,
+            name: "延夏",
+            data_centre: "猫小胖",
+            region: Region::from_u32(5),
+            travel: false,
+        },
+        1170 => WorldInfo {
+            id: 1170,
+            name: "潮风亭",
+            data_centre: "莫古力",
+            region: Region::from_u32(5),
+            travel: false,
+        },
+        1171 => WorldInfo {
+            id: 1171,
+            name: "神拳痕",
+            data_centre: "莫古力",
+            region: Region::from_u32(5),
+            travel: false,
+        },
+        1172 => WorldInfo {
+            id: 1172,
+            name: "白银乡",
+            data_centre: "莫古力",
+            region: Region::from_u32(5),
+            travel: false,
+        },
+        1173 => WorldInfo {
+            id: 1173,
+            name: "宇宙和音",
+            data_centre: "陆行鸟",
+            region: Region::from_u32(5),
+            travel: false,
+        },
+        1174 => WorldInfo {
+            id: 1174,
+            name: "沃仙曦染",
+            data_centre: "陆行鸟",
+            region: Region::from_u32(5),
+            travel: false,
+        },
+        1175 => WorldInfo {
+            id: 1175,
+            name: "晨曦王座",
+            data_centre: "陆行鸟",
+            region: Region::from_u32(5),
+            travel: false,
+        },
+        1176 => WorldInfo {
+            id: 1176,
+            name: "梦羽宝境",
+            data_centre: "莫古力",
+            region: Region::from_u32(5),
+            travel: false,
+        },
+        1177 => WorldInfo {
+            id: 1177,
+            name: "海猫茶屋",
+            data_centre: "猫小胖",
+            region: Region::from_u32(5),
+            travel: false,
+        },
+        1178 => WorldInfo {
+            id: 1178,
+            name: "柔风海湾",
+            data_centre: "猫小胖",
+            region: Region::from_u32(5),
+            travel: false,
+        },
+        1179 => WorldInfo {
+            id: 1179,
+            name: "琥珀原",
+            data_centre: "猫小胖",
+            region: Region::from_u32(5),
+            travel: false,
+        },
+        1180 => WorldInfo {
+            id: 1180,
+            name: "太阳海岸",
+            data_centre: "豆豆柴",
+            region: Region::from_u32(5),
+            travel: false,
+        },
+        1183 => WorldInfo {
+            id: 1183,
+            name: "银泪湖",
+            data_centre: "豆豆柴",
+            region: Region::from_u32(5),
+            travel: false,
+        },
+        1186 => WorldInfo {
+            id: 1186,
+            name: "伊修加德",
+            data_centre: "豆豆柴",
+            region: Region::from_u32(5),
+            travel: false,
+        },
+        1192 => WorldInfo {
+            id: 1192,
+            name: "水晶塔",
+            data_centre: "豆豆柴",
+            region: Region::from_u32(5),
+            travel: false,
+        },
+        1201 => WorldInfo {
+            id: 1201,
+            name: "红茶川",
+            data_centre: "豆豆柴",
+            region: Region::from_u32(5),
+            travel: false,
+        },
+        2075 => WorldInfo {
+            id: 2075,
+            name: "카벙클",
+            data_centre: "한국",
+            region: Region::from_u32(6),
+            travel: false,
+        },
+        2076 => WorldInfo {
+            id: 2076,
+            name: "초코보",
+            data_centre: "한국",
+            region: Region::from_u32(6),
+            travel: false,
+        },
+        2077 => WorldInfo {
+            id: 2077,
+            name: "모그리",
+            data_centre: "한국",
+            region: Region::from_u32(6),
+            travel: false,
+        },
+        2078 => WorldInfo {
+            id: 2078,
+            name: "톤베리",
+            data_centre: "한국",
+            region: Region::from_u32(6),
+            travel: false,
+        },
+        2080 => WorldInfo {
+            id: 2080,
+            name: "펜리르",
+            data_centre: "한국",
+            region: Region::from_u32(6),
+            travel: false,
+        },
     };
 }
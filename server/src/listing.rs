@@ -1,16 +1,21 @@
 use std::borrow::Cow;
+use std::cell::OnceCell;
 
 use bitflags::bitflags;
 use ffxiv_types::jobs::{Class, ClassJob, Job};
-use ffxiv_types::{Role, World};
+use ffxiv_types::Role;
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use sestring::SeString;
 
 use crate::ffxiv::jobs::JOBS_TO_FLAGS;
-use crate::ffxiv::{Language, LocalisedText, JOBS};
+use crate::description::DescriptionTags;
+use crate::ffxiv::duties::DutyInfo;
+use crate::ffxiv::search::normalise;
+use crate::ffxiv::{Expansion, Language, LanguageChain, LocalisedText, PartySize, WorldInfo, JOBS};
+use crate::sestring_ext::SeStringExt;
 
-#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub struct PartyFinderListing {
     pub id: u32,
     pub content_id_lower: u32,
@@ -49,8 +54,8 @@ impl PartyFinderListing {
         self.search_area.contains(SearchAreaFlags::DATA_CENTRE)
     }
 
-    pub fn duty_name(&self, lang: &Language) -> Cow<str> {
-        crate::ffxiv::duty_name(self.duty_type, self.category, self.duty, *lang)
+    pub fn duty_name(&self, langs: &LanguageChain) -> Cow<'_, str> {
+        crate::ffxiv::duty_name(self.duty_type, self.category, self.duty, langs)
     }
 
     pub fn slots(&self) -> Vec<std::result::Result<ClassJob, (String, String)>> {
@@ -105,27 +110,23 @@ impl PartyFinderListing {
         jobs.bits()
     }
 
-    pub fn created_world(&self) -> Option<World> {
-        crate::ffxiv::WORLDS
-            .get(&u32::from(self.created_world))
-            .copied()
+    pub fn created_world(&self) -> Option<&'static WorldInfo> {
+        crate::ffxiv::world(u32::from(self.created_world))
     }
 
-    pub fn created_world_string(&self) -> Cow<str> {
+    pub fn created_world_string(&self) -> Cow<'_, str> {
         self.created_world()
-            .map(|world| Cow::from(world.name()))
+            .map(|world| Cow::from(world.name))
             .unwrap_or_else(|| Cow::from(self.created_world.to_string()))
     }
 
-    pub fn home_world(&self) -> Option<World> {
-        crate::ffxiv::WORLDS
-            .get(&u32::from(self.home_world))
-            .copied()
+    pub fn home_world(&self) -> Option<&'static WorldInfo> {
+        crate::ffxiv::world(u32::from(self.home_world))
     }
 
-    pub fn home_world_string(&self) -> Cow<str> {
+    pub fn home_world_string(&self) -> Cow<'_, str> {
         self.home_world()
-            .map(|world| Cow::from(world.name()))
+            .map(|world| Cow::from(world.name))
             .unwrap_or_else(|| Cow::from(self.home_world.to_string()))
     }
 
@@ -174,41 +175,122 @@ impl PartyFinderListing {
     }
 
     pub fn data_centre_name(&self) -> Option<&'static str> {
-        crate::ffxiv::WORLDS
-            .get(&u32::from(self.created_world))
-            .map(|w| w.data_center().name())
+        self.created_world().map(|world| world.data_centre)
     }
 
-    pub fn high_end(&self) -> bool {
-        if self.duty_type != DutyType::Normal {
-            return false;
+    pub fn description_tags(&self) -> DescriptionTags {
+        crate::description::analyse(&self.description)
+    }
+
+    /// Whether every word of `query` appears in the recruiter's name, the
+    /// duty's name or the description, in any language.
+    pub fn matches_search(&self, query: &str) -> bool {
+        PreparedListing::new(self).matches_search(query)
+    }
+
+    fn search_text(&self) -> String {
+        let mut texts = vec![self.name.full_text(&LanguageChain::from(Language::English))];
+
+        let duty_name = self.duty_info().map(|info| info.name);
+        for lang in Language::ALL {
+            if let Some(name) = duty_name {
+                texts.push(name.get(lang).to_string());
+            }
+
+            // auto-translate phrases differ by language
+            texts.push(self.description.full_text(&LanguageChain::from(lang)));
         }
 
-        crate::ffxiv::duty(u32::from(self.duty))
+        texts.sort_unstable();
+        texts.dedup();
+        texts
+            .iter()
+            .map(|text| normalise(text))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    pub fn duty_info(&self) -> Option<DutyInfo> {
+        crate::ffxiv::listing_duty(self.duty_type, self.category, self.duty)
+    }
+
+    pub fn high_end(&self) -> bool {
+        self.duty_info()
             .map(|info| info.high_end)
             .unwrap_or_default()
     }
 
     pub fn content_kind(&self) -> u32 {
-        if self.duty_type != DutyType::Normal {
-            return 0;
-        }
-
-        crate::ffxiv::duty(u32::from(self.duty))
+        self.duty_info()
             .map(|info| info.content_kind.as_u32())
             .unwrap_or_default()
     }
 
+    pub fn expansion(&self) -> Option<Expansion> {
+        self.duty_info().and_then(|info| info.expansion)
+    }
+
+    pub fn party_size(&self) -> PartySize {
+        self.duty_info()
+            .map(|info| info.party_size)
+            .unwrap_or(PartySize::Unknown)
+    }
+
+    pub fn html_expansion(&self) -> &'static str {
+        self.expansion()
+            .map(Expansion::as_str)
+            .unwrap_or("unknown")
+    }
+
     pub fn pf_category(&self) -> PartyFinderCategory {
         self.category.pf_category()
     }
 
+    /// Identifies the listing's card on the listings page. Listing ids are
+    /// only unique per world and server restart.
+    pub fn html_anchor(&self) -> String {
+        format!("listing-{}-{}-{}", self.created_world, self.last_server_restart, self.id)
+    }
+
     pub fn html_pf_category(&self) -> &'static str {
         self.pf_category().as_str()
     }
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq)]
+/// A listing with the parts that take a while to work out kept once worked
+/// out, for matching it against many queries.
+pub struct PreparedListing<'a> {
+    pub listing: &'a PartyFinderListing,
+    search_text: OnceCell<String>,
+    description_tags: OnceCell<DescriptionTags>,
+}
+
+impl<'a> PreparedListing<'a> {
+    pub fn new(listing: &'a PartyFinderListing) -> Self {
+        Self {
+            listing,
+            search_text: OnceCell::new(),
+            description_tags: OnceCell::new(),
+        }
+    }
+
+    /// See [`PartyFinderListing::matches_search`].
+    pub fn matches_search(&self, query: &str) -> bool {
+        let query = normalise(query);
+        if query.is_empty() {
+            return true;
+        }
+
+        let text = self.search_text.get_or_init(|| self.listing.search_text());
+        query.split(' ').all(|word| text.contains(word))
+    }
+
+    pub fn description_tags(&self) -> &DescriptionTags {
+        self.description_tags.get_or_init(|| self.listing.description_tags())
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub struct PartyFinderSlot {
     pub accepting: JobFlags,
 }
@@ -231,7 +313,7 @@ impl PartyFinderSlot {
 #[derive(Debug, Clone, Copy, Deserialize_repr, Serialize_repr, PartialEq)]
 #[repr(u32)]
 pub enum DutyCategory {
-    None = 0 << 0,
+    None = 0,
     DutyRoulette = 1 << 1,
     Dungeon = 1 << 2,
     Guildhest = 1 << 3,
@@ -251,6 +333,25 @@ pub enum DutyCategory {
 
 #[allow(unused)]
 impl DutyCategory {
+    pub const ALL: [Self; 16] = [
+        Self::None,
+        Self::DutyRoulette,
+        Self::Dungeon,
+        Self::Guildhest,
+        Self::Trial,
+        Self::Raid,
+        Self::HighEndDuty,
+        Self::PvP,
+        Self::GoldSaucer,
+        Self::Fate,
+        Self::TreasureHunt,
+        Self::TheHunt,
+        Self::GatheringForay,
+        Self::DeepDungeon,
+        Self::FieldOperation,
+        Self::VariantAndCriterionDungeon,
+    ];
+
     pub fn from_u32(u: u32) -> Option<Self> {
         Some(match u {
             0 => Self::None,
@@ -568,6 +669,10 @@ impl JobFlags {
         classes.join(" ")
     }
 
+    pub fn union_of(jobs: &[JobFlags]) -> JobFlags {
+        jobs.iter().fold(JobFlags::empty(), |flags, job| flags | *job)
+    }
+
     pub fn get_all_jobs() -> Vec<(LocalisedText, Vec<JobFlags>)> {
         vec![
             (
@@ -576,6 +681,9 @@ impl JobFlags {
                     ja: "タンク",
                     de: "Verteidiger",
                     fr: "Tank",
+                    ko: "탱커",
+                    zh_hans: "防护职业",
+                    zh_hant: "防護職業",
                 },
                 vec![
                     Self::PALADIN,
@@ -590,6 +698,9 @@ impl JobFlags {
                     ja: "ヒーラー",
                     de: "Heiler",
                     fr: "Soigneur",
+                    ko: "힐러",
+                    zh_hans: "治疗职业",
+                    zh_hant: "治療職業",
                 },
                 vec![
                     Self::WHITE_MAGE,
@@ -604,6 +715,9 @@ impl JobFlags {
                     ja: "近接物理DPS",
                     de: "Nahkampf-Angreifer",
                     fr: "DPS de mêlée",
+                    ko: "근거리 물리 딜러",
+                    zh_hans: "近战职业",
+                    zh_hant: "近戰職業",
                 },
                 vec![
                     Self::MONK,
@@ -620,6 +734,9 @@ impl JobFlags {
                     ja: "遠隔物理DPS",
                     de: "Physischer Fernkampf-Angreifer",
                     fr: "DPS physiques à distance",
+                    ko: "원거리 물리 딜러",
+                    zh_hans: "远程物理职业",
+                    zh_hant: "遠程物理職業",
                 },
                 vec![Self::BARD, Self::MACHINIST, Self::DANCER],
             ),
@@ -629,6 +746,9 @@ impl JobFlags {
                     ja: "遠隔魔法DPS",
                     de: "Magischer Fernkampf-Angreifer",
                     fr: "DPS magiques à distance",
+                    ko: "원거리 마법 딜러",
+                    zh_hans: "远程魔法职业",
+                    zh_hant: "遠程魔法職業",
                 },
                 vec![
                     Self::BLACK_MAGE,
@@ -682,6 +802,11 @@ impl PartyFinderCategory {
         Self::None,
     ];
 
+    /// Its position when sorting by category.
+    pub fn order(self) -> u8 {
+        self as u8
+    }
+
     pub fn as_str(self) -> &'static str {
         match self {
             Self::DutyRoulette => "DutyRoulette",
@@ -710,96 +835,144 @@ impl PartyFinderCategory {
                 ja: "コンテンツルーレット",
                 de: "Zufallsinhalte",
                 fr: "Missions aléatoires",
+                ko: "무작위 임무",
+                zh_hans: "随机任务",
+                zh_hant: "隨機任務",
             },
             Self::Dungeons => LocalisedText {
                 en: "Dungeons",
                 ja: "ダンジョン",
                 de: "Dungeons",
                 fr: "Donjons",
+                ko: "던전",
+                zh_hans: "迷宫挑战",
+                zh_hant: "迷宮挑戰",
             },
             Self::Guildhests => LocalisedText {
                 en: "Guildhests",
                 ja: "ギルドオーダー",
                 de: "Gildengeheiße",
                 fr: "Opérations de guilde",
+                ko: "길드작전",
+                zh_hans: "行会令",
+                zh_hant: "行會令",
             },
             Self::Trials => LocalisedText {
                 en: "Trials",
                 ja: "討伐・討滅戦",
                 de: "Prüfungen",
                 fr: "Défis",
+                ko: "토벌전",
+                zh_hans: "讨伐歼灭战",
+                zh_hant: "討伐殲滅戰",
             },
             Self::Raids => LocalisedText {
                 en: "Raids",
                 ja: "レイド",
                 de: "Raids",
                 fr: "Raids",
+                ko: "레이드",
+                zh_hans: "大型任务",
+                zh_hant: "大型任務",
             },
             Self::HighEndDuty => LocalisedText {
                 en: "High-end Duty",
                 ja: "高難易度コンテンツ",
                 de: "Schwierige Inhalte",
                 fr: "Missions à difficulté élevée",
+                ko: "고난도 임무",
+                zh_hans: "高难度任务",
+                zh_hant: "高難度任務",
             },
             Self::Pvp => LocalisedText {
                 en: "PvP",
                 ja: "PvP",
                 de: "PvP",
                 fr: "JcJ",
+                ko: "PvP",
+                zh_hans: "PvP",
+                zh_hant: "PvP",
             },
             Self::GoldSaucer => LocalisedText {
                 en: "Gold Saucer",
                 ja: "ゴールドソーサー",
                 de: "Gold Saucer",
                 fr: "Gold Saucer",
+                ko: "골드 소서",
+                zh_hans: "金碟游乐场",
+                zh_hant: "金碟遊樂場",
             },
             Self::Fates => LocalisedText {
                 en: "FATEs",
                 ja: "F.A.T.E.",
                 de: "FATEs",
                 fr: "ALÉA",
+                ko: "돌발 임무",
+                zh_hans: "危命任务",
+                zh_hant: "危命任務",
             },
             Self::TreasureHunt => LocalisedText {
                 en: "Treasure Hunt",
                 ja: "トレジャーハント",
                 de: "Schatzsuche",
                 fr: "Chasse aux trésors",
+                ko: "보물찾기",
+                zh_hans: "寻宝",
+                zh_hant: "尋寶",
             },
             Self::TheHunt => LocalisedText {
                 en: "The Hunt",
                 ja: "モブハント ",
                 de: "Hohe Jagd",
                 fr: "Contrats de chasse",
+                ko: "마물 사냥",
+                zh_hans: "怪物狩猎",
+                zh_hant: "怪物狩獵",
             },
             Self::GatheringForays => LocalisedText {
                 en: "Gathering Forays",
                 ja: "採集活動",
                 de: "Sammeln",
                 fr: "Récolte",
+                ko: "채집",
+                zh_hans: "采集",
+                zh_hant: "採集",
             },
             Self::DeepDungeons => LocalisedText {
                 en: "Deep Dungeons",
                 ja: "ディープダンジョン",
                 de: "Tiefe Gewölbe",
                 fr: "Donjons sans fond",
+                ko: "딥 던전",
+                zh_hans: "深层迷宫",
+                zh_hant: "深層迷宮",
             },
             Self::FieldOperations => LocalisedText {
                 en: "Field Operations",
                 ja: "特殊フィールド探索",
                 de: "Feldexkursion",
                 fr: "Missions d'exploration",
+                ko: "특수 필드 탐색",
+                zh_hans: "特殊场景探索",
+                zh_hant: "特殊場景探索",
             },
             Self::VariantAndCriterionDungeonFinder => LocalisedText {
                 en: "V&C Dungeon Finder",
                 ja: "特殊ダンジョン探索",
                 de: "Gewölbesuche",
                 fr: "Donjons spéciaux",
+                ko: "",
+                zh_hans: "多变/异闻迷宫",
+                zh_hant: "多變/異聞迷宮",
             },
             Self::None => LocalisedText {
                 en: "None",
                 ja: "設定なし",
                 de: "Nicht festgelegt",
                 fr: "Non spécifiée",
+                ko: "설정 안 함",
+                zh_hans: "不限",
+                zh_hant: "不限",
             },
         }
     }
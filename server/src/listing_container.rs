@@ -2,6 +2,7 @@ use crate::listing::PartyFinderListing;
 use chrono::{DateTime, Duration, TimeDelta, Utc};
 use chrono_humanize::HumanTime;
 use serde::{Deserialize, Serialize};
+use std::cmp::{Ordering, Reverse};
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub struct ListingContainer {
@@ -25,6 +26,24 @@ pub struct QueriedListing {
 }
 
 impl QueriedListing {
+    /// A listing as it was just contributed, as it would be queried now.
+    pub fn contributed(listing: PartyFinderListing) -> Self {
+        let now = Utc::now();
+        // as in the query, updates are grouped into five minutes
+        let minute = now.timestamp() / 300 * 300;
+        Self {
+            created_at: now,
+            updated_at: now,
+            updated_minute: DateTime::from_timestamp(minute, 0).unwrap_or(now),
+            time_left: f64::from(listing.seconds_remaining),
+            listing,
+        }
+    }
+
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.updated_at + TimeDelta::seconds(i64::from(self.listing.seconds_remaining))
+    }
+
     pub fn human_time_left(&self) -> HumanTime {
         HumanTime::from(
             TimeDelta::try_milliseconds((self.time_left * 1000f64) as i64)
@@ -32,6 +51,10 @@ impl QueriedListing {
         )
     }
 
+    pub fn slots_open(&self) -> usize {
+        (self.listing.slots_available as usize).saturating_sub(self.listing.slots_filled())
+    }
+
     pub fn since_updated(&self) -> Duration {
         Utc::now() - self.updated_at
     }
@@ -40,3 +63,72 @@ impl QueriedListing {
         HumanTime::from(-self.since_updated())
     }
 }
+
+/// The orders listings can be shown in. Ties fall back to the default order,
+/// and then to the listing id, so the order is the same on every request.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ListingsSort {
+    /// Recently updated first, then by category, then expiring soonest.
+    #[default]
+    Updated,
+    Newest,
+    ExpiringSoon,
+    SlotsOpen,
+    ItemLevel,
+    Category,
+}
+
+impl ListingsSort {
+    pub const ALL: [Self; 6] = [
+        Self::Updated,
+        Self::Newest,
+        Self::ExpiringSoon,
+        Self::SlotsOpen,
+        Self::ItemLevel,
+        Self::Category,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Updated => "updated",
+            Self::Newest => "newest",
+            Self::ExpiringSoon => "expiring_soon",
+            Self::SlotsOpen => "slots_open",
+            Self::ItemLevel => "item_level",
+            Self::Category => "category",
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Updated => "recently updated",
+            Self::Newest => "newest",
+            Self::ExpiringSoon => "expiring soon",
+            Self::SlotsOpen => "most slots open",
+            Self::ItemLevel => "item level",
+            Self::Category => "category",
+        }
+    }
+
+    pub fn sort(self, listings: &mut [QueriedListing]) {
+        listings.sort_by(|a, b| self.compare(a, b));
+    }
+
+    fn compare(self, a: &QueriedListing, b: &QueriedListing) -> Ordering {
+        let order = match self {
+            Self::Updated => Ordering::Equal,
+            Self::Newest => b.created_at.cmp(&a.created_at),
+            Self::ExpiringSoon => a.time_left.total_cmp(&b.time_left),
+            Self::SlotsOpen => Reverse(a.slots_open()).cmp(&Reverse(b.slots_open())),
+            Self::ItemLevel => b.listing.min_item_level.cmp(&a.listing.min_item_level),
+            Self::Category => a.listing.pf_category().cmp(&b.listing.pf_category()),
+        };
+
+        order
+            .then_with(|| b.updated_minute.cmp(&a.updated_minute))
+            .then_with(|| a.listing.pf_category().cmp(&b.listing.pf_category()))
+            .then_with(|| a.time_left.total_cmp(&b.time_left))
+            .then_with(|| a.listing.id.cmp(&b.listing.id))
+    }
+}
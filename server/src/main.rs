@@ -1,5 +1,4 @@
-#![feature(try_blocks, iter_intersperse)]
-#![feature(let_chains)]
+#![feature(iter_intersperse)]
 
 use crate::config::Config;
 use anyhow::Context;
@@ -11,13 +10,17 @@ use tokio::io::AsyncReadExt;
 
 mod base64_sestring;
 mod config;
+mod description;
 mod ffxiv;
 mod listing;
 mod listing_container;
+mod opt_out;
 mod sestring_ext;
 mod stats;
 mod template;
+mod unknown_duties;
 mod web;
+mod webhooks;
 mod ws;
 
 mod api;
@@ -42,6 +45,17 @@ async fn main() {
         }
     };
 
+    if let Some(game_data) = &config.game_data {
+        if let Err(e) = self::ffxiv::game_data::load(game_data.path.clone()).await {
+            eprintln!("could not load game data, using built-in tables: {:#?}", e);
+        }
+
+        #[cfg(unix)]
+        if let Err(e) = self::ffxiv::game_data::reload_on_sighup(game_data.path.clone()) {
+            eprintln!("could not listen for SIGHUP: {:#?}", e);
+        }
+    }
+
     if let Err(e) = self::web::start(Arc::new(config)).await {
         eprintln!("error: {}", e);
         eprintln!("  {:?}", e);
@@ -57,7 +71,8 @@ async fn get_config<P: AsRef<Path>>(path: P) -> anyhow::Result<Config> {
     f.read_to_string(&mut toml)
         .await
         .context("could not read config file")?;
-    let config = toml::from_str(&toml).context("could not parse config file")?;
+    let config: Config = toml::from_str(&toml).context("could not parse config file")?;
+    config.validate().context("invalid config file")?;
 
     Ok(config)
 }
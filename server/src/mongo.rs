@@ -1,12 +1,15 @@
 use anyhow::Context;
 use crate::listing::PartyFinderListing;
 use crate::listing_container::{ListingContainer, QueriedListing};
-use chrono::{TimeDelta, Utc};
-use futures_util::StreamExt;
-use mongodb::bson::doc;
+use chrono::{DateTime, TimeDelta, Utc};
+use futures_util::{StreamExt, TryStreamExt};
+use mongodb::bson::{doc, Bson};
 use mongodb::results::UpdateResult;
 use mongodb::Collection;
-use mongodb::options::UpdateOptions;
+use mongodb::options::{AggregateOptions, UpdateOptions};
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::sync::Mutex;
 
 pub async fn get_current_listings(
     collection: Collection<ListingContainer>,
@@ -83,17 +86,31 @@ pub async fn get_current_listings(
     Ok(collect)
 }
 
+/// World ids are well below this, in every region.
+const MAX_WORLD_ID: u16 = 10_000;
+
+lazy_static::lazy_static! {
+    /// Unknown worlds that have already been logged.
+    static ref UNKNOWN_WORLDS: Mutex<HashSet<u16>> = Default::default();
+}
+
 pub async fn insert_listing(
     collection: Collection<ListingContainer>,
     listing: &PartyFinderListing,
 ) -> anyhow::Result<UpdateResult> {
-    if listing.created_world >= 1_000
-        || listing.home_world >= 1_000
-        || listing.current_world >= 1_000
-    {
+    let worlds = [listing.created_world, listing.home_world, listing.current_world];
+    if worlds.iter().any(|&world| world == 0 || world >= MAX_WORLD_ID) {
         anyhow::bail!("invalid listing");
     }
 
+    // the world table is out of date whenever new worlds open, which is when
+    // listings from them matter most, so only warn
+    for world in worlds {
+        if crate::ffxiv::world(u32::from(world)).is_none() && UNKNOWN_WORLDS.lock().unwrap().insert(world) {
+            eprintln!("listing from unknown world {}", world);
+        }
+    }
+
     let opts = UpdateOptions::builder().upsert(true).build();
     let bson_value = mongodb::bson::to_bson(&listing)?;
     let now = Utc::now();
@@ -120,3 +137,114 @@ pub async fn insert_listing(
         .await
         .context("could not insert record")
 }
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeBucket {
+    Hour,
+    Day,
+}
+
+impl TimeBucket {
+    pub fn unit(self) -> &'static str {
+        match self {
+            Self::Hour => "hour",
+            Self::Day => "day",
+        }
+    }
+
+    pub fn duration(self) -> TimeDelta {
+        match self {
+            Self::Hour => TimeDelta::try_hours(1).unwrap(),
+            Self::Day => TimeDelta::try_days(1).unwrap(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeSeriesSplit {
+    Category,
+    Duty,
+    DataCentre,
+}
+
+impl TimeSeriesSplit {
+    fn group_key(self) -> Bson {
+        match self {
+            Self::Category => Bson::from("$listing.category"),
+            Self::Duty => Bson::from(vec![
+                Bson::from("$listing.duty_type"),
+                Bson::from("$listing.category"),
+                Bson::from("$listing.duty"),
+            ]),
+            // grouped by world here, as the world -> data centre mapping lives in rust
+            Self::DataCentre => Bson::from("$listing.created_world"),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListingCount {
+    #[serde(rename = "_id")]
+    pub key: ListingCountKey,
+    pub count: usize,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListingCountKey {
+    #[serde(with = "mongodb::bson::serde_helpers::chrono_datetime_as_bson_datetime")]
+    pub time: DateTime<Utc>,
+    #[serde(default)]
+    pub split: Bson,
+}
+
+/// Counts listings created between `from` and `to`, grouped by `bucket` and
+/// optionally by `split`. Empty buckets are not returned.
+pub async fn get_listing_counts(
+    collection: Collection<ListingContainer>,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    bucket: TimeBucket,
+    split: Option<TimeSeriesSplit>,
+) -> anyhow::Result<Vec<ListingCount>> {
+    let split = split.map(TimeSeriesSplit::group_key).unwrap_or(Bson::Null);
+    let mut cursor = collection
+        .aggregate(
+            [
+                doc! {
+                    "$match": {
+                        "created_at": { "$gte": from, "$lt": to },
+                        // filter private pfs
+                        "listing.search_area": { "$bitsAllClear": 2 },
+                    }
+                },
+                doc! {
+                    "$group": {
+                        "_id": {
+                            "time": {
+                                "$dateTrunc": {
+                                    "date": "$created_at",
+                                    "unit": bucket.unit(),
+                                },
+                            },
+                            "split": split,
+                        },
+                        "count": { "$sum": 1 },
+                    }
+                },
+                doc! {
+                    "$sort": { "_id.time": 1 },
+                },
+            ],
+            AggregateOptions::builder().allow_disk_use(true).build(),
+        )
+        .await?;
+
+    let mut counts = Vec::new();
+    while let Some(doc) = cursor.try_next().await? {
+        counts.push(mongodb::bson::from_document(doc)?);
+    }
+
+    Ok(counts)
+}
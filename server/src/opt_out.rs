@@ -0,0 +1,118 @@
+//! Self-service opt-out from the host statistics.
+//!
+//! A player requests a code on `/opt-out` and puts it in the description of a
+//! party finder listing. Once that listing is uploaded, the recruiter's content
+//! id is excluded from the stats and their aliases.
+
+use crate::ffxiv::{Language, LanguageChain};
+use crate::listing::PartyFinderListing;
+use crate::sestring_ext::SeStringExt;
+use crate::web::State;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use futures_util::TryStreamExt;
+use mongodb::bson::doc;
+use mongodb::options::UpdateOptions;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+pub const CODE_PREFIX: &str = "RPF-OPTOUT-";
+const CODE_LEN: usize = 8;
+// no 0/O or 1/I, as these are typed in by hand
+const CODE_ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+
+/// How long a requested code stays valid, in seconds.
+pub const CODE_TTL_SECS: u64 = 60 * 60 * 24;
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct OptOutRequest {
+    #[serde(rename = "_id")]
+    pub code: String,
+    #[serde(with = "mongodb::bson::serde_helpers::chrono_datetime_as_bson_datetime")]
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct OptOut {
+    #[serde(rename = "_id")]
+    pub content_id: u32,
+    #[serde(with = "mongodb::bson::serde_helpers::chrono_datetime_as_bson_datetime")]
+    pub opted_out_at: DateTime<Utc>,
+}
+
+pub fn generate_code() -> String {
+    let mut rng = rand::thread_rng();
+    let suffix: String = (0..CODE_LEN)
+        .map(|_| CODE_ALPHABET[rng.gen_range(0..CODE_ALPHABET.len())] as char)
+        .collect();
+    format!("{}{}", CODE_PREFIX, suffix)
+}
+
+/// Finds an opt-out code in a listing description, ignoring case.
+pub fn find_code(text: &str) -> Option<String> {
+    let upper = text.to_uppercase();
+    let start = upper.find(CODE_PREFIX)? + CODE_PREFIX.len();
+    let suffix: String = upper[start..]
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric())
+        .collect();
+    if suffix.len() != CODE_LEN {
+        return None;
+    }
+
+    Some(format!("{}{}", CODE_PREFIX, suffix))
+}
+
+pub async fn create_request(state: &State) -> Result<String> {
+    let request = OptOutRequest {
+        code: generate_code(),
+        created_at: Utc::now(),
+    };
+    state
+        .opt_out_request_collection()
+        .insert_one(&request, None)
+        .await?;
+    Ok(request.code)
+}
+
+/// Opts out the recruiter of `listing` if its description contains a pending code.
+pub async fn check_listing(state: &State, listing: &PartyFinderListing) -> Result<bool> {
+    let Some(code) = find_code(&listing.description.full_text(&LanguageChain::from(Language::English))) else {
+        return Ok(false);
+    };
+
+    // expired requests are removed by a ttl index, but that only runs once a minute
+    let oldest = Utc::now() - chrono::Duration::seconds(CODE_TTL_SECS as i64);
+    let request = state
+        .opt_out_request_collection()
+        .find_one_and_delete(
+            doc! { "_id": &code, "created_at": { "$gte": oldest } },
+            None,
+        )
+        .await?;
+    if request.is_none() {
+        return Ok(false);
+    }
+
+    state
+        .opt_out_collection()
+        .update_one(
+            doc! { "_id": listing.content_id_lower },
+            doc! { "$setOnInsert": { "opted_out_at": Utc::now() } },
+            UpdateOptions::builder().upsert(true).build(),
+        )
+        .await?;
+
+    Ok(true)
+}
+
+/// All opted-out content ids, both from the config and self-service.
+pub async fn opted_out_ids(state: &State) -> Result<Vec<u32>> {
+    let mut ids = state.config.stats.opt_out.clone();
+    let mut cursor = state.opt_out_collection().find(None, None).await?;
+    while let Some(opt_out) = cursor.try_next().await? {
+        ids.push(opt_out.content_id);
+    }
+
+    Ok(ids)
+}
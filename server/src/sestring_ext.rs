@@ -1,21 +1,200 @@
-use crate::ffxiv::Language;
+use crate::ffxiv::LanguageChain;
 use sestring::{Payload, SeString};
+use std::fmt::Write;
 
 pub trait SeStringExt {
-    fn full_text(&self, lang: &Language) -> String;
+    fn full_text(&self, langs: &LanguageChain) -> String;
+
+    /// Renders the string as HTML, keeping colours, emphasis, icons,
+    /// auto-translate brackets and links. Text is escaped.
+    fn html(&self, langs: &LanguageChain) -> String;
 }
 
 impl SeStringExt for SeString {
-    fn full_text(&self, lang: &Language) -> String {
+    fn full_text(&self, langs: &LanguageChain) -> String {
         self.0
             .iter()
             .flat_map(|payload| match payload {
                 Payload::Text(t) => Some(&*t.0),
-                Payload::AutoTranslate(at) => crate::ffxiv::AUTO_TRANSLATE
-                    .get(&(u32::from(at.group), at.key))
-                    .map(|text| text.text(lang)),
+                Payload::AutoTranslate(at) => crate::ffxiv::auto_translate_text(u32::from(at.group), at.key)
+                    .map(|text| text.text(langs)),
                 _ => None,
             })
             .collect()
     }
+
+    fn html(&self, langs: &LanguageChain) -> String {
+        let mut html = HtmlWriter::default();
+
+        for payload in &self.0 {
+            match payload {
+                Payload::Text(t) => html.text(&t.0),
+                Payload::AutoTranslate(at) => {
+                    if let Some(text) = crate::ffxiv::auto_translate_text(u32::from(at.group), at.key) {
+                        html.push_str("<span class=\"auto-translate\">");
+                        html.text(text.text(langs));
+                        html.push_str("</span>");
+                    }
+                }
+                Payload::Emphasis(emphasis) if emphasis.enabled() => html.open(Tag::Emphasis, "<em>".into()),
+                Payload::Emphasis(_) => html.close(Tag::Emphasis),
+                Payload::UiForeground(colour) if colour.0 == 0 => html.close(Tag::Foreground),
+                Payload::UiForeground(colour) => {
+                    let tag = match crate::ffxiv::ui_colour(colour.0) {
+                        Some(rgba) => format!("<span class=\"colour\" style=\"color: {}\">", css_colour(rgba.foreground)),
+                        None => format!("<span class=\"colour\" data-colour=\"{}\">", colour.0),
+                    };
+                    html.open(Tag::Foreground, tag);
+                }
+                Payload::UiGlow(colour) if colour.0 == 0 => html.close(Tag::Glow),
+                Payload::UiGlow(colour) => {
+                    let tag = match crate::ffxiv::ui_colour(colour.0) {
+                        Some(rgba) => format!("<span class=\"glow\" style=\"text-shadow: 0 0 2px {}\">", css_colour(rgba.glow)),
+                        None => format!("<span class=\"glow\" data-colour=\"{}\">", colour.0),
+                    };
+                    html.open(Tag::Glow, tag);
+                }
+                Payload::Icon(icon) => html.push_str(&format!("<span class=\"icon\" data-icon=\"{}\"></span>", icon.0)),
+                // the link text follows as normal payloads, up to a link terminator
+                Payload::Item(item) => {
+                    let hq = if item.hq { " hq" } else { "" };
+                    html.open_link(format!("<span class=\"link item{}\" data-item=\"{}\">", hq, item.id));
+                }
+                Payload::MapLink(map) => {
+                    html.open_link(format!(
+                        "<span class=\"link map\" data-territory=\"{}\" data-map=\"{}\">",
+                        map.territory_type, map.map,
+                    ));
+                }
+                Payload::Quest(_) | Payload::Status(_) | Payload::Player(_) | Payload::DalamudLink(_) => {
+                    html.open_link("<span class=\"link\">".into());
+                }
+                Payload::Raw(raw) if is_link_terminator(&raw.0) => html.close(Tag::Link),
+                Payload::NewLine(_) => html.push_str("<br>"),
+                Payload::SeHyphen(_) => html.push_str("-"),
+                Payload::Raw(_) => {}
+            }
+        }
+
+        html.finish()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Tag {
+    Emphasis,
+    Foreground,
+    Glow,
+    Link,
+}
+
+impl Tag {
+    fn end(self) -> &'static str {
+        match self {
+            Self::Emphasis => "</em>",
+            Self::Foreground | Self::Glow | Self::Link => "</span>",
+        }
+    }
+}
+
+#[derive(Default)]
+struct HtmlWriter {
+    html: String,
+    /// Open tags, innermost last, with the markup that opened them and whether
+    /// it has been written yet.
+    open: Vec<(Tag, String, bool)>,
+}
+
+impl HtmlWriter {
+    /// Writes the markup of tags reopened by `close`, now that they have content.
+    fn flush(&mut self) {
+        for (_, markup, written) in &mut self.open {
+            if !*written {
+                self.html.push_str(markup);
+                *written = true;
+            }
+        }
+    }
+
+    fn push_str(&mut self, html: &str) {
+        self.flush();
+        self.html.push_str(html);
+    }
+
+    fn text(&mut self, text: &str) {
+        if !text.is_empty() {
+            self.flush();
+        }
+
+        for c in text.chars() {
+            match c {
+                '&' => self.html.push_str("&amp;"),
+                '<' => self.html.push_str("&lt;"),
+                '>' => self.html.push_str("&gt;"),
+                '"' => self.html.push_str("&quot;"),
+                '\'' => self.html.push_str("&#39;"),
+                // the game font's boxed numbers and letters
+                '\u{e060}'..='\u{e069}' => self.glyph(char::from_u32(c as u32 - 0xe060 + '0' as u32)),
+                '\u{e071}'..='\u{e08a}' => self.glyph(char::from_u32(c as u32 - 0xe071 + 'A' as u32)),
+                '\u{e03c}' => self.html.push_str("<span class=\"glyph\">HQ</span>"),
+                c => self.html.push(c),
+            }
+        }
+    }
+
+    fn glyph(&mut self, c: Option<char>) {
+        if let Some(c) = c {
+            let _ = write!(self.html, "<span class=\"glyph\">{}</span>", c);
+        }
+    }
+
+    fn open(&mut self, tag: Tag, markup: String) {
+        self.push_str(&markup);
+        self.open.push((tag, markup, true));
+    }
+
+    /// Links don't nest, so an unterminated link ends at the next one.
+    fn open_link(&mut self, markup: String) {
+        self.close(Tag::Link);
+        self.open(Tag::Link, markup);
+    }
+
+    /// Closes the innermost `tag`, reopening anything opened inside it. The
+    /// reopened tags are only written once there is content for them, so tags
+    /// closed straight after don't leave empty elements behind.
+    fn close(&mut self, tag: Tag) {
+        let Some(i) = self.open.iter().rposition(|(open, _, _)| *open == tag) else {
+            return;
+        };
+
+        let inner = self.open.split_off(i);
+        for (open, _, written) in inner.iter().rev() {
+            if *written {
+                self.html.push_str(open.end());
+            }
+        }
+
+        self.open
+            .extend(inner.into_iter().skip(1).map(|(open, markup, _)| (open, markup, false)));
+    }
+
+    fn finish(mut self) -> String {
+        while let Some((tag, _, written)) = self.open.pop() {
+            if written {
+                self.html.push_str(tag.end());
+            }
+        }
+
+        self.html
+    }
+}
+
+fn css_colour(rgba: u32) -> String {
+    format!("#{:08x}", rgba)
+}
+
+/// Link terminators are interactable chunks (0x27) of kind 0xCF, which the
+/// sestring crate keeps as raw payloads.
+pub fn is_link_terminator(raw: &[u8]) -> bool {
+    raw.first() == Some(&0x27) && raw.get(2) == Some(&0xcf)
 }
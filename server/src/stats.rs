@@ -1,14 +1,65 @@
-use crate::ffxiv::Language;
+use crate::ffxiv::LanguageChain;
 use crate::listing::{DutyCategory, DutyType};
-use serde::{Deserialize, Deserializer};
+use chrono::{DateTime, Utc};
+use chrono_humanize::HumanTime;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use sestring::SeString;
 use std::borrow::Cow;
 use std::collections::HashMap;
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StatsWindow {
+    AllTime,
+    SevenDays,
+}
+
+impl StatsWindow {
+    pub const ALL: [Self; 2] = [Self::AllTime, Self::SevenDays];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::AllTime => "all_time",
+            Self::SevenDays => "seven_days",
+        }
+    }
+}
+
+/// The latest statistics for each window. Windows are generated independently,
+/// so either may be missing.
+#[derive(Debug, Clone, Default)]
 pub struct CachedStatistics {
-    pub all_time: Statistics,
-    pub seven_days: Statistics,
+    pub all_time: Option<GeneratedStatistics>,
+    pub seven_days: Option<GeneratedStatistics>,
+}
+
+impl CachedStatistics {
+    pub fn get(&self, window: StatsWindow) -> Option<&GeneratedStatistics> {
+        match window {
+            StatsWindow::AllTime => self.all_time.as_ref(),
+            StatsWindow::SevenDays => self.seven_days.as_ref(),
+        }
+    }
+
+    pub fn set(&mut self, window: StatsWindow, stats: GeneratedStatistics) {
+        match window {
+            StatsWindow::AllTime => self.all_time = Some(stats),
+            StatsWindow::SevenDays => self.seven_days = Some(stats),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct GeneratedStatistics {
+    pub stats: Statistics,
+    #[serde(with = "mongodb::bson::serde_helpers::chrono_datetime_as_bson_datetime")]
+    pub generated_at: DateTime<Utc>,
+}
+
+impl GeneratedStatistics {
+    pub fn human_since_generated(&self) -> HumanTime {
+        HumanTime::from(self.generated_at - Utc::now())
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -17,10 +68,11 @@ pub struct Aliases {
     pub aliases: HashMap<u32, Alias>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Statistics {
     pub count: Vec<Count>,
-    #[serde(default)]
+    // not part of the main aggregation, but persisted along with the rest
+    #[serde(default, deserialize_with = "alias_de", serialize_with = "alias_ser")]
     pub aliases: HashMap<u32, Alias>,
     pub duties: Vec<DutyInfo>,
     pub hosts: Vec<HostInfo>,
@@ -40,6 +92,21 @@ where
     Ok(map)
 }
 
+fn alias_ser<S>(aliases: &HashMap<u32, Alias>, ser: S) -> std::result::Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    // bson only supports string keys, so store the aliases the same way the aggregation returns them
+    let aliases: Vec<AliasInfo> = aliases
+        .iter()
+        .map(|(&content_id, alias)| AliasInfo {
+            content_id,
+            alias: alias.clone(),
+        })
+        .collect();
+    aliases.serialize(ser)
+}
+
 impl Statistics {
     pub fn num_listings(&self) -> usize {
         if self.count.is_empty() {
@@ -49,14 +116,14 @@ impl Statistics {
         self.count[0].count
     }
 
-    pub fn player_name(&self, cid: &u32) -> Cow<str> {
+    pub fn player_name(&self, cid: &u32) -> Cow<'_, str> {
         let alias = match self.aliases.get(cid) {
             Some(a) => a,
             None => return "<unknown>".into(),
         };
 
-        let world = match crate::ffxiv::WORLDS.get(&alias.home_world) {
-            Some(world) => world.name(),
+        let world = match crate::ffxiv::world(alias.home_world) {
+            Some(world) => world.name,
             None => "<unknown>",
         };
 
@@ -64,26 +131,26 @@ impl Statistics {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Count {
     pub count: usize,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct AliasInfo {
     #[serde(rename = "_id")]
     pub content_id: u32,
     pub alias: Alias,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Alias {
     #[serde(with = "crate::base64_sestring")]
     pub name: SeString,
     pub home_world: u32,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct DutyInfo {
     #[serde(rename = "_id")]
     pub info: (u8, u32, u16),
@@ -91,7 +158,7 @@ pub struct DutyInfo {
 }
 
 impl DutyInfo {
-    pub fn name(&self, lang: &Language) -> Cow<str> {
+    pub fn name(&self, langs: &LanguageChain) -> Cow<'_, str> {
         let kind = match DutyType::from_u8(self.info.0) {
             Some(k) => k,
             None => return Cow::from("<unknown>"),
@@ -100,11 +167,11 @@ impl DutyInfo {
             Some(c) => c,
             None => return Cow::from("<unknown>"),
         };
-        crate::ffxiv::duty_name(kind, category, self.info.2, *lang)
+        crate::ffxiv::duty_name(kind, category, self.info.2, langs)
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct HostInfo {
     #[serde(rename = "_id")]
     pub created_world: u32,
@@ -119,27 +186,27 @@ impl HostInfo {
     }
 
     pub fn world_name(&self) -> &'static str {
-        match crate::ffxiv::WORLDS.get(&self.created_world) {
-            Some(world) => world.name(),
+        match crate::ffxiv::world(self.created_world) {
+            Some(world) => world.name,
             None => "<unknown>",
         }
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct HostInfoInfo {
     pub content_id: u32,
     pub count: usize,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct HourInfo {
     #[serde(rename = "_id")]
     pub hour: u8,
     pub count: usize,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct DayInfo {
     #[serde(rename = "_id")]
     pub day: u8,
@@ -0,0 +1,38 @@
+use crate::ffxiv::LanguageChain;
+use crate::listing_container::QueriedListing;
+use crate::sestring_ext::SeStringExt;
+use askama::Template;
+use chrono::{DateTime, Utc};
+use std::borrow::Borrow;
+use warp::http::Uri;
+
+#[derive(Debug, Template)]
+#[template(path = "listings.atom.xml")]
+pub struct ListingsFeedTemplate {
+    pub containers: Vec<QueriedListing>,
+    pub lang: LanguageChain,
+    /// The site's public url, without a trailing slash.
+    pub base_url: String,
+    /// The filters the feed was requested with, including the `?`.
+    pub query_string: String,
+    pub updated: DateTime<Utc>,
+}
+
+impl ListingsFeedTemplate {
+    /// A `tag:` URI for an entry. Listings don't have a page of their own that
+    /// outlives them, so entries are identified without linking anywhere.
+    fn entry_id(&self, container: &QueriedListing) -> String {
+        let host = self
+            .base_url
+            .parse::<Uri>()
+            .ok()
+            .and_then(|uri| uri.host().map(str::to_string))
+            .unwrap_or_default();
+        format!(
+            "tag:{},{}:{}",
+            host,
+            container.created_at.format("%Y-%m-%d"),
+            container.listing.html_anchor(),
+        )
+    }
+}
@@ -1,7 +1,9 @@
-use crate::ffxiv::Language;
+use crate::api::ListingsQuery;
+use crate::description::{self, Tag};
+use crate::ffxiv::{self, Expansion, Language, LanguageChain, PartySize};
 use crate::listing::JobFlags;
 use crate::listing::PartyFinderCategory;
-use crate::listing_container::QueriedListing;
+use crate::listing_container::{ListingsSort, QueriedListing};
 use crate::sestring_ext::SeStringExt;
 use askama::Template;
 use std::borrow::Borrow;
@@ -10,5 +12,17 @@ use std::borrow::Borrow;
 #[template(path = "listings.html")]
 pub struct ListingsTemplate {
     pub containers: Vec<QueriedListing>,
-    pub lang: Language,
+    pub lang: LanguageChain,
+    /// The filters applied by the server.
+    pub query: ListingsQuery,
+    /// Whether the listings feed is served.
+    pub feed: bool,
+}
+
+/// A single listing card, sent to the listings page as listings change.
+#[derive(Debug, Template)]
+#[template(path = "listing.html")]
+pub struct ListingTemplate {
+    pub container: QueriedListing,
+    pub lang: LanguageChain,
 }
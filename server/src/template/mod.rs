@@ -1,2 +1,4 @@
+pub mod feed;
 pub mod listings;
+pub mod opt_out;
 pub mod stats;
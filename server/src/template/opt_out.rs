@@ -0,0 +1,9 @@
+use crate::ffxiv::{Language, LanguageChain};
+use askama::Template;
+
+#[derive(Debug, Template)]
+#[template(path = "opt_out.html")]
+pub struct OptOutTemplate {
+    pub code: Option<String>,
+    pub lang: LanguageChain,
+}
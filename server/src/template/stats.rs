@@ -1,10 +1,13 @@
-use crate::ffxiv::Language;
+use crate::ffxiv::{Language, LanguageChain};
 use crate::stats::Statistics;
 use askama::Template;
+use chrono_humanize::HumanTime;
 
 #[derive(Debug, Template)]
 #[template(path = "stats.html")]
 pub struct StatsTemplate {
     pub stats: Statistics,
-    pub lang: Language,
+    pub generated_at: HumanTime,
+    pub anonymise_hosts: bool,
+    pub lang: LanguageChain,
 }
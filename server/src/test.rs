@@ -2,6 +2,12 @@ use crate::listing::{
     ConditionFlags, DutyCategory, DutyFinderSettingsFlags, DutyType, JobFlags, LootRuleFlags,
     ObjectiveFlags, PartyFinderListing, PartyFinderSlot, SearchAreaFlags,
 };
+use crate::ffxiv::datasheet::{Lookup, Sheet};
+use crate::api::ListingsQuery;
+use crate::ffxiv::{Expansion, Language, LanguageChain, LocalisedText, PartySize, Region};
+use crate::opt_out::{find_code, generate_code};
+use crate::stats::{Alias, Count, GeneratedStatistics, Statistics};
+use chrono::{TimeZone, Utc};
 use sestring::SeString;
 
 const LISTING: &str = r###"
@@ -21,6 +27,7 @@ const LISTING: &str = r###"
   "min_item_level": 0,
   "num_parties": 1,
   "slots_available": 7,
+  "last_server_restart": 0,
   "objective": 3,
   "conditions": 1,
   "duty_finder_settings": 0,
@@ -52,7 +59,7 @@ lazy_static::lazy_static! {
         created_world: 73,
         home_world: 73,
         current_world: 73,
-        category: DutyCategory::Duty,
+        category: DutyCategory::None,
         duty: 55,
         duty_type: DutyType::Normal,
         beginners_welcome: false,
@@ -60,6 +67,7 @@ lazy_static::lazy_static! {
         min_item_level: 0,
         num_parties: 1,
         slots_available: 7,
+        last_server_restart: 0,
         objective: ObjectiveFlags::PRACTICE | ObjectiveFlags::DUTY_COMPLETION,
         conditions: ConditionFlags::NONE,
         duty_finder_settings: DutyFinderSettingsFlags::NONE,
@@ -87,3 +95,827 @@ fn serialise_listing() {
         LISTING.trim(),
     );
 }
+
+#[test]
+fn generated_stats_bson_round_trip() {
+    let alias = Alias {
+        name: SeString::parse(b"Test Name").unwrap(),
+        home_world: 73,
+    };
+    let stats = Statistics {
+        count: vec![Count { count: 1 }],
+        aliases: maplit::hashmap! { 456 => alias },
+        duties: vec![],
+        hosts: vec![],
+        hours: vec![],
+        days: vec![],
+    };
+    let generated = GeneratedStatistics {
+        stats,
+        generated_at: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+    };
+
+    let doc = mongodb::bson::to_document(&generated).unwrap();
+    let round_trip: GeneratedStatistics = mongodb::bson::from_document(doc).unwrap();
+
+    assert_eq!(round_trip.generated_at, generated.generated_at);
+    assert_eq!(round_trip.stats.num_listings(), 1);
+    assert_eq!(round_trip.stats.player_name(&456), "Test Name @ Adamantoise");
+}
+
+#[test]
+fn find_opt_out_code() {
+    assert_eq!(
+        find_code("LFM prog rpf-optout-abcd2345 thanks").as_deref(),
+        Some("RPF-OPTOUT-ABCD2345"),
+    );
+    assert_eq!(find_code("RPF-OPTOUT-ABC"), None);
+    assert_eq!(find_code("RPF-OPTOUT-ABCD23456"), None);
+    assert_eq!(find_code("This is my test description."), None);
+
+    let code = generate_code();
+    assert_eq!(find_code(&format!("[{}]", code)), Some(code));
+}
+
+#[test]
+fn parse_datasheet() {
+    let sheet = Sheet::parse(concat!(
+        "key,0,1,2\r\n",
+        "#,Name,HighEndDuty,ContentType\r\n",
+        "int32,str,bit,ContentType\r\n",
+        "0,\"\",False,0\r\n",
+        "1,\"The Thousand Maws of Toto-Rak\",False,2\r\n",
+        "2,\"The \"\"Quoted\"\", Duty\",True,5\r\n",
+    ))
+    .unwrap();
+
+    assert_eq!(sheet.row_ids().collect::<Vec<_>>(), [0, 1, 2]);
+    assert_eq!(sheet.get(1, "Name"), Some("The Thousand Maws of Toto-Rak"));
+    assert_eq!(sheet.get(2, "0"), Some("The \"Quoted\", Duty"));
+    assert!(sheet.get_bool(2, "HighEndDuty"));
+    assert_eq!(sheet.get_u32(2, "ContentType"), 5);
+    assert_eq!(sheet.get(3, "Name"), None);
+}
+
+#[test]
+fn load_datasheet_directory() {
+    use crate::ffxiv::datasheet::Tables;
+
+    let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("test-data/game-data");
+    let tables = Tables::load(&dir).unwrap();
+
+    let toto_rak = &tables.duties[&4];
+    assert_eq!(toto_rak.name[0], "The Thousand Maws of Toto-Rak");
+    assert_eq!(toto_rak.name[1], "監獄廃墟 トトラクの千獄");
+    // regional sheets are optional, and rows missing from them are left empty
+    assert_eq!(toto_rak.name[4], "토토라크 감옥");
+    assert_eq!(tables.duties[&908].name[4], "");
+    assert_eq!(tables.duties[&908].name[5], "");
+    assert_eq!((toto_rak.level, toto_rak.members_per_party, toto_rak.ex_version), (24, 4, Some(0)));
+    assert!(!tables.duties.contains_key(&0));
+
+    assert_eq!(tables.roulettes.len(), 2);
+    assert!(tables.roulettes[&40].pvp);
+    assert_eq!(tables.roulettes[&1].name[2], "Zufallsinhalt: Stufensteigerung");
+
+    assert_eq!(tables.territory_names[&128][0], "Limsa Lominsa Upper Decks");
+    assert_eq!(tables.territory_names.len(), 4);
+    assert_eq!(tables.fate_territories.iter().copied().collect::<Vec<_>>(), [1187]);
+
+    // the texture row isn't a map, so the peisteskin map is number 2
+    assert_eq!(tables.treasure_maps[&0][0], "All Levels");
+    assert_eq!(tables.treasure_maps[&1][0], "Timeworn Leather Map");
+    assert_eq!(tables.treasure_maps[&2][0], "Timeworn Peisteskin Map");
+    assert_eq!(tables.treasure_maps.len(), 3);
+
+    assert_eq!(tables.worlds.keys().copied().collect::<Vec<_>>(), [73, 408, 2075]);
+    assert_eq!(tables.worlds[&408].data_centre, "Dynamis");
+    assert_eq!(tables.worlds[&73].region, 2);
+    assert!(tables.worlds[&73].travel);
+    assert_eq!(tables.worlds[&2075].data_centre, "초코보");
+    assert!(!tables.worlds[&2075].travel);
+
+    assert_eq!(tables.auto_translate[&(1, 100)][1], "【言語】");
+    assert_eq!(tables.auto_translate[&(2, 1)][0], "Duty Roulette: Leveling");
+    // outside the lookup's rows
+    assert!(!tables.auto_translate.contains_key(&(2, 40)));
+    assert!(!tables.auto_translate.keys().any(|&(group, _)| group == 61));
+
+    assert_eq!(tables.ui_colours[&500].foreground, 0xffff00ff);
+}
+
+#[test]
+fn parse_auto_translate_lookup() {
+    assert_eq!(
+        Lookup::parse("ContentFinderCondition[1-5,10,col-43,noun]"),
+        Some(Lookup {
+            sheet: "ContentFinderCondition".to_string(),
+            rows: vec![(1, 5), (10, 10)],
+            columns: vec![43],
+        }),
+    );
+    assert_eq!(
+        Lookup::parse("Mount"),
+        Some(Lookup {
+            sheet: "Mount".to_string(),
+            rows: vec![],
+            columns: vec![0],
+        }),
+    );
+    assert_eq!(Lookup::parse("Item[1-"), None);
+}
+
+#[test]
+fn world_data_centres() {
+    let world = crate::ffxiv::world(408).unwrap();
+    assert_eq!(world.name, "Cuchulainn");
+    assert_eq!(world.data_centre, "Dynamis");
+    assert_eq!(world.region, Region::NorthAmerica);
+    assert!(world.travel);
+    assert!(!crate::ffxiv::world(2075).unwrap().travel);
+
+    let data_centres = crate::ffxiv::data_centres();
+    let (_, north_america) = data_centres
+        .iter()
+        .find(|(region, _)| *region == Region::NorthAmerica)
+        .unwrap();
+    assert_eq!(north_america, &["Aether", "Crystal", "Dynamis", "Primal"]);
+}
+
+#[test]
+fn regional_languages() {
+    let code = |codes| LanguageChain::from_codes(Some(codes)).code();
+    assert_eq!(code("ko-KR,ko;q=0.9"), "ko");
+    assert_eq!(code("zh-CN,zh;q=0.9"), "zh-Hans");
+    assert_eq!(code("zh-TW"), "zh-Hant");
+    assert_eq!(code("zh-Hant-HK"), "zh-Hant");
+
+    // weights, not the order given, decide
+    assert_eq!(code("de;q=0.5, fr;q=0.9, ja;q=0.7"), "fr");
+    assert_eq!(code("de;q=0.5,ja"), "ja");
+    assert_eq!(code("fr;Q=1.000,de"), "fr");
+    // refused or malformed entries are left out
+    assert_eq!(code("fr;q=0,de;q=0.1"), "de");
+    assert_eq!(code("fr;q=2,de;q=0.1"), "de");
+    assert_eq!(code("fr;q=0.5555,de;q=0.1"), "de");
+
+    // regional text falls back to english until it's translated
+    let text = crate::ffxiv::duty(1).unwrap().name;
+    assert_eq!(text.text(&Language::Korean.into()), text.en);
+}
+
+#[test]
+fn language_fallback_chain() {
+    let text = LocalisedText {
+        en: "",
+        ja: "日本語",
+        de: "Deutsch",
+        fr: "",
+        ko: "",
+        zh_hans: "",
+        zh_hant: "",
+    };
+
+    // fr -> en -> ja
+    assert_eq!(text.text(&Language::French.into()), "日本語");
+    assert_eq!(text.text(&LanguageChain::new([Language::French, Language::German])), "Deutsch");
+    // the api shows each language as it is
+    assert_eq!(text.text(&LanguageChain::only(Language::French)), "");
+    assert_eq!(text.text(&LanguageChain::only(Language::German)), "Deutsch");
+
+    let chain = LanguageChain::from_codes(Some("fr"));
+    assert_eq!(
+        chain.iter().collect::<Vec<_>>(),
+        [Language::French, Language::English, Language::Japanese],
+    );
+}
+
+#[test]
+fn duty_party_size_and_expansion() {
+    assert_eq!(PartySize::from_members(4, false), PartySize::Light);
+    assert_eq!(PartySize::from_members(8, false), PartySize::Full);
+    assert_eq!(PartySize::from_members(8, true), PartySize::Alliance);
+    assert_eq!(PartySize::from_members(0, false), PartySize::Unknown);
+
+    assert_eq!(Expansion::from_u32(5), Some(Expansion::Dawntrail));
+    assert_eq!(Expansion::from_u32(99), None);
+
+    // the Omega Protocol, as it is in the game's sheets
+    let dir = std::env::temp_dir().join(format!("rpf-test-duties-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let cfc = concat!(
+        "key,0,1,2,3,4,5,6,7,8,9\n",
+        "#,Name,HighEndDuty,ContentType,ClassJobLevelRequired,ItemLevelSync,ContentMemberType,AllianceRoulette,TerritoryType,Icon,Image\n",
+        "int32,str,bit,ContentType,byte,uint16,ContentMemberType,bit,TerritoryType,Image,Image\n",
+        "0,\"\",False,0,0,0,0,False,0,0,0\n",
+        "908,\"the Omega Protocol (Ultimate)\",True,28,90,0,3,False,1122,61807,112389\n",
+    );
+    for lang in ["en", "ja", "de", "fr"] {
+        std::fs::write(dir.join(format!("ContentFinderCondition.{}.csv", lang)), cfc).unwrap();
+    }
+    std::fs::write(
+        dir.join("ContentMemberType.en.csv"),
+        "key,0,1,2,3\n#,TanksPerParty,HealersPerParty,MeleesPerParty,RangedPerParty\nint32,byte,byte,byte,byte\n0,0,0,0,0\n3,2,2,2,2\n",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.join("TerritoryType.en.csv"),
+        "key,0,1\n#,PlaceName,ExVersion\nint32,PlaceName,ExVersion\n0,0,0\n1122,3771,4\n",
+    )
+    .unwrap();
+
+    let mut duties = crate::ffxiv::datasheet::load_duties(&dir).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+    let top = crate::ffxiv::game_data::duty_info(duties.remove(&908).unwrap());
+    assert_eq!(top.name.en, "The Omega Protocol (Ultimate)");
+    assert_eq!(top.party_size, PartySize::Full);
+    assert_eq!(top.expansion, Some(Expansion::Endwalker));
+    assert_eq!(top.level, Some(90));
+    assert!(top.high_end);
+}
+
+#[test]
+fn listings_query_filters() {
+    let listing: PartyFinderListing = serde_json::from_str(LISTING).unwrap();
+
+    let query: ListingsQuery = serde_json::from_str("{}").unwrap();
+    assert!(query.matches(&listing));
+
+    let query: ListingsQuery = serde_json::from_str(r#"{"expansion": "dawntrail", "party_size": "full"}"#).unwrap();
+    assert_eq!(query.expansion, Some(Expansion::Dawntrail));
+    assert_eq!(query.party_size, Some(PartySize::Full));
+    // the sample duty's details aren't in the checked-in table, so it's shown
+    assert_eq!(listing.party_size(), PartySize::Unknown);
+    assert!(query.matches(&listing));
+
+    let query: ListingsQuery = serde_json::from_str(r#"{"content_kind": 28}"#).unwrap();
+    assert!(!query.matches(&listing));
+
+    let query: ListingsQuery = serde_json::from_str(r#"{"high_end": false}"#).unwrap();
+    assert!(query.matches(&listing));
+
+    // vague duties don't resolve to whichever duty scored best
+    let query: ListingsQuery = serde_json::from_str(r#"{"duty": "omega"}"#).unwrap();
+    assert!(query.resolved_duty().is_none());
+    assert!(!query.matches(&listing));
+    let query: ListingsQuery = serde_json::from_str(r#"{"duty": "TOP"}"#).unwrap();
+    assert!(query.resolved_duty().unwrap().ids.contains(&908));
+}
+
+#[test]
+fn search_duties() {
+    use crate::ffxiv::search::{normalise, search};
+
+    assert_eq!(normalise("Ｔｈｅ Minstrel's  Ballad"), "the minstrels ballad");
+    assert_eq!(normalise("アルテマ・ウェポン"), "あるてま うぇぽん");
+
+    let top = &search("top", 5)[0];
+    assert_eq!(top.duty_type, DutyType::Normal);
+    assert!(top.ids.contains(&908));
+
+    let uwu = &search("絶アルテマ", 5)[0];
+    assert_eq!(uwu.name.en, "The Weapon's Refrain (Ultimate)");
+
+    // one typo
+    assert_eq!(search("omgea protocol", 1)[0].name.en, "The Omega Protocol (Ultimate)");
+
+    let potd = &search("The Palace of the Dead", 1)[0];
+    assert_eq!(potd.category, Some(DutyCategory::DeepDungeon));
+    assert_eq!(potd.ids, [1]);
+
+    // only territories with FATEs are FATE results
+    let is_fate = |query| search(query, 5).iter().any(|result| result.category == Some(DutyCategory::Fate));
+    assert!(is_fate("Urqopacha"));
+    assert!(!is_fate("Limsa Lominsa Upper Decks"));
+
+    assert!(search("  ", 5).is_empty());
+    // long queries are cut short rather than scanned in full
+    assert!(search(&"x".repeat(100_000), 5).is_empty());
+}
+
+#[test]
+fn meta_tables() {
+    // every job in the role filter can be resolved to a job id
+    for (_, jobs) in JobFlags::get_all_jobs() {
+        for job in jobs {
+            let code = job.classjobs()[0].code();
+            assert!(crate::ffxiv::JOBS.values().any(|classjob| classjob.code() == code), "{}", code);
+        }
+    }
+
+    assert!(crate::ffxiv::all_duties().contains_key(&908));
+    assert!(crate::ffxiv::all_worlds().contains_key(&73));
+    assert!(!crate::ffxiv::all_roulettes().is_empty());
+}
+
+#[test]
+fn special_duty_info() {
+    use crate::ffxiv::duties::ContentKind;
+    use crate::ffxiv::listing_duty;
+    use crate::ffxiv::special_duties::{Source, SpecialDuty};
+
+    let hoh = listing_duty(DutyType::Other, DutyCategory::DeepDungeon, 2).unwrap();
+    assert_eq!(hoh.name.en, "Heaven-on-High");
+    assert_eq!(hoh.content_kind.as_u32(), ContentKind::DeepDungeons.as_u32());
+
+    // random chocobo race comes first in PF, but last in the sheet
+    let race = listing_duty(DutyType::Other, DutyCategory::GoldSaucer, 12).unwrap();
+    assert_eq!(race.name.en, crate::ffxiv::roulette(21).unwrap().name.en);
+
+    // the table's kind wins over the duty's own
+    let special = SpecialDuty {
+        duty_type: None,
+        category: DutyCategory::GoldSaucer,
+        duty: Some(20),
+        source: Source::Duty(195),
+        content_kind: ContentKind::GATE,
+        high_end: true,
+    };
+    let duty = special.info(20).unwrap();
+    assert_eq!(duty.name.en, crate::ffxiv::duty(195).unwrap().name.en);
+    assert_eq!(duty.content_kind.as_u32(), ContentKind::GATE.as_u32());
+    assert!(duty.high_end);
+
+    assert!(listing_duty(DutyType::Other, DutyCategory::TheHunt, 0).is_some());
+    assert!(listing_duty(DutyType::Other, DutyCategory::DeepDungeon, 99).is_none());
+}
+
+#[test]
+fn unknown_duty_telemetry() {
+    use crate::unknown_duties::{UnknownDuties, MAX_DUTIES};
+
+    let mut listing: PartyFinderListing = serde_json::from_str(LISTING).unwrap();
+    let unknown = UnknownDuties::default();
+
+    listing.duty_type = DutyType::Normal;
+    listing.duty = 55;
+    unknown.record(&listing);
+    assert!(unknown.snapshot().duties.is_empty());
+
+    listing.duty = u16::MAX;
+    unknown.record(&listing);
+    unknown.record(&listing);
+    listing.id += 1;
+    unknown.record(&listing);
+
+    let snapshot = unknown.snapshot();
+    assert_eq!(snapshot.duties.len(), 1);
+    assert_eq!(snapshot.duties[0].duty, u16::MAX);
+    assert_eq!(snapshot.duties[0].count, 3);
+    assert_eq!(snapshot.duties[0].sample_listings, [123, 124]);
+
+    unknown.reset();
+    assert!(unknown.snapshot().duties.is_empty());
+
+    // new duties past the limit are only counted as untracked
+    for duty in 0..MAX_DUTIES as u16 + 2 {
+        listing.duty = 50_000 + duty;
+        unknown.record(&listing);
+    }
+    listing.duty = 50_000;
+    unknown.record(&listing);
+
+    let snapshot = unknown.snapshot();
+    assert_eq!(snapshot.duties.len(), MAX_DUTIES);
+    assert_eq!(snapshot.untracked_listings, 2);
+    assert_eq!(snapshot.duties[0].count, 2);
+}
+
+#[test]
+fn description_html() {
+    use sestring::payload::{AutoTranslatePayload, EmphasisPayload, ItemPayload, RawPayload, TextPayload, UiForegroundPayload};
+    use sestring::Payload;
+    use crate::sestring_ext::SeStringExt;
+
+    let langs = LanguageChain::from(Language::English);
+    let text = |text: &str| Payload::Text(TextPayload(text.to_string()));
+
+    let description = SeString(vec![
+        text("<b>"),
+        Payload::Emphasis(EmphasisPayload::enable()),
+        text("prog "),
+        Payload::UiForeground(UiForegroundPayload(500)),
+        text("P5"),
+        Payload::Emphasis(EmphasisPayload::disable()),
+        Payload::UiForeground(UiForegroundPayload(0)),
+        Payload::Item(ItemPayload { id: 4551, hq: true, name: None }),
+        text("Potion"),
+        Payload::Raw(RawPayload(vec![0x27, 0x07, 0xcf, 0x01, 0x01, 0x01, 0xff, 0x01, 0x03])),
+        text(" \u{e061}"),
+    ]);
+
+    assert_eq!(
+        description.html(&langs),
+        "&lt;b&gt;<em>prog <span class=\"colour\" data-colour=\"500\">P5</span></em>\
+         <span class=\"link item hq\" data-item=\"4551\">Potion</span> <span class=\"glyph\">1</span>",
+    );
+
+    // tags closed out of order are reopened once there's text for them
+    let overlapping = SeString(vec![
+        Payload::UiForeground(UiForegroundPayload(500)),
+        text("a"),
+        Payload::Emphasis(EmphasisPayload::enable()),
+        text("b"),
+        Payload::UiForeground(UiForegroundPayload(0)),
+        text("c"),
+    ]);
+    assert_eq!(
+        overlapping.html(&langs),
+        "<span class=\"colour\" data-colour=\"500\">a<em>b</em></span><em>c</em>",
+    );
+
+    let (&(group, key), _) = crate::ffxiv::AUTO_TRANSLATE.iter().next().unwrap();
+    let at = SeString(vec![Payload::AutoTranslate(AutoTranslatePayload { group: group as u8, key })]);
+    assert!(at.html(&langs).starts_with("<span class=\"auto-translate\">"));
+}
+
+#[test]
+fn description_segments() {
+    use crate::api::segments::segments;
+    use sestring::payload::{EmphasisPayload, MapLinkPayload, RawPayload, TextPayload};
+    use sestring::Payload;
+
+    let description = SeString(vec![
+        Payload::Emphasis(EmphasisPayload::enable()),
+        Payload::Text(TextPayload("meet at".to_string())),
+        Payload::MapLink(MapLinkPayload { territory_type: 128, map: 11, raw_x: 1000, raw_y: -2000 }),
+        Payload::Raw(RawPayload(vec![0x27, 0x07, 0xcf, 0x01, 0x01, 0x01, 0xff, 0x01, 0x03])),
+        Payload::Raw(RawPayload(vec![0x99])),
+    ]);
+
+    let json = serde_json::to_value(segments(&description)).unwrap();
+    assert_eq!(json.as_array().unwrap().len(), 4);
+    assert_eq!(json[0], serde_json::json!({"type": "emphasis", "enabled": true}));
+    assert_eq!(json[1], serde_json::json!({"type": "text", "text": "meet at"}));
+    assert_eq!(json[2]["type"], "map_link");
+    assert_eq!(json[2]["raw_y"], -2000);
+    assert_eq!(json[3], serde_json::json!({"type": "link_end"}));
+}
+
+#[test]
+fn description_tags() {
+    use crate::description::{analyse_text, Tag};
+
+    let tags = analyse_text("Prog P5 w/ PF strats, VC required");
+    assert_eq!(tags.tags, vec![Tag::Prog, Tag::VoiceChat]);
+    assert_eq!(tags.prog_point.as_deref(), Some("P5"));
+    assert_eq!(tags.strat, Some("PF"));
+
+    // a savage floor isn't a phase
+    let tags = analyse_text("p5s farm, no sprouts");
+    assert_eq!(tags.tags, vec![Tag::Farm, Tag::NoSprouts]);
+    assert_eq!(tags.prog_point, None);
+
+    assert_eq!(analyse_text("c41 reclear").tags, vec![Tag::Reclear]);
+    assert_eq!(analyse_text("c41 kill then farm").tags, vec![Tag::Farm, Tag::Clear]);
+    assert_eq!(analyse_text("練習 phase 3 から").prog_point.as_deref(), Some("P3"));
+    assert!(analyse_text("progressive").tags.is_empty());
+
+    let query: ListingsQuery = serde_json::from_str(r#"{"tags": "prog,voice_chat"}"#).unwrap();
+    assert!(!query.matches(&EXPECTED));
+
+    // bare words that usually mean something else
+    assert_eq!(analyse_text("no toxic players, elemental dc").strat, None);
+    assert_eq!(analyse_text("toxic strats").strat, Some("Toxic Friends"));
+    assert_eq!(analyse_text("elemental strat").strat, Some("Elemental"));
+
+    let tags = analyse_text("hector");
+    assert!(tags.tags.is_empty() && !tags.is_empty());
+
+    let mut listing = EXPECTED.clone();
+    listing.description = SeString::parse(b"prog p5, pf strats").unwrap();
+    let query: ListingsQuery = serde_json::from_str(r#"{"prog_point": "p5", "strat": "pf"}"#).unwrap();
+    assert!(query.matches(&listing));
+    let query: ListingsQuery = serde_json::from_str(r#"{"prog_point": "P6"}"#).unwrap();
+    assert!(!query.matches(&listing));
+    let query: ListingsQuery = serde_json::from_str(r#"{"strat": "Hector"}"#).unwrap();
+    assert!(!query.matches(&listing));
+    let query: ListingsQuery = serde_json::from_str(r#"{"prog_point": "", "strat": " "}"#).unwrap();
+    assert!(query.matches(&listing));
+}
+
+#[test]
+fn search_listings() {
+    let listing: PartyFinderListing = serde_json::from_str(LISTING).unwrap();
+    assert!(listing.matches_search(""));
+    assert!(listing.matches_search("test DESCRIPTION"));
+    assert!(listing.matches_search("ｔｅｓｔ　ｎａｍｅ"));
+    assert!(!listing.matches_search("test raid"));
+
+    let duty = listing.duty_info().unwrap().name;
+    assert!(listing.matches_search(duty.en));
+    assert!(listing.matches_search(duty.ja));
+
+    let query: ListingsQuery = serde_json::from_str(r#"{"q": "my test"}"#).unwrap();
+    assert!(query.matches(&listing));
+}
+
+#[test]
+fn listings_page_filters() {
+    let listing: PartyFinderListing = serde_json::from_str(LISTING).unwrap();
+    let category = listing.html_pf_category();
+    let centre = listing.data_centre_name().unwrap();
+
+    let query: ListingsQuery = serde_json::from_str(r#"{"centre": "All", "roles": ""}"#).unwrap();
+    assert_eq!(query.roles, None);
+    assert!(query.matches(&listing));
+
+    let query: ListingsQuery = serde_json::from_value(serde_json::json!({
+        "category": format!("pvp,{}", category),
+        "centre": centre.to_lowercase(),
+    }))
+    .unwrap();
+    assert!(query.matches(&listing));
+
+    let query: ListingsQuery = serde_json::from_str(r#"{"centre": "Nowhere"}"#).unwrap();
+    assert!(!query.matches(&listing));
+
+    let joinable = listing.joinable_roles();
+    let query: ListingsQuery = serde_json::from_value(serde_json::json!({"roles": joinable.to_string()})).unwrap();
+    assert!(query.matches(&listing));
+    let query: ListingsQuery = serde_json::from_value(serde_json::json!({"roles": (!joinable).to_string()})).unwrap();
+    assert!(!query.matches(&listing));
+
+    // the page ignores parameters it can't use instead of failing
+    let query = ListingsQuery::parse_lenient("centre=Aether&expansion=all&sort=foo&high_end=1&roles=3&centre=Primal");
+    assert_eq!(query.centre.as_deref(), Some("Aether"));
+    assert_eq!(query.roles, Some(3));
+    assert_eq!((query.expansion, query.high_end), (None, None));
+    assert_eq!(query.sort, crate::listing_container::ListingsSort::default());
+    assert!(ListingsQuery::parse_lenient("%zz&&=").centre.is_none());
+}
+
+#[test]
+fn listings_sort() {
+    use crate::listing_container::{ListingsSort, QueriedListing};
+
+    let queried = |id: u32, minute: i64, time_left: f64, item_level: u16| {
+        let mut listing: PartyFinderListing = serde_json::from_str(LISTING).unwrap();
+        listing.id = id;
+        listing.min_item_level = item_level;
+        let at = Utc.timestamp_opt(minute * 60, 0).unwrap();
+        QueriedListing {
+            created_at: Utc.timestamp_opt(id as i64, 0).unwrap(),
+            updated_at: at,
+            updated_minute: at,
+            time_left,
+            listing,
+        }
+    };
+    let ids = |sort: ListingsSort| {
+        let mut listings = vec![
+            queried(1, 10, 300.0, 0),
+            queried(2, 20, 600.0, 700),
+            queried(3, 20, 100.0, 0),
+            queried(4, 10, 300.0, 710),
+        ];
+        sort.sort(&mut listings);
+        listings.iter().map(|listing| listing.listing.id).collect::<Vec<_>>()
+    };
+
+    assert_eq!(ids(ListingsSort::default()), [3, 2, 1, 4]);
+    assert_eq!(ids(ListingsSort::Newest), [4, 3, 2, 1]);
+    assert_eq!(ids(ListingsSort::ExpiringSoon), [3, 1, 4, 2]);
+    assert_eq!(ids(ListingsSort::ItemLevel), [4, 2, 3, 1]);
+
+    let query: ListingsQuery = serde_json::from_str(r#"{"sort": "slots_open"}"#).unwrap();
+    assert_eq!(query.sort, ListingsSort::SlotsOpen);
+}
+
+#[test]
+fn render_listing_card() {
+    use crate::listing_container::QueriedListing;
+    use crate::template::listings::ListingTemplate;
+    use askama::Template;
+
+    let mut listing: PartyFinderListing = serde_json::from_str(LISTING).unwrap();
+    // the sample only has one slot
+    listing.slots_available = 1;
+    let container = QueriedListing::contributed(listing);
+    assert_eq!((container.expires_at() - container.updated_at).num_seconds(), 3300);
+
+    let html = ListingTemplate {
+        container,
+        lang: Language::English.into(),
+    }
+    .render()
+    .unwrap();
+    assert!(html.trim_start().starts_with("<div\n  class=\"listing\"\n  id=\"listing-73-0-123\"\n  data-id=\"123\""));
+    assert!(html.contains("This is my test description."));
+    assert!(html.contains("Test Name"));
+}
+
+#[test]
+fn cached_listing_cards() {
+    use crate::ws::CardCache;
+    use std::sync::Arc;
+
+    let mut listing: PartyFinderListing = serde_json::from_str(LISTING).unwrap();
+    listing.slots_available = 1;
+    let batch: Arc<[PartyFinderListing]> = vec![listing].into();
+    let cache = CardCache::default();
+    let english = LanguageChain::from(Language::English);
+
+    let first = cache.render(&batch, 0, &english).unwrap();
+    assert!(first.contains("data-slots-open=\"0\""));
+    assert!(first.contains("data-category-order=\"15\""));
+    // rendered once per language for each batch
+    assert!(Arc::ptr_eq(&first, &cache.render(&batch, 0, &english).unwrap()));
+    let japanese = cache.render(&batch, 0, &Language::Japanese.into()).unwrap();
+    assert!(!Arc::ptr_eq(&first, &japanese));
+    let next: Arc<[PartyFinderListing]> = batch.to_vec().into();
+    assert!(!Arc::ptr_eq(&first, &cache.render(&next, 0, &english).unwrap()));
+}
+
+#[test]
+fn listings_feed() {
+    use crate::listing_container::QueriedListing;
+    use crate::template::feed::ListingsFeedTemplate;
+    use askama::Template;
+
+    let mut listing: PartyFinderListing = serde_json::from_str(LISTING).unwrap();
+    listing.description = SeString::parse(b"<b>clear</b> & farm").unwrap();
+    let container = QueriedListing::contributed(listing);
+    let duty = container.listing.duty_info().unwrap().name;
+
+    let query: ListingsQuery = serde_json::from_value(serde_json::json!({"duty": duty.en})).unwrap();
+    assert!(query.matches(&container.listing));
+    let other = crate::ffxiv::all_duties()
+        .into_values()
+        .find(|info| info.name.en != duty.en && !info.name.en.is_empty())
+        .unwrap();
+    let query: ListingsQuery = serde_json::from_value(serde_json::json!({"duty": other.name.en})).unwrap();
+    assert!(!query.matches(&container.listing));
+
+    let query: ListingsQuery = serde_json::from_str(r#"{"jobs": "whm, SCH,nope"}"#).unwrap();
+    assert_eq!(query.job_flags(), Some((JobFlags::WHITE_MAGE | JobFlags::SCHOLAR).bits()));
+
+    let created_at = container.created_at;
+    let feed = ListingsFeedTemplate {
+        updated: container.updated_at,
+        containers: vec![container],
+        lang: Language::English.into(),
+        base_url: "https://example.com".into(),
+        query_string: "?centre=Aether&category=raids".into(),
+    }
+    .render()
+    .unwrap();
+    assert!(feed.starts_with("<?xml"));
+    assert!(feed.contains("<id>https://example.com/feeds/listings.atom?centre=Aether&amp;category=raids</id>"));
+    let id = format!("<id>tag:example.com,{}:listing-73-0-123</id>", created_at.format("%Y-%m-%d"));
+    assert!(feed.contains(&id));
+    // listings don't outlive their page, so entries don't link to it
+    assert!(!feed.contains("/listings#"));
+    assert!(feed.contains(&format!("<title>{}</title>", duty.en)));
+    // the description's html, escaped again for xml
+    assert!(feed.contains("<content type=\"html\">&amp;lt;b&amp;gt;clear&amp;lt;/b&amp;gt; &amp;amp; farm</content>"));
+}
+
+#[test]
+fn saved_search_webhooks() {
+    use crate::config;
+    use crate::webhooks::{client_ip, normalise_url, validate_url, DiscordPayload, SavedFilters, FILTERS_VERSION};
+
+    let mut listing: PartyFinderListing = serde_json::from_str(LISTING).unwrap();
+    listing.slots_available = 1;
+    listing.min_item_level = 600;
+
+    let query: ListingsQuery = serde_json::from_str(r#"{"centre": "Aether", "item_level": "", "roles": ""}"#).unwrap();
+    assert_eq!(query.item_level, None);
+    let query: ListingsQuery = serde_json::from_str(r#"{"centre": "Aether", "item_level": "590"}"#).unwrap();
+    assert!(!query.matches(&listing));
+    let query: ListingsQuery = serde_json::from_str(r#"{"centre": "Aether", "item_level": "600", "q": "test"}"#).unwrap();
+    assert!(query.matches(&listing));
+
+    // saved searches store their own filters, not the api's query
+    let query: ListingsQuery =
+        serde_json::from_str(r#"{"roles": 4, "item_level": 610, "sort": "newest", "description_html": true}"#).unwrap();
+    let filters = SavedFilters::new(&query);
+    assert_eq!(filters.version, FILTERS_VERSION);
+    let bson = mongodb::bson::to_document(&filters).unwrap();
+    assert!(!bson.contains_key("sort") && !bson.contains_key("description_html"));
+    let stored: SavedFilters = mongodb::bson::from_document(bson).unwrap();
+    assert_eq!(stored, filters);
+    let query = stored.query();
+    assert_eq!((query.roles, query.item_level), (Some(4), Some(610)));
+    assert_eq!(query.sort, crate::listing_container::ListingsSort::default());
+
+    let config = config::Webhooks::default();
+    assert!(validate_url(&config, "https://discord.com/api/webhooks/1/abc").is_ok());
+    assert!(validate_url(&config, "https://Discord.com:443/api/webhooks/1/abc").is_ok());
+    assert!(validate_url(&config, "http://discord.com/api/webhooks/1/abc").is_err());
+    assert!(validate_url(&config, "https://discord.com:8443/api/webhooks/1/abc").is_err());
+    assert!(validate_url(&config, "https://user@discord.com/api/webhooks/1/abc").is_err());
+    assert!(validate_url(&config, "https://example.com/api/webhooks/1/abc").is_err());
+    assert!(validate_url(&config, "discord.com/api/webhooks").is_err());
+    let url = validate_url(&config, "https://Discord.com/api/webhooks/1/AbC").unwrap();
+    assert_eq!(normalise_url(&url), "https://discord.com/api/webhooks/1/AbC");
+
+    let remote = Some(([10, 0, 0, 1], 1234).into());
+    assert_eq!(client_ip(&config, remote, Some("1.2.3.4")), Some([10, 0, 0, 1].into()));
+    let proxied = config::Webhooks {
+        trust_forwarded_for: true,
+        ..Default::default()
+    };
+    assert_eq!(client_ip(&proxied, remote, Some("6.6.6.6, 1.2.3.4")), Some([1, 2, 3, 4].into()));
+    assert_eq!(client_ip(&proxied, remote, None), None);
+
+    listing.description = SeString::parse("a".repeat(5000).as_bytes()).unwrap();
+    let payload = DiscordPayload::new(&listing, &Language::English.into(), Some("https://example.com/"));
+    let json = serde_json::to_value(&payload).unwrap();
+    let embed = &json["embeds"][0];
+    assert_eq!(embed["title"], listing.duty_name(&Language::English.into()).as_ref());
+    assert_eq!(embed["url"], "https://example.com/listings?centre=Aether");
+    assert_eq!(embed["description"].as_str().unwrap().chars().count(), 4096);
+    assert!(embed["description"].as_str().unwrap().ends_with('…'));
+    assert_eq!(embed["fields"][0]["value"], "Test Name @ Adamantoise");
+    assert_eq!(embed["fields"][1]["value"], "Aether");
+    assert_eq!(embed["fields"][2]["value"], "1/1");
+    assert_eq!(embed["fields"][3]["value"], "600");
+
+    let payload = DiscordPayload::new(&listing, &Language::English.into(), None);
+    assert!(serde_json::to_value(&payload).unwrap()["embeds"][0].get("url").is_none());
+}
+
+#[test]
+fn webhook_delivery() {
+    use crate::webhooks::{rate_limit_wait, retry_wait, seconds_header, Outcome};
+    use hyper::header::{HeaderMap, HeaderValue, RETRY_AFTER};
+    use hyper::StatusCode;
+    use std::time::Duration;
+
+    let headers = |pairs: &[(&'static str, &'static str)]| {
+        let mut headers = HeaderMap::new();
+        for &(name, value) in pairs {
+            headers.insert(name, HeaderValue::from_static(value));
+        }
+        headers
+    };
+    let none = HeaderMap::new();
+
+    assert_eq!(Outcome::from_status(StatusCode::NO_CONTENT, &none), Outcome::Delivered);
+    assert_eq!(Outcome::from_status(StatusCode::NOT_FOUND, &none), Outcome::Gone);
+    assert_eq!(Outcome::from_status(StatusCode::UNAUTHORIZED, &none), Outcome::Gone);
+    assert_eq!(Outcome::from_status(StatusCode::BAD_GATEWAY, &none), Outcome::Retry(None));
+    assert_eq!(
+        Outcome::from_status(StatusCode::BAD_REQUEST, &none),
+        Outcome::Rejected(StatusCode::BAD_REQUEST),
+    );
+    let retry_after = headers(&[(RETRY_AFTER.as_str(), "1.5")]);
+    assert_eq!(
+        Outcome::from_status(StatusCode::TOO_MANY_REQUESTS, &retry_after),
+        Outcome::Retry(Some(Duration::from_millis(1500))),
+    );
+    assert_eq!(Outcome::from_status(StatusCode::TOO_MANY_REQUESTS, &none), Outcome::Retry(None));
+
+    let seconds = |value| seconds_header(&headers(&[("x-wait", value)]), "x-wait");
+    assert_eq!(seconds(" 2 "), Some(Duration::from_secs(2)));
+    assert_eq!(seconds("soon"), None);
+    assert_eq!(seconds("-1"), None);
+    // waits are capped
+    assert_eq!(seconds("100000"), Some(Duration::from_secs(600)));
+    assert_eq!(seconds_header(&none, "x-wait"), None);
+
+    let reset_after = ("x-ratelimit-reset-after", "0.25");
+    assert_eq!(rate_limit_wait(&headers(&[("x-ratelimit-remaining", "1"), reset_after])), None);
+    assert_eq!(
+        rate_limit_wait(&headers(&[("x-ratelimit-remaining", "0"), reset_after])),
+        Some(Duration::from_millis(250)),
+    );
+    assert_eq!(rate_limit_wait(&headers(&[("x-ratelimit-remaining", "0")])), None);
+
+    // the delay doubles with each attempt, unless the webhook asked for a wait
+    let delay = Duration::from_secs(1);
+    assert_eq!(retry_wait(None, delay, 1), delay);
+    assert_eq!(retry_wait(None, delay, 2), Duration::from_secs(2));
+    assert_eq!(retry_wait(None, delay, 4), Duration::from_secs(8));
+    assert_eq!(retry_wait(Some(Duration::from_secs(5)), delay, 4), Duration::from_secs(5));
+    assert_eq!(retry_wait(None, delay, 40), Duration::from_secs(600));
+}
+
+#[test]
+fn config_validation() {
+    use crate::config::Config;
+
+    let config = |stats: &str| -> Config {
+        toml::from_str(&format!(
+            "[web]\nhost = \"127.0.0.1:8000\"\n[mongo]\nurl = \"mongodb://localhost\"\n[stats]\n{}",
+            stats
+        ))
+        .unwrap()
+    };
+
+    assert!(config("").validate().is_ok());
+    assert!(config("interval_secs = 0").validate().is_err());
+    assert!(config("retry_secs = 0").validate().is_err());
+    assert!(config("retry_secs = 120\nmax_retry_secs = 60").validate().is_err());
+    assert!(config("[webhooks]").validate().is_ok());
+    assert!(config("[webhooks]\nexpire_days = 0").validate().is_err());
+
+    assert!(config("[admin]\ntoken = \"3f9c1d27a8e64b05\"").validate().is_ok());
+    assert!(config("[admin]\ntoken = \"\"").validate().is_err());
+    assert!(config("[admin]\ntoken = \"change me\"").validate().is_err());
+    assert!(config("[admin]\ntoken = \"short\"").validate().is_err());
+    assert!(config("[admin]\ntoken = \"please-change-me-before-use\"").validate().is_err());
+    assert!(config("[admin]\ntoken = \"aaaaaaaaaaaaaaaaaaaa\"").validate().is_err());
+}
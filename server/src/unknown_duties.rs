@@ -0,0 +1,102 @@
+//! Listings whose duty can't be resolved to a name, so maintainers can see
+//! which ids need adding after a patch.
+
+use crate::listing::{DutyCategory, DutyType, PartyFinderListing};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// How many listing ids to keep for each unknown duty.
+const MAX_SAMPLES: usize = 5;
+
+/// How many unknown duties to track. Anyone can contribute listings, so
+/// duties past this aren't tracked until the next reset.
+pub const MAX_DUTIES: usize = 1000;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UnknownDuty {
+    #[serde(serialize_with = "serialize_debug")]
+    pub duty_type: DutyType,
+    #[serde(serialize_with = "serialize_debug")]
+    pub category: DutyCategory,
+    pub duty: u16,
+    pub count: u64,
+    pub first_seen: DateTime<Utc>,
+    pub last_seen: DateTime<Utc>,
+    pub sample_listings: Vec<u32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Snapshot {
+    /// Most common first.
+    pub duties: Vec<UnknownDuty>,
+    pub max_duties: usize,
+    /// Listings that weren't counted because `max_duties` duties were already tracked.
+    pub untracked_listings: u64,
+}
+
+#[derive(Debug, Default)]
+struct Seen {
+    duties: HashMap<(u8, u32, u16), UnknownDuty>,
+    untracked_listings: u64,
+}
+
+#[derive(Debug, Default)]
+pub struct UnknownDuties {
+    seen: Mutex<Seen>,
+}
+
+impl UnknownDuties {
+    /// Counts `listing` if its duty is unknown.
+    pub fn record(&self, listing: &PartyFinderListing) {
+        if listing.duty_info().is_some() {
+            return;
+        }
+
+        let key = (listing.duty_type.as_u8(), listing.category as u32, listing.duty);
+        let now = Utc::now();
+
+        let mut seen = self.seen.lock().unwrap();
+        if seen.duties.len() >= MAX_DUTIES && !seen.duties.contains_key(&key) {
+            seen.untracked_listings += 1;
+            return;
+        }
+
+        let unknown = seen.duties.entry(key).or_insert_with(|| UnknownDuty {
+            duty_type: listing.duty_type,
+            category: listing.category,
+            duty: listing.duty,
+            count: 0,
+            first_seen: now,
+            last_seen: now,
+            sample_listings: Vec::new(),
+        });
+
+        unknown.count += 1;
+        unknown.last_seen = now;
+        if unknown.sample_listings.len() < MAX_SAMPLES && !unknown.sample_listings.contains(&listing.id) {
+            unknown.sample_listings.push(listing.id);
+        }
+    }
+
+    /// The unknown duties seen since startup or the last reset.
+    pub fn snapshot(&self) -> Snapshot {
+        let seen = self.seen.lock().unwrap();
+        let mut duties: Vec<UnknownDuty> = seen.duties.values().cloned().collect();
+        duties.sort_by(|a, b| b.count.cmp(&a.count).then(a.first_seen.cmp(&b.first_seen)));
+        Snapshot {
+            duties,
+            max_duties: MAX_DUTIES,
+            untracked_listings: seen.untracked_listings,
+        }
+    }
+
+    pub fn reset(&self) {
+        *self.seen.lock().unwrap() = Seen::default();
+    }
+}
+
+fn serialize_debug<T: std::fmt::Debug, S: serde::Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&format_args!("{:?}", value))
+}
@@ -1,4 +1,4 @@
-use std::{cmp::Ordering, convert::Infallible, sync::Arc, time::Duration};
+use std::{convert::Infallible, sync::Arc, time::Duration};
 
 use anyhow::{Context, Result};
 use mongodb::{
@@ -10,14 +10,23 @@ use tokio::sync::broadcast::Sender;
 use tokio::sync::RwLock;
 use warp::{filters::BoxedFilter, http::Uri, Filter, Reply};
 
-use crate::api::api;
+use crate::api::{api, ListingsQuery};
 use crate::mongo::{get_current_listings, insert_listing};
+use crate::unknown_duties::UnknownDuties;
+use crate::webhooks::{SavedSearch, Webhooks};
+use crate::ws::CardCache;
+use crate::opt_out::{self, OptOut, OptOutRequest};
+use self::language::PageLanguage;
+use self::stats::StatsScheduler;
 use crate::{
-    config::Config, ffxiv::Language, listing::PartyFinderListing,
-    listing_container::ListingContainer, stats::CachedStatistics,
-    template::listings::ListingsTemplate, template::stats::StatsTemplate,
+    config::Config, ffxiv::LanguageChain, listing::PartyFinderListing,
+    listing_container::ListingContainer, stats::{CachedStatistics, GeneratedStatistics, StatsWindow},
+    template::listings::ListingsTemplate, template::opt_out::OptOutTemplate,
+    template::stats::StatsTemplate,
 };
 
+mod feed;
+mod language;
 mod stats;
 
 pub async fn start(config: Arc<Config>) -> Result<()> {
@@ -29,9 +38,14 @@ pub async fn start(config: Arc<Config>) -> Result<()> {
 }
 
 pub struct State {
+    pub config: Arc<Config>,
     pub mongo: MongoClient,
-    pub stats: RwLock<Option<CachedStatistics>>,
+    pub stats: RwLock<CachedStatistics>,
+    pub stats_scheduler: StatsScheduler,
     pub listings_channel: Sender<Arc<[PartyFinderListing]>>,
+    pub listing_cards: CardCache,
+    pub unknown_duties: UnknownDuties,
+    pub webhooks: Webhooks,
 }
 
 impl State {
@@ -42,9 +56,14 @@ impl State {
 
         let (tx, _) = tokio::sync::broadcast::channel(16);
         let state = Arc::new(Self {
+            config,
             mongo,
             stats: Default::default(),
+            stats_scheduler: StatsScheduler::new(),
             listings_channel: tx,
+            listing_cards: Default::default(),
+            unknown_duties: Default::default(),
+            webhooks: Default::default(),
         });
 
         state
@@ -76,33 +95,31 @@ impl State {
             .await
             .context("could not create updated_at index")?;
 
-        let task_state = Arc::clone(&state);
-        tokio::task::spawn(async move {
-            loop {
-                let all_time = match self::stats::get_stats(&*task_state).await {
-                    Ok(stats) => stats,
-                    Err(e) => {
-                        eprintln!("error generating stats: {:#?}", e);
-                        continue;
-                    }
-                };
-
-                let seven_days = match self::stats::get_stats_seven_days(&*task_state).await {
-                    Ok(stats) => stats,
-                    Err(e) => {
-                        eprintln!("error generating stats: {:#?}", e);
-                        continue;
-                    }
-                };
-
-                *task_state.stats.write().await = Some(CachedStatistics {
-                    all_time,
-                    seven_days,
-                });
-
-                tokio::time::sleep(Duration::from_secs(60 * 60 * 12)).await;
-            }
-        });
+        state
+            .opt_out_request_collection()
+            .create_index(
+                IndexModel::builder()
+                    .keys(mongodb::bson::doc! {
+                        "created_at": 1,
+                    })
+                    .options(
+                        IndexOptions::builder()
+                            .expire_after(Duration::from_secs(opt_out::CODE_TTL_SECS))
+                            .build(),
+                    )
+                    .build(),
+                None,
+            )
+            .await
+            .context("could not create opt-out request ttl index")?;
+
+        StatsScheduler::spawn(Arc::clone(&state));
+
+        if state.config.webhooks.is_some() {
+            Webhooks::load(&state)
+                .await
+                .context("could not load saved searches")?;
+        }
 
         Ok(state)
     }
@@ -110,15 +127,34 @@ impl State {
     pub fn collection(&self) -> Collection<ListingContainer> {
         self.mongo.database("rpf").collection("listings")
     }
+
+    pub fn stats_collection(&self) -> Collection<GeneratedStatistics> {
+        self.mongo.database("rpf").collection("stats")
+    }
+
+    pub fn opt_out_collection(&self) -> Collection<OptOut> {
+        self.mongo.database("rpf").collection("opt_outs")
+    }
+
+    pub fn opt_out_request_collection(&self) -> Collection<OptOutRequest> {
+        self.mongo.database("rpf").collection("opt_out_requests")
+    }
+
+    pub fn webhook_collection(&self) -> Collection<SavedSearch> {
+        self.mongo.database("rpf").collection("webhooks")
+    }
 }
 
 fn router(state: Arc<State>) -> BoxedFilter<(impl Reply,)> {
     index()
         .or(listings(Arc::clone(&state)))
+        .or(feed::listings_feed(Arc::clone(&state)))
         .or(contribute(Arc::clone(&state)))
         .or(contribute_multiple(Arc::clone(&state)))
         .or(stats(Arc::clone(&state)))
         .or(stats_seven_days(Arc::clone(&state)))
+        .or(opt_out_page())
+        .or(opt_out_request(Arc::clone(&state)))
         .or(assets())
         .or(api(Arc::clone(&state)))
         .boxed()
@@ -228,105 +264,126 @@ fn index() -> BoxedFilter<(impl Reply,)> {
 fn listings(state: Arc<State>) -> BoxedFilter<(impl Reply,)> {
     async fn logic(
         state: Arc<State>,
-        codes: Option<String>,
+        page_lang: PageLanguage,
+        query: ListingsQuery,
     ) -> std::result::Result<impl Reply, Infallible> {
-        let lang = Language::from_codes(codes.as_deref());
+        let lang = page_lang.lang.clone();
 
         let res = get_current_listings(state.collection()).await;
         Ok(match res {
             Ok(mut containers) => {
-                containers.sort_by(|a, b| {
-                    a.time_left
-                        .partial_cmp(&b.time_left)
-                        .unwrap_or(Ordering::Equal)
-                });
-
-                containers.sort_by_key(|container| container.listing.pf_category());
-                containers.reverse();
+                containers.retain(|container| query.matches(&container.listing));
 
-                containers.sort_by_key(|container| container.updated_minute);
-                containers.reverse();
+                query.sort.sort(&mut containers);
 
-                ListingsTemplate { containers, lang }
+                page_lang.reply(ListingsTemplate {
+                    containers,
+                    lang,
+                    query,
+                    feed: state.config.web.base_url.is_some(),
+                })
             }
             Err(e) => {
                 eprintln!("{:#?}", e);
-                ListingsTemplate {
+                page_lang.reply(ListingsTemplate {
                     containers: Default::default(),
                     lang,
-                }
+                    query,
+                    feed: state.config.web.base_url.is_some(),
+                })
             }
         })
     }
 
-    let route = warp::path("listings")
-        .and(warp::path::end())
-        .and(
-            warp::cookie::<String>("lang")
-                .or(warp::header::<String>("accept-language"))
-                .unify()
-                .map(Some)
-                .or(warp::any().map(|| None))
-                .unify(),
-        )
-        .and_then(move |codes: Option<String>| logic(Arc::clone(&state), codes));
+    let route = language::page(warp::path("listings").and(warp::path::end()).boxed())
+        .and(ListingsQuery::lenient())
+        .and_then(move |lang: PageLanguage, query: ListingsQuery| {
+            logic(Arc::clone(&state), lang, query)
+        });
 
     warp::get().and(route).boxed()
 }
 
 async fn stats_logic(
     state: Arc<State>,
-    codes: Option<String>,
-    seven_days: bool,
+    page_lang: PageLanguage,
+    window: StatsWindow,
 ) -> std::result::Result<impl Reply, Infallible> {
-    let lang = Language::from_codes(codes.as_deref());
-    let stats = state.stats.read().await.clone();
+    let stats = state.stats.read().await.get(window).cloned();
     Ok(match stats {
-        Some(stats) => StatsTemplate {
-            stats: if seven_days {
-                stats.seven_days
-            } else {
-                stats.all_time
-            },
-            lang,
-        }.into_response(),
+        Some(stats) => page_lang.reply(StatsTemplate {
+            generated_at: stats.human_since_generated(),
+            stats: stats.stats,
+            anonymise_hosts: state.config.stats.anonymise_hosts,
+            lang: page_lang.lang.clone(),
+        }),
         None => "Stats haven't been calculated yet. Please wait :(".into_response(),
     })
 }
 
 fn stats(state: Arc<State>) -> BoxedFilter<(impl Reply,)> {
-    let route = warp::path("stats")
-        .and(warp::path::end())
-        .and(
-            warp::cookie::<String>("lang")
-                .or(warp::header::<String>("accept-language"))
-                .unify()
-                .map(Some)
-                .or(warp::any().map(|| None))
-                .unify(),
-        )
-        .and_then(move |codes: Option<String>| stats_logic(Arc::clone(&state), codes, false));
+    let route = language::page(warp::path("stats").and(warp::path::end()).boxed())
+        .and_then(move |lang: PageLanguage| stats_logic(Arc::clone(&state), lang, StatsWindow::AllTime));
 
     warp::get().and(route).boxed()
 }
 
 fn stats_seven_days(state: Arc<State>) -> BoxedFilter<(impl Reply,)> {
-    let route = warp::path("stats")
-        .and(warp::path("7days"))
-        .and(warp::path::end())
-        .and(
-            warp::cookie::<String>("lang")
-                .or(warp::header::<String>("accept-language"))
-                .unify()
-                .map(Some)
-                .or(warp::any().map(|| None))
-                .unify(),
-        )
-        .and_then(move |codes: Option<String>| stats_logic(Arc::clone(&state), codes, true));
+    let route = language::page(
+        warp::path("stats")
+            .and(warp::path("7days"))
+            .and(warp::path::end())
+            .boxed(),
+    )
+    .and_then(move |lang: PageLanguage| stats_logic(Arc::clone(&state), lang, StatsWindow::SevenDays));
+
+    warp::get().and(route).boxed()
+}
+
+fn opt_out_page() -> BoxedFilter<(impl Reply,)> {
+    let route = language::page(warp::path("opt-out").and(warp::path::end()).boxed())
+        .map(|lang: PageLanguage| {
+            lang.reply(OptOutTemplate {
+                code: None,
+                lang: lang.lang.clone(),
+            })
+        });
 
     warp::get().and(route).boxed()
 }
 
+fn opt_out_request(state: Arc<State>) -> BoxedFilter<(impl Reply,)> {
+    async fn logic(
+        state: Arc<State>,
+        codes: Option<String>,
+    ) -> std::result::Result<impl Reply, Infallible> {
+        let lang = LanguageChain::from_codes(codes.as_deref());
+
+        Ok(match opt_out::create_request(&state).await {
+            Ok(code) => OptOutTemplate {
+                code: Some(code),
+                lang,
+            }
+            .into_response(),
+            Err(e) => {
+                eprintln!("{:#?}", e);
+                warp::reply::with_status(
+                    "Could not create an opt-out code. Please try again later.",
+                    warp::http::StatusCode::INTERNAL_SERVER_ERROR,
+                )
+                .into_response()
+            }
+        })
+    }
+
+    let route = warp::path("opt-out")
+        .and(warp::path::end())
+        .and(language::codes())
+        .and_then(move |codes: Option<String>| logic(Arc::clone(&state), codes));
+
+    warp::post().and(route).boxed()
+}
+
 fn contribute(state: Arc<State>) -> BoxedFilter<(impl Reply,)> {
     async fn logic(
         state: Arc<State>,
@@ -337,6 +394,15 @@ fn contribute(state: Arc<State>) -> BoxedFilter<(impl Reply,)> {
         }
 
         let result = insert_listing(state.collection(), &listing).await;
+        if let Ok(result) = &result {
+            state.unknown_duties.record(&listing);
+            if result.upserted_id.is_some() {
+                Webhooks::listing_inserted(&state, &listing);
+            }
+        }
+        if let Err(e) = opt_out::check_listing(&state, &listing).await {
+            eprintln!("error checking opt-out: {:#?}", e);
+        }
 
         // publish listings to websockets
         let _ = state.listings_channel.send(vec![listing].into()); // ignore is OK, as `send` only fails when there are no receivers (which may happen)
@@ -365,11 +431,19 @@ fn contribute_multiple(state: Arc<State>) -> BoxedFilter<(impl Reply,)> {
             }
 
             let result = insert_listing(state.collection(), listing).await;
-            if result.is_ok() {
+            if let Ok(result) = &result {
                 successful += 1;
+                state.unknown_duties.record(listing);
+                if result.upserted_id.is_some() {
+                    Webhooks::listing_inserted(&state, listing);
+                }
             } else {
                 eprintln!("{:#?}", result);
             }
+
+            if let Err(e) = opt_out::check_listing(&state, listing).await {
+                eprintln!("error checking opt-out: {:#?}", e);
+            }
         }
 
         let _ = state.listings_channel.send(listings.into()); // ignore is OK, as `send` only fails when there are no receivers (which may happen)
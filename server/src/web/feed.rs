@@ -0,0 +1,79 @@
+use super::language::{self, PageLanguage};
+use super::State;
+use crate::api::ListingsQuery;
+use crate::listing_container::ListingsSort;
+use crate::mongo::get_current_listings;
+use crate::template::feed::ListingsFeedTemplate;
+use chrono::Utc;
+use std::convert::Infallible;
+use std::sync::Arc;
+use warp::filters::BoxedFilter;
+use warp::http::header::CONTENT_TYPE;
+use warp::http::StatusCode;
+use warp::{Filter, Reply};
+
+/// Feed readers only need the newest listings.
+const MAX_ENTRIES: usize = 50;
+
+/// An Atom feed of the newest listings, taking the same filters as the
+/// listings page. Atom needs absolute links, so this is only served when
+/// `base_url` is configured.
+pub fn listings_feed(state: Arc<State>) -> BoxedFilter<(impl Reply,)> {
+    async fn logic(
+        state: Arc<State>,
+        page_lang: PageLanguage,
+        query: ListingsQuery,
+        raw_query: String,
+    ) -> Result<warp::reply::Response, Infallible> {
+        let Some(base_url) = &state.config.web.base_url else {
+            return Ok(StatusCode::NOT_FOUND.into_response());
+        };
+
+        let containers = match get_current_listings(state.collection()).await {
+            Ok(containers) => containers,
+            Err(e) => {
+                eprintln!("error generating listings feed: {:#?}", e);
+                return Ok(warp::reply::with_status(warp::reply(), StatusCode::INTERNAL_SERVER_ERROR)
+                    .into_response());
+            }
+        };
+
+        let mut containers: Vec<_> = containers
+            .into_iter()
+            .filter(|container| query.matches(&container.listing))
+            .collect();
+        ListingsSort::Newest.sort(&mut containers);
+        containers.truncate(MAX_ENTRIES);
+
+        let feed = ListingsFeedTemplate {
+            updated: containers
+                .iter()
+                .map(|container| container.updated_at)
+                .max()
+                .unwrap_or_else(Utc::now),
+            containers,
+            lang: page_lang.lang,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            query_string: if raw_query.is_empty() {
+                String::new()
+            } else {
+                format!("?{}", raw_query)
+            },
+        };
+
+        Ok(warp::reply::with_header(feed, CONTENT_TYPE, "application/atom+xml; charset=utf-8")
+            .into_response())
+    }
+
+    let route = language::page(
+        warp::path("feeds")
+            .and(warp::path("listings.atom"))
+            .and(warp::path::end())
+            .boxed(),
+    )
+    .and(ListingsQuery::lenient())
+    .and(warp::query::raw().or(warp::any().map(String::new)).unify())
+    .and_then(move |lang, query, raw_query| logic(Arc::clone(&state), lang, query, raw_query));
+
+    warp::get().and(route).boxed()
+}
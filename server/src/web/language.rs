@@ -0,0 +1,84 @@
+use crate::ffxiv::{Language, LanguageChain};
+use serde::Deserialize;
+use warp::filters::BoxedFilter;
+use warp::http::header::{HeaderValue, SET_COOKIE};
+use warp::reply::Response;
+use warp::{Filter, Reply};
+
+/// The language a page is shown in.
+#[derive(Debug, Clone)]
+pub struct PageLanguage {
+    pub lang: LanguageChain,
+    /// Set when the URL chose the language, so that it's remembered.
+    overridden: Option<Language>,
+}
+
+impl PageLanguage {
+    /// Adds the `lang` cookie to `reply` if the URL chose the language.
+    pub fn reply(&self, reply: impl Reply) -> Response {
+        let mut response = reply.into_response();
+        if let Some(lang) = self.overridden {
+            // not http-only, since the listings page reads it
+            let cookie = format!("lang={};Path=/;Max-Age=31536000;SameSite=Lax", lang.code());
+            if let Ok(cookie) = HeaderValue::from_str(&cookie) {
+                response.headers_mut().append(SET_COOKIE, cookie);
+            }
+        }
+
+        response
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct LanguageQuery {
+    lang: Option<String>,
+}
+
+/// Negotiates the language for the page matched by `route`, which may also
+/// be reached under a language prefix, like `/ja/listings`.
+///
+/// In order, the language comes from `?lang=`, the prefix, the `lang`
+/// cookie and then `Accept-Language`.
+pub fn page(route: BoxedFilter<()>) -> BoxedFilter<(PageLanguage,)> {
+    let prefix = warp::path::param::<String>()
+        .and_then(|code: String| async move {
+            Language::from_tag(&code).ok_or_else(warp::reject::not_found)
+        })
+        .map(Some)
+        .or(warp::any().map(|| None))
+        .unify();
+
+    let query = warp::query::<LanguageQuery>()
+        .or(warp::any().map(LanguageQuery::default))
+        .unify()
+        .map(|query: LanguageQuery| query.lang.as_deref().and_then(Language::from_tag));
+
+    prefix
+        .and(route)
+        .and(query)
+        .and(codes())
+        .map(|prefix: Option<Language>, query: Option<Language>, codes: Option<String>| {
+            match query.or(prefix) {
+                Some(lang) => PageLanguage {
+                    lang: lang.into(),
+                    overridden: Some(lang),
+                },
+                None => PageLanguage {
+                    lang: LanguageChain::from_codes(codes.as_deref()),
+                    overridden: None,
+                },
+            }
+        })
+        .boxed()
+}
+
+/// The `lang` cookie, or the `Accept-Language` header without one.
+pub fn codes() -> BoxedFilter<(Option<String>,)> {
+    warp::cookie::<String>("lang")
+        .or(warp::header::<String>("accept-language"))
+        .unify()
+        .map(Some)
+        .or(warp::any().map(|| None))
+        .unify()
+        .boxed()
+}
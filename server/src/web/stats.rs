@@ -1,10 +1,15 @@
-use crate::stats::{Aliases, Statistics};
+use crate::stats::{Aliases, GeneratedStatistics, Statistics, StatsWindow};
 use crate::web::State;
 use anyhow::Result;
-use chrono::{TimeDelta, Utc};
+use chrono::{DateTime, TimeDelta, Utc};
 use futures_util::TryStreamExt;
 use mongodb::bson::{doc, Document};
-use mongodb::options::AggregateOptions;
+use mongodb::options::{AggregateOptions, ReplaceOptions};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Notify, RwLock};
 
 lazy_static::lazy_static! {
     static ref QUERY: [Document; 2] = [
@@ -170,6 +175,14 @@ async fn get_stats_internal(
     state: &State,
     docs: impl IntoIterator<Item = Document>,
 ) -> Result<Statistics> {
+    let opted_out = crate::opt_out::opted_out_ids(state).await?;
+    let docs = std::iter::once(doc! {
+        "$match": {
+            "listing.content_id_lower": { "$nin": opted_out },
+        }
+    })
+    .chain(docs);
+
     let mut cursor = state
         .collection()
         .aggregate(
@@ -181,6 +194,11 @@ async fn get_stats_internal(
     let doc = doc.ok_or_else(|| anyhow::anyhow!("missing document"))?;
     let mut stats: Statistics = mongodb::bson::from_document(doc)?;
 
+    // don't even store names if they will never be shown
+    if state.config.stats.anonymise_hosts {
+        return Ok(stats);
+    }
+
     let ids: Vec<u32> = stats
         .hosts
         .iter()
@@ -212,3 +230,169 @@ async fn get_stats_internal(
 
     Ok(stats)
 }
+
+pub async fn load_stats(state: &State, window: StatsWindow) -> Result<Option<GeneratedStatistics>> {
+    let stats = state
+        .stats_collection()
+        .find_one(doc! { "_id": window.as_str() }, None)
+        .await?;
+    Ok(stats)
+}
+
+pub async fn save_stats(state: &State, window: StatsWindow, stats: &GeneratedStatistics) -> Result<()> {
+    state
+        .stats_collection()
+        .replace_one(
+            doc! { "_id": window.as_str() },
+            stats,
+            ReplaceOptions::builder().upsert(true).build(),
+        )
+        .await?;
+    Ok(())
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct StatsJobStatus {
+    pub running: bool,
+    pub last_success: Option<DateTime<Utc>>,
+    pub last_error: Option<StatsJobError>,
+    pub consecutive_failures: u32,
+    pub next_run: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StatsJobError {
+    pub at: DateTime<Utc>,
+    pub message: String,
+}
+
+/// Regenerates each stats window on its own schedule, backing off
+/// exponentially after failures.
+pub struct StatsScheduler {
+    statuses: RwLock<HashMap<StatsWindow, StatsJobStatus>>,
+    triggers: HashMap<StatsWindow, Notify>,
+}
+
+impl StatsScheduler {
+    pub fn new() -> Self {
+        Self {
+            statuses: Default::default(),
+            triggers: StatsWindow::ALL
+                .into_iter()
+                .map(|window| (window, Notify::new()))
+                .collect(),
+        }
+    }
+
+    /// Requests an immediate regeneration of `window`, or of every window if `None`.
+    /// A window that is already being generated will run again once it finishes.
+    pub fn trigger(&self, window: Option<StatsWindow>) {
+        for (w, notify) in &self.triggers {
+            if window.is_none_or(|window| window == *w) {
+                notify.notify_one();
+            }
+        }
+    }
+
+    pub async fn statuses(&self) -> HashMap<StatsWindow, StatsJobStatus> {
+        self.statuses.read().await.clone()
+    }
+
+    async fn update(&self, window: StatsWindow, f: impl FnOnce(&mut StatsJobStatus)) {
+        f(self.statuses.write().await.entry(window).or_default());
+    }
+
+    pub fn spawn(state: Arc<State>) {
+        for window in StatsWindow::ALL {
+            tokio::task::spawn(Self::run(Arc::clone(&state), window));
+        }
+    }
+
+    async fn run(state: Arc<State>, window: StatsWindow) {
+        let config = &state.config.stats;
+        let scheduler = &state.stats_scheduler;
+
+        match load_stats(&state, window).await {
+            Ok(Some(stats)) => {
+                scheduler
+                    .update(window, |status| status.last_success = Some(stats.generated_at))
+                    .await;
+                state.stats.write().await.set(window, stats);
+            }
+            Ok(None) => {}
+            Err(e) => eprintln!("error loading persisted {} stats: {:#?}", window.as_str(), e),
+        }
+
+        loop {
+            let status = scheduler.statuses().await.remove(&window).unwrap_or_default();
+            let delay = if status.consecutive_failures > 0 {
+                let exponent = (status.consecutive_failures - 1).min(16);
+                config.retry_delay().saturating_mul(1 << exponent).min(config.max_retry_delay())
+            } else {
+                // don't recompute stats on every restart if the persisted ones are still fresh
+                let age = status
+                    .last_success
+                    .map(|at| (Utc::now() - at).to_std().unwrap_or_default());
+                match age {
+                    Some(age) => config.interval().saturating_sub(age),
+                    None => Duration::ZERO,
+                }
+            };
+
+            let next_run = TimeDelta::from_std(delay).ok().map(|delay| Utc::now() + delay);
+            scheduler.update(window, |status| status.next_run = next_run).await;
+
+            tokio::select! {
+                _ = tokio::time::sleep(delay) => {}
+                _ = scheduler.triggers[&window].notified() => {}
+            }
+
+            scheduler
+                .update(window, |status| {
+                    status.running = true;
+                    status.next_run = None;
+                })
+                .await;
+
+            let result = match window {
+                StatsWindow::AllTime => get_stats(&state).await,
+                StatsWindow::SevenDays => get_stats_seven_days(&state).await,
+            };
+
+            match result {
+                Ok(stats) => {
+                    let stats = GeneratedStatistics {
+                        stats,
+                        generated_at: Utc::now(),
+                    };
+
+                    if let Err(e) = save_stats(&state, window, &stats).await {
+                        eprintln!("error persisting {} stats: {:#?}", window.as_str(), e);
+                    }
+
+                    scheduler
+                        .update(window, |status| {
+                            status.running = false;
+                            status.last_success = Some(stats.generated_at);
+                            status.consecutive_failures = 0;
+                        })
+                        .await;
+                    state.stats.write().await.set(window, stats);
+                }
+                Err(e) => {
+                    eprintln!("error generating {} stats: {:#?}", window.as_str(), e);
+                    scheduler
+                        .update(window, |status| {
+                            status.running = false;
+                            status.last_error = Some(StatsJobError {
+                                at: Utc::now(),
+                                message: format!("{:#}", e),
+                            });
+                            status.consecutive_failures += 1;
+                        })
+                        .await;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,765 @@
+//! Saved searches that post new matching listings to a webhook, as a Discord
+//! embed.
+//!
+//! Each webhook has its own queue and delivery task, shared by every saved
+//! search posting to it, so a slow or rate-limited webhook doesn't hold up the
+//! others. Posts are spaced out by `min_interval_ms`, and by Discord's rate
+//! limit headers when it sends them.
+//!
+//! A webhook has to accept a test message before a search is saved for it,
+//! and searches expire after `expire_days`.
+
+use crate::api::ListingsQuery;
+use crate::config;
+use crate::ffxiv::{Expansion, LanguageChain, PartySize};
+use crate::listing::{PartyFinderListing, PreparedListing, SearchAreaFlags};
+use crate::sestring_ext::SeStringExt;
+use crate::web::State;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use futures_util::TryStreamExt;
+use hyper::header::{HeaderMap, CONTENT_TYPE, HOST, RETRY_AFTER, USER_AGENT};
+use hyper::{Body, Request, Response, StatusCode, Uri};
+use mongodb::bson::{doc, Document};
+use rand::distributions::{Alphanumeric, DistString};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio::sync::mpsc::{self, error::TrySendError, Receiver, Sender, WeakSender};
+use tokio::time::Instant;
+use tokio_rustls::rustls::{ClientConfig, OwnedTrustAnchor, RootCertStore, ServerName};
+use tokio_rustls::TlsConnector;
+
+const ID_LEN: usize = 16;
+const TOKEN_LEN: usize = 32;
+
+/// How long a single post may take, including connecting.
+const TIMEOUT: Duration = Duration::from_secs(30);
+/// Longest wait a webhook can ask for before a retry.
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(60 * 10);
+/// How often expired searches are removed.
+const EXPIRY_CHECK: Duration = Duration::from_secs(60 * 60);
+
+const TEST_MESSAGE: &str = "This webhook will now receive new listings matching a saved search on Remote Party Finder.";
+
+// discord's limits for embeds
+const MAX_TITLE: usize = 256;
+const MAX_DESCRIPTION: usize = 4096;
+const MAX_FIELD: usize = 1024;
+
+lazy_static::lazy_static! {
+    static ref TLS_CONFIG: Arc<ClientConfig> = {
+        let mut roots = RootCertStore::empty();
+        roots.add_trust_anchors(webpki_roots::TLS_SERVER_ROOTS.iter().map(|anchor| {
+            OwnedTrustAnchor::from_subject_spki_name_constraints(
+                anchor.subject,
+                anchor.spki,
+                anchor.name_constraints,
+            )
+        }));
+
+        Arc::new(
+            ClientConfig::builder()
+                .with_safe_defaults()
+                .with_root_certificates(roots)
+                .with_no_client_auth(),
+        )
+    };
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SavedSearch {
+    #[serde(rename = "_id")]
+    pub id: String,
+    /// Bearer token needed to delete the search.
+    pub token: String,
+    pub url: String,
+    /// `Accept-Language`-style codes the embeds are written in.
+    #[serde(default)]
+    pub lang: Option<String>,
+    pub filters: SavedFilters,
+    /// The address of the client that registered the search, for limiting
+    /// searches per client.
+    #[serde(default)]
+    pub ip: Option<String>,
+    #[serde(with = "mongodb::bson::serde_helpers::chrono_datetime_as_bson_datetime")]
+    pub created_at: DateTime<Utc>,
+    #[serde(with = "mongodb::bson::serde_helpers::chrono_datetime_as_bson_datetime")]
+    pub expires_at: DateTime<Utc>,
+}
+
+/// The version of [`SavedFilters`] that new searches are stored with.
+pub const FILTERS_VERSION: u32 = 1;
+
+/// The filters of a saved search, as stored in the database.
+///
+/// This is kept apart from [`ListingsQuery`] so that changes to the API's
+/// parameters don't break stored searches. Bump [`FILTERS_VERSION`] when a
+/// field changes meaning.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct SavedFilters {
+    pub version: u32,
+    pub category: Option<String>,
+    pub centre: Option<String>,
+    pub roles: Option<u32>,
+    pub jobs: Option<String>,
+    pub duty: Option<String>,
+    pub expansion: Option<Expansion>,
+    pub party_size: Option<PartySize>,
+    pub content_kind: Option<u32>,
+    pub high_end: Option<bool>,
+    pub item_level: Option<u16>,
+    pub q: Option<String>,
+    pub tags: Option<String>,
+    pub prog_point: Option<String>,
+    pub strat: Option<String>,
+}
+
+impl SavedFilters {
+    /// The filters of `query` that apply to single listings.
+    pub fn new(query: &ListingsQuery) -> Self {
+        Self {
+            version: FILTERS_VERSION,
+            category: query.category.clone(),
+            centre: query.centre.clone(),
+            roles: query.roles,
+            jobs: query.jobs.clone(),
+            duty: query.duty.clone(),
+            expansion: query.expansion,
+            party_size: query.party_size,
+            content_kind: query.content_kind,
+            high_end: query.high_end,
+            item_level: query.item_level,
+            q: query.q.clone(),
+            tags: query.tags.clone(),
+            prog_point: query.prog_point.clone(),
+            strat: query.strat.clone(),
+        }
+    }
+
+    pub fn query(&self) -> ListingsQuery {
+        let mut query = ListingsQuery::default();
+        query.category = self.category.clone();
+        query.centre = self.centre.clone();
+        query.roles = self.roles;
+        query.jobs = self.jobs.clone();
+        query.duty = self.duty.clone();
+        query.expansion = self.expansion;
+        query.party_size = self.party_size;
+        query.content_kind = self.content_kind;
+        query.high_end = self.high_end;
+        query.item_level = self.item_level;
+        query.q = self.q.clone();
+        query.tags = self.tags.clone();
+        query.prog_point = self.prog_point.clone();
+        query.strat = self.strat.clone();
+        query
+    }
+}
+
+struct Subscriber {
+    search: SavedSearch,
+    query: ListingsQuery,
+    lang: LanguageChain,
+    /// The queue of the search's webhook, shared with the other searches
+    /// posting to it. `None` when the search can't be delivered, e.g. because
+    /// its host is no longer allowed. It's kept so that it can still be deleted.
+    queue: Option<Sender<Vec<u8>>>,
+}
+
+/// The registered saved searches, each with the queue of its webhook.
+#[derive(Default)]
+pub struct Webhooks {
+    subscribers: RwLock<HashMap<String, Subscriber>>,
+}
+
+impl Webhooks {
+    /// Starts delivering to the saved searches in the database. Searches that
+    /// can't be read are logged and skipped.
+    pub async fn load(state: &Arc<State>) -> Result<()> {
+        let mut cursor = state
+            .webhook_collection()
+            .clone_with_type::<Document>()
+            .find(None, None)
+            .await?;
+        while let Some(document) = cursor.try_next().await? {
+            let id = document.get("_id").cloned();
+            let search = match mongodb::bson::from_document::<SavedSearch>(document) {
+                Ok(search) if search.filters.version <= FILTERS_VERSION => search,
+                Ok(search) => {
+                    eprintln!("skipping saved search {} from a newer version", search.id);
+                    continue;
+                }
+                Err(e) => {
+                    eprintln!("skipping unreadable saved search {:?}: {:#?}", id, e);
+                    continue;
+                }
+            };
+
+            Self::start(state, &mut state.webhooks.subscribers.write().unwrap(), search);
+        }
+
+        tokio::task::spawn(remove_expired(Arc::clone(state)));
+
+        Ok(())
+    }
+
+    /// Saves a search and starts delivering to it. The limits are checked
+    /// again here, as the search is added, so that requests verifying their
+    /// webhooks at the same time can't go past them together. A [`Limit`] is
+    /// returned as the error if one is reached.
+    pub async fn register(
+        state: &Arc<State>,
+        url: String,
+        lang: Option<String>,
+        filters: SavedFilters,
+        ip: Option<String>,
+    ) -> Result<SavedSearch> {
+        let Some(config) = &state.config.webhooks else {
+            anyhow::bail!("webhooks are not enabled");
+        };
+
+        let created_at = Utc::now();
+        let search = SavedSearch {
+            id: Alphanumeric.sample_string(&mut rand::thread_rng(), ID_LEN),
+            token: Alphanumeric.sample_string(&mut rand::thread_rng(), TOKEN_LEN),
+            url,
+            lang,
+            filters,
+            ip,
+            created_at,
+            expires_at: created_at + config.expire_after(),
+        };
+
+        {
+            let mut subscribers = state.webhooks.subscribers.write().unwrap();
+            check_limits(config, &subscribers, &search.url, &search.filters, &search.ip)?;
+            Self::start(state, &mut subscribers, search.clone());
+        }
+
+        if let Err(e) = state.webhook_collection().insert_one(&search, None).await {
+            state.webhooks.subscribers.write().unwrap().remove(&search.id);
+            return Err(e.into());
+        }
+
+        Ok(search)
+    }
+
+    /// Removes a saved search. Once a webhook has no searches left, anything
+    /// still queued for it is dropped.
+    pub async fn remove(state: &State, id: &str) -> Result<()> {
+        state.webhook_collection().delete_one(doc! { "_id": id }, None).await?;
+        state.webhooks.subscribers.write().unwrap().remove(id);
+        Ok(())
+    }
+
+    /// Removes every saved search posting to the webhook `url`.
+    async fn remove_url(state: &State, url: &str) -> Result<()> {
+        state.webhook_collection().delete_many(doc! { "url": url }, None).await?;
+        state
+            .webhooks
+            .subscribers
+            .write()
+            .unwrap()
+            .retain(|_, subscriber| subscriber.search.url != url);
+        Ok(())
+    }
+
+    /// Checks whether a search could be registered, before its webhook is
+    /// verified. [`Webhooks::register`] checks again.
+    pub fn check_limits(
+        &self,
+        config: &config::Webhooks,
+        url: &str,
+        filters: &SavedFilters,
+        ip: &Option<String>,
+    ) -> Result<(), Limit> {
+        check_limits(config, &self.subscribers.read().unwrap(), url, filters, ip)
+    }
+
+    /// The token needed to delete the saved search `id`.
+    pub fn token(&self, id: &str) -> Option<String> {
+        self.subscribers
+            .read()
+            .unwrap()
+            .get(id)
+            .map(|subscriber| subscriber.search.token.clone())
+    }
+
+    fn start(state: &Arc<State>, subscribers: &mut HashMap<String, Subscriber>, search: SavedSearch) {
+        let Some(config) = &state.config.webhooks else {
+            return;
+        };
+
+        // searches for the same webhook share its queue, so that its rate limit is shared too
+        let shared = subscribers
+            .values()
+            .filter(|subscriber| subscriber.search.url == search.url)
+            .find_map(|subscriber| subscriber.queue.clone());
+        let queue = match shared {
+            Some(queue) => Some(queue),
+            None => match validate_url(config, &search.url) {
+                Ok(url) => {
+                    let (queue, receiver) = mpsc::channel(config.queue_size.max(1));
+                    tokio::task::spawn(deliver(Arc::clone(state), url, queue.downgrade(), receiver));
+                    Some(queue)
+                }
+                Err(e) => {
+                    eprintln!("not starting webhook for saved search {}: {}", search.id, e);
+                    None
+                }
+            },
+        };
+
+        let subscriber = Subscriber {
+            query: search.filters.query(),
+            lang: LanguageChain::from_codes(search.lang.as_deref()),
+            search,
+            queue,
+        };
+        subscribers.insert(subscriber.search.id.clone(), subscriber);
+    }
+
+    /// Queues a post for each saved search matching a newly inserted listing.
+    /// Matching happens on a blocking thread, off the contributor's request.
+    pub fn listing_inserted(state: &Arc<State>, listing: &PartyFinderListing) {
+        if listing.search_area.contains(SearchAreaFlags::PRIVATE) {
+            return;
+        }
+        if state.webhooks.subscribers.read().unwrap().is_empty() {
+            return;
+        }
+
+        let state = Arc::clone(state);
+        let listing = listing.clone();
+        tokio::task::spawn_blocking(move || state.webhooks.queue_matching(&state, &listing));
+    }
+
+    fn queue_matching(&self, state: &State, listing: &PartyFinderListing) {
+        // the listing's search text and tags are worked out once, for all searches
+        let prepared = PreparedListing::new(listing);
+        let matching: Vec<(String, LanguageChain, Sender<Vec<u8>>)> = self
+            .subscribers
+            .read()
+            .unwrap()
+            .values()
+            .filter_map(|subscriber| {
+                let queue = subscriber.queue.as_ref()?;
+                subscriber
+                    .query
+                    .matches_prepared(&prepared)
+                    .then(|| (subscriber.search.id.clone(), subscriber.lang.clone(), queue.clone()))
+            })
+            .collect();
+
+        let base_url = state.config.web.base_url.as_deref();
+        for (id, lang, queue) in matching {
+            let payload = DiscordPayload::new(listing, &lang, base_url);
+            let body = match serde_json::to_vec(&payload) {
+                Ok(body) => body,
+                Err(e) => {
+                    eprintln!("could not serialise webhook payload: {:#?}", e);
+                    continue;
+                }
+            };
+
+            if let Err(TrySendError::Full(_)) = queue.try_send(body) {
+                eprintln!("webhook for saved search {} is falling behind, dropping listing {}", id, listing.id);
+            }
+        }
+    }
+}
+
+/// A limit on saved searches that stops one from being registered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Limit {
+    /// The webhook already has a search with the same filters.
+    Duplicate,
+    PerIp,
+    PerUrl,
+    Total,
+}
+
+impl Limit {
+    pub fn message(self) -> &'static str {
+        match self {
+            Self::Duplicate => "this webhook already has a saved search with these filters",
+            Self::PerIp => "too many saved searches from this address",
+            Self::PerUrl => "too many saved searches for this webhook",
+            Self::Total => "too many saved searches, try again later",
+        }
+    }
+}
+
+impl std::fmt::Display for Limit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl std::error::Error for Limit {}
+
+fn check_limits(
+    config: &config::Webhooks,
+    subscribers: &HashMap<String, Subscriber>,
+    url: &str,
+    filters: &SavedFilters,
+    ip: &Option<String>,
+) -> Result<(), Limit> {
+    let count = |filter: &dyn Fn(&SavedSearch) -> bool| {
+        subscribers
+            .values()
+            .filter(|subscriber| filter(&subscriber.search))
+            .count()
+    };
+
+    if count(&|search| search.url == url && search.filters == *filters) > 0 {
+        return Err(Limit::Duplicate);
+    }
+
+    // clients without a known address share one allowance
+    if count(&|search| search.ip == *ip) >= config.max_searches_per_ip {
+        return Err(Limit::PerIp);
+    }
+
+    if count(&|search| search.url == url) >= config.max_searches_per_url {
+        return Err(Limit::PerUrl);
+    }
+
+    if subscribers.len() >= config.max_searches {
+        return Err(Limit::Total);
+    }
+
+    Ok(())
+}
+
+/// Removes saved searches once they expire.
+async fn remove_expired(state: Arc<State>) {
+    loop {
+        let now = Utc::now();
+        let expired: Vec<String> = state
+            .webhooks
+            .subscribers
+            .read()
+            .unwrap()
+            .values()
+            .filter(|subscriber| subscriber.search.expires_at <= now)
+            .map(|subscriber| subscriber.search.id.clone())
+            .collect();
+        for id in expired {
+            if let Err(e) = Webhooks::remove(&state, &id).await {
+                eprintln!("error removing expired saved search: {:#?}", e);
+            }
+        }
+
+        tokio::time::sleep(EXPIRY_CHECK).await;
+    }
+}
+
+/// The address a request came from, for limiting searches per client.
+pub fn client_ip(config: &config::Webhooks, remote: Option<SocketAddr>, forwarded_for: Option<&str>) -> Option<IpAddr> {
+    if config.trust_forwarded_for {
+        // the proxy appends the address it saw, anything before is the client's word
+        return forwarded_for?.rsplit(',').next()?.trim().parse().ok();
+    }
+
+    remote.map(|remote| remote.ip())
+}
+
+/// `url` with its host lowercased, so that the same webhook is always
+/// written the same way.
+pub fn normalise_url(url: &Uri) -> String {
+    let host = url.host().unwrap_or_default().to_ascii_lowercase();
+    let path = url.path_and_query().map(|path| path.as_str()).unwrap_or("/");
+    format!("https://{}{}", host, path)
+}
+
+/// `url` without its last path segment, which holds the token of a Discord
+/// webhook, for logging.
+fn url_label(url: &Uri) -> String {
+    let path = url.path();
+    let path = path.rsplit_once('/').map(|(path, _)| path).unwrap_or(path);
+    format!("{}{}", url.host().unwrap_or_default(), path)
+}
+
+/// Posts a test message to a webhook, which has to succeed before a search
+/// is saved for it.
+pub async fn verify(url: &Uri) -> Result<(), &'static str> {
+    let body = serde_json::to_vec(&serde_json::json!({ "content": TEST_MESSAGE }))
+        .map_err(|_| "could not write the test message")?;
+    match tokio::time::timeout(TIMEOUT, post(url, body)).await {
+        Ok(Ok(response)) => match Outcome::from_status(response.status(), response.headers()) {
+            Outcome::Delivered => Ok(()),
+            _ => Err("the webhook did not accept a test message"),
+        },
+        Ok(Err(e)) => {
+            eprintln!("error verifying webhook: {:#?}", e);
+            Err("could not post a test message to the webhook")
+        }
+        Err(_) => Err("timed out posting a test message to the webhook"),
+    }
+}
+
+/// Checks that `url` is an https url on one of the allowed hosts.
+pub fn validate_url(config: &config::Webhooks, url: &str) -> Result<Uri, &'static str> {
+    let url: Uri = url.parse().map_err(|_| "invalid url")?;
+    if url.scheme_str() != Some("https") {
+        return Err("url must use https");
+    }
+
+    let Some(authority) = url.authority() else {
+        return Err("url must have a host");
+    };
+    if authority.as_str().contains('@') || url.port_u16().is_some_and(|port| port != 443) {
+        return Err("url must not have credentials or a port");
+    }
+
+    let allowed = config
+        .allowed_hosts
+        .iter()
+        .any(|host| host.eq_ignore_ascii_case(authority.host()));
+    if !allowed {
+        return Err("url host is not allowed");
+    }
+
+    Ok(url)
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Delivered,
+    /// Worth trying again, after at least the given delay.
+    Retry(Option<Duration>),
+    /// The webhook was deleted, so the saved search is removed too.
+    Gone,
+    Rejected(StatusCode),
+}
+
+impl Outcome {
+    pub fn from_status(status: StatusCode, headers: &HeaderMap) -> Self {
+        match status {
+            status if status.is_success() => Self::Delivered,
+            StatusCode::NOT_FOUND | StatusCode::UNAUTHORIZED => Self::Gone,
+            StatusCode::TOO_MANY_REQUESTS => Self::Retry(seconds_header(headers, RETRY_AFTER.as_str())),
+            status if status.is_server_error() => Self::Retry(None),
+            status => Self::Rejected(status),
+        }
+    }
+}
+
+/// A header holding a number of seconds, which Discord sends as a decimal.
+pub fn seconds_header(headers: &HeaderMap, name: &str) -> Option<Duration> {
+    let seconds: f64 = headers.get(name)?.to_str().ok()?.trim().parse().ok()?;
+    Duration::try_from_secs_f64(seconds)
+        .ok()
+        .map(|delay| delay.min(MAX_RETRY_AFTER))
+}
+
+/// How long Discord wants us to wait before the next post, once its rate
+/// limit bucket is empty.
+pub fn rate_limit_wait(headers: &HeaderMap) -> Option<Duration> {
+    let remaining = headers.get("x-ratelimit-remaining")?;
+    if remaining != "0" {
+        return None;
+    }
+
+    seconds_header(headers, "x-ratelimit-reset-after")
+}
+
+/// How long to wait before trying a post again after `attempt` failed: as
+/// long as the webhook asked for, or else `retry_delay`, doubled for each
+/// attempt after the first.
+pub fn retry_wait(after: Option<Duration>, retry_delay: Duration, attempt: u32) -> Duration {
+    let backoff = 2u32.saturating_pow(attempt.saturating_sub(1));
+    after
+        .unwrap_or_else(|| retry_delay.saturating_mul(backoff))
+        .min(MAX_RETRY_AFTER)
+}
+
+/// Posts what's queued for the webhook `url` until none of its saved searches
+/// are left. `sender` is the queue's own sender, which can no longer be
+/// upgraded once the last search is removed.
+async fn deliver(state: Arc<State>, url: Uri, sender: WeakSender<Vec<u8>>, mut queue: Receiver<Vec<u8>>) {
+    let Some(config) = &state.config.webhooks else {
+        return;
+    };
+
+    let label = url_label(&url);
+    let mut next_post = Instant::now();
+    while let Some(body) = queue.recv().await {
+        for attempt in 1..=config.max_attempts {
+            tokio::time::sleep_until(next_post).await;
+            if sender.upgrade().is_none() {
+                return;
+            }
+
+            next_post = Instant::now() + config.min_interval();
+            let outcome = match tokio::time::timeout(TIMEOUT, post(&url, body.clone())).await {
+                Ok(Ok(response)) => {
+                    if let Some(wait) = rate_limit_wait(response.headers()) {
+                        next_post = next_post.max(Instant::now() + wait);
+                    }
+
+                    Outcome::from_status(response.status(), response.headers())
+                }
+                Ok(Err(e)) => {
+                    eprintln!("error posting to webhook {}: {:#?}", label, e);
+                    Outcome::Retry(None)
+                }
+                Err(_) => {
+                    eprintln!("timed out posting to webhook {}", label);
+                    Outcome::Retry(None)
+                }
+            };
+
+            match outcome {
+                Outcome::Delivered => break,
+                Outcome::Retry(after) => {
+                    if attempt == config.max_attempts {
+                        eprintln!("giving up on a post to webhook {} after {} attempts", label, attempt);
+                        break;
+                    }
+
+                    next_post = next_post.max(Instant::now() + retry_wait(after, config.retry_delay(), attempt));
+                }
+                Outcome::Gone => {
+                    eprintln!("webhook {} no longer exists, removing its saved searches", label);
+                    if let Err(e) = Webhooks::remove_url(&state, &normalise_url(&url)).await {
+                        eprintln!("error removing saved searches: {:#?}", e);
+                    }
+
+                    return;
+                }
+                Outcome::Rejected(status) => {
+                    eprintln!("webhook {} rejected a post with {}", label, status);
+                    break;
+                }
+            }
+        }
+    }
+}
+
+async fn post(url: &Uri, body: Vec<u8>) -> Result<Response<Body>> {
+    let host = url.host().context("webhook url has no host")?;
+    let server_name = ServerName::try_from(host).context("invalid webhook host")?;
+
+    let tcp = TcpStream::connect((host, url.port_u16().unwrap_or(443)))
+        .await
+        .context("could not connect to webhook")?;
+    let tls = TlsConnector::from(Arc::clone(&TLS_CONFIG))
+        .connect(server_name, tcp)
+        .await
+        .context("could not start tls")?;
+    let (mut sender, connection) = hyper::client::conn::handshake(tls).await?;
+    tokio::task::spawn(async move {
+        if let Err(e) = connection.await {
+            eprintln!("webhook connection error: {:#?}", e);
+        }
+    });
+
+    let path = url.path_and_query().map(|path| path.as_str()).unwrap_or("/");
+    let request = Request::post(path)
+        .header(HOST, host)
+        .header(CONTENT_TYPE, "application/json")
+        .header(USER_AGENT, concat!("remote-party-finder/", env!("CARGO_PKG_VERSION")))
+        .body(Body::from(body))?;
+
+    Ok(sender.send_request(request).await?)
+}
+
+/// A Discord webhook message with one embed for a listing.
+#[derive(Debug, Serialize)]
+pub struct DiscordPayload {
+    pub embeds: Vec<Embed>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Embed {
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    pub timestamp: DateTime<Utc>,
+    pub fields: Vec<EmbedField>,
+    pub footer: EmbedFooter,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EmbedField {
+    pub name: &'static str,
+    pub value: String,
+    pub inline: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EmbedFooter {
+    pub text: String,
+}
+
+impl DiscordPayload {
+    pub fn new(listing: &PartyFinderListing, lang: &LanguageChain, base_url: Option<&str>) -> Self {
+        let description = listing.description.full_text(lang);
+        let field = |name, value: String| EmbedField {
+            name,
+            value: truncate(value.trim(), MAX_FIELD),
+            inline: true,
+        };
+
+        let mut fields = vec![
+            field(
+                "Recruiter",
+                format!("{} @ {}", listing.name.text(), listing.home_world_string()),
+            ),
+            field("Data Centre", listing.data_centre_name().unwrap_or("Unknown").to_string()),
+            field(
+                "Slots",
+                format!("{}/{}", listing.slots_filled(), listing.slots_available),
+            ),
+        ];
+        if listing.min_item_level > 0 {
+            fields.push(field("Min IL", listing.min_item_level.to_string()));
+        }
+
+        Self {
+            embeds: vec![Embed {
+                title: truncate(&listing.duty_name(lang), MAX_TITLE),
+                description: Some(truncate(description.trim(), MAX_DESCRIPTION))
+                    .filter(|description| !description.is_empty()),
+                // listings don't have a page of their own, so this links to their data centre's
+                url: base_url.map(|base_url| listings_url(base_url, listing)),
+                timestamp: Utc::now(),
+                fields,
+                footer: EmbedFooter {
+                    text: listing.pf_category().name().text(lang).to_string(),
+                },
+            }],
+        }
+    }
+}
+
+fn listings_url(base_url: &str, listing: &PartyFinderListing) -> String {
+    let base_url = base_url.trim_end_matches('/');
+    match listing.data_centre_name() {
+        Some(centre) => format!(
+            "{}/listings?{}",
+            base_url,
+            serde_urlencoded::to_string([("centre", centre)]).unwrap_or_default(),
+        ),
+        None => format!("{}/listings", base_url),
+    }
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+
+    let mut truncated: String = text.chars().take(max_chars - 1).collect();
+    truncated.push('…');
+    truncated
+}
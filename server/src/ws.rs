@@ -1,9 +1,16 @@
-use crate::listing::PartyFinderListing;
+use crate::api::ListingsQuery;
+use crate::ffxiv::{Language, LanguageChain};
+use crate::listing::{PartyFinderListing, SearchAreaFlags};
+use crate::listing_container::QueriedListing;
+use crate::template::listings::ListingTemplate;
 use crate::web::State;
+use askama::Template;
 use futures_util::stream::{SplitSink, SplitStream};
 use futures_util::{SinkExt, StreamExt};
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use tokio::sync::broadcast::error::RecvError;
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
 use tokio::task::{AbortHandle, JoinHandle};
 use warp::ws::{Message, WebSocket};
@@ -12,13 +19,22 @@ pub struct WsApiClient {
     state: Arc<State>,
     outbound: UnboundedSender<OutboundApiMessage>,
     listings: Option<LiveHandle>,
+    listing_cards: Option<LiveHandle>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(tag = "type")]
 #[serde(rename_all = "snake_case")]
 enum InboundApiMessage {
-    Subscribe { channel: MessageChannel },
+    Subscribe {
+        channel: MessageChannel,
+        /// For `listing_cards`, the `Accept-Language`-style codes to render in.
+        #[serde(default)]
+        lang: Option<String>,
+        /// For `listing_cards`, the query string the page was loaded with.
+        #[serde(default)]
+        query: String,
+    },
     Unsubscribe { channel: MessageChannel },
 }
 
@@ -29,6 +45,7 @@ enum OutboundApiMessage {
     Subscribed { channel: MessageChannel },
     Unsubscribed { channel: MessageChannel },
     Listings { listings: Arc<[PartyFinderListing]> },
+    ListingCards { cards: Vec<ListingCard> },
     Err { message: String },
 }
 
@@ -36,12 +53,108 @@ enum OutboundApiMessage {
 #[serde(rename_all = "snake_case")]
 enum MessageChannel {
     Listings,
+    /// Listings rendered as they are on the listings page.
+    ListingCards,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+struct ListingCard {
+    id: u32,
+    /// `None` when the card should be removed, e.g. because the listing went
+    /// private or no longer matches the page's filters.
+    html: Option<Arc<str>>,
+}
+
+impl ListingCard {
+    fn new(
+        cache: &CardCache,
+        listings: &Arc<[PartyFinderListing]>,
+        index: usize,
+        lang: &LanguageChain,
+        query: &ListingsQuery,
+    ) -> Self {
+        let listing = &listings[index];
+        let shown = !listing.search_area.contains(SearchAreaFlags::PRIVATE)
+            && listing.seconds_remaining > 0
+            && query.matches(listing);
+
+        Self {
+            id: listing.id,
+            html: shown.then(|| cache.render(listings, index, lang)).flatten(),
+        }
+    }
+}
+
+/// How many batches of contributed listings to keep rendered cards for.
+/// Clients that fall further behind render again.
+const CACHED_BATCHES: usize = 4;
+
+/// Cards rendered for the latest batches of contributed listings, shared by
+/// every client, so that each card is rendered once per language.
+#[derive(Default)]
+pub struct CardCache {
+    batches: Mutex<VecDeque<CachedBatch>>,
+}
+
+struct CachedBatch {
+    listings: Arc<[PartyFinderListing]>,
+    cards: HashMap<(Vec<Language>, usize), Option<Arc<str>>>,
+}
+
+impl CardCache {
+    pub(crate) fn render(&self, listings: &Arc<[PartyFinderListing]>, index: usize, lang: &LanguageChain) -> Option<Arc<str>> {
+        let key = (lang.iter().collect::<Vec<_>>(), index);
+        let cached = self
+            .batches
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|batch| Arc::ptr_eq(&batch.listings, listings))
+            .and_then(|batch| batch.cards.get(&key).cloned());
+        if let Some(html) = cached {
+            return html;
+        }
+
+        // rendered without the lock, so another client may render it too
+        let template = ListingTemplate {
+            container: QueriedListing::contributed(listings[index].clone()),
+            lang: lang.clone(),
+        };
+        let html = match template.render() {
+            Ok(html) => Some(Arc::from(html)),
+            Err(e) => {
+                eprintln!("failed to render listing card: {:#?}", e);
+                None
+            }
+        };
+
+        let mut batches = self.batches.lock().unwrap();
+        let position = batches
+            .iter()
+            .position(|batch| Arc::ptr_eq(&batch.listings, listings));
+        let batch = match position {
+            Some(i) => &mut batches[i],
+            None => {
+                if batches.len() >= CACHED_BATCHES {
+                    batches.pop_front();
+                }
+                batches.push_back(CachedBatch {
+                    listings: Arc::clone(listings),
+                    cards: HashMap::new(),
+                });
+                batches.back_mut().unwrap()
+            }
+        };
+        batch.cards.insert(key, html.clone());
+
+        html
+    }
 }
 
 impl WsApiClient {
     async fn handle(&mut self, msg: InboundApiMessage) {
         match msg {
-            InboundApiMessage::Subscribe { channel } => {
+            InboundApiMessage::Subscribe { channel, lang, query } => {
                 match channel {
                     MessageChannel::Listings => {
                         self.listings = Some(
@@ -52,6 +165,17 @@ impl WsApiClient {
                             .into(),
                         )
                     }
+                    MessageChannel::ListingCards => {
+                        self.listing_cards = Some(
+                            tokio::spawn(Self::listing_cards_task(
+                                self.state.clone(),
+                                self.outbound.clone(),
+                                LanguageChain::from_codes(lang.as_deref()),
+                                ListingsQuery::parse_lenient(&query),
+                            ))
+                            .into(),
+                        )
+                    }
                 };
 
                 // send a message letting the client know they've been subscribed
@@ -64,6 +188,9 @@ impl WsApiClient {
                     MessageChannel::Listings => {
                         self.listings = None; // drops the task.
                     }
+                    MessageChannel::ListingCards => {
+                        self.listing_cards = None;
+                    }
                 }
 
                 // send a message letting the client know they've been unsubscribed
@@ -82,6 +209,7 @@ impl WsApiClient {
             state,
             outbound: outbound_sender,
             listings: None,
+            listing_cards: None,
         };
 
         let send_task = Self::send_task(&mut outbound_receiver, &mut ws_sender);
@@ -137,6 +265,30 @@ impl WsApiClient {
             let _ = sender.send(OutboundApiMessage::Listings { listings });
         }
     }
+
+    async fn listing_cards_task(
+        state: Arc<State>,
+        sender: UnboundedSender<OutboundApiMessage>,
+        lang: LanguageChain,
+        query: ListingsQuery,
+    ) {
+        let mut receiver = state.listings_channel.subscribe();
+
+        loop {
+            let listings = match receiver.recv().await {
+                Ok(listings) => listings,
+                // rendering is slower than forwarding, so skip what was missed
+                // rather than stopping
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => break,
+            };
+
+            let cards = (0..listings.len())
+                .map(|index| ListingCard::new(&state.listing_cards, &listings, index, &lang, &query))
+                .collect();
+            let _ = sender.send(OutboundApiMessage::ListingCards { cards });
+        }
+    }
 }
 
 /// A handle to a tokio task that aborts the task when dropped.